@@ -1,10 +1,10 @@
 use crate::{FunctionVarsBoundAnalysis, LocSpan, LocStrSpan, ReportConfig, VarBoundAnalysis};
 
 use graph::{
-    elem::Elem,
-    nodes::{Concrete, ContextNode},
+    elem::{Elem, Reference},
+    nodes::{Concrete, ContextNode, ContextVarNode},
     range_string::ToRangeString,
-    GraphBackend, Range, RangeEval, SolcRange,
+    GraphBackend, Node, Range, RangeEval, SolcRange,
 };
 use shared::{RangeArena, StorageLocation};
 
@@ -25,6 +25,8 @@ pub struct AnalysisItem {
     pub ctx_conditionals: Vec<(String, Vec<RangePart>)>,
     pub parts: Vec<RangePart>,
     pub unsat: bool,
+    /// Source text of the expression that caused the bound change, if requested
+    pub cause_expr: Option<String>,
 }
 
 #[derive(PartialEq, Eq, Clone, Debug, Hash)]
@@ -187,7 +189,7 @@ impl From<AnalysisItem> for Label<LocStrSpan> {
 
         Label::new(val.loc)
             .with_message(format!(
-                "{}\"{}\"{}{}",
+                "{}\"{}\"{}{}{}",
                 match val.storage {
                     Some(StorageLocation::Memory(..)) => "Memory var ",
                     Some(StorageLocation::Storage(..)) => "Storage var ",
@@ -206,7 +208,10 @@ impl From<AnalysisItem> for Label<LocStrSpan> {
                     " - unsatisfiable range, unreachable".fg(Color::Red)
                 } else {
                     "".fg(Color::Red)
-                }
+                },
+                val.cause_expr
+                    .map(|expr| format!(" (from `{expr}`)"))
+                    .unwrap_or_default()
             ))
             .with_color(color)
             .with_order(order)
@@ -256,6 +261,14 @@ impl ToString for RangePart {
     }
 }
 
+/// The hex form of a concrete address or bytes bound, if the report asks for hex values
+fn hex_bound(report_config: &ReportConfig, bound: &Elem<Concrete>) -> Option<String> {
+    if !report_config.hex_values {
+        return None;
+    }
+    bound.maybe_concrete()?.val.as_display_hex()
+}
+
 /// Creates an Vec<[RangePart]> from a range based on the current [ReportConfig]
 pub fn range_parts(
     analyzer: &impl GraphBackend,
@@ -264,37 +277,47 @@ pub fn range_parts(
     range: &SolcRange,
 ) -> (Vec<RangePart>, bool) {
     let mut parts = vec![];
-    let min = if report_config.eval_bounds {
-        range
-            .evaled_range_min(analyzer, arena)
-            .unwrap()
-            .to_range_string(false, analyzer, arena)
-            .s
-    } else if report_config.simplify_bounds {
-        range
-            .simplified_range_min(analyzer, arena)
-            .unwrap()
-            .to_range_string(false, analyzer, arena)
-            .s
+    let min = if let Some(name) = report_config
+        .preserve_constant_names
+        .then(|| constant_name(range.range_min().into_owned(), false, analyzer, arena))
+        .flatten()
+    {
+        name
     } else {
-        range.range_min().to_range_string(false, analyzer, arena).s
+        let bound = if report_config.eval_bounds {
+            range.evaled_range_min(analyzer, arena).unwrap()
+        } else if report_config.simplify_bounds {
+            range.simplified_range_min(analyzer, arena).unwrap()
+        } else {
+            range.range_min().into_owned()
+        };
+        hex_bound(report_config, &bound)
+            .unwrap_or_else(|| bound.to_range_string(false, analyzer, arena).s)
     };
-    let max = if report_config.eval_bounds {
-        range
-            .evaled_range_max(analyzer, arena)
-            .unwrap()
-            .to_range_string(true, analyzer, arena)
-            .s
-    } else if report_config.simplify_bounds {
-        range
-            .simplified_range_max(analyzer, arena)
-            .unwrap()
-            .to_range_string(true, analyzer, arena)
-            .s
+    let max = if let Some(name) = report_config
+        .preserve_constant_names
+        .then(|| constant_name(range.range_max().into_owned(), true, analyzer, arena))
+        .flatten()
+    {
+        name
     } else {
-        range.range_max().to_range_string(true, analyzer, arena).s
+        let bound = if report_config.eval_bounds {
+            range.evaled_range_max(analyzer, arena).unwrap()
+        } else if report_config.simplify_bounds {
+            range.simplified_range_max(analyzer, arena).unwrap()
+        } else {
+            range.range_max().into_owned()
+        };
+        hex_bound(report_config, &bound)
+            .unwrap_or_else(|| bound.to_range_string(true, analyzer, arena).s)
     };
 
+    // show how a literal was written (i.e. `1 ether`) next to its value
+    let (min, max) = (
+        with_literal_source(min, range.range_min().into_owned(), false, analyzer, arena),
+        with_literal_source(max, range.range_max().into_owned(), true, analyzer, arena),
+    );
+
     if min == max {
         parts.push(RangePart::Equal(min));
     } else {
@@ -323,3 +346,72 @@ pub fn range_parts(
     let unsat = range.unsat(analyzer, arena);
     (parts, unsat)
 }
+
+/// If a range bound folds directly to a contract `constant` (possibly through variables that
+/// were simply assigned it), returns the constant's name
+fn constant_name(
+    bound: Elem<Concrete>,
+    maximize: bool,
+    analyzer: &impl GraphBackend,
+    arena: &mut RangeArena<Elem<Concrete>>,
+) -> Option<String> {
+    let cvar = bound_source_var(bound, maximize, analyzer, arena, |cvar| {
+        cvar.is_contract_constant(analyzer).unwrap_or(false)
+    })?;
+    cvar.display_name(analyzer).ok()
+}
+
+/// Appends the source text of the literal a range bound folds directly to, if it was written
+/// differently than its value is rendered (i.e. `1000000000000000000 (1 ether)`). Hex digits
+/// differing only in case, like an address's checksum, aren't a different spelling
+fn with_literal_source(
+    rendered: String,
+    bound: Elem<Concrete>,
+    maximize: bool,
+    analyzer: &impl GraphBackend,
+    arena: &mut RangeArena<Elem<Concrete>>,
+) -> String {
+    let source_repr = bound_source_var(bound, maximize, analyzer, arena, |cvar| {
+        cvar.underlying(analyzer)
+            .is_ok_and(|underlying| underlying.source_repr.is_some())
+    })
+    // a variable derived from a literal may no longer have the literal's value
+    .filter(|cvar| cvar.is_const(analyzer, arena).unwrap_or(false))
+    .and_then(|cvar| cvar.underlying(analyzer).ok()?.source_repr.clone());
+    match source_repr {
+        Some(repr) if !repr.eq_ignore_ascii_case(&rendered) => format!("{rendered} ({repr})"),
+        _ => rendered,
+    }
+}
+
+/// Follows a range bound through the variables it is simply assigned from until one satisfies
+/// `found`
+fn bound_source_var(
+    mut bound: Elem<Concrete>,
+    maximize: bool,
+    analyzer: &impl GraphBackend,
+    arena: &mut RangeArena<Elem<Concrete>>,
+    found: impl Fn(ContextVarNode) -> bool,
+) -> Option<ContextVarNode> {
+    let mut seen = BTreeSet::new();
+    loop {
+        match bound {
+            Elem::Arena(_) => bound = bound.dearenaize_clone(arena),
+            Elem::Reference(Reference { idx, .. }) => {
+                if !seen.insert(idx) || !matches!(analyzer.node(idx), Node::ContextVar(_)) {
+                    return None;
+                }
+                let cvar = ContextVarNode::from(idx);
+                if found(cvar) {
+                    return Some(cvar);
+                }
+                bound = if maximize {
+                    cvar.range_max(analyzer).ok()??
+                } else {
+                    cvar.range_min(analyzer).ok()??
+                };
+            }
+            _ => return None,
+        }
+    }
+}
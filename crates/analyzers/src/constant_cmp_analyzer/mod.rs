@@ -0,0 +1,65 @@
+use crate::LocStrSpan;
+
+use graph::{nodes::ContextNode, GraphBackend};
+use shared::GraphError;
+
+use std::collections::BTreeMap;
+
+mod report_display;
+
+/// A function with comparisons that always have the same value, i.e. `x >= 0` for an unsigned `x`
+#[derive(Debug, Clone)]
+pub struct ConstantCmpAnalysis {
+    /// The analyzed function's entry context
+    pub ctx: ContextNode,
+    /// The name of the function
+    pub func_name: String,
+    /// The function definition
+    pub func_span: LocStrSpan,
+    /// Each such comparison, with the value it always has
+    pub cmps: Vec<(LocStrSpan, bool)>,
+}
+
+impl<T> ConstantCmpAnalyzer for T where T: GraphBackend + Sized {}
+pub trait ConstantCmpAnalyzer: GraphBackend + Sized {
+    /// Collects the comparisons in the function that `ctx` is the entry context of whose
+    /// operands' ranges decide their value on every path that evaluates them, returning an
+    /// advisory if any
+    fn constant_cmps(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Result<Option<ConstantCmpAnalysis>, GraphError> {
+        let mut ctxs = ctx.family_tree(self)?;
+        ctxs.push(ctx);
+
+        let mut cmps = BTreeMap::default();
+        for c in ctxs {
+            for (loc, val) in c.constant_cmps(self)? {
+                cmps.entry(loc)
+                    .and_modify(|seen: &mut Option<bool>| {
+                        if *seen != val {
+                            *seen = None;
+                        }
+                    })
+                    .or_insert(val);
+            }
+        }
+
+        let cmps = cmps
+            .into_iter()
+            .filter_map(|(loc, val)| Some((LocStrSpan::new(file_mapping, loc), val?)))
+            .collect::<Vec<_>>();
+        if cmps.is_empty() {
+            return Ok(None);
+        }
+
+        let func = ctx.associated_fn(self)?;
+        Ok(Some(ConstantCmpAnalysis {
+            ctx,
+            func_name: func.name(self)?,
+            func_span: LocStrSpan::new(file_mapping, func.underlying(self)?.loc),
+            cmps,
+        }))
+    }
+}
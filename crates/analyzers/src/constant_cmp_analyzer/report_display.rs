@@ -0,0 +1,89 @@
+use crate::{ConstantCmpAnalysis, LocStrSpan, ReportDisplay, ReportKind};
+
+use graph::{elem::Elem, nodes::Concrete, GraphBackend};
+
+use shared::RangeArena;
+
+use ariadne::{Cache, Color, Config, Fmt, Label, Report, Span};
+
+impl ReportDisplay for ConstantCmpAnalysis {
+    fn report_kind(&self) -> ReportKind<'_> {
+        ReportKind::Custom("Advisory", Color::Blue)
+    }
+    fn msg(
+        &self,
+        _analyzer: &impl GraphBackend,
+        _arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> String {
+        format!(
+            "Function `{}` has comparisons that always have the same value",
+            self.func_name
+        )
+    }
+    fn labels(
+        &self,
+        _analyzer: &impl GraphBackend,
+        _arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Vec<Label<LocStrSpan>> {
+        self.cmps
+            .iter()
+            .map(|(span, val)| {
+                let msg = if *val {
+                    "comparison is always true (tautological)"
+                } else {
+                    "comparison is always false (contradictory)"
+                };
+                Label::new(span.clone())
+                    .with_message(msg.fg(Color::Blue))
+                    .with_color(Color::Blue)
+            })
+            .collect()
+    }
+
+    fn reports(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Vec<Report<'_, LocStrSpan>> {
+        let mut report = Report::build(
+            self.report_kind(),
+            self.func_span.source(),
+            self.func_span.start(),
+        )
+        .with_message(self.msg(analyzer, arena))
+        .with_config(
+            Config::default()
+                .with_cross_gap(false)
+                .with_underlines(true)
+                .with_index_type(ariadne::IndexType::Byte)
+                .with_tab_width(4),
+        );
+
+        report.add_labels(self.labels(analyzer, arena));
+        vec![report.finish()]
+    }
+
+    fn print_reports(
+        &self,
+        mut src: &mut impl Cache<String>,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) {
+        let reports = self.reports(analyzer, arena);
+        reports.into_iter().for_each(|report| {
+            report.print(&mut src).unwrap();
+        });
+    }
+
+    fn eprint_reports(
+        &self,
+        mut src: &mut impl Cache<String>,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) {
+        let reports = self.reports(analyzer, arena);
+        reports.into_iter().for_each(|report| {
+            report.eprint(&mut src).unwrap();
+        });
+    }
+}
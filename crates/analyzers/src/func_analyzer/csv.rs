@@ -0,0 +1,69 @@
+use crate::{bounds::range_parts, bounds::RangePart, FunctionVarsBoundAnalysis};
+
+use graph::{elem::Elem, nodes::Concrete, GraphBackend};
+use shared::RangeArena;
+
+use ariadne::Span;
+
+impl FunctionVarsBoundAnalysis {
+    /// Renders the analyses as CSV for importing into a spreadsheet: a header row followed by one
+    /// row per variable per subcontext with its final bounds. The function column is the path of
+    /// the subcontext the bounds hold in, and the definition location is a byte range of the file
+    pub fn to_csv(
+        analyses: &[FunctionVarsBoundAnalysis],
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> String {
+        let mut csv = "function,variable,def_file,def_start,def_end,min,max\n".to_string();
+        analyses
+            .iter()
+            .flat_map(|analysis| analysis.vars_by_ctx.iter())
+            .for_each(|(ctx, analyses)| {
+                let func = ctx.path(analyzer);
+                analyses.iter().for_each(|analysis| {
+                    let final_range = analysis
+                        .bound_changes
+                        .last()
+                        .map(|(_, range)| range)
+                        .or(analysis.var_def.1.as_ref());
+                    let Some(range) = final_range else {
+                        return;
+                    };
+                    let (parts, _unsat) =
+                        range_parts(analyzer, arena, &analysis.report_config, range);
+                    let (min, max) = match parts.first() {
+                        Some(RangePart::Equal(val)) => (val.clone(), val.clone()),
+                        Some(RangePart::Inclusion(min, max)) => (min.clone(), max.clone()),
+                        _ => Default::default(),
+                    };
+                    let span = &analysis.var_def.0;
+                    let row = [
+                        func.clone(),
+                        analysis.var_display_name.clone(),
+                        span.source().to_string(),
+                        span.start().to_string(),
+                        span.end().to_string(),
+                        min,
+                        max,
+                    ];
+                    csv.push_str(
+                        &row.iter()
+                            .map(|field| csv_escape(field))
+                            .collect::<Vec<_>>()
+                            .join(","),
+                    );
+                    csv.push('\n');
+                });
+            });
+        csv
+    }
+}
+
+/// Quotes a CSV field if it contains a delimiter, quote or newline, doubling any quotes
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{bounds::range_parts, bounds::RangePart, FunctionVarsBoundAnalysis, LocStrSpan};
+
+use graph::{elem::Elem, nodes::Concrete, GraphBackend};
+use shared::RangeArena;
+
+use ariadne::Span;
+use std::collections::BTreeMap;
+
+impl FunctionVarsBoundAnalysis {
+    /// Renders the analysis as Markdown: a table of each variable's final bounds per subcontext
+    /// followed by the source lines of the analyzed function. `sources` maps a source path (as
+    /// used in the file mapping) to its contents
+    pub fn to_markdown(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        sources: &BTreeMap<String, String>,
+    ) -> String {
+        let mut md = format!(
+            "## Bounds for function: `function {}`\n",
+            self.ctx.associated_fn_name(analyzer).unwrap()
+        );
+
+        self.vars_by_ctx.iter().for_each(|(ctx, analyses)| {
+            md.push_str(&format!("\n### Subcontext: `{}`", ctx.path(analyzer)));
+            if let Some((_, kind)) = ctx.underlying(analyzer).unwrap().killed {
+                md.push_str(&format!(" ({})", kind.analysis_str()));
+            }
+            ctx.widened_loops(analyzer)
+                .unwrap()
+                .iter()
+                .for_each(|(loop_ctx, info)| {
+                    md.push_str(&format!(
+                        "\n\n> `{}`: {}",
+                        loop_ctx.path(analyzer),
+                        info.analysis_str()
+                    ));
+                });
+            md.push_str("\n\n| variable | definition loc | min | max | exclusions |\n");
+            md.push_str("| --- | --- | --- | --- | --- |\n");
+            analyses.iter().for_each(|analysis| {
+                let final_range = analysis
+                    .bound_changes
+                    .last()
+                    .map(|(_, range)| range)
+                    .or(analysis.var_def.1.as_ref());
+                let Some(range) = final_range else {
+                    return;
+                };
+                let (parts, _unsat) = range_parts(analyzer, arena, &analysis.report_config, range);
+                let (min, max) = match parts.first() {
+                    Some(RangePart::Equal(val)) => (val.clone(), val.clone()),
+                    Some(RangePart::Inclusion(min, max)) => (min.clone(), max.clone()),
+                    _ => Default::default(),
+                };
+                let exclusions = parts
+                    .iter()
+                    .filter_map(|part| match part {
+                        RangePart::Exclusion(excls) => Some(
+                            excls
+                                .iter()
+                                .map(|excl| excl.to_string())
+                                .collect::<Vec<_>>()
+                                .join(", "),
+                        ),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                md.push_str(&format!(
+                    "| `{}` | {} | {} | {} | {} |\n",
+                    md_escape(&analysis.var_display_name),
+                    md_loc(&analysis.var_def.0, sources),
+                    md_escape(&min),
+                    md_escape(&max),
+                    md_escape(&exclusions),
+                ));
+            });
+        });
+
+        if let Some(src) = sources.get(self.ctx_loc.source()) {
+            md.push_str(&format!(
+                "\n```solidity\n{}\n```\n",
+                source_lines(src, self.ctx_loc.start(), self.ctx_loc.end())
+            ));
+        }
+
+        md
+    }
+}
+
+/// Escapes characters that would break a Markdown table cell
+fn md_escape(s: &str) -> String {
+    s.replace('|', "\\|").replace('\n', " ")
+}
+
+/// Renders a span as `path:line:column` (1-indexed), falling back to just the path if the source
+/// is unknown
+fn md_loc(span: &LocStrSpan, sources: &BTreeMap<String, String>) -> String {
+    match sources.get(span.source()) {
+        Some(src) => {
+            let (line, col) = line_col(src, span.start());
+            format!("{}:{line}:{col}", span.source())
+        }
+        None => span.source().to_string(),
+    }
+}
+
+/// Converts a byte offset into a 1-indexed (line, column) pair
+fn line_col(src: &str, offset: usize) -> (usize, usize) {
+    let offset = offset.min(src.len());
+    let before = &src[..offset];
+    let line = before.matches('\n').count() + 1;
+    let col = offset - before.rfind('\n').map(|i| i + 1).unwrap_or(0) + 1;
+    (line, col)
+}
+
+/// Returns the full source lines that the byte range `[start, end)` touches
+fn source_lines(src: &str, start: usize, end: usize) -> &str {
+    let start = src[..start.min(src.len())]
+        .rfind('\n')
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let end = end.min(src.len());
+    let end = src[end..].find('\n').map(|i| end + i).unwrap_or(src.len());
+    &src[start..end]
+}
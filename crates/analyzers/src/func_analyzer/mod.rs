@@ -1,6 +1,6 @@
 use crate::{
-    bounds::range_parts, LocStrSpan, ReportConfig, ReportDisplay, ReportKind, VarBoundAnalysis,
-    VarBoundAnalyzer,
+    bounds::range_parts, LocStrSpan, ReportConfig, ReportDisplay, ReportKind, TmpFilter,
+    VarBoundAnalysis, VarBoundAnalyzer,
 };
 
 use graph::{
@@ -12,11 +12,27 @@ use shared::{RangeArena, Search};
 
 use ariadne::{Color, Config, Fmt, Label, Report, Span};
 use solang_parser::pt::CodeLocation;
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
+mod csv;
+mod markdown;
 mod report_display;
 pub use report_display::*;
 
+/// A killed context under a function and why it was killed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CtxKill {
+    /// The killed context
+    pub ctx: ContextNode,
+    /// The name of the function the context belongs to. Forks of a function (i.e. from an `if`)
+    /// roll up to it, and a kill in a called function is reported at the call site
+    pub func_name: String,
+    /// Where the context was killed
+    pub loc: LocStrSpan,
+    /// Why the context was killed
+    pub kind: KilledKind,
+}
+
 #[derive(Debug, Clone)]
 pub struct FunctionVarsBoundAnalysis {
     /// Entry context location string span
@@ -29,6 +45,8 @@ pub struct FunctionVarsBoundAnalysis {
     pub report_config: ReportConfig,
     /// Mapping of context node (i.e. for the lineage of the entry context) to a vector of bound analyses
     pub vars_by_ctx: BTreeMap<ContextNode, Vec<VarBoundAnalysis>>,
+    /// Every killed context in the lineage of the entry context
+    pub killed_ctxs: Vec<CtxKill>,
 }
 
 impl<'a> FunctionVarsBoundAnalysis {
@@ -39,10 +57,83 @@ impl<'a> FunctionVarsBoundAnalysis {
         CLIFunctionVarsBoundAnalysis::new(file_mapping, self)
     }
 
+    /// Resolves the causing expression of every variable's bound changes, see
+    /// [`VarBoundAnalysis::with_cause_exprs`]
+    pub fn with_cause_exprs(mut self, sources: &HashMap<String, String>) -> Self {
+        self.vars_by_ctx = self
+            .vars_by_ctx
+            .into_iter()
+            .map(|(ctx, analyses)| {
+                let analyses = analyses
+                    .into_iter()
+                    .map(|analysis| analysis.with_cause_exprs(sources))
+                    .collect();
+                (ctx, analyses)
+            })
+            .collect();
+        self
+    }
+
+    /// Overrides [`ReportConfig::eval_bounds`] for the variables named (by display name) in
+    /// `overrides`, see [`VarBoundAnalysis::with_eval_bounds`]
+    pub fn with_eval_overrides(mut self, overrides: &BTreeMap<String, bool>) -> Self {
+        self.vars_by_ctx
+            .values_mut()
+            .flatten()
+            .for_each(|analysis| {
+                if let Some(eval_bounds) = overrides.get(&analysis.var_display_name) {
+                    analysis.report_config.eval_bounds = *eval_bounds;
+                }
+            });
+        self
+    }
+
     fn report_kind(&self) -> ReportKind {
         ReportKind::Custom("Bounds", Color::Cyan)
     }
 
+    /// A label for each killed context naming its function and why it was killed
+    pub fn killed_ctx_labels(&self) -> Vec<Label<LocStrSpan>> {
+        self.killed_ctxs
+            .iter()
+            .map(|kill| {
+                Label::new(kill.loc.clone())
+                    .with_message(format!(
+                        "killed in {}: {}",
+                        kill.func_name,
+                        kill.kind.analysis_str().fg(Color::Red)
+                    ))
+                    .with_color(Color::Red)
+            })
+            .collect()
+    }
+
+    /// A report of every killed context, if any
+    pub fn killed_ctxs_report(&self) -> Option<Report<'_, LocStrSpan>> {
+        if self.killed_ctxs.is_empty() {
+            return None;
+        }
+        let mut report = Report::build(
+            ReportKind::Custom("Killed contexts", Color::Red),
+            self.ctx_loc.source(),
+            self.ctx_loc.start(),
+        )
+        .with_message(format!(
+            "{} killed context(s) in {}",
+            self.killed_ctxs.len(),
+            self.ctx_loc.0
+        ))
+        .with_config(
+            Config::default()
+                .with_cross_gap(false)
+                .with_underlines(true)
+                .with_index_type(ariadne::IndexType::Byte)
+                .with_tab_width(4),
+        );
+        report.add_labels(self.killed_ctx_labels());
+        Some(report.finish())
+    }
+
     pub fn reports_for_forks(
         &self,
         file_mapping: &'a BTreeMap<usize, String>,
@@ -281,6 +372,21 @@ impl<'a> FunctionVarsBoundAnalysis {
                         })
                         .collect::<Vec<_>>(),
                 );
+                labels.extend(
+                    ctx.widened_loops(analyzer)
+                        .unwrap()
+                        .into_iter()
+                        .map(|(loop_ctx, info)| {
+                            Label::new(LocStrSpan::new(
+                                file_mapping,
+                                loop_ctx.underlying(analyzer).unwrap().loc,
+                            ))
+                            .with_message(info.analysis_str().fg(Color::Magenta))
+                            .with_color(Color::Magenta)
+                            .with_order(40)
+                        })
+                        .collect::<Vec<_>>(),
+                );
                 if !self_handled {
                     if let Some(body) = ctx
                         .associated_fn(analyzer)
@@ -333,10 +439,11 @@ pub trait FunctionVarsBoundAnalyzer: VarBoundAnalyzer + Search + AnalyzerBackend
                     return None;
                 }
                 if !report_config.show_reverts
-                    && matches!(
-                        fork.underlying(self).unwrap().killed,
-                        Some((_, KilledKind::Revert))
-                    )
+                    && fork
+                        .underlying(self)
+                        .unwrap()
+                        .killed
+                        .is_some_and(|(_, kind)| kind.is_revert())
                 {
                     return None;
                 }
@@ -352,29 +459,54 @@ pub trait FunctionVarsBoundAnalyzer: VarBoundAnalyzer + Search + AnalyzerBackend
                 );
                 vars.sort_by_key(|a| a.name(self));
                 vars.dedup_by(|a, b| a.name(self) == b.name(self));
-                Some((
-                    *fork,
-                    vars.iter()
-                        .filter_map(|var| {
-                            let is_ret = var.is_return_node_in_any(&parents, self);
-                            if is_ret
-                                | report_config.show_tmps
-                                | (report_config.show_consts && var.is_const(self, arena).unwrap())
-                                | (report_config.show_symbolics && var.is_symbolic(self).unwrap())
-                            {
-                                Some(self.bounds_for_var_in_family_tree(
-                                    arena,
-                                    file_mapping,
-                                    parents.clone(),
-                                    var.name(self).unwrap(),
-                                    report_config,
-                                ))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<Vec<VarBoundAnalysis>>(),
-                ))
+                let mut analyses = vars
+                    .iter()
+                    .filter_map(|var| {
+                        let is_ret = var.is_return_node_in_any(&parents, self);
+                        if is_ret
+                            | (report_config.show_tmps == TmpFilter::All)
+                            | (report_config.show_consts && var.is_const(self, arena).unwrap())
+                            | (report_config.show_symbolics && var.is_symbolic(self).unwrap())
+                        {
+                            self.bounds_for_var_in_family_tree(
+                                arena,
+                                file_mapping,
+                                parents.clone(),
+                                var.name(self).unwrap(),
+                                report_config,
+                            )
+                            .ok()
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<VarBoundAnalysis>>();
+
+                // temporaries aren't versioned across contexts, so each is its own analysis
+                let tmps = parents
+                    .iter()
+                    .flat_map(|parent| parent.tmp_vars(self).values().copied())
+                    .filter(|tmp| match report_config.show_tmps {
+                        TmpFilter::None => false,
+                        TmpFilter::ConditionsOnly => tmp
+                            .tmp_of(self)
+                            .unwrap()
+                            .is_some_and(|tmp_of| tmp_of.op.is_condition()),
+                        TmpFilter::All => true,
+                    })
+                    .collect::<Vec<_>>();
+                analyses.extend(tmps.into_iter().map(|tmp| {
+                    self.bounds_for_var_node(
+                        arena,
+                        &None,
+                        file_mapping,
+                        &tmp.display_name(self).unwrap(),
+                        tmp,
+                        report_config,
+                        false,
+                    )
+                }));
+                Some((*fork, analyses))
             })
             .collect::<BTreeMap<ContextNode, Vec<VarBoundAnalysis>>>();
 
@@ -387,6 +519,18 @@ pub trait FunctionVarsBoundAnalyzer: VarBoundAnalyzer + Search + AnalyzerBackend
                 .map(|(loc, kind)| (LocStrSpan::new(file_mapping, loc), kind)),
             vars_by_ctx: lineage_analyses,
             report_config,
+            killed_ctxs: edges
+                .iter()
+                .filter_map(|fork| {
+                    let (loc, kind) = fork.killed_loc(self).unwrap()?;
+                    Some(CtxKill {
+                        ctx: *fork,
+                        func_name: fork.associated_fn_name(self).unwrap(),
+                        loc: LocStrSpan::new(file_mapping, loc),
+                        kind,
+                    })
+                })
+                .collect(),
         }
     }
 
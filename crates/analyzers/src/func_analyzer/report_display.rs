@@ -80,6 +80,7 @@ impl<'a> ReportDisplay for CLIFunctionVarsBoundAnalysis<'a> {
         }
 
         let mut reports = vec![report.finish()];
+        reports.extend(self.func_var_bound_analysis.killed_ctxs_report());
 
         reports.extend(self.func_var_bound_analysis.reports_for_forks(
             self.file_mapping,
@@ -0,0 +1,86 @@
+use crate::LocStrSpan;
+
+use graph::{
+    nodes::{ContextNode, GuardKind},
+    GraphBackend,
+};
+use shared::GraphError;
+
+use solang_parser::pt::Loc;
+
+use std::collections::BTreeMap;
+
+mod report_display;
+
+/// The `require`s or `assert`s of a function whose condition may fail
+#[derive(Debug, Clone)]
+pub struct FailableGuardAnalysis {
+    /// The analyzed function's entry context
+    pub ctx: ContextNode,
+    /// The name of the function
+    pub func_name: String,
+    /// The function definition
+    pub func_span: LocStrSpan,
+    /// Whether these are `require`s or `assert`s. A failable `assert` is likely a bug, while a
+    /// failable `require` is usually expected input validation
+    pub kind: GuardKind,
+    /// Each failable guard
+    pub guards: Vec<LocStrSpan>,
+}
+
+impl<T> GuardAnalyzer for T where T: GraphBackend + Sized {}
+pub trait GuardAnalyzer: GraphBackend + Sized {
+    /// Collects the `assert`s and `require`s whose conditions are not already known to hold on
+    /// some path through the function that `ctx` is the entry context of. Returns the failable
+    /// `assert`s, if any, followed by the failable `require`s, if any
+    fn failable_guards(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Result<Vec<FailableGuardAnalysis>, GraphError> {
+        let mut ctxs = ctx.family_tree(self)?;
+        ctxs.push(ctx);
+
+        let mut guards = BTreeMap::default();
+        let mut failable_conds = vec![];
+        for c in ctxs {
+            guards.extend(c.guards(self)?);
+            failable_conds.extend(c.failable_conds(self)?);
+        }
+
+        let func = ctx.associated_fn(self)?;
+        let func_name = func.name(self)?;
+        let func_span = LocStrSpan::new(file_mapping, func.underlying(self)?.loc);
+        let analyses = [GuardKind::Assert, GuardKind::Require]
+            .into_iter()
+            .filter_map(|kind| {
+                let failable = guards
+                    .iter()
+                    .filter(|(loc, guard_kind)| {
+                        **guard_kind == kind
+                            && failable_conds.iter().any(|cond| contains(**loc, *cond))
+                    })
+                    .map(|(loc, _)| LocStrSpan::new(file_mapping, *loc))
+                    .collect::<Vec<_>>();
+                (!failable.is_empty()).then(|| FailableGuardAnalysis {
+                    ctx,
+                    func_name: func_name.clone(),
+                    func_span: func_span.clone(),
+                    kind,
+                    guards: failable,
+                })
+            })
+            .collect();
+        Ok(analyses)
+    }
+}
+
+/// Whether `inner` is within `outer` in the same file
+fn contains(outer: Loc, inner: Loc) -> bool {
+    match (outer, inner) {
+        (Loc::File(file, start, end), Loc::File(inner_file, inner_start, inner_end)) => {
+            file == inner_file && start <= inner_start && inner_end <= end
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{FailableGuardAnalysis, LocStrSpan, ReportDisplay, ReportKind};
+
+use graph::{
+    elem::Elem,
+    nodes::{Concrete, GuardKind},
+    GraphBackend,
+};
+
+use shared::RangeArena;
+
+use ariadne::{Cache, Color, Config, Fmt, Label, Report, Span};
+
+impl ReportDisplay for FailableGuardAnalysis {
+    fn report_kind(&self) -> ReportKind<'_> {
+        match self.kind {
+            GuardKind::Assert => ReportKind::Warning,
+            GuardKind::Require => ReportKind::Advice,
+        }
+    }
+    fn msg(
+        &self,
+        _analyzer: &impl GraphBackend,
+        _arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> String {
+        match self.kind {
+            GuardKind::Assert => format!(
+                "Function `{}` has asserts that may fail, which is likely a bug",
+                self.func_name
+            ),
+            GuardKind::Require => format!(
+                "Function `{}` has requires that may fail and revert",
+                self.func_name
+            ),
+        }
+    }
+    fn labels(
+        &self,
+        _analyzer: &impl GraphBackend,
+        _arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Vec<Label<LocStrSpan>> {
+        let (msg, color) = match self.kind {
+            GuardKind::Assert => ("This assertion may not hold", Color::Yellow),
+            GuardKind::Require => ("This requirement may not be met", Color::Blue),
+        };
+        self.guards
+            .iter()
+            .map(|span| {
+                Label::new(span.clone())
+                    .with_message(msg.fg(color))
+                    .with_color(color)
+            })
+            .collect()
+    }
+
+    fn reports(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Vec<Report<'_, LocStrSpan>> {
+        let mut report = Report::build(
+            self.report_kind(),
+            self.func_span.source(),
+            self.func_span.start(),
+        )
+        .with_message(self.msg(analyzer, arena))
+        .with_config(
+            Config::default()
+                .with_cross_gap(false)
+                .with_underlines(true)
+                .with_index_type(ariadne::IndexType::Byte)
+                .with_tab_width(4),
+        );
+
+        report.add_labels(self.labels(analyzer, arena));
+        vec![report.finish()]
+    }
+
+    fn print_reports(
+        &self,
+        mut src: &mut impl Cache<String>,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) {
+        let reports = self.reports(analyzer, arena);
+        reports.into_iter().for_each(|report| {
+            report.print(&mut src).unwrap();
+        });
+    }
+
+    fn eprint_reports(
+        &self,
+        mut src: &mut impl Cache<String>,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) {
+        let reports = self.reports(analyzer, arena);
+        reports.into_iter().for_each(|report| {
+            report.eprint(&mut src).unwrap();
+        });
+    }
+}
@@ -0,0 +1,75 @@
+use crate::LocStrSpan;
+
+use graph::{
+    nodes::{Concrete, ContextNode},
+    GraphBackend,
+};
+use shared::GraphError;
+
+use std::collections::BTreeMap;
+
+mod report_display;
+
+/// A function with array accesses whose index may be out of bounds on some path
+#[derive(Debug, Clone)]
+pub struct IndexBoundsAnalysis {
+    /// The analyzed function's entry context
+    pub ctx: ContextNode,
+    /// The name of the function
+    pub func_name: String,
+    /// The function definition
+    pub func_span: LocStrSpan,
+    /// Each possibly out of bounds access, with the index and array length display names
+    pub accesses: Vec<(LocStrSpan, String, String)>,
+    /// Values for the inputs (keyed by display name) that make the first access it could be
+    /// found for go out of bounds, if any
+    pub counterexample: Option<BTreeMap<String, Concrete>>,
+}
+
+impl<T> IndexBoundsAnalyzer for T where T: GraphBackend + Sized {}
+pub trait IndexBoundsAnalyzer: GraphBackend + Sized {
+    /// Collects the array accesses in the function that `ctx` is the entry context of whose
+    /// index may be greater than or equal to the array length, returning a diagnostic if any
+    fn index_bounds(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Result<Option<IndexBoundsAnalysis>, GraphError> {
+        let mut ctxs = ctx.family_tree(self)?;
+        ctxs.push(ctx);
+
+        let mut accesses = BTreeMap::default();
+        let mut counterexample = None;
+        for c in ctxs {
+            for oob_index in c.oob_indices(self)? {
+                if accesses.contains_key(&oob_index.loc) {
+                    continue;
+                }
+                accesses.insert(
+                    oob_index.loc,
+                    (
+                        LocStrSpan::new(file_mapping, oob_index.loc),
+                        oob_index.index.display_name(self)?,
+                        oob_index.len.display_name(self)?,
+                    ),
+                );
+                if counterexample.is_none() {
+                    counterexample = oob_index.counterexample;
+                }
+            }
+        }
+
+        if accesses.is_empty() {
+            return Ok(None);
+        }
+
+        let func = ctx.associated_fn(self)?;
+        Ok(Some(IndexBoundsAnalysis {
+            ctx,
+            func_name: func.name(self)?,
+            func_span: LocStrSpan::new(file_mapping, func.underlying(self)?.loc),
+            accesses: accesses.into_values().collect(),
+            counterexample,
+        }))
+    }
+}
@@ -2,16 +2,31 @@
 
 pub mod bounds;
 
-use ariadne::{Cache, Label, Report, ReportKind, Span};
+use ariadne::{sources, Cache, Label, Report, ReportKind, Span};
 use graph::{elem::Elem, nodes::Concrete, AnalyzerBackend, GraphBackend};
 use shared::{RangeArena, Search};
 use solang_parser::pt::Loc;
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    io::{self, Write},
+};
 
 mod func_analyzer;
 pub use func_analyzer::*;
 mod var_analyzer;
 pub use var_analyzer::*;
+mod revert_analyzer;
+pub use revert_analyzer::*;
+mod index_analyzer;
+pub use index_analyzer::*;
+mod overflow_analyzer;
+pub use overflow_analyzer::*;
+mod uninit_analyzer;
+pub use uninit_analyzer::*;
+mod guard_analyzer;
+pub use guard_analyzer::*;
+mod constant_cmp_analyzer;
+pub use constant_cmp_analyzer::*;
 
 pub trait ContextAnalyzer:
     AnalyzerBackend + Search + VarBoundAnalyzer + FunctionVarsBoundAnalyzer
@@ -107,11 +122,24 @@ impl Span for LocStrSpan {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+/// Which temporary variables a report shows bounds for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TmpFilter {
+    /// No temporaries
+    #[default]
+    None,
+    /// Only the temporaries that are conditions, i.e. the result of a comparison or logical
+    /// operation, and not intermediate arithmetic results
+    ConditionsOnly,
+    /// Every temporary
+    All,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ReportConfig {
     pub eval_bounds: bool,
     pub simplify_bounds: bool,
-    pub show_tmps: bool,
+    pub show_tmps: TmpFilter,
     pub show_consts: bool,
     pub show_symbolics: bool,
     pub show_initial_bounds: bool,
@@ -119,13 +147,24 @@ pub struct ReportConfig {
     pub show_reverts: bool,
     pub show_unreachables: bool,
     pub show_nonreverts: bool,
+    /// Render bounds that fold to a `constant` by the constant's name instead of its value
+    pub preserve_constant_names: bool,
+    /// Only report a bound change if the evaluated bounds differ in value from the previous ones,
+    /// instead of whenever they differ structurally
+    pub only_meaningful_changes: bool,
+    /// Append the source text of the expression that caused each bound change to its label. The
+    /// text is only available once the analysis has been given the sources (see
+    /// [`VarBoundAnalysis::with_cause_exprs`])
+    pub show_cause_expr: bool,
+    /// Render address and bytes values as `0x` prefixed hex, with addresses checksummed
+    pub hex_values: bool,
 }
 
 impl ReportConfig {
     pub fn new(
         eval_bounds: bool,
         simplify_bounds: bool,
-        show_tmps: bool,
+        show_tmps: TmpFilter,
         show_consts: bool,
         show_symbolics: bool,
         show_initial_bounds: bool,
@@ -133,6 +172,10 @@ impl ReportConfig {
         show_reverts: bool,
         show_unreachables: bool,
         show_nonreverts: bool,
+        preserve_constant_names: bool,
+        only_meaningful_changes: bool,
+        show_cause_expr: bool,
+        hex_values: bool,
     ) -> Self {
         Self {
             eval_bounds,
@@ -145,6 +188,10 @@ impl ReportConfig {
             show_reverts,
             show_unreachables,
             show_nonreverts,
+            preserve_constant_names,
+            only_meaningful_changes,
+            show_cause_expr,
+            hex_values,
         }
     }
 }
@@ -154,7 +201,7 @@ impl Default for ReportConfig {
         Self {
             eval_bounds: true,
             simplify_bounds: false,
-            show_tmps: false,
+            show_tmps: TmpFilter::None,
             show_consts: false,
             show_symbolics: true,
             show_initial_bounds: false,
@@ -162,10 +209,104 @@ impl Default for ReportConfig {
             show_reverts: false,
             show_unreachables: false,
             show_nonreverts: true,
+            preserve_constant_names: false,
+            only_meaningful_changes: false,
+            show_cause_expr: false,
+            hex_values: false,
         }
     }
 }
 
+impl ReportConfig {
+    /// A builder starting from the default config, so only the fields that differ need setting
+    pub fn builder() -> ReportConfigBuilder {
+        ReportConfigBuilder::default()
+    }
+}
+
+/// Builds a [`ReportConfig`] by chaining setters onto the defaults, i.e.
+/// `ReportConfig::builder().show_tmps(TmpFilter::All).show_reverts(true).build()`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReportConfigBuilder {
+    config: ReportConfig,
+}
+
+impl ReportConfigBuilder {
+    pub fn eval_bounds(mut self, eval_bounds: bool) -> Self {
+        self.config.eval_bounds = eval_bounds;
+        self
+    }
+
+    pub fn simplify_bounds(mut self, simplify_bounds: bool) -> Self {
+        self.config.simplify_bounds = simplify_bounds;
+        self
+    }
+
+    pub fn show_tmps(mut self, show_tmps: TmpFilter) -> Self {
+        self.config.show_tmps = show_tmps;
+        self
+    }
+
+    pub fn show_consts(mut self, show_consts: bool) -> Self {
+        self.config.show_consts = show_consts;
+        self
+    }
+
+    pub fn show_symbolics(mut self, show_symbolics: bool) -> Self {
+        self.config.show_symbolics = show_symbolics;
+        self
+    }
+
+    pub fn show_initial_bounds(mut self, show_initial_bounds: bool) -> Self {
+        self.config.show_initial_bounds = show_initial_bounds;
+        self
+    }
+
+    pub fn show_all_lines(mut self, show_all_lines: bool) -> Self {
+        self.config.show_all_lines = show_all_lines;
+        self
+    }
+
+    pub fn show_reverts(mut self, show_reverts: bool) -> Self {
+        self.config.show_reverts = show_reverts;
+        self
+    }
+
+    pub fn show_unreachables(mut self, show_unreachables: bool) -> Self {
+        self.config.show_unreachables = show_unreachables;
+        self
+    }
+
+    pub fn show_nonreverts(mut self, show_nonreverts: bool) -> Self {
+        self.config.show_nonreverts = show_nonreverts;
+        self
+    }
+
+    pub fn preserve_constant_names(mut self, preserve_constant_names: bool) -> Self {
+        self.config.preserve_constant_names = preserve_constant_names;
+        self
+    }
+
+    pub fn only_meaningful_changes(mut self, only_meaningful_changes: bool) -> Self {
+        self.config.only_meaningful_changes = only_meaningful_changes;
+        self
+    }
+
+    pub fn show_cause_expr(mut self, show_cause_expr: bool) -> Self {
+        self.config.show_cause_expr = show_cause_expr;
+        self
+    }
+
+    pub fn hex_values(mut self, hex_values: bool) -> Self {
+        self.config.hex_values = hex_values;
+        self
+    }
+
+    pub fn build(self) -> ReportConfig {
+        self.config
+    }
+}
+
 pub trait ReportDisplay {
     fn report_kind(&self) -> ReportKind;
     fn msg(&self, analyzer: &impl GraphBackend, arena: &mut RangeArena<Elem<Concrete>>) -> String;
@@ -191,4 +332,43 @@ pub trait ReportDisplay {
         analyzer: &impl GraphBackend,
         arena: &mut RangeArena<Elem<Concrete>>,
     );
+    /// Writes the reports to `w`, resolving each label against `srcs`, a map of file number to
+    /// source text. The labels of a report may point into different files
+    fn write_multi_source_reports(
+        &self,
+        w: &mut impl Write,
+        file_mapping: &BTreeMap<usize, String>,
+        srcs: &BTreeMap<usize, String>,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> io::Result<()> {
+        let mut cache = multi_source_cache(file_mapping, srcs);
+        self.reports(analyzer, arena)
+            .iter()
+            .try_for_each(|report| report.write(&mut cache, &mut *w))
+    }
+}
+
+/// Builds a source cache over every file of a multi-file project from a map of file number to
+/// source text, keyed by the file names in `file_mapping` that [`LocStrSpan`]s refer to. Files
+/// missing from `file_mapping` are skipped
+pub fn multi_source_cache(
+    file_mapping: &BTreeMap<usize, String>,
+    srcs: &BTreeMap<usize, String>,
+) -> impl Cache<String> {
+    sources(
+        srcs.iter()
+            .filter_map(|(file_no, src)| Some((file_mapping.get(file_no)?.clone(), src.clone())))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Formats a diagnostic's counterexample as a report note, i.e. `counterexample: a = 1, b = 2`
+pub(crate) fn counterexample_note(counterexample: &BTreeMap<String, Concrete>) -> String {
+    let assignments = counterexample
+        .iter()
+        .map(|(name, val)| format!("{name} = {}", val.as_human_string()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("counterexample: {assignments}")
 }
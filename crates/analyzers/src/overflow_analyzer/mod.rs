@@ -0,0 +1,134 @@
+use crate::LocStrSpan;
+
+use graph::{
+    elem::{Elem, RangeOp},
+    nodes::{Concrete, ContextNode, ContextVarNode},
+    GraphBackend,
+};
+use shared::{GraphError, RangeArena};
+
+use std::collections::BTreeMap;
+
+mod report_display;
+
+/// A function with checked arithmetic whose result may overflow its type on some path
+#[derive(Debug, Clone)]
+pub struct OverflowAnalysis {
+    /// The analyzed function's entry context
+    pub ctx: ContextNode,
+    /// The name of the function
+    pub func_name: String,
+    /// The function definition
+    pub func_span: LocStrSpan,
+    /// Each operation that may overflow, with the result's display name
+    pub overflows: Vec<(LocStrSpan, String)>,
+    /// Values for the inputs (keyed by display name) that make the first operation it could be
+    /// found for overflow, if any
+    pub counterexample: Option<BTreeMap<String, Concrete>>,
+}
+
+impl<T> OverflowAnalyzer for T where T: GraphBackend + Sized {}
+pub trait OverflowAnalyzer: GraphBackend + Sized {
+    /// Collects the checked arithmetic in the function that `ctx` is the entry context of whose
+    /// result may overflow, returning a diagnostic if any
+    fn overflows(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<OverflowAnalysis>, GraphError> {
+        let mut ctxs = ctx.family_tree(self)?;
+        ctxs.push(ctx);
+
+        let mut overflows = BTreeMap::default();
+        let mut counterexample = None;
+        for c in ctxs {
+            for (loc, result) in c.possible_overflows(self)? {
+                if overflows.contains_key(&loc) {
+                    continue;
+                }
+                overflows.insert(
+                    loc,
+                    (
+                        LocStrSpan::new(file_mapping, loc),
+                        result.display_name(self)?,
+                    ),
+                );
+                if counterexample.is_none() {
+                    counterexample = overflow_witness(c, result, self, arena)?;
+                }
+            }
+        }
+
+        if overflows.is_empty() {
+            return Ok(None);
+        }
+
+        let func = ctx.associated_fn(self)?;
+        Ok(Some(OverflowAnalysis {
+            ctx,
+            func_name: func.name(self)?,
+            func_span: LocStrSpan::new(file_mapping, func.underlying(self)?.loc),
+            overflows: overflows.into_values().collect(),
+            counterexample,
+        }))
+    }
+}
+
+/// The inputs that push the result of an operation past its type's bounds. The operation
+/// overflows upwards if applying it to the operand bounds that maximize the result does,
+/// otherwise it is assumed to underflow. The inputs must be ones the context admits, so a value
+/// the path excludes is stepped past (see [`ContextNode::admitted_witness`])
+fn overflow_witness(
+    ctx: ContextNode,
+    result: ContextVarNode,
+    analyzer: &impl GraphBackend,
+    arena: &mut RangeArena<Elem<Concrete>>,
+) -> Result<Option<BTreeMap<String, Concrete>>, GraphError> {
+    let Some(tmp) = result.tmp_of(analyzer)? else {
+        return Ok(None);
+    };
+    let Some(rhs) = tmp.rhs else {
+        return Ok(None);
+    };
+
+    let lhs_max = tmp.lhs.evaled_range_max(analyzer, arena)?;
+    let rhs_extreme = if matches!(tmp.op, RangeOp::Sub(_)) {
+        rhs.evaled_range_min(analyzer, arena)?
+    } else {
+        rhs.evaled_range_max(analyzer, arena)?
+    };
+    let builtin = result.ty(analyzer)?.as_builtin(analyzer).ok();
+    let overflows_at_max = match (
+        lhs_max.and_then(|max| max.maybe_concrete()),
+        rhs_extreme.and_then(|extreme| extreme.maybe_concrete()),
+        builtin.clone(),
+    ) {
+        (Some(l), Some(r), Some(builtin)) => l
+            .val
+            .cast(builtin)
+            .is_some_and(|l| l.overflows_with(tmp.op, &r.val)),
+        _ => false,
+    };
+
+    let candidate = if overflows_at_max {
+        result.witness_max(analyzer, arena)?
+    } else {
+        result.witness_min(analyzer, arena)?
+    };
+    let Some(candidate) = candidate else {
+        return Ok(None);
+    };
+    ctx.admitted_witness(candidate, analyzer, arena, |assignment, analyzer, arena| {
+        let (Some(lhs), Some(rhs), Some(builtin)) = (
+            tmp.lhs.eval_with(assignment, analyzer, arena)?,
+            rhs.eval_with(assignment, analyzer, arena)?,
+            builtin.clone(),
+        ) else {
+            return Ok(true);
+        };
+        Ok(lhs
+            .cast(builtin)
+            .is_some_and(|lhs| lhs.overflows_with(tmp.op, &rhs)))
+    })
+}
@@ -0,0 +1,87 @@
+use crate::{counterexample_note, LocStrSpan, OverflowAnalysis, ReportDisplay, ReportKind};
+
+use graph::{elem::Elem, nodes::Concrete, GraphBackend};
+
+use shared::RangeArena;
+
+use ariadne::{Cache, Color, Config, Fmt, Label, Report, Span};
+
+impl ReportDisplay for OverflowAnalysis {
+    fn report_kind(&self) -> ReportKind<'_> {
+        ReportKind::Custom("Overflow", Color::Yellow)
+    }
+    fn msg(
+        &self,
+        _analyzer: &impl GraphBackend,
+        _arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> String {
+        format!(
+            "Function `{}` has arithmetic that may overflow",
+            self.func_name
+        )
+    }
+    fn labels(
+        &self,
+        _analyzer: &impl GraphBackend,
+        _arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Vec<Label<LocStrSpan>> {
+        self.overflows
+            .iter()
+            .map(|(span, result)| {
+                Label::new(span.clone())
+                    .with_message(format!("\"{result}\" may overflow").fg(Color::Yellow))
+                    .with_color(Color::Yellow)
+            })
+            .collect()
+    }
+
+    fn reports(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Vec<Report<'_, LocStrSpan>> {
+        let mut report = Report::build(
+            self.report_kind(),
+            self.func_span.source(),
+            self.func_span.start(),
+        )
+        .with_message(self.msg(analyzer, arena))
+        .with_config(
+            Config::default()
+                .with_cross_gap(false)
+                .with_underlines(true)
+                .with_index_type(ariadne::IndexType::Byte)
+                .with_tab_width(4),
+        );
+
+        report.add_labels(self.labels(analyzer, arena));
+        if let Some(counterexample) = &self.counterexample {
+            report.set_note(counterexample_note(counterexample));
+        }
+        vec![report.finish()]
+    }
+
+    fn print_reports(
+        &self,
+        mut src: &mut impl Cache<String>,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) {
+        let reports = self.reports(analyzer, arena);
+        reports.into_iter().for_each(|report| {
+            report.print(&mut src).unwrap();
+        });
+    }
+
+    fn eprint_reports(
+        &self,
+        mut src: &mut impl Cache<String>,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) {
+        let reports = self.reports(analyzer, arena);
+        reports.into_iter().for_each(|report| {
+            report.eprint(&mut src).unwrap();
+        });
+    }
+}
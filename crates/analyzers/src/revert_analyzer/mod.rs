@@ -0,0 +1,60 @@
+use crate::LocStrSpan;
+
+use graph::{
+    nodes::{ContextNode, KilledKind},
+    GraphBackend,
+};
+use shared::GraphError;
+
+use std::collections::BTreeMap;
+
+mod report_display;
+
+/// A function for which every path ends in a revert (or unsatisfiable bounds)
+#[derive(Debug, Clone)]
+pub struct AlwaysRevertsAnalysis {
+    /// The analyzed function's entry context
+    pub ctx: ContextNode,
+    /// The name of the function
+    pub func_name: String,
+    /// The function definition
+    pub func_span: LocStrSpan,
+    /// Where each terminal context was killed
+    pub revert_locs: Vec<(LocStrSpan, KilledKind)>,
+}
+
+impl<T> RevertAnalyzer for T where T: GraphBackend + Sized {}
+pub trait RevertAnalyzer: GraphBackend + Sized {
+    /// Checks whether the function that `ctx` is the entry context of always reverts, returning
+    /// a diagnostic if so
+    fn always_reverts(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Result<Option<AlwaysRevertsAnalysis>, GraphError> {
+        if !ctx.always_reverts(self)? {
+            return Ok(None);
+        }
+
+        let func = ctx.associated_fn(self)?;
+        let mut leaves = ctx.all_edges(self)?;
+        if leaves.is_empty() {
+            leaves.push(ctx);
+        }
+        let revert_locs = leaves
+            .iter()
+            .filter_map(|leaf| leaf.killed_loc(self).transpose())
+            .map(|killed| {
+                let (loc, kind) = killed?;
+                Ok((LocStrSpan::new(file_mapping, loc), kind))
+            })
+            .collect::<Result<Vec<_>, GraphError>>()?;
+
+        Ok(Some(AlwaysRevertsAnalysis {
+            ctx,
+            func_name: func.name(self)?,
+            func_span: LocStrSpan::new(file_mapping, func.underlying(self)?.loc),
+            revert_locs,
+        }))
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{AlwaysRevertsAnalysis, LocStrSpan, ReportDisplay, ReportKind};
+
+use graph::{elem::Elem, nodes::Concrete, GraphBackend};
+
+use shared::RangeArena;
+
+use ariadne::{Cache, Color, Config, Fmt, Label, Report, Span};
+
+impl ReportDisplay for AlwaysRevertsAnalysis {
+    fn report_kind(&self) -> ReportKind<'_> {
+        ReportKind::Custom("Always reverts", Color::Red)
+    }
+    fn msg(
+        &self,
+        _analyzer: &impl GraphBackend,
+        _arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> String {
+        format!("Function `{}` always reverts", self.func_name)
+    }
+    fn labels(
+        &self,
+        _analyzer: &impl GraphBackend,
+        _arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Vec<Label<LocStrSpan>> {
+        let mut labels = vec![Label::new(self.func_span.clone())
+            .with_message("No path through this function completes successfully")
+            .with_color(Color::White)
+            .with_priority(-2)
+            .with_order(-2)];
+        labels.extend(
+            self.revert_locs
+                .iter()
+                .filter(|(_, kind)| kind.is_revert())
+                .map(|(span, kind)| {
+                    Label::new(span.clone())
+                        .with_message(kind.analysis_str().fg(Color::Red))
+                        .with_color(Color::Red)
+                        .with_priority(10)
+                }),
+        );
+        labels
+    }
+
+    fn reports(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Vec<Report<'_, LocStrSpan>> {
+        let mut report = Report::build(
+            self.report_kind(),
+            self.func_span.source(),
+            self.func_span.start(),
+        )
+        .with_message(self.msg(analyzer, arena))
+        .with_config(
+            Config::default()
+                .with_cross_gap(false)
+                .with_underlines(true)
+                .with_index_type(ariadne::IndexType::Byte)
+                .with_tab_width(4),
+        );
+
+        report.add_labels(self.labels(analyzer, arena));
+        vec![report.finish()]
+    }
+
+    fn print_reports(
+        &self,
+        mut src: &mut impl Cache<String>,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) {
+        let reports = self.reports(analyzer, arena);
+        reports.into_iter().for_each(|report| {
+            report.print(&mut src).unwrap();
+        });
+    }
+
+    fn eprint_reports(
+        &self,
+        mut src: &mut impl Cache<String>,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) {
+        let reports = self.reports(analyzer, arena);
+        reports.into_iter().for_each(|report| {
+            report.eprint(&mut src).unwrap();
+        });
+    }
+}
@@ -0,0 +1,65 @@
+use crate::LocStrSpan;
+
+use graph::{nodes::ContextNode, GraphBackend};
+use shared::GraphError;
+
+use std::collections::BTreeMap;
+
+mod report_display;
+
+/// A function that reads a local before assigning it on some path
+#[derive(Debug, Clone)]
+pub struct UninitReadAnalysis {
+    /// The analyzed function's entry context
+    pub ctx: ContextNode,
+    /// The name of the function
+    pub func_name: String,
+    /// The function definition
+    pub func_span: LocStrSpan,
+    /// The first read of each such local, with the local's display name and type
+    pub reads: Vec<(LocStrSpan, String, String)>,
+}
+
+impl<T> UninitReadAnalyzer for T where T: GraphBackend + Sized {}
+pub trait UninitReadAnalyzer: GraphBackend + Sized {
+    /// Collects the reads of locals that were declared without a value and not assigned before
+    /// being read on some path in the function that `ctx` is the entry context of, returning an
+    /// advisory if any. Parameters and named returns are never flagged
+    fn uninit_reads(
+        &self,
+        file_mapping: &BTreeMap<usize, String>,
+        ctx: ContextNode,
+    ) -> Result<Option<UninitReadAnalysis>, GraphError> {
+        let mut ctxs = ctx.family_tree(self)?;
+        ctxs.push(ctx);
+
+        let mut reads = BTreeMap::default();
+        for c in ctxs {
+            for (loc, var) in c.uninit_reads(self)? {
+                if reads.contains_key(&loc) {
+                    continue;
+                }
+                reads.insert(
+                    loc,
+                    (
+                        LocStrSpan::new(file_mapping, loc),
+                        var.display_name(self)?,
+                        var.ty(self)?.as_string(self)?,
+                    ),
+                );
+            }
+        }
+
+        if reads.is_empty() {
+            return Ok(None);
+        }
+
+        let func = ctx.associated_fn(self)?;
+        Ok(Some(UninitReadAnalysis {
+            ctx,
+            func_name: func.name(self)?,
+            func_span: LocStrSpan::new(file_mapping, func.underlying(self)?.loc),
+            reads: reads.into_values().collect(),
+        }))
+    }
+}
@@ -0,0 +1,105 @@
+use crate::VarBoundAnalyzer;
+
+use graph::{
+    elem::{Elem, RangeElem},
+    nodes::{Concrete, ContextNode},
+    Range, SolcRange,
+};
+use shared::{GraphError, RangeArena};
+
+use std::cmp::Ordering;
+
+/// How a variable's range changed from one context to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RangeChange {
+    /// The range is the same in both contexts
+    Unchanged,
+    /// The range in the second context is contained in the range in the first
+    Narrowed,
+    /// The range in the second context contains the range in the first
+    Widened,
+    /// The ranges overlap or are disjoint, but neither contains the other
+    Shifted,
+    /// The variable has no range in the first context
+    NewlyConstrained,
+}
+
+/// A variable's range in two contexts and how it changed between them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeDiff {
+    /// The variable's name
+    pub var_name: String,
+    /// The evaluated range in the first context, if the variable had one there
+    pub before: Option<SolcRange>,
+    /// The evaluated range in the second context
+    pub after: SolcRange,
+    /// How the range changed
+    pub change: RangeChange,
+}
+
+impl<T> RangeDiffer for T where T: VarBoundAnalyzer + Sized {}
+pub trait RangeDiffer: VarBoundAnalyzer + Sized {
+    /// Compares the evaluated range of every non-temporary variable visible in `after` against the
+    /// same variable in `before` (i.e. the context before and after a `require` or fork)
+    fn diff_ctx_ranges(
+        &self,
+        before: ContextNode,
+        after: ContextNode,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Vec<RangeDiff>, GraphError> {
+        let mut diffs = vec![];
+        for (name, cvar) in after.vars(self).clone() {
+            if cvar.is_tmp(self)? {
+                continue;
+            }
+            let Some(after_range) = self.final_range(after, &name, arena) else {
+                continue;
+            };
+            let before_range = self.final_range(before, &name, arena);
+            let change = match &before_range {
+                Some(before_range) => range_change(before_range, &after_range, arena),
+                None => RangeChange::NewlyConstrained,
+            };
+            diffs.push(RangeDiff {
+                var_name: name,
+                before: before_range,
+                after: after_range,
+                change,
+            });
+        }
+        Ok(diffs)
+    }
+}
+
+/// Classifies the change between two evaluated ranges. Exclusions count towards narrowing
+/// (gaining exclusions) and widening (losing them)
+fn range_change(
+    before: &SolcRange,
+    after: &SolcRange,
+    arena: &mut RangeArena<Elem<Concrete>>,
+) -> RangeChange {
+    let before_excl = before.range_exclusions();
+    let after_excl = after.range_exclusions();
+    if before.min == after.min && before.max == after.max && before_excl == after_excl {
+        return RangeChange::Unchanged;
+    }
+
+    let (Some(min_ord), Some(max_ord)) = (
+        after.min.range_ord(&before.min, arena),
+        after.max.range_ord(&before.max, arena),
+    ) else {
+        return RangeChange::Shifted;
+    };
+    let gained_excls = before_excl.iter().all(|excl| after_excl.contains(excl));
+    let lost_excls = after_excl.iter().all(|excl| before_excl.contains(excl));
+
+    let narrowed = min_ord != Ordering::Less && max_ord != Ordering::Greater && gained_excls;
+    let widened = min_ord != Ordering::Greater && max_ord != Ordering::Less && lost_excls;
+    match (narrowed, widened) {
+        // equal bounds and the same exclusions in a different order
+        (true, true) => RangeChange::Unchanged,
+        (true, false) => RangeChange::Narrowed,
+        (false, true) => RangeChange::Widened,
+        (false, false) => RangeChange::Shifted,
+    }
+}
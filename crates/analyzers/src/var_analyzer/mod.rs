@@ -1,21 +1,26 @@
 use crate::{
     bounds::{range_parts, AnalysisItem, RangePart},
-    LocStrSpan, ReportConfig,
+    LocSpan, LocStrSpan, ReportConfig,
 };
 
 use graph::{
-    elem::Elem,
+    elem::{Elem, RangeElem},
     nodes::{Concrete, ContextNode, ContextVarNode, KilledKind},
     AnalyzerBackend, GraphBackend, Range, SolcRange,
 };
-use shared::{RangeArena, Search, StorageLocation};
+use shared::{GraphError, RangeArena, Search, StorageLocation};
 
 use std::collections::BTreeSet;
 
+use ariadne::Span;
 use solang_parser::pt::CodeLocation;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
+mod diff;
+mod ordering;
 mod report_display;
+pub use diff::*;
+pub use ordering::*;
 
 #[derive(PartialOrd, Eq, PartialEq, Ord, Clone, Debug)]
 pub struct CtxSwitch {
@@ -47,6 +52,8 @@ pub struct VarBoundAnalysis {
     pub spanned_ctx_info: BTreeSet<CtxSwitch>,
     /// Location where context was killed
     pub ctx_killed: Option<(LocStrSpan, KilledKind)>,
+    /// Source text of the expression behind each bound change, see [`Self::with_cause_exprs`]
+    pub cause_exprs: BTreeMap<LocStrSpan, String>,
 }
 
 impl Default for VarBoundAnalysis {
@@ -62,11 +69,34 @@ impl Default for VarBoundAnalysis {
             storage: None,
             ctx_killed: None,
             spanned_ctx_info: Default::default(),
+            cause_exprs: Default::default(),
         }
     }
 }
 
 impl VarBoundAnalysis {
+    /// Resolves the source text of the expression behind each bound change if
+    /// [`ReportConfig::show_cause_expr`] is set. `sources` maps a source path (as used in
+    /// [`LocStrSpan`]) to the file's contents
+    pub fn with_cause_exprs(mut self, sources: &HashMap<String, String>) -> Self {
+        if !self.report_config.show_cause_expr {
+            return self;
+        }
+        self.cause_exprs = self
+            .bound_changes
+            .iter()
+            .filter_map(|(span, _)| Some((span.clone(), source_snippet(sources, span)?)))
+            .collect();
+        self
+    }
+
+    /// Overrides [`ReportConfig::eval_bounds`] for this variable only, i.e. to render its bounds
+    /// as formulas of its dependencies while the rest of the report is evaluated
+    pub fn with_eval_bounds(mut self, eval_bounds: bool) -> Self {
+        self.report_config.eval_bounds = eval_bounds;
+        self
+    }
+
     pub fn conditionals(
         &self,
         analyzer: &impl GraphBackend,
@@ -111,6 +141,7 @@ impl VarBoundAnalysis {
                 ctx_conditionals: self.conditionals(analyzer, arena),
                 parts,
                 unsat,
+                cause_expr: None,
             })
         }
     }
@@ -119,7 +150,8 @@ impl VarBoundAnalysis {
 impl<T> VarBoundAnalyzer for T where T: Search + AnalyzerBackend + Sized {}
 pub trait VarBoundAnalyzer: Search + AnalyzerBackend + Sized {
     /// Given a lineage of a context (first element being the youngest, last element being the oldest),
-    /// generate a bound analysis for a variable throughout the lineage
+    /// generate a bound analysis for a variable throughout the lineage. Errors with
+    /// [`GraphError::UnknownVariable`] if no context in the lineage has a variable with the name
     fn bounds_for_var_in_family_tree(
         &self,
         arena: &mut RangeArena<Elem<Concrete>>,
@@ -127,7 +159,7 @@ pub trait VarBoundAnalyzer: Search + AnalyzerBackend + Sized {
         ordered_ctxs: Vec<ContextNode>,
         var_name: String,
         report_config: ReportConfig,
-    ) -> VarBoundAnalysis {
+    ) -> Result<VarBoundAnalysis, GraphError> {
         let mut inherited = None;
         ordered_ctxs
             .into_iter()
@@ -144,7 +176,50 @@ pub trait VarBoundAnalyzer: Search + AnalyzerBackend + Sized {
                 );
                 inherited = Some(analysis);
             });
-        inherited.unwrap_or_default()
+        inherited.ok_or_else(|| {
+            GraphError::UnknownVariable(format!("No variable in context with name: {var_name}"))
+        })
+    }
+
+    /// Returns the evaluated range of the latest version of a variable in a context, without
+    /// building a [`VarBoundAnalysis`]. Returns `None` if no variable with the name exists or it
+    /// has no range
+    fn final_range(
+        &self,
+        ctx: ContextNode,
+        var_name: &str,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Option<SolcRange> {
+        let cvar = ctx
+            .var_by_name_or_recurse(self, var_name)
+            .ok()??
+            .latest_version_or_inherited_in_ctx(ctx, self);
+        let range = cvar.ref_range(self).ok()??;
+        Some(SolcRange::new(
+            range.evaled_range_min(self, arena).ok()?,
+            range.evaled_range_max(self, arena).ok()?,
+            range.range_exclusions(),
+        ))
+    }
+
+    /// Returns every version of a variable, from its first to its latest, with the version's
+    /// index, location and range. Unlike [`VarBoundAnalysis::bound_changes`], versions whose
+    /// range didn't change are included
+    fn range_history(
+        &self,
+        cvar: ContextVarNode,
+    ) -> Result<Vec<(usize, LocSpan, Option<SolcRange>)>, GraphError> {
+        let mut history = vec![];
+        let mut curr = Some(cvar.first_version(self));
+        while let Some(version) = curr {
+            history.push((
+                history.len(),
+                LocSpan(version.loc(self)?),
+                version.range(self)?,
+            ));
+            curr = version.next_version(self);
+        }
+        Ok(history)
     }
 
     /// Analyzes the bounds for a variable up to the provided node
@@ -255,9 +330,12 @@ pub trait VarBoundAnalyzer: Search + AnalyzerBackend + Sized {
 
                     // check if there was a bound change
                     if report_config.show_all_lines
-                        || nr_min != cr_min
-                        || nr_max != cr_max
-                        || nr_excl != &cr_excl
+                        || bounds_changed(
+                            &report_config,
+                            (&cr_min, &cr_max, &cr_excl),
+                            (&nr_min, &nr_max, nr_excl),
+                            arena,
+                        )
                     {
                         cr_min = nr_min;
                         cr_max = nr_max;
@@ -281,9 +359,12 @@ pub trait VarBoundAnalyzer: Search + AnalyzerBackend + Sized {
 
                     // check if there was a bound change
                     if report_config.show_all_lines
-                        || nr_min != cr_min
-                        || nr_max != cr_max
-                        || nr_excl != &cr_excl
+                        || bounds_changed(
+                            &report_config,
+                            (&cr_min, &cr_max, &cr_excl),
+                            (&nr_min, &nr_max, nr_excl),
+                            arena,
+                        )
                     {
                         cr_min = nr_min;
                         cr_max = nr_max;
@@ -306,6 +387,52 @@ pub trait VarBoundAnalyzer: Search + AnalyzerBackend + Sized {
             }
         }
 
+        ba.bound_changes = collapse_same_loc(std::mem::take(&mut ba.bound_changes));
         ba
     }
 }
+
+/// Collapses consecutive bound changes at the same location (i.e. a loop advancing a variable
+/// several times in one statement) into the last, most constrained, one
+fn collapse_same_loc(changes: Vec<(LocStrSpan, SolcRange)>) -> Vec<(LocStrSpan, SolcRange)> {
+    let mut collapsed: Vec<(LocStrSpan, SolcRange)> = Vec::with_capacity(changes.len());
+    for (span, range) in changes {
+        match collapsed.last_mut() {
+            Some((last_span, last_range)) if *last_span == span => *last_range = range,
+            _ => collapsed.push((span, range)),
+        }
+    }
+    collapsed
+}
+
+/// Whether a variable's evaluated bounds changed between two versions. Bounds are compared
+/// structurally, unless [`ReportConfig::only_meaningful_changes`] is set, in which case bounds
+/// that evaluate to the same value (i.e. the same bytes with different padding) are unchanged
+fn bounds_changed(
+    report_config: &ReportConfig,
+    (prev_min, prev_max, prev_excl): (&Elem<Concrete>, &Elem<Concrete>, &[Elem<Concrete>]),
+    (next_min, next_max, next_excl): (&Elem<Concrete>, &Elem<Concrete>, &[Elem<Concrete>]),
+    arena: &mut RangeArena<Elem<Concrete>>,
+) -> bool {
+    if prev_excl != next_excl {
+        return true;
+    }
+    if !report_config.only_meaningful_changes {
+        return next_min != prev_min || next_max != prev_max;
+    }
+    // dynamic elems only compare the entries the shorter one has, so check both directions
+    let same = |a: &Elem<Concrete>, b: &Elem<Concrete>, arena: &mut _| {
+        a == b
+            || a.range_ord(b, arena) == Some(std::cmp::Ordering::Equal)
+            || b.range_ord(a, arena) == Some(std::cmp::Ordering::Equal)
+    };
+    !same(next_min, prev_min, arena) || !same(next_max, prev_max, arena)
+}
+
+/// The source text a span covers, with whitespace collapsed so multiline statements fit a label
+fn source_snippet(sources: &HashMap<String, String>, span: &LocStrSpan) -> Option<String> {
+    let src = sources.get(span.source())?;
+    let snippet = src.get(span.start()..span.end())?;
+    let snippet = snippet.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!snippet.is_empty()).then_some(snippet)
+}
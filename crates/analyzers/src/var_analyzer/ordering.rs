@@ -0,0 +1,111 @@
+use crate::VarBoundAnalyzer;
+
+use graph::{
+    elem::{Elem, RangeElem, RangeOp},
+    nodes::{Concrete, ContextNode, ContextVarNode},
+    GraphBackend, Range,
+};
+use shared::{GraphError, RangeArena};
+
+use std::cmp::Ordering;
+
+impl<T> OrderedPairs for T where T: VarBoundAnalyzer + Sized {}
+pub trait OrderedPairs: VarBoundAnalyzer + Sized {
+    /// Returns every pair of integer variables in the context whose ordering is provable from
+    /// their ranges. `(a, Ordering::Less, b)` means `a <= b` always holds, `Greater` means
+    /// `a >= b` and `Equal` means `a == b`. Orderings are derived either from concrete bounds
+    /// (i.e. `a`'s max is at most `b`'s min) or from symbolic bounds set by requires and
+    /// assignments (i.e. `require(a <= b)` bounds `a`'s max by `b`)
+    fn ordered_pairs(
+        &self,
+        ctx: ContextNode,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Vec<(ContextVarNode, Ordering, ContextVarNode)>, GraphError> {
+        let mut vars = vec![];
+        for (_name, cvar) in ctx.vars(self).clone() {
+            if cvar.first_version(self).is_tmp(self)? {
+                continue;
+            }
+            let cvar = cvar.latest_version_or_inherited_in_ctx(ctx, self);
+            let is_num = cvar
+                .ty(self)?
+                .as_builtin(self)
+                .is_ok_and(|builtin| builtin.num_size().is_some());
+            if is_num && cvar.ref_range(self)?.is_some() {
+                vars.push(cvar);
+            }
+        }
+
+        let mut pairs = vec![];
+        for (i, a) in vars.iter().enumerate() {
+            for b in vars.iter().skip(i + 1) {
+                if let Some(ord) = provable_order(*a, *b, self, arena)? {
+                    pairs.push((*a, ord, *b));
+                }
+            }
+        }
+        Ok(pairs)
+    }
+}
+
+fn provable_order(
+    a: ContextVarNode,
+    b: ContextVarNode,
+    analyzer: &impl GraphBackend,
+    arena: &mut RangeArena<Elem<Concrete>>,
+) -> Result<Option<Ordering>, GraphError> {
+    let (Some(a_range), Some(b_range)) = (a.ref_range(analyzer)?, b.ref_range(analyzer)?) else {
+        return Ok(None);
+    };
+    let a_min = a_range.evaled_range_min(analyzer, arena)?;
+    let a_max = a_range.evaled_range_max(analyzer, arena)?;
+    let b_min = b_range.evaled_range_min(analyzer, arena)?;
+    let b_max = b_range.evaled_range_max(analyzer, arena)?;
+
+    let at_most = |x: &Elem<Concrete>, y: &Elem<Concrete>, arena: &mut _| {
+        matches!(
+            x.range_ord(y, arena),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    };
+    let lte = at_most(&a_max, &b_min, arena)
+        || bounded_by(&a_range.max, b, RangeOp::Min, analyzer, arena)?
+        || bounded_by(&b_range.min, a, RangeOp::Max, analyzer, arena)?;
+    let gte = at_most(&b_max, &a_min, arena)
+        || bounded_by(&b_range.max, a, RangeOp::Min, analyzer, arena)?
+        || bounded_by(&a_range.min, b, RangeOp::Max, analyzer, arena)?;
+
+    Ok(match (lte, gte) {
+        (true, true) => Some(Ordering::Equal),
+        (true, false) => Some(Ordering::Less),
+        (false, true) => Some(Ordering::Greater),
+        (false, false) => None,
+    })
+}
+
+/// Whether a bound is a version of `var` that `var` still holds the value of, or a `min`/`max`
+/// (per `op`) that includes it. A maximum of `min(var, ..)` is at most `var`, and a minimum of
+/// `max(var, ..)` is at least `var`
+fn bounded_by(
+    bound: &Elem<Concrete>,
+    var: ContextVarNode,
+    op: RangeOp,
+    analyzer: &impl GraphBackend,
+    arena: &mut RangeArena<Elem<Concrete>>,
+) -> Result<bool, GraphError> {
+    match bound {
+        Elem::Reference(reference) => {
+            Ok(var.holds_value_of(ContextVarNode::from(reference.idx), analyzer))
+        }
+        Elem::Expr(expr) if expr.op == op => Ok(bounded_by(&expr.lhs, var, op, analyzer, arena)?
+            || bounded_by(&expr.rhs, var, op, analyzer, arena)?),
+        Elem::Arena(_) => {
+            let bound = bound.dearenaize_clone(arena);
+            if matches!(bound, Elem::Arena(_)) {
+                return Ok(false);
+            }
+            bounded_by(&bound, var, op, analyzer, arena)
+        }
+        _ => Ok(false),
+    }
+}
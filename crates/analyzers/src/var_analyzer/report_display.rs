@@ -51,6 +51,11 @@ impl ReportDisplay for VarBoundAnalysis {
                         ctx_conditionals: self.conditionals(analyzer, arena),
                         parts,
                         unsat,
+                        cause_expr: self
+                            .report_config
+                            .show_cause_expr
+                            .then(|| self.cause_exprs.get(&bound_change.0).cloned())
+                            .flatten(),
                     }
                     .into()
                 })
@@ -1,15 +1,19 @@
-use analyzers::{FunctionVarsBoundAnalyzer, ReportConfig, ReportDisplay};
+use analyzers::{
+    ConstantCmpAnalyzer, FunctionVarsBoundAnalyzer, GuardAnalyzer, IndexBoundsAnalyzer,
+    OverflowAnalyzer, ReportConfig, ReportDisplay, RevertAnalyzer, TmpFilter, UninitReadAnalyzer,
+};
 use graph::{
-    nodes::{ContractNode, FunctionNode},
+    elem::Elem,
+    nodes::{Concrete, ContextNode, ContractNode, FunctionNode},
     solvers::{AtomicSolveStatus, BruteBinSearchSolver, SolcSolver},
     Edge,
 };
 use pyrometer::{Analyzer, Root, SourcePath};
 use reqwest::Client;
-use shared::{post_to_site, Search};
+use shared::{post_to_site, RangeArena, Search};
 use shared::{GraphDot, USE_DEBUG_SITE};
 
-use ariadne::sources;
+use ariadne::{sources, Cache};
 use clap::{ArgAction, Parser, ValueHint};
 
 use tracing::{error, trace};
@@ -78,6 +82,18 @@ struct Args {
     /// Whether to show initial values in the bounds analysis output
     #[clap(long)]
     pub show_inits: Option<bool>,
+    /// Whether to render bounds that fold to a `constant` by the constant's name instead of its value
+    #[clap(long)]
+    pub preserve_constant_names: Option<bool>,
+    /// Whether to only show bound changes whose evaluated bounds differ in value from the previous ones
+    #[clap(long)]
+    pub only_meaningful_changes: Option<bool>,
+    /// Whether to append the source text of the expression that caused each bound change to its label
+    #[clap(long)]
+    pub show_cause_expr: Option<bool>,
+    /// Whether to render address and bytes values as hex, with addresses checksummed
+    #[clap(long)]
+    pub hex_values: Option<bool>,
     /// Show reverting paths
     #[clap(long)]
     pub show_reverts: Option<bool>,
@@ -87,6 +103,29 @@ struct Args {
     /// Show non-revert paths
     #[clap(long)]
     pub show_nonreverts: Option<bool>,
+    /// Report functions whose every path reverts
+    #[clap(long)]
+    pub show_always_reverts: bool,
+    /// Report array accesses whose index may be out of bounds
+    #[clap(long)]
+    pub show_index_bounds: bool,
+    /// Report locals read before they are assigned on some path
+    #[clap(long)]
+    pub show_uninit_reads: bool,
+    /// Report comparisons whose operands' ranges make them always true or always false
+    #[clap(long)]
+    pub show_constant_cmps: bool,
+    /// Report `assert`s and `require`s that may fail. Failable asserts are reported as warnings
+    /// since they are likely bugs, failable requires as advice since they usually validate input
+    #[clap(long)]
+    pub show_failable_guards: bool,
+    /// Report checked arithmetic that may overflow, with inputs that make it overflow where they
+    /// can be found
+    #[clap(long)]
+    pub show_overflows: bool,
+    /// Show the cyclomatic complexity of each analyzed function, for prioritizing review
+    #[clap(long)]
+    pub show_complexity: bool,
     /// A debugging command to prevent bound analysis printing. Useful for debugging parse errors during development. Only prints out parse errors
     /// then ends the program
     #[clap(long)]
@@ -100,10 +139,38 @@ struct Args {
     #[clap(long, default_value = "200")]
     pub max_stack_depth: usize,
 
+    /// Track linear equalities between variables (i.e. `a + b == 100`) so narrowing one narrows
+    /// the others. More precise, but slower
+    #[clap(long)]
+    pub track_relations: bool,
+
+    /// Max nesting depth of struct fields to assign field by field
+    #[clap(long, default_value = "32")]
+    pub max_struct_depth: usize,
+
+    /// Max number of times a recursive function is inlined into its own call stack. Deeper calls
+    /// return the full range of their return types
+    #[clap(long, default_value = "4")]
+    pub max_recursion_depth: usize,
+
+    /// Max number of nodes analyzing a single function may add to the graph. Once it adds more,
+    /// the function is aborted with a diagnostic rather than running out of memory
+    #[clap(long)]
+    pub max_nodes: Option<usize>,
+
+    /// Error on implicit integer narrowing that could lose information (i.e. a `uint256`
+    /// assigned to a `uint128`) rather than silently clamping it
+    #[clap(long)]
+    pub strict_casts: bool,
+
     /// Print stats about the IR
     #[clap(long)]
     pub stats: bool,
 
+    /// Print how long analyzing each function took, slowest first
+    #[clap(long)]
+    pub time_functions: bool,
+
     /// Post pyrometer debugging information to debugging site
     #[clap(long)]
     pub debug_site: bool,
@@ -139,118 +206,46 @@ fn main() {
     tree_subscriber();
     let args = Args::parse();
     let verbosity = args.verbosity;
-    let config = match verbosity {
-        0 => ReportConfig {
-            eval_bounds: args.eval.unwrap_or(true),
-            simplify_bounds: args.simplify.unwrap_or(false),
-            show_tmps: false,
-            show_consts: false,
-            show_symbolics: false,
-            show_initial_bounds: args.show_inits.unwrap_or(false),
-            show_all_lines: false,
-            show_reverts: args.show_reverts.unwrap_or(false),
-            show_unreachables: args.show_unreachables.unwrap_or(false),
-            show_nonreverts: args.show_nonreverts.unwrap_or(true),
-        },
-        1 => ReportConfig {
-            eval_bounds: args.eval.unwrap_or(true),
-            simplify_bounds: args.simplify.unwrap_or(false),
-            show_tmps: false,
-            show_consts: false,
-            show_symbolics: true,
-            show_initial_bounds: args.show_inits.unwrap_or(false),
-            show_all_lines: false,
-            show_reverts: args.show_reverts.unwrap_or(false),
-            show_unreachables: args.show_unreachables.unwrap_or(false),
-            show_nonreverts: args.show_nonreverts.unwrap_or(true),
-        },
-        2 => ReportConfig {
-            eval_bounds: args.eval.unwrap_or(true),
-            simplify_bounds: args.simplify.unwrap_or(false),
-            show_tmps: true,
-            show_consts: false,
-            show_symbolics: true,
-            show_initial_bounds: args.show_inits.unwrap_or(false),
-            show_all_lines: false,
-            show_reverts: args.show_reverts.unwrap_or(false),
-            show_unreachables: args.show_unreachables.unwrap_or(false),
-            show_nonreverts: args.show_nonreverts.unwrap_or(true),
-        },
-        3 => ReportConfig {
-            eval_bounds: args.eval.unwrap_or(true),
-            simplify_bounds: args.simplify.unwrap_or(false),
-            show_tmps: true,
-            show_consts: false,
-            show_symbolics: true,
-            show_initial_bounds: args.show_inits.unwrap_or(true),
-            show_all_lines: false,
-            show_reverts: args.show_reverts.unwrap_or(false),
-            show_unreachables: args.show_unreachables.unwrap_or(false),
-            show_nonreverts: args.show_nonreverts.unwrap_or(true),
-        },
-        4 => ReportConfig {
-            eval_bounds: args.eval.unwrap_or(true),
-            simplify_bounds: args.simplify.unwrap_or(false),
-            show_tmps: true,
-            show_consts: true,
-            show_symbolics: true,
-            show_initial_bounds: args.show_inits.unwrap_or(true),
-            show_all_lines: false,
-            show_reverts: args.show_reverts.unwrap_or(false),
-            show_unreachables: args.show_unreachables.unwrap_or(false),
-            show_nonreverts: args.show_nonreverts.unwrap_or(true),
-        },
-        5 => ReportConfig {
-            eval_bounds: args.eval.unwrap_or(true),
-            simplify_bounds: args.simplify.unwrap_or(false),
-            show_tmps: true,
-            show_consts: true,
-            show_symbolics: true,
-            show_initial_bounds: args.show_inits.unwrap_or(true),
-            show_all_lines: false,
-            show_reverts: args.show_reverts.unwrap_or(true),
-            show_unreachables: args.show_unreachables.unwrap_or(false),
-            show_nonreverts: args.show_nonreverts.unwrap_or(true),
-        },
-        6 => ReportConfig {
-            eval_bounds: args.eval.unwrap_or(true),
-            simplify_bounds: args.simplify.unwrap_or(false),
-            show_tmps: true,
-            show_consts: true,
-            show_symbolics: true,
-            show_initial_bounds: args.show_inits.unwrap_or(true),
-            show_all_lines: false,
-            show_reverts: args.show_reverts.unwrap_or(true),
-            show_unreachables: args.show_unreachables.unwrap_or(true),
-            show_nonreverts: args.show_nonreverts.unwrap_or(true),
-        },
-        _ => ReportConfig {
-            eval_bounds: args.eval.unwrap_or(true),
-            simplify_bounds: args.simplify.unwrap_or(false),
-            show_tmps: true,
-            show_consts: true,
-            show_symbolics: true,
-            show_initial_bounds: args.show_inits.unwrap_or(true),
-            show_all_lines: true,
-            show_reverts: args.show_reverts.unwrap_or(true),
-            show_unreachables: args.show_unreachables.unwrap_or(true),
-            show_nonreverts: args.show_nonreverts.unwrap_or(true),
-        },
-    };
+    // each verbosity level shows everything the previous one did
+    let config = ReportConfig::builder()
+        .eval_bounds(args.eval.unwrap_or(true))
+        .simplify_bounds(args.simplify.unwrap_or(false))
+        .show_tmps(if verbosity >= 2 {
+            TmpFilter::All
+        } else {
+            TmpFilter::None
+        })
+        .show_consts(verbosity >= 4)
+        .show_symbolics(verbosity >= 1)
+        .show_initial_bounds(args.show_inits.unwrap_or(verbosity >= 3))
+        .show_all_lines(verbosity >= 7)
+        .show_reverts(args.show_reverts.unwrap_or(verbosity >= 5))
+        .show_unreachables(args.show_unreachables.unwrap_or(verbosity >= 6))
+        .show_nonreverts(args.show_nonreverts.unwrap_or(true))
+        .preserve_constant_names(args.preserve_constant_names.unwrap_or(false))
+        .only_meaningful_changes(args.only_meaningful_changes.unwrap_or(false))
+        .show_cause_expr(args.show_cause_expr.unwrap_or(false))
+        .hex_values(args.hex_values.unwrap_or(false))
+        .build();
 
     let mut analyzer = Analyzer {
         max_depth: args.max_stack_depth,
+        track_relations: args.track_relations,
+        max_struct_depth: args.max_struct_depth,
+        max_recursion_depth: args.max_recursion_depth,
+        max_nodes: args.max_nodes,
+        strict_casts: args.strict_casts,
+        time_functions: args.time_functions,
         root: Root::RemappingsDirectory(env::current_dir().unwrap()),
         debug_panic: args.debug_panic || args.minimize_debug.is_some(),
-        minimize_debug: args.minimize_debug,
+        minimize_debug: args.minimize_debug.clone(),
         ..Default::default()
     };
 
     let (current_path, sol) = if args.path.ends_with(".sol") {
         let sol = fs::read_to_string(args.path.clone()).expect("Could not find file");
         // Remappings file only required for Solidity files
-        if args.remappings.is_some() {
-            let remappings = args.remappings.unwrap();
+        if let Some(remappings) = args.remappings.clone() {
             analyzer.set_remappings_and_root(remappings);
         }
 
@@ -259,10 +254,12 @@ fn main() {
             sol,
         )
     } else if args.path.ends_with(".json") {
-        let json_path_buf = PathBuf::from(args.path.clone());
-        analyzer.update_with_solc_json(&json_path_buf);
-        let (current_path, sol, _, _) = analyzer.sources.first().unwrap().clone();
-        (current_path, sol)
+        // every source of the Standard JSON input is parsed, so there is no single file
+        let json = fs::read_to_string(args.path.clone()).expect("Could not find file");
+        (
+            SourcePath::SolcJSON(PathBuf::from(args.path.clone()), String::new()),
+            json,
+        )
     } else {
         panic!("Unsupported file type")
     };
@@ -294,7 +291,13 @@ fn main() {
     }
 
     let t0 = std::time::Instant::now();
-    let maybe_entry = analyzer.parse(arena, &sol, &current_path, true);
+    let maybe_entry = match &current_path {
+        SourcePath::SolcJSON(json_path, _) => {
+            analyzer.parse_solc_json(arena, json_path, &sol);
+            Some(analyzer.entry)
+        }
+        SourcePath::SolidityFile(_) => analyzer.parse(arena, &sol, &current_path, true),
+    };
     let t_end = t0.elapsed();
     let parse_time = t_end.as_millis();
 
@@ -324,6 +327,14 @@ fn main() {
     if args.stats {
         println!("{}", analyzer.stats(t_end, arena));
     }
+
+    if args.time_functions {
+        let mut timings = analyzer.function_timings.iter().collect::<Vec<_>>();
+        timings.sort_by(|(_, a), (_, b)| b.cmp(a));
+        timings.iter().for_each(|(func, time)| {
+            println!("{func}: {}ms", time.as_millis());
+        });
+    }
     // println!("Arena: {:#?}", analyzer.range_arena);
 
     // use self.sources to fill a BTreeMap with the file_no and SourcePath.path_to_solidity_file
@@ -341,7 +352,7 @@ fn main() {
             sol.to_string(),
         );
     }
-    let mut source_map = sources(src_map);
+    let mut source_map = sources(src_map.clone());
 
     let entry = maybe_entry.unwrap();
 
@@ -485,6 +496,7 @@ fn main() {
                                 // println!("-----------------------");
                                 let analysis = analyzer
                                     .bounds_for_lineage(arena, &file_mapping, *c, vec![*c], config)
+                                    .with_cause_exprs(&src_map)
                                     .as_cli_compat(&file_mapping);
                                 analysis.print_reports(&mut source_map, &analyzer, arena);
                                 // return;
@@ -499,8 +511,18 @@ fn main() {
                 ) {
                     let analysis = analyzer
                         .bounds_for_all(arena, &file_mapping, ctx, config)
+                        .with_cause_exprs(&src_map)
                         .as_cli_compat(&file_mapping);
                     analysis.print_reports(&mut source_map, &analyzer, arena);
+                    print_diagnostics(
+                        &args,
+                        &analyzer,
+                        arena,
+                        &file_mapping,
+                        &mut source_map,
+                        FunctionNode::from(func),
+                        ctx,
+                    );
                 }
             }
         }
@@ -521,6 +543,7 @@ fn main() {
                             let ctx = func.body_ctx(&mut analyzer);
                             let analysis = analyzer
                                 .bounds_for_all(arena, &file_mapping, ctx, config)
+                                .with_cause_exprs(&src_map)
                                 .as_cli_compat(&file_mapping);
                             analysis.print_reports(&mut source_map, &analyzer, arena);
                         }
@@ -528,8 +551,18 @@ fn main() {
                         let ctx = func.body_ctx(&mut analyzer);
                         let analysis = analyzer
                             .bounds_for_all(arena, &file_mapping, ctx, config)
+                            .with_cause_exprs(&src_map)
                             .as_cli_compat(&file_mapping);
                         analysis.print_reports(&mut source_map, &analyzer, arena);
+                        print_diagnostics(
+                            &args,
+                            &analyzer,
+                            arena,
+                            &file_mapping,
+                            &mut source_map,
+                            func,
+                            ctx,
+                        );
                     }
                 }
             });
@@ -575,3 +608,52 @@ fn main() {
     //     println!();
     // });
 }
+
+/// Prints the diagnostics enabled by the arguments for a function's body context
+fn print_diagnostics(
+    args: &Args,
+    analyzer: &Analyzer,
+    arena: &mut RangeArena<Elem<Concrete>>,
+    file_mapping: &BTreeMap<usize, String>,
+    source_map: &mut impl Cache<String>,
+    func: FunctionNode,
+    ctx: ContextNode,
+) {
+    if args.show_always_reverts {
+        if let Some(diagnostic) = analyzer.always_reverts(file_mapping, ctx).unwrap() {
+            diagnostic.print_reports(source_map, analyzer, arena);
+        }
+    }
+    if args.show_index_bounds {
+        if let Some(diagnostic) = analyzer.index_bounds(file_mapping, ctx).unwrap() {
+            diagnostic.print_reports(source_map, analyzer, arena);
+        }
+    }
+    if args.show_overflows {
+        if let Some(diagnostic) = analyzer.overflows(file_mapping, ctx, arena).unwrap() {
+            diagnostic.print_reports(source_map, analyzer, arena);
+        }
+    }
+    if args.show_uninit_reads {
+        if let Some(diagnostic) = analyzer.uninit_reads(file_mapping, ctx).unwrap() {
+            diagnostic.print_reports(source_map, analyzer, arena);
+        }
+    }
+    if args.show_constant_cmps {
+        if let Some(diagnostic) = analyzer.constant_cmps(file_mapping, ctx).unwrap() {
+            diagnostic.print_reports(source_map, analyzer, arena);
+        }
+    }
+    if args.show_failable_guards {
+        for diagnostic in analyzer.failable_guards(file_mapping, ctx).unwrap() {
+            diagnostic.print_reports(source_map, analyzer, arena);
+        }
+    }
+    if args.show_complexity {
+        println!(
+            "{}: cyclomatic complexity {}",
+            func.name(analyzer).unwrap(),
+            func.cyclomatic_complexity(analyzer).unwrap()
+        );
+    }
+}
@@ -367,9 +367,9 @@ pub enum ContextEdge {
     SlotAccess,
 
     // Variable incoming edges
-    /// Unused
+    /// A written (i.e. assigned) variable version to the version it overwrote
     Assign,
-    /// Unused
+    /// A storage reference local variable to the storage variable it aliases
     StorageAssign,
     /// Unused
     MemoryAssign,
@@ -344,6 +344,19 @@ impl Builtin {
         matches!(self, Builtin::Int(_))
     }
 
+    /// Returns whether the builtin is an address (address, address payable, payable)
+    pub fn is_address(&self) -> bool {
+        matches!(
+            self,
+            Builtin::Address | Builtin::AddressPayable | Builtin::Payable
+        )
+    }
+
+    /// Returns whether the builtin is a string
+    pub fn is_string(&self) -> bool {
+        matches!(self, Builtin::String)
+    }
+
     /// Returns whether the builtin is indexable (bytes, array[], array[5], mapping(..), bytes32, string)
     pub fn is_indexable(&self) -> bool {
         matches!(
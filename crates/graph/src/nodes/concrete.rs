@@ -1,7 +1,10 @@
-use crate::{nodes::Builtin, AnalyzerBackend, GraphBackend, Node, VarType};
+use crate::{elem::RangeOp, nodes::Builtin, AnalyzerBackend, GraphBackend, Node, VarType};
 use shared::{GraphError, NodeIdx};
 
-use ethers_core::types::{Address, H256, I256, U256};
+use ethers_core::{
+    types::{Address, H256, I256, U256},
+    utils::to_checksum,
+};
 
 /// An index in the graph that references a [`Concrete`] node
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
@@ -146,7 +149,7 @@ impl<const N: usize> From<[u8; N]> for Concrete {
     fn from(u: [u8; N]) -> Self {
         assert!(N <= 32);
         let mut h = H256::default();
-        h.assign_from_slice(&u[..]);
+        h.0[..N].copy_from_slice(&u[..]);
         Concrete::Bytes(N.try_into().unwrap(), h)
     }
 }
@@ -351,8 +354,8 @@ impl Concrete {
         self.cast(other.as_builtin())
     }
 
-    /// Cast from one concrete variant given another concrete variant, but since its a literal
-    /// uint to bytesX are right padded
+    /// Cast a literal from one concrete variant given another concrete variant. See
+    /// [`Concrete::literal_cast`]
     pub fn literal_cast_from(self, other: &Self) -> Option<Self> {
         self.literal_cast(other.as_builtin())
     }
@@ -379,6 +382,29 @@ impl Concrete {
         }
     }
 
+    /// Whether two concretes hold the same value, ignoring their declared sizes, i.e.
+    /// `Uint(8, 5)` and `Uint(256, 5)`. A signed and an unsigned integer are only equal if the
+    /// signed one isn't negative
+    pub fn eq_value(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Concrete::Uint(_, val), Concrete::Uint(_, other_val)) => val == other_val,
+            (Concrete::Int(_, val), Concrete::Int(_, other_val)) => val == other_val,
+            (Concrete::Uint(_, uint), Concrete::Int(_, int))
+            | (Concrete::Int(_, int), Concrete::Uint(_, uint)) => {
+                !int.is_negative() && int.into_raw() == *uint
+            }
+            (Concrete::Bytes(_, val), Concrete::Bytes(_, other_val)) => val == other_val,
+            (Concrete::Array(vals), Concrete::Array(other_vals)) => {
+                vals.len() == other_vals.len()
+                    && vals
+                        .iter()
+                        .zip(other_vals.iter())
+                        .all(|(val, other_val)| val.eq_value(other_val))
+            }
+            _ => self == other,
+        }
+    }
+
     /// Returns whether this concrete is an unsigned integer
     pub fn is_int(&self) -> bool {
         matches!(self, Concrete::Int(_, _))
@@ -391,30 +417,10 @@ impl Concrete {
         }
     }
 
-    /// Performs a literal cast to another type
+    /// Performs a literal cast to another type. A number literal cast to `bytesN` must have
+    /// exactly `N` bytes, so it casts like any other number
     pub fn literal_cast(self, builtin: Builtin) -> Option<Self> {
-        match self {
-            Concrete::Uint(_, val) => match builtin {
-                Builtin::Bytes(size) => {
-                    let mask = if size == 32 {
-                        U256::MAX
-                    } else {
-                        U256::from(2).pow((size as u16 * 8).into()) - 1
-                    };
-
-                    let h = H256::from_slice(
-                        &(val & mask)
-                            .0
-                            .iter()
-                            .flat_map(|v| v.to_le_bytes())
-                            .collect::<Vec<_>>()[..],
-                    );
-                    Some(Concrete::Bytes(size, h))
-                }
-                _ => self.cast(builtin),
-            },
-            _ => self.cast(builtin),
-        }
+        self.cast(builtin)
     }
 
     /// Concatenate two concretes together
@@ -473,19 +479,7 @@ impl Concrete {
                         }
                     }
                     Builtin::Int(size) => Some(Concrete::Int(size, I256::from_raw(val))),
-                    Builtin::Bytes(size) => {
-                        let mask = if size == 32 {
-                            U256::MAX
-                        } else {
-                            let v = U256::from(2).pow((size as u16 * 8).into()) - 1;
-                            v << v.leading_zeros()
-                        };
-
-                        // let h = H256::from_slice(&(val & mask).0.iter().flat_map(|v| v.to_le_bytes()).collect::<Vec<_>>()[..]);
-                        let mut h = H256::default();
-                        (val & mask).to_big_endian(&mut h.0);
-                        Some(Concrete::Bytes(size, h))
-                    }
+                    Builtin::Bytes(size) => Some(Concrete::Bytes(size, low_bytes(val, size))),
                     Builtin::Bool => {
                         if val > U256::zero() {
                             Some(Concrete::from(true))
@@ -544,14 +538,7 @@ impl Concrete {
                     }
                 }
                 Builtin::Bytes(size) => {
-                    let mask = if size == 32 {
-                        U256::MAX
-                    } else {
-                        U256::from(2).pow((size as u16 * 8).into()) - 1
-                    };
-                    let mut h = H256::default();
-                    (val.into_raw() & mask).to_big_endian(h.as_mut());
-                    Some(Concrete::Bytes(size, h))
+                    Some(Concrete::Bytes(size, low_bytes(val.into_raw(), size)))
                 }
                 Builtin::Bool => {
                     if val.abs() > I256::from(0i32) {
@@ -570,7 +557,7 @@ impl Concrete {
                     } else {
                         U256::from(2).pow(size.into()) - 1
                     };
-                    let val = U256::from_big_endian(b.as_bytes());
+                    let val = U256::from_big_endian(&b.as_bytes()[..cap.into()]);
                     Some(Concrete::Uint(size, val & mask))
                 }
                 Builtin::Int(size) => {
@@ -579,7 +566,7 @@ impl Concrete {
                     } else {
                         U256::from(2).pow(size.into()) - 1
                     };
-                    let val = U256::from_big_endian(b.as_bytes());
+                    let val = U256::from_big_endian(&b.as_bytes()[..cap.into()]);
                     Some(Concrete::Int(size, I256::from_raw(val & mask)))
                 }
                 Builtin::Bytes(size) => {
@@ -876,6 +863,51 @@ impl Concrete {
         }
     }
 
+    /// Whether a checked arithmetic operation between this value and `other` leaves the range of
+    /// this value's type. Only addition, subtraction, multiplication and (unsigned) exponentiation
+    /// of integers are considered, anything else is reported as not overflowing
+    pub fn overflows_with(&self, op: RangeOp, other: &Self) -> bool {
+        match self {
+            Concrete::Uint(_, lhs) => {
+                let Some(rhs) = other.uint_val() else {
+                    return false;
+                };
+                let Some(max) = self.max_of_type().and_then(|max| max.uint_val()) else {
+                    return false;
+                };
+                let res = match op {
+                    RangeOp::Add(_) => lhs.checked_add(rhs),
+                    RangeOp::Sub(_) => lhs.checked_sub(rhs),
+                    RangeOp::Mul(_) => lhs.checked_mul(rhs),
+                    RangeOp::Exp(_) => lhs.checked_pow(rhs),
+                    _ => return false,
+                };
+                res.map_or(true, |res| res > max)
+            }
+            Concrete::Int(_, lhs) => {
+                let rhs = match other {
+                    Concrete::Int(_, rhs) => *rhs,
+                    Concrete::Uint(_, rhs) if *rhs <= I256::MAX.into_raw() => I256::from_raw(*rhs),
+                    _ => return false,
+                };
+                let (Some(min), Some(max)) = (
+                    self.min_of_type().and_then(|min| min.int_val()),
+                    self.max_of_type().and_then(|max| max.int_val()),
+                ) else {
+                    return false;
+                };
+                let res = match op {
+                    RangeOp::Add(_) => lhs.checked_add(rhs),
+                    RangeOp::Sub(_) => lhs.checked_sub(rhs),
+                    RangeOp::Mul(_) => lhs.checked_mul(rhs),
+                    _ => return false,
+                };
+                res.map_or(true, |res| res < min || res > max)
+            }
+            _ => false,
+        }
+    }
+
     /// Gets the size of some concrete type
     pub fn int_size(&self) -> Option<u16> {
         match self {
@@ -1023,6 +1055,17 @@ impl Concrete {
         }
     }
 
+    /// The value as `0x` prefixed hex if it is an address or bytes, with addresses in their
+    /// checksummed form
+    pub fn as_display_hex(&self) -> Option<String> {
+        match self {
+            Concrete::Address(a) => Some(to_checksum(a, None)),
+            Concrete::Bytes(size, b) => Some(format!("0x{}", hex::encode(&b.0[..*size as usize]))),
+            Concrete::DynBytes(a) => Some(format!("0x{}", hex::encode(a))),
+            _ => None,
+        }
+    }
+
     /// Converts to a human readable string. For integers, this means trying to find a
     /// power of 2 that is close to the value.
     pub fn as_human_string(&self) -> String {
@@ -1106,3 +1149,50 @@ impl Concrete {
         }
     }
 }
+
+/// The low `size` bytes of a number as a left-aligned `bytesN` value, i.e. `bytes4(0x78561234)`
+/// holds `0x78` at index 0
+fn low_bytes(val: U256, size: u8) -> H256 {
+    let size = usize::from(size.min(32));
+    let mut bytes = [0u8; 32];
+    val.to_big_endian(&mut bytes);
+    let mut h = H256::default();
+    h.0[..size].copy_from_slice(&bytes[32 - size..]);
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eq_value_ignores_size() {
+        let small = Concrete::Uint(8, U256::from(5));
+        let big = Concrete::Uint(256, U256::from(5));
+        assert_ne!(small, big);
+        assert!(small.eq_value(&big));
+        assert!(Concrete::Int(16, I256::from(-3)).eq_value(&Concrete::Int(256, I256::from(-3))));
+        assert!(Concrete::Int(8, I256::from(5)).eq_value(&small));
+        assert!(!small.eq_value(&Concrete::Uint(256, U256::from(6))));
+    }
+
+    #[test]
+    fn eq_value_respects_sign() {
+        let neg_one = Concrete::Int(8, I256::from(-1));
+        let max = Concrete::Uint(8, U256::from(255));
+        assert!(!neg_one.eq_value(&max));
+        assert!(!max.eq_value(&neg_one));
+    }
+
+    #[test]
+    fn number_to_fixed_bytes_is_big_endian() {
+        let val = Concrete::Uint(32, U256::from(0x78561234u64));
+        let bytes = val.clone().cast(Builtin::Bytes(4)).unwrap();
+        let Concrete::Bytes(4, h) = bytes else {
+            panic!("not bytes4: {bytes:?}");
+        };
+        assert_eq!(h.0[..4], [0x78, 0x56, 0x12, 0x34]);
+        assert!(h.0[4..].iter().all(|b| *b == 0));
+        assert_eq!(bytes.cast(Builtin::Uint(32)), Some(val));
+    }
+}
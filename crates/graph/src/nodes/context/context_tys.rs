@@ -1,6 +1,7 @@
-use crate::nodes::{ContextNode, ContextVarNode, ContractNode, FunctionNode, StructNode};
+use crate::nodes::{Concrete, ContextNode, ContextVarNode, ContractNode, FunctionNode, StructNode};
 use shared::NodeIdx;
 
+use ethers_core::types::{I256, U256};
 use solang_parser::pt::Loc;
 
 use std::collections::BTreeMap;
@@ -68,6 +69,89 @@ impl ModifierState {
     }
 }
 
+/// A linear equality between variables, `terms[0] + terms[1] + .. == constant`. Each term is a
+/// variable name, negated if its flag is set
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LinearEq {
+    pub terms: Vec<(String, bool)>,
+    pub constant: I256,
+}
+
+impl LinearEq {
+    /// Whether a variable with the name is one of the terms
+    pub fn mentions(&self, name: &str) -> bool {
+        self.terms.iter().any(|(term, _)| term == name)
+    }
+}
+
+/// The known contents of a `bytes` or `string` variable in a context
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct MemoryLayout {
+    /// The minimum length, if it is concrete
+    pub len_min: Option<U256>,
+    /// The maximum length, if it is concrete
+    pub len_max: Option<U256>,
+    /// The bytes whose index and value are known, by index
+    pub indices: BTreeMap<U256, u8>,
+}
+
+/// The builtin a guard condition was checked with
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GuardKind {
+    /// `require(..)`, which validates inputs and reverts on failure
+    Require,
+    /// `assert(..)`, which checks an invariant and panics on failure
+    Assert,
+}
+
+/// Records how a loop subcontext's body was interpreted
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub struct LoopInfo {
+    /// An estimate of the most times the loop body runs, if the loop condition bounds a counter
+    /// the body steps by constants
+    pub iterations: Option<usize>,
+    /// The number of times the loop body was interpreted before stopping
+    pub interpreted: usize,
+    /// Whether the variables mutated in the loop were widened to their type's range instead of
+    /// being precisely tracked through every iteration
+    pub widened: bool,
+}
+
+impl LoopInfo {
+    /// Returns a string explanation of how precise the loop's analysis is
+    pub fn analysis_str(&self) -> String {
+        let runs = self
+            .iterations
+            .map(|iterations| format!("runs at most {iterations} time(s), "))
+            .unwrap_or_default();
+        if self.widened {
+            format!(
+                "Loop approximated: {runs}body interpreted {} time(s), then variables mutated in the loop were widened",
+                self.interpreted
+            )
+        } else {
+            format!(
+                "Loop {runs}body interpreted {} time(s) without widening",
+                self.interpreted
+            )
+        }
+    }
+}
+
+/// An array access whose index may not be less than the array length
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OobIndex {
+    /// The access location
+    pub loc: Loc,
+    /// The index variable
+    pub index: ContextVarNode,
+    /// The length variable
+    pub len: ContextVarNode,
+    /// Values for the inputs (keyed by display name) that make the index reach the length and
+    /// that the constraints at the access admit, if any were found
+    pub counterexample: Option<BTreeMap<String, Concrete>>,
+}
+
 /// Holds cached information about the context to speed up lookups
 #[derive(Default, Debug, Clone, Eq, PartialEq)]
 pub struct ContextCache {
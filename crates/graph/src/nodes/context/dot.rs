@@ -0,0 +1,74 @@
+use crate::{
+    nodes::{ContextNode, ContextVarNode},
+    Edge, GraphBackend, Node,
+};
+
+use shared::{GraphError, NodeIdx};
+
+use petgraph::visit::EdgeRef;
+use std::collections::BTreeSet;
+
+impl ContextNode {
+    /// Exports this context, its subcontexts and their variables as a Graphviz DOT digraph, for
+    /// debugging how ranges propagated. Contexts are boxes, variables are ellipses, and each edge
+    /// between them is labeled by its [`ContextEdge`](crate::ContextEdge) variant
+    pub fn export_dot(&self, analyzer: &impl GraphBackend) -> Result<String, GraphError> {
+        let mut ctxs = self.family_tree(analyzer)?;
+        ctxs.push(*self);
+        let ctxs = ctxs.into_iter().collect::<BTreeSet<_>>();
+
+        let vars = analyzer
+            .graph()
+            .node_indices()
+            .filter(|idx| matches!(analyzer.node(*idx), Node::ContextVar(_)))
+            .map(ContextVarNode::from)
+            .filter(|cvar| {
+                cvar.maybe_ctx(analyzer)
+                    .is_some_and(|ctx| ctxs.contains(&ctx))
+            })
+            .collect::<BTreeSet<_>>();
+
+        let mut lines = vec![format!("digraph \"{}\" {{", escape(&self.path(analyzer)))];
+        for ctx in &ctxs {
+            lines.push(format!(
+                "    {} [label=\"{}\", shape=box];",
+                ctx.0,
+                escape(&ctx.path(analyzer))
+            ));
+        }
+        for cvar in &vars {
+            lines.push(format!(
+                "    {} [label=\"{}: {}\"];",
+                cvar.0,
+                escape(&cvar.display_name(analyzer)?),
+                escape(&cvar.ty(analyzer)?.as_string(analyzer)?)
+            ));
+        }
+
+        let nodes = ctxs
+            .iter()
+            .map(|ctx| NodeIdx::from(ctx.0))
+            .chain(vars.iter().map(|cvar| NodeIdx::from(cvar.0)))
+            .collect::<BTreeSet<_>>();
+        for edge in analyzer.graph().edge_references() {
+            let Edge::Context(kind) = edge.weight() else {
+                continue;
+            };
+            if nodes.contains(&edge.source()) && nodes.contains(&edge.target()) {
+                lines.push(format!(
+                    "    {} -> {} [label=\"{}\"];",
+                    edge.source().index(),
+                    edge.target().index(),
+                    escape(&format!("{kind:?}"))
+                ));
+            }
+        }
+        lines.push("}".to_string());
+        Ok(lines.join("\n"))
+    }
+}
+
+/// Escapes a string for use in a quoted DOT label
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -14,10 +14,14 @@ pub enum KilledKind {
     Unreachable,
     /// Execution guaranteed to revert here!
     Revert,
+    /// Division or modulo by zero, execution guaranteed to revert here!
+    DivisionByZero,
     /// Unexpected parse error. This is likely a bug or invalid solidity. See the `errors` section of the CLI output or rerun with `--debug` for more information
     ParseError,
     /// This context was not evaluated because it was not on the path to analyzing the requested context to debug
     DebugIgnored,
+    /// Analysis was aborted because the function added more nodes to the graph than the configured limit
+    TooLarge,
 }
 
 impl KilledKind {
@@ -28,10 +32,17 @@ impl KilledKind {
             Ended => "Execution ended here successfully",
             Unreachable => "Unsatisifiable bounds, therefore dead code",
             Revert => "Execution guaranteed to revert here!",
+            DivisionByZero => "Division or modulo by zero, execution guaranteed to revert here!",
             ParseError => "Unexpected parse error. This is likely a bug or invalid solidity. See the `errors` section of the CLI output or rerun with `--debug` for more information",
             DebugIgnored => "Ignored due to debug_ctx_path being set",
+            TooLarge => "Analysis aborted, the function added more nodes to the graph than the configured limit",
         }
     }
+
+    /// Whether the context was killed by a revert of any kind
+    pub fn is_revert(&self) -> bool {
+        matches!(self, KilledKind::Revert | KilledKind::DivisionByZero)
+    }
 }
 
 /// A representation of the evaluation of an expression
@@ -0,0 +1,133 @@
+use crate::{
+    nodes::{Concrete, ContextNode, ContextVarNode},
+    range::{elem::*, Range},
+    AnalyzerBackend, GraphBackend, SolcRange,
+};
+
+use shared::{GraphError, JoinStrategy, RangeArena};
+
+use std::cmp::Ordering;
+
+impl ContextNode {
+    /// The range of a variable where the branches under this context rejoin, computed with the
+    /// analyzer's [`JoinStrategy`]. Only branches that were not killed are joined. Returns `None`
+    /// if every branch was killed or no branch has a ranged variable with the name
+    pub fn joined_var_range(
+        &self,
+        var_name: &str,
+        analyzer: &impl AnalyzerBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<SolcRange>, GraphError> {
+        let mut branches = self.all_edges(analyzer)?;
+        if branches.is_empty() {
+            branches.push(*self);
+        }
+
+        let mut cvars = vec![];
+        for branch in branches {
+            if branch.is_killed(analyzer)? {
+                continue;
+            }
+            if let Some(cvar) = branch.var_by_name_or_recurse(analyzer, var_name)? {
+                cvars.push(cvar.latest_version_or_inherited_in_ctx(branch, analyzer));
+            }
+        }
+        Self::join_vars(&cvars, analyzer, arena)
+    }
+
+    /// The range of the given branch versions of a variable where the branches rejoin, computed
+    /// with the analyzer's [`JoinStrategy`]. Widening falls back to the union if some variable's
+    /// type has no concrete range
+    fn join_vars(
+        cvars: &[ContextVarNode],
+        analyzer: &impl AnalyzerBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<SolcRange>, GraphError> {
+        match analyzer.join_strategy() {
+            JoinStrategy::WidenToType => {
+                let mut joined: Option<SolcRange> = None;
+                for cvar in cvars {
+                    let Some(ty_range) = Self::ty_range(*cvar, analyzer)? else {
+                        return Self::union_of(cvars, analyzer, arena);
+                    };
+                    let Some(curr) = joined.as_mut() else {
+                        joined = Some(ty_range);
+                        continue;
+                    };
+                    if ty_range.min.range_ord(&curr.min, arena) == Some(Ordering::Less) {
+                        curr.min = ty_range.min;
+                    }
+                    if ty_range.max.range_ord(&curr.max, arena) == Some(Ordering::Greater) {
+                        curr.max = ty_range.max;
+                    }
+                }
+                Ok(joined)
+            }
+            JoinStrategy::Union => Self::union_of(cvars, analyzer, arena),
+        }
+    }
+
+    /// The full range of a variable's type, if it is a builtin with concrete bounds
+    fn ty_range(
+        cvar: ContextVarNode,
+        analyzer: &impl GraphBackend,
+    ) -> Result<Option<SolcRange>, GraphError> {
+        let (Some(min), Some(max)) = (
+            cvar.ty_min_concrete(analyzer)?,
+            cvar.ty_max_concrete(analyzer)?,
+        ) else {
+            return Ok(None);
+        };
+        Ok(Some(SolcRange::new(
+            Elem::from(min),
+            Elem::from(max),
+            vec![],
+        )))
+    }
+
+    /// The smallest range containing every variable's evaluated range. Only exclusions common to
+    /// every range are kept. If two bounds cannot be compared, the type's range is used instead
+    pub fn union_of(
+        cvars: &[ContextVarNode],
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<SolcRange>, GraphError> {
+        let mut joined: Option<SolcRange> = None;
+        for cvar in cvars {
+            let Some(range) = cvar.ref_range(analyzer)? else {
+                continue;
+            };
+            let min = range.evaled_range_min(analyzer, arena)?;
+            let max = range.evaled_range_max(analyzer, arena)?;
+            let excls = range.range_exclusions();
+
+            let Some(curr) = joined.as_mut() else {
+                joined = Some(SolcRange::new(min, max, excls));
+                continue;
+            };
+
+            match (
+                min.range_ord(&curr.min, arena),
+                max.range_ord(&curr.max, arena),
+            ) {
+                (Some(min_ord), Some(max_ord)) => {
+                    if min_ord == Ordering::Less {
+                        curr.min = min;
+                    }
+                    if max_ord == Ordering::Greater {
+                        curr.max = max;
+                    }
+                }
+                _ => return Self::ty_range(*cvar, analyzer),
+            }
+
+            let common = curr
+                .range_exclusions()
+                .into_iter()
+                .filter(|excl| excls.contains(excl))
+                .collect::<Vec<_>>();
+            curr.set_range_exclusions(common);
+        }
+        Ok(joined)
+    }
+}
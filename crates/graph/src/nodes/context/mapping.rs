@@ -0,0 +1,132 @@
+use crate::{
+    elem::{Elem, RangeElem},
+    nodes::{Builtin, Concrete, ContextNode, ContextVarNode},
+    GraphBackend, SolcRange, VarType,
+};
+
+use shared::{GraphError, RangeArena};
+
+use std::cmp::Ordering;
+
+impl ContextNode {
+    /// The range of the value a mapping holds for a concrete key as of this context, or with no
+    /// key, the range joined across every value it was accessed with. A key that was never
+    /// accessed holds the zero value of the value type. An access through a symbolic key may
+    /// alias any key, so its value is joined into the range of keys accessed before it. Returns
+    /// `None` if the ranges can't be compared or the value type has no zero value
+    pub fn mapping_value_range(
+        &self,
+        mapping: ContextVarNode,
+        key: Option<Concrete>,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<SolcRange>, GraphError> {
+        let name = mapping.name(analyzer)?;
+        let mut ctxs = self.parent_list(analyzer)?;
+        ctxs.push(*self);
+
+        // the latest version of each access of the mapping, with its key if it is concrete
+        let mut accesses = vec![];
+        for ctx in ctxs {
+            for var in ctx.vars(analyzer).values() {
+                let Some(arr) = var.index_access_to_array(analyzer) else {
+                    continue;
+                };
+                if arr.name(analyzer)? != name {
+                    continue;
+                }
+                let latest = var.latest_version_or_inherited_in_ctx(*self, analyzer);
+                if accesses.iter().any(|(_, access)| *access == latest) {
+                    continue;
+                }
+                let access_key = match var.index_access_to_index(analyzer) {
+                    Some(idx) if idx.is_const(analyzer, arena)? => idx
+                        .evaled_range_min(analyzer, arena)?
+                        .and_then(|min| min.maybe_concrete())
+                        .map(|min| min.val),
+                    _ => None,
+                };
+                accesses.push((access_key, latest));
+            }
+        }
+        accesses.sort_by_key(|(_, access)| access.0);
+
+        let symbolic_after = |after: usize| {
+            accesses
+                .iter()
+                .filter(move |(access_key, access)| access_key.is_none() && access.0 > after)
+                .map(|(_, access)| *access)
+        };
+        let (base, aliased): (_, Vec<_>) = match &key {
+            Some(key) => {
+                // literal keys have the smallest type that fits them, so compare by value
+                let key = Elem::from(key.clone());
+                match accesses.iter().rev().find(|(access_key, _)| {
+                    access_key.as_ref().is_some_and(|access_key| {
+                        Elem::from(access_key.clone()).range_ord(&key, arena)
+                            == Some(Ordering::Equal)
+                    })
+                }) {
+                    Some((_, access)) => (Some(*access), symbolic_after(access.0).collect()),
+                    None => (None, symbolic_after(0).collect()),
+                }
+            }
+            None => (None, accesses.iter().map(|(_, access)| *access).collect()),
+        };
+
+        let mut bounds = match base {
+            Some(access) => vec![(
+                access.evaled_range_min(analyzer, arena)?,
+                access.evaled_range_max(analyzer, arena)?,
+            )],
+            None if key.is_some() || aliased.is_empty() => {
+                let Some(zero) = zero_value_range(mapping, analyzer)? else {
+                    return Ok(None);
+                };
+                vec![(Some(zero.min), Some(zero.max))]
+            }
+            None => vec![],
+        };
+        for access in aliased {
+            bounds.push((
+                access.evaled_range_min(analyzer, arena)?,
+                access.evaled_range_max(analyzer, arena)?,
+            ));
+        }
+
+        let mut bounds = bounds.into_iter();
+        let Some((Some(mut min), Some(mut max))) = bounds.next() else {
+            return Ok(None);
+        };
+        for (next_min, next_max) in bounds {
+            let (Some(next_min), Some(next_max)) = (next_min, next_max) else {
+                return Ok(None);
+            };
+            match next_min.range_ord(&min, arena) {
+                Some(Ordering::Less) => min = next_min,
+                Some(_) => {}
+                None => return Ok(None),
+            }
+            match next_max.range_ord(&max, arena) {
+                Some(Ordering::Greater) => max = next_max,
+                Some(_) => {}
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(SolcRange::new(min, max, vec![])))
+    }
+}
+
+/// The zero range of the value type of a mapping, i.e. `mapping(address => uint256)` -> `[0, 0]`
+fn zero_value_range(
+    mapping: ContextVarNode,
+    analyzer: &impl GraphBackend,
+) -> Result<Option<SolcRange>, GraphError> {
+    let VarType::BuiltIn(node, _) = mapping.ty(analyzer)? else {
+        return Ok(None);
+    };
+    match node.underlying(analyzer)? {
+        Builtin::Mapping(_, VarType::BuiltIn(value, _)) => value.zero_range(analyzer),
+        _ => Ok(None),
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    elem::{Elem, RangeElem},
+    nodes::{Concrete, ContextNode, ContextVarNode, MemoryLayout},
+    GraphBackend,
+};
+
+use shared::{GraphError, RangeArena};
+
+use ethers_core::types::U256;
+use std::collections::BTreeMap;
+
+impl ContextNode {
+    /// The length range and known bytes of a `bytes` or `string` variable, as of its latest
+    /// version in this context. A fully symbolic variable has no known bytes
+    pub fn memory_layout(
+        &self,
+        var: ContextVarNode,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<MemoryLayout, GraphError> {
+        let var = var.latest_version_or_inherited_in_ctx(*self, analyzer);
+        let (Some(min), Some(max)) = (
+            var.evaled_range_min(analyzer, arena)?,
+            var.evaled_range_max(analyzer, arena)?,
+        ) else {
+            return Ok(MemoryLayout::default());
+        };
+        let (min_len, min_bytes) = known_bytes(&min, analyzer, arena)?;
+        let (max_len, max_bytes) = known_bytes(&max, analyzer, arena)?;
+        // a byte is only known if it is the same at both ends of the range
+        let indices = min_bytes
+            .into_iter()
+            .filter(|(idx, byte)| max_bytes.get(idx) == Some(byte))
+            .collect();
+        Ok(MemoryLayout {
+            len_min: min_len,
+            len_max: max_len,
+            indices,
+        })
+    }
+}
+
+/// The concrete length and the bytes at concrete indices of a dynamic bytes-like element
+fn known_bytes(
+    elem: &Elem<Concrete>,
+    analyzer: &impl GraphBackend,
+    arena: &mut RangeArena<Elem<Concrete>>,
+) -> Result<(Option<U256>, BTreeMap<U256, u8>), GraphError> {
+    match elem {
+        Elem::ConcreteDyn(rd) => {
+            let len = rd.len.minimize(analyzer, arena)?.maybe_concrete();
+            let mut bytes = BTreeMap::default();
+            for (idx, (val, _op)) in rd.val.iter() {
+                let (Some(idx), Some(val)) = (
+                    idx.minimize(analyzer, arena)?.maybe_concrete(),
+                    val.minimize(analyzer, arena)?.maybe_concrete(),
+                ) else {
+                    continue;
+                };
+                if let (Some(idx), Concrete::Bytes(1, byte)) = (idx.val.uint_val(), val.val) {
+                    bytes.insert(idx, byte.0[0]);
+                }
+            }
+            Ok((len.and_then(|len| len.val.uint_val()), bytes))
+        }
+        Elem::Concrete(c) => {
+            let raw = match &c.val {
+                Concrete::DynBytes(v) => v.clone(),
+                Concrete::String(s) => s.as_bytes().to_vec(),
+                _ => return Ok((None, BTreeMap::default())),
+            };
+            let bytes = raw
+                .into_iter()
+                .enumerate()
+                .map(|(idx, byte)| (U256::from(idx), byte))
+                .collect::<BTreeMap<_, _>>();
+            Ok((Some(U256::from(bytes.len())), bytes))
+        }
+        _ => Ok((None, BTreeMap::default())),
+    }
+}
@@ -1,17 +1,23 @@
 mod context_tys;
 mod expr_ret;
 mod invariants;
+mod joining;
 mod node;
 mod underlying;
 mod var;
 
-pub use context_tys::{CallFork, ContextCache, ModifierState};
+pub use context_tys::{
+    CallFork, ContextCache, GuardKind, LinearEq, LoopInfo, MemoryLayout, ModifierState, OobIndex,
+};
 pub use expr_ret::{ExprRet, KilledKind};
 pub use node::ContextNode;
 pub use underlying::Context;
-pub use var::{ContextVar, ContextVarNode, TmpConstruction};
+pub use var::{ContextVar, ContextVarNode, RangeKind, TmpConstruction};
 
 // ContextNode implementations are split to ease in maintainability
+mod dot;
+mod mapping;
+mod memory;
 mod querying;
 mod solving;
 mod typing;
@@ -1,5 +1,8 @@
 use crate::{
-    nodes::{Concrete, Context, ContextVarNode, FunctionNode, KilledKind},
+    nodes::{
+        Concrete, Context, ContextVarNode, FunctionNode, GuardKind, KilledKind, LinearEq, LoopInfo,
+        OobIndex,
+    },
     range::elem::Elem,
     AnalyzerBackend, AsDotStr, GraphBackend, Node,
 };
@@ -90,6 +93,24 @@ impl ContextNode {
         Ok(self.underlying(analyzer)?.killed)
     }
 
+    /// Returns whether every terminal context reachable from this context was killed by a revert
+    /// or unsatisfiable bounds, i.e. there is no path through it that completes successfully
+    pub fn always_reverts(&self, analyzer: &impl GraphBackend) -> Result<bool, GraphError> {
+        let mut leaves = self.all_edges(analyzer)?;
+        if leaves.is_empty() {
+            leaves.push(*self);
+        }
+        let mut any_revert = false;
+        for leaf in leaves {
+            match leaf.killed_loc(analyzer)? {
+                Some((_, kind)) if kind.is_revert() => any_revert = true,
+                Some((_, KilledKind::Unreachable)) => {}
+                _ => return Ok(false),
+            }
+        }
+        Ok(any_revert)
+    }
+
     /// Add a return node to the context
     pub fn add_return_node(
         &self,
@@ -139,6 +160,306 @@ impl ContextNode {
         Ok(self.underlying(analyzer)?.ret.clone())
     }
 
+    /// Records an emitted event and the variables passed as its arguments
+    pub fn add_emit(
+        &self,
+        emit_loc: Loc,
+        event_name: String,
+        args: Vec<ContextVarNode>,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<(), GraphError> {
+        self.underlying_mut(analyzer)?
+            .emits
+            .push((emit_loc, event_name, args));
+        Ok(())
+    }
+
+    /// Gets the events emitted in this context along with their argument variables
+    pub fn emits(
+        &self,
+        analyzer: &impl GraphBackend,
+    ) -> Result<Vec<(Loc, String, Vec<ContextVarNode>)>, GraphError> {
+        Ok(self.underlying(analyzer)?.emits.clone())
+    }
+
+    /// Records an array access whose index may be greater than or equal to the array's length
+    pub fn add_oob_index(
+        &self,
+        oob_index: OobIndex,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<(), GraphError> {
+        self.underlying_mut(analyzer)?.oob_indices.push(oob_index);
+        Ok(())
+    }
+
+    /// Gets the array accesses in this context whose index may be out of bounds
+    pub fn oob_indices(&self, analyzer: &impl GraphBackend) -> Result<Vec<OobIndex>, GraphError> {
+        Ok(self.underlying(analyzer)?.oob_indices.clone())
+    }
+
+    /// Records a checked arithmetic operation whose result may overflow its type
+    pub fn add_possible_overflow(
+        &self,
+        op_loc: Loc,
+        result: ContextVarNode,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<(), GraphError> {
+        self.underlying_mut(analyzer)?
+            .possible_overflows
+            .push((op_loc, result));
+        Ok(())
+    }
+
+    /// Gets the checked arithmetic operations in this context whose result may overflow
+    pub fn possible_overflows(
+        &self,
+        analyzer: &impl GraphBackend,
+    ) -> Result<Vec<(Loc, ContextVarNode)>, GraphError> {
+        Ok(self.underlying(analyzer)?.possible_overflows.clone())
+    }
+
+    /// Records that a local was declared without a value, so its first read before an
+    /// assignment can be flagged
+    pub fn add_uninit_local(
+        &self,
+        name: &str,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<(), GraphError> {
+        self.underlying_mut(analyzer)?
+            .uninit_locals
+            .insert(name.to_string());
+        Ok(())
+    }
+
+    /// Stops tracking a local declared without a value (i.e. because it was assigned). Returns
+    /// whether it was still tracked
+    pub fn remove_uninit_local(
+        &self,
+        name: &str,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<bool, GraphError> {
+        Ok(self.underlying_mut(analyzer)?.uninit_locals.remove(name))
+    }
+
+    /// Records a read of a local before it was assigned
+    pub fn add_uninit_read(
+        &self,
+        read_loc: Loc,
+        var: ContextVarNode,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<(), GraphError> {
+        self.underlying_mut(analyzer)?
+            .uninit_reads
+            .push((read_loc, var));
+        Ok(())
+    }
+
+    /// Undoes a read recorded by [`Self::add_uninit_read`] at `read_loc`, tracking the local again.
+    /// Used when the read was actually the left hand side of an assignment
+    pub fn undo_uninit_read(
+        &self,
+        read_loc: Loc,
+        name: &str,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<(), GraphError> {
+        let underlying = self.underlying_mut(analyzer)?;
+        let len = underlying.uninit_reads.len();
+        underlying.uninit_reads.retain(|(loc, _)| *loc != read_loc);
+        if underlying.uninit_reads.len() != len {
+            underlying.uninit_locals.insert(name.to_string());
+        }
+        Ok(())
+    }
+
+    /// Gets the reads of locals in this context that happened before they were assigned
+    pub fn uninit_reads(
+        &self,
+        analyzer: &impl GraphBackend,
+    ) -> Result<Vec<(Loc, ContextVarNode)>, GraphError> {
+        Ok(self.underlying(analyzer)?.uninit_reads.clone())
+    }
+
+    /// Records a linear equality between variables that holds from here on
+    pub fn add_linear_eq(
+        &self,
+        eq: LinearEq,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<(), GraphError> {
+        let underlying = self.underlying_mut(analyzer)?;
+        if !underlying.linear_eqs.contains(&eq) {
+            underlying.linear_eqs.push(eq);
+        }
+        Ok(())
+    }
+
+    /// Drops the linear equalities involving a variable, i.e. because it was reassigned
+    pub fn forget_linear_eqs(
+        &self,
+        name: &str,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<(), GraphError> {
+        self.underlying_mut(analyzer)?
+            .linear_eqs
+            .retain(|eq| !eq.mentions(name));
+        Ok(())
+    }
+
+    /// Gets the linear equalities between variables that hold in this context
+    pub fn linear_eqs(&self, analyzer: &impl GraphBackend) -> Result<Vec<LinearEq>, GraphError> {
+        Ok(self.underlying(analyzer)?.linear_eqs.clone())
+    }
+
+    /// Gets the linear equalities of this context that only involve non-storage variables, i.e.
+    /// the ones that still hold after a call, which may write any storage
+    pub fn non_storage_linear_eqs(
+        &self,
+        analyzer: &impl GraphBackend,
+    ) -> Result<Vec<LinearEq>, GraphError> {
+        let mut eqs = self.linear_eqs(analyzer)?;
+        let mut kept = Vec::with_capacity(eqs.len());
+        for eq in eqs.drain(..) {
+            let mut storage = false;
+            for (name, _) in &eq.terms {
+                if let Some(var) = self.var_by_name_or_recurse(analyzer, name)? {
+                    storage |= var.is_storage(analyzer)?;
+                }
+            }
+            if !storage {
+                kept.push(eq);
+            }
+        }
+        Ok(kept)
+    }
+
+    /// Records a `require` or `assert` checked in this context
+    pub fn add_guard(
+        &self,
+        loc: Loc,
+        kind: GuardKind,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<(), GraphError> {
+        self.underlying_mut(analyzer)?.guards.push((loc, kind));
+        Ok(())
+    }
+
+    /// Gets the `require`s and `assert`s checked in this context
+    pub fn guards(
+        &self,
+        analyzer: &impl GraphBackend,
+    ) -> Result<Vec<(Loc, GuardKind)>, GraphError> {
+        Ok(self.underlying(analyzer)?.guards.clone())
+    }
+
+    /// Records a condition checked in this context that is not already known to hold
+    pub fn add_failable_cond(
+        &self,
+        loc: Loc,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<(), GraphError> {
+        self.underlying_mut(analyzer)?.failable_conds.push(loc);
+        Ok(())
+    }
+
+    /// Gets the locations of conditions checked in this context that may fail
+    pub fn failable_conds(&self, analyzer: &impl GraphBackend) -> Result<Vec<Loc>, GraphError> {
+        Ok(self.underlying(analyzer)?.failable_conds.clone())
+    }
+
+    /// Records a condition checked in this context that can't hold
+    pub fn add_impossible_cond(
+        &self,
+        loc: Loc,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<(), GraphError> {
+        self.underlying_mut(analyzer)?.impossible_conds.push(loc);
+        Ok(())
+    }
+
+    /// Gets the locations of conditions checked in this context that can't hold
+    pub fn impossible_conds(&self, analyzer: &impl GraphBackend) -> Result<Vec<Loc>, GraphError> {
+        Ok(self.underlying(analyzer)?.impossible_conds.clone())
+    }
+
+    /// Records a comparison evaluated in this context, with the value it always has if the
+    /// operands' ranges decide it
+    pub fn add_constant_cmp(
+        &self,
+        loc: Loc,
+        val: Option<bool>,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<(), GraphError> {
+        self.underlying_mut(analyzer)?
+            .constant_cmps
+            .push((loc, val));
+        Ok(())
+    }
+
+    /// Negates the recorded values of the comparison at `loc` in this context and its
+    /// descendants, for when the comparison was checked inverted (i.e. in an `else` branch)
+    pub fn negate_constant_cmps(
+        &self,
+        loc: Loc,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<(), GraphError> {
+        let mut ctxs = self.family_tree(analyzer)?;
+        ctxs.push(*self);
+        for ctx in ctxs {
+            ctx.underlying_mut(analyzer)?
+                .constant_cmps
+                .iter_mut()
+                .filter(|(cmp_loc, _)| *cmp_loc == loc)
+                .for_each(|(_, val)| *val = val.map(|val| !val));
+        }
+        Ok(())
+    }
+
+    /// Gets the comparisons evaluated in this context, with the value each always has if it was
+    /// decided
+    pub fn constant_cmps(
+        &self,
+        analyzer: &impl GraphBackend,
+    ) -> Result<Vec<(Loc, Option<bool>)>, GraphError> {
+        Ok(self.underlying(analyzer)?.constant_cmps.clone())
+    }
+
+    /// Gets the loop information if this is a loop subcontext
+    pub fn loop_info(&self, analyzer: &impl GraphBackend) -> Result<Option<LoopInfo>, GraphError> {
+        Ok(self.underlying(analyzer)?.loop_info)
+    }
+
+    /// Records how the body of this loop subcontext was interpreted
+    pub fn set_loop_info(
+        &self,
+        info: LoopInfo,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<(), GraphError> {
+        let Some(loop_info) = &mut self.underlying_mut(analyzer)?.loop_info else {
+            return Err(GraphError::NodeConfusion(
+                "Tried to set loop info on a non-loop context".to_string(),
+            ));
+        };
+        *loop_info = info;
+        Ok(())
+    }
+
+    /// Gets the loop subcontexts, from the root down to this context, whose ranges were
+    /// approximated by widening
+    pub fn widened_loops(
+        &self,
+        analyzer: &impl GraphBackend,
+    ) -> Result<Vec<(ContextNode, LoopInfo)>, GraphError> {
+        let mut ctxs = self.parent_list(analyzer)?;
+        ctxs.reverse();
+        ctxs.push(*self);
+        ctxs.into_iter()
+            .filter_map(|ctx| match ctx.loop_info(analyzer) {
+                Ok(Some(info)) if info.widened => Some(Ok((ctx, info))),
+                Ok(_) => None,
+                Err(e) => Some(Err(e)),
+            })
+            .collect()
+    }
+
     /// Returns a string for dot-string things
     pub fn as_string(&mut self) -> String {
         "Context".to_string()
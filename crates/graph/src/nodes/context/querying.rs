@@ -278,4 +278,36 @@ impl ContextNode {
     pub fn associated_fn_name(&self, analyzer: &impl GraphBackend) -> Result<String, GraphError> {
         self.associated_fn(analyzer)?.name(analyzer)
     }
+
+    /// The number of calls to a function on the call stack of this context, including the
+    /// function being analyzed if it is the one passed. Calls that have already returned are
+    /// not on the stack
+    pub fn recursion_depth(
+        &self,
+        func: FunctionNode,
+        analyzer: &impl GraphBackend,
+    ) -> Result<usize, GraphError> {
+        let mut depth = 0;
+        let mut curr = *self;
+        loop {
+            let underlying = curr.underlying(analyzer)?;
+            if let Some(caller) = underlying.returning_ctx {
+                // a context resuming after a call continues on the caller's stack
+                curr = caller;
+                continue;
+            }
+            if underlying.fn_call == Some(func) || underlying.ext_fn_call == Some(func) {
+                depth += 1;
+            }
+            match underlying.parent_ctx {
+                Some(parent) => curr = parent,
+                None => {
+                    if underlying.parent_fn == func {
+                        depth += 1;
+                    }
+                    return Ok(depth);
+                }
+            }
+        }
+    }
 }
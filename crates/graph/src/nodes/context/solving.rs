@@ -1,21 +1,170 @@
-use crate::elem::Elem;
+use crate::elem::{Elem, RangeConcrete};
 
 use crate::{
-    nodes::{Concrete, ContextNode, ContextVarNode},
-    range::Range,
+    nodes::{Concrete, ContextNode, ContextVarNode, KilledKind},
+    range::{elem::RangeElem, Range, RangeEval},
     solvers::{
         dl::{DLSolver, SolveStatus},
         Atomize, SolverAtom,
     },
-    AnalyzerBackend, GraphBackend,
+    AnalyzerBackend, GraphBackend, SolcRange,
 };
+use ethers_core::types::U256;
 use std::borrow::Cow;
 
 use shared::{GraphError, RangeArena};
 
-use std::collections::BTreeMap;
+use std::{cmp::Ordering, collections::BTreeMap};
+
+/// How far [`ContextNode::admitted_witness`] steps an input from its candidate value
+pub const WITNESS_SEARCH_STEPS: u64 = 16;
 
 impl ContextNode {
+    /// Whether the path to this context is feasible. It is not if the context was killed because
+    /// it can't be reached, if it or its parents checked a condition that can't hold (i.e. a
+    /// `require` contradicting an earlier one), or if a live variable of it or its parents has an
+    /// empty range: a minimum above its maximum, or every value between them excluded. A path
+    /// ending in a `revert` or a division by zero is feasible
+    pub fn is_satisfiable(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<bool, GraphError> {
+        if let Some((_, KilledKind::Unreachable)) = self.killed_loc(analyzer)? {
+            return Ok(false);
+        }
+
+        let mut ctxs = self.parent_list(analyzer)?;
+        ctxs.push(*self);
+        for ctx in ctxs {
+            if !ctx.impossible_conds(analyzer)?.is_empty() {
+                return Ok(false);
+            }
+            let vars = ctx
+                .vars(analyzer)
+                .values()
+                .chain(ctx.tmp_vars(analyzer).values());
+            for var in vars.copied().collect::<Vec<_>>() {
+                let var = var.latest_version_or_inherited_in_ctx(*self, analyzer);
+                let Some(range) = var.ref_range(analyzer)? else {
+                    continue;
+                };
+                let range = range.into_owned();
+                if range.unsat(analyzer, arena) {
+                    return Ok(false);
+                }
+                let (Some(min), Some(max)) = (
+                    range.evaled_range_min(analyzer, arena)?.maybe_concrete(),
+                    range.evaled_range_max(analyzer, arena)?.maybe_concrete(),
+                ) else {
+                    continue;
+                };
+                if range.exclusions.complement(&min, &max, arena).is_empty() {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// Whether assigning the inputs the values of `assignment` (keyed by display name, as from
+    /// [`ContextVarNode::witness_max`]) is consistent with this context's constraints: each value
+    /// lies within its input's range here and isn't excluded from it, and no condition on the path
+    /// fails. A constraint that can't be evaluated under the assignment is assumed to hold
+    pub fn admits(
+        &self,
+        assignment: &BTreeMap<String, Concrete>,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<bool, GraphError> {
+        for (name, val) in assignment {
+            let Some(var) = self.var_by_name_or_recurse(analyzer, name)? else {
+                continue;
+            };
+            let var = var.latest_version_or_inherited_in_ctx(*self, analyzer);
+            let Some(range) = var.ref_range(analyzer)? else {
+                continue;
+            };
+            let range = range.into_owned();
+            let (Some(min), Some(max)) = (
+                range.evaled_range_min(analyzer, arena)?.maybe_concrete(),
+                range.evaled_range_max(analyzer, arena)?.maybe_concrete(),
+            ) else {
+                continue;
+            };
+            let val = RangeConcrete::new(val.clone(), min.loc);
+            let allowed = range.exclusions.complement(&min, &max, arena);
+            let within = |range: &SolcRange, arena: &mut RangeArena<Elem<Concrete>>| {
+                let (Some(lo), Some(hi)) = (range.min.maybe_concrete(), range.max.maybe_concrete())
+                else {
+                    return true;
+                };
+                !matches!(
+                    Elem::Concrete(val.clone()).range_ord(&Elem::Concrete(lo), arena),
+                    Some(Ordering::Less)
+                ) && !matches!(
+                    Elem::Concrete(val.clone()).range_ord(&Elem::Concrete(hi), arena),
+                    Some(Ordering::Greater)
+                )
+            };
+            if !allowed.iter().any(|range| within(range, arena)) {
+                return Ok(false);
+            }
+        }
+        for dep in self.ctx_deps(analyzer)? {
+            if let Some(Concrete::Bool(false)) = dep.eval_with(assignment, analyzer, arena)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Finds an assignment of the inputs (keyed by display name) near `candidate` that this
+    /// context [admits](ContextNode::admits) and that `witnesses` accepts, i.e. that still makes
+    /// an access out of bounds. If the candidate itself is ruled out, each unsigned input is in
+    /// turn stepped from its value towards the middle of its type, by up to
+    /// [`WITNESS_SEARCH_STEPS`], so that under `require(i != type(uint256).max)` a candidate of
+    /// `i = 2**256 - 1` becomes `2**256 - 2`. Returns `None` if no such assignment is found
+    pub fn admitted_witness<A: GraphBackend>(
+        &self,
+        candidate: BTreeMap<String, Concrete>,
+        analyzer: &A,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        witnesses: impl Fn(
+            &BTreeMap<String, Concrete>,
+            &A,
+            &mut RangeArena<Elem<Concrete>>,
+        ) -> Result<bool, GraphError>,
+    ) -> Result<Option<BTreeMap<String, Concrete>>, GraphError> {
+        if self.admits(&candidate, analyzer, arena)? && witnesses(&candidate, analyzer, arena)? {
+            return Ok(Some(candidate));
+        }
+        for (name, val) in &candidate {
+            let Concrete::Uint(size, start) = val else {
+                continue;
+            };
+            let downwards = start.bit(*size as usize - 1);
+            for step in 1..=WITNESS_SEARCH_STEPS {
+                let stepped = if downwards {
+                    start.checked_sub(U256::from(step))
+                } else {
+                    start.checked_add(U256::from(step))
+                };
+                let Some(stepped) = stepped else {
+                    break;
+                };
+                let mut assignment = candidate.clone();
+                assignment.insert(name.clone(), Concrete::Uint(*size, stepped));
+                if self.admits(&assignment, analyzer, arena)?
+                    && witnesses(&assignment, analyzer, arena)?
+                {
+                    return Ok(Some(assignment));
+                }
+            }
+        }
+        Ok(None)
+    }
+
     /// Use a Difference Logic solver to see if it is unreachable
     pub fn unreachable(
         &self,
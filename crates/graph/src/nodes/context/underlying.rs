@@ -1,7 +1,7 @@
 use crate::{
     nodes::{
-        CallFork, ContextCache, ContextNode, ContextVarNode, ExprRet, FunctionNode, KilledKind,
-        ModifierState,
+        CallFork, ContextCache, ContextNode, ContextVarNode, ExprRet, FunctionNode, GuardKind,
+        KilledKind, LinearEq, LoopInfo, ModifierState, OobIndex,
     },
     solvers::dl::DLSolver,
     AnalyzerBackend,
@@ -62,6 +62,38 @@ pub struct Context {
     pub dl_solver: DLSolver,
     /// Functions applied (but not reparsed) in this context
     pub applies: Vec<FunctionNode>,
+    /// Events emitted in this context: the emit location, the event name and the argument variables
+    pub emits: Vec<(Loc, String, Vec<ContextVarNode>)>,
+    /// If this is a loop subcontext, how the loop body was interpreted
+    pub loop_info: Option<LoopInfo>,
+    /// Array accesses in this context whose index may not be less than the array length
+    pub oob_indices: Vec<OobIndex>,
+    /// Checked arithmetic in this context whose result may overflow its type: the operation
+    /// location and the result variable
+    pub possible_overflows: Vec<(Loc, ContextVarNode)>,
+    /// Names of locals declared without a value that have been neither assigned nor read on the
+    /// path to this context
+    pub uninit_locals: BTreeSet<String>,
+    /// The number of nodes in the graph when the analysis of the function this context belongs
+    /// to began
+    pub entry_node_count: usize,
+    /// Reads of locals before they were assigned on the path to this context: the read location
+    /// and the variable read
+    pub uninit_reads: Vec<(Loc, ContextVarNode)>,
+    /// Linear equalities between variables that hold on the path to this context, tracked if the
+    /// analyzer tracks relations
+    pub linear_eqs: Vec<LinearEq>,
+    /// `require`s and `assert`s checked in this context: the call location and the builtin
+    pub guards: Vec<(Loc, GuardKind)>,
+    /// Locations of conditions checked in this context that were not already known to hold, i.e.
+    /// that may fail
+    pub failable_conds: Vec<Loc>,
+    /// Locations of conditions checked in this context that can't hold, making the path past them
+    /// infeasible
+    pub impossible_conds: Vec<Loc>,
+    /// Comparisons evaluated in this context: the comparison location and, if the operands'
+    /// ranges already decided it, the value it always has
+    pub constant_cmps: Vec<(Loc, Option<bool>)>,
 }
 
 impl Context {
@@ -92,6 +124,18 @@ impl Context {
             cache: Default::default(),
             dl_solver: Default::default(),
             applies: Default::default(),
+            emits: vec![],
+            loop_info: None,
+            oob_indices: vec![],
+            possible_overflows: vec![],
+            uninit_locals: Default::default(),
+            entry_node_count: 0,
+            uninit_reads: vec![],
+            linear_eqs: vec![],
+            guards: vec![],
+            failable_conds: vec![],
+            impossible_conds: vec![],
+            constant_cmps: vec![],
         }
     }
 
@@ -242,6 +286,30 @@ impl Context {
             },
             dl_solver: parent_ctx.underlying(analyzer)?.dl_solver.clone(),
             applies: Default::default(),
+            emits: vec![],
+            loop_info: None,
+            oob_indices: vec![],
+            possible_overflows: vec![],
+            uninit_locals: if fork_expr.is_some() {
+                parent_ctx.underlying(analyzer)?.uninit_locals.clone()
+            } else if let Some(ret_ctx) = returning_ctx {
+                ret_ctx.underlying(analyzer)?.uninit_locals.clone()
+            } else {
+                Default::default()
+            },
+            entry_node_count: parent_ctx.underlying(analyzer)?.entry_node_count,
+            uninit_reads: vec![],
+            linear_eqs: if fork_expr.is_some() {
+                parent_ctx.underlying(analyzer)?.linear_eqs.clone()
+            } else if let Some(ret_ctx) = returning_ctx {
+                ret_ctx.non_storage_linear_eqs(analyzer)?
+            } else {
+                vec![]
+            },
+            guards: vec![],
+            failable_conds: vec![],
+            impossible_conds: vec![],
+            constant_cmps: vec![],
         })
     }
 
@@ -305,6 +373,18 @@ impl Context {
             },
             dl_solver: parent_ctx.underlying(analyzer)?.dl_solver.clone(),
             applies: Default::default(),
+            emits: vec![],
+            loop_info: Some(LoopInfo::default()),
+            oob_indices: vec![],
+            possible_overflows: vec![],
+            uninit_locals: parent_ctx.underlying(analyzer)?.uninit_locals.clone(),
+            entry_node_count: parent_ctx.underlying(analyzer)?.entry_node_count,
+            uninit_reads: vec![],
+            linear_eqs: parent_ctx.underlying(analyzer)?.linear_eqs.clone(),
+            guards: vec![],
+            failable_conds: vec![],
+            impossible_conds: vec![],
+            constant_cmps: vec![],
         })
     }
 
@@ -6,4 +6,5 @@ mod underlying;
 mod versioning;
 
 pub use node::*;
+pub use ranging::RangeKind;
 pub use underlying::*;
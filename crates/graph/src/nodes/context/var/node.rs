@@ -426,6 +426,35 @@ impl ContextVarNode {
         Ok(result)
     }
 
+    /// Every variable this variable's range ultimately derives from: the variables it was
+    /// constructed from ([`Self::dependent_on`]) and those its range references, followed
+    /// transitively. The variable itself is not included, even if it depends on itself
+    pub fn dependencies(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Vec<Self>, GraphError> {
+        let mut seen = vec![*self];
+        let mut stack = vec![*self];
+        let mut result = vec![];
+
+        while let Some(node) = stack.pop() {
+            let mut deps = node.dependent_on(analyzer, false)?;
+            if let Some(range) = node.ref_range(analyzer)? {
+                deps.extend(range.dependent_on(analyzer, arena));
+            }
+            deps.into_iter().for_each(|dep| {
+                if !seen.contains(&dep) {
+                    seen.push(dep);
+                    stack.push(dep);
+                    result.push(dep);
+                }
+            });
+        }
+
+        Ok(result)
+    }
+
     pub fn graph_dependent_on(
         &self,
         analyzer: &impl GraphBackend,
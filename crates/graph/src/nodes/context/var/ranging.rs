@@ -1,14 +1,32 @@
 use crate::range::elem::*;
 use crate::{
     nodes::{Concrete, ContextVarNode},
-    range::{range_string::ToRangeString, Range, RangeEval},
-    AnalyzerBackend, GraphBackend, SolcRange, VarType,
+    range::{range_string::ToRangeString, Exclusions, Range, RangeEval},
+    AnalyzerBackend, GraphBackend, Node, SolcRange, VarType,
 };
 
 use shared::{GraphError, RangeArena};
 
+use ethers_core::types::{Address, H256, I256, U256};
+
 use solang_parser::pt::Loc;
 
+use std::collections::BTreeMap;
+
+/// The shape of a variable's evaluated range
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeKind {
+    /// No value satisfies the range: the minimum is greater than the maximum, or the variable's
+    /// context was killed
+    Empty,
+    /// The range is a single concrete value
+    Singleton(Concrete),
+    /// The range is narrower than the variable's type allows
+    Bounded,
+    /// The range spans every value of the variable's type, or has no range at all
+    Unbounded,
+}
+
 impl ContextVarNode {
     pub fn range(&self, analyzer: &impl GraphBackend) -> Result<Option<SolcRange>, GraphError> {
         self.underlying(analyzer)?.ty.range(analyzer)
@@ -58,6 +76,48 @@ impl ContextVarNode {
         }
     }
 
+    /// Classifies the evaluated range of this variable, see [`RangeKind`]
+    pub fn range_kind(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<RangeKind, GraphError> {
+        if let Some(ctx) = self.maybe_ctx(analyzer) {
+            if ctx.is_killed(analyzer)? {
+                return Ok(RangeKind::Empty);
+            }
+        }
+
+        let (Some(min), Some(max)) = (
+            self.evaled_range_min(analyzer, arena)?,
+            self.evaled_range_max(analyzer, arena)?,
+        ) else {
+            return Ok(RangeKind::Unbounded);
+        };
+
+        match min.range_ord(&max, arena) {
+            Some(std::cmp::Ordering::Greater) => return Ok(RangeKind::Empty),
+            Some(std::cmp::Ordering::Equal) => {
+                if let Some(val) = min.maybe_concrete_value() {
+                    return Ok(RangeKind::Singleton(val.val));
+                }
+            }
+            _ => {}
+        }
+
+        let (Some(ty_min), Some(ty_max)) = (
+            self.ty_min_concrete(analyzer)?,
+            self.ty_max_concrete(analyzer)?,
+        ) else {
+            return Ok(RangeKind::Unbounded);
+        };
+        if min.range_eq(&Elem::from(ty_min), arena) && max.range_eq(&Elem::from(ty_max), arena) {
+            Ok(RangeKind::Unbounded)
+        } else {
+            Ok(RangeKind::Bounded)
+        }
+    }
+
     pub fn ref_range<'a>(
         &self,
         analyzer: &'a impl GraphBackend,
@@ -111,6 +171,94 @@ impl ContextVarNode {
         }
     }
 
+    /// The concrete value of this variable, if its evaluated range is a single constant. Unlike
+    /// [`ContextVarNode::as_concrete`] this also holds for variables narrowed to a constant
+    pub fn evaled_concrete(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<Concrete>, GraphError> {
+        if !self.is_const(analyzer, arena)? {
+            return Ok(None);
+        }
+        Ok(self
+            .evaled_range_min(analyzer, arena)?
+            .and_then(|min| min.maybe_concrete_value())
+            .map(|min| min.val))
+    }
+
+    /// The value of this variable if it is a constant unsigned integer
+    pub fn as_concrete_uint(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<U256>, GraphError> {
+        match self.evaled_concrete(analyzer, arena)? {
+            Some(Concrete::Uint(_, val)) => Ok(Some(val)),
+            _ => Ok(None),
+        }
+    }
+
+    /// The value of this variable if it is a constant signed integer
+    pub fn as_concrete_int(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<I256>, GraphError> {
+        match self.evaled_concrete(analyzer, arena)? {
+            Some(Concrete::Int(_, val)) => Ok(Some(val)),
+            _ => Ok(None),
+        }
+    }
+
+    /// The value of this variable if it is a constant bool
+    pub fn as_concrete_bool(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<bool>, GraphError> {
+        match self.evaled_concrete(analyzer, arena)? {
+            Some(Concrete::Bool(val)) => Ok(Some(val)),
+            _ => Ok(None),
+        }
+    }
+
+    /// The value of this variable if it is a constant address
+    pub fn as_concrete_address(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<Address>, GraphError> {
+        match self.evaled_concrete(analyzer, arena)? {
+            Some(Concrete::Address(val)) => Ok(Some(val)),
+            _ => Ok(None),
+        }
+    }
+
+    /// The value of this variable if it is a constant fixed size `bytesN`, left aligned
+    pub fn as_concrete_bytes(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<H256>, GraphError> {
+        match self.evaled_concrete(analyzer, arena)? {
+            Some(Concrete::Bytes(_, val)) => Ok(Some(val)),
+            _ => Ok(None),
+        }
+    }
+
+    /// The value of this variable if it is a constant string
+    pub fn as_concrete_string(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<String>, GraphError> {
+        match self.evaled_concrete(analyzer, arena)? {
+            Some(Concrete::String(val)) => Ok(Some(val)),
+            _ => Ok(None),
+        }
+    }
+
     pub fn as_range_elem(
         &self,
         analyzer: &impl GraphBackend,
@@ -309,7 +457,7 @@ impl ContextVarNode {
     pub fn set_range_exclusions(
         &self,
         analyzer: &mut impl GraphBackend,
-        new_exclusions: Vec<Elem<Concrete>>,
+        new_exclusions: Exclusions,
     ) -> Result<(), GraphError> {
         tracing::trace!(
             "setting range exclusions for {}",
@@ -405,7 +553,7 @@ impl ContextVarNode {
     pub fn try_set_range_exclusions(
         &self,
         analyzer: &mut impl GraphBackend,
-        new_exclusions: Vec<Elem<Concrete>>,
+        new_exclusions: Exclusions,
     ) -> Result<bool, GraphError> {
         tracing::trace!(
             "setting range exclusions for: {}",
@@ -447,4 +595,224 @@ impl ContextVarNode {
         }
         Ok(())
     }
+
+    /// Returns an assignment of this variable's input variables (keyed by display name) that
+    /// produces its maximum value, by walking the temporary-construction tree and picking the
+    /// extreme of each input's range that pushes the result up. Relies on each operation being
+    /// monotone in its operands, which only holds for unsigned arithmetic, and divisors are kept
+    /// nonzero. Constants are left out of the assignment. Returns `None` if the tree contains a
+    /// signed value or an unsupported operation, it depends on a value that isn't an input (i.e.
+    /// a call's result), an input lacks a concrete extreme, or an input would need to be both
+    /// maximized and minimized
+    pub fn witness_max(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<BTreeMap<String, Concrete>>, GraphError> {
+        let mut witness = BTreeMap::default();
+        if self.witness_extreme(true, false, analyzer, arena, &mut witness)? {
+            Ok(Some(witness))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns an assignment of this variable's input variables that produces its minimum value.
+    /// See [`ContextVarNode::witness_max`]
+    pub fn witness_min(
+        &self,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<BTreeMap<String, Concrete>>, GraphError> {
+        let mut witness = BTreeMap::default();
+        if self.witness_extreme(false, false, analyzer, arena, &mut witness)? {
+            Ok(Some(witness))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns an assignment of the input variables that maximizes this variable while minimizing
+    /// `other`, i.e. that makes this variable reach `other` if any assignment does. Returns
+    /// `None` if either has no witness or they disagree on an input
+    pub fn witness_max_over(
+        &self,
+        other: ContextVarNode,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<BTreeMap<String, Concrete>>, GraphError> {
+        let (Some(mut witness), Some(other_witness)) = (
+            self.witness_max(analyzer, arena)?,
+            other.witness_min(analyzer, arena)?,
+        ) else {
+            return Ok(None);
+        };
+        for (name, val) in other_witness {
+            match witness.get(&name) {
+                Some(existing) if *existing != val => return Ok(None),
+                _ => {
+                    witness.insert(name, val);
+                }
+            }
+        }
+        Ok(Some(witness))
+    }
+
+    /// Evaluates this variable with its inputs set to the values of `assignment` (keyed by display
+    /// name), by replaying the temporary-construction tree. Returns `None` if it depends on a
+    /// value that is neither in the assignment nor constant, or an operation can't be evaluated
+    pub fn eval_with(
+        &self,
+        assignment: &BTreeMap<String, Concrete>,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<Concrete>, GraphError> {
+        if let Some(tmp) = self.tmp_of(analyzer)? {
+            let Some(lhs) = tmp.lhs.eval_with(assignment, analyzer, arena)? else {
+                return Ok(None);
+            };
+            let Some(rhs) = tmp.rhs else {
+                if let RangeOp::Cast = tmp.op {
+                    let builtin = self.ty(analyzer)?.as_builtin(analyzer).ok();
+                    return Ok(builtin.and_then(|builtin| lhs.cast(builtin)));
+                }
+                return Ok(None);
+            };
+            let Some(rhs) = rhs.eval_with(assignment, analyzer, arena)? else {
+                return Ok(None);
+            };
+            let expr = Elem::Expr(RangeExpr::new(Elem::from(lhs), tmp.op, Elem::from(rhs)));
+            return Ok(expr
+                .maximize(analyzer, arena)?
+                .maybe_concrete()
+                .map(|c| c.val));
+        }
+
+        if let Some(val) = assignment.get(&self.display_name(analyzer)?) {
+            return Ok(Some(val.clone()));
+        }
+        if self.is_const(analyzer, arena)? {
+            return Ok(self
+                .evaled_range_min(analyzer, arena)?
+                .and_then(|min| min.maybe_concrete())
+                .map(|c| c.val));
+        }
+        // an assigned variable's range just references the assigned value, follow it through
+        let bound = self.range_min(analyzer)?;
+        match bound.and_then(|bound| referenced_var(bound, analyzer, arena)) {
+            Some(assigned) if assigned != *self => assigned.eval_with(assignment, analyzer, arena),
+            _ => Ok(None),
+        }
+    }
+
+    /// Picks the extreme of this variable's inputs, recording them in `witness`. If `nonzero` is
+    /// set, the picked value may not be zero, as is the case for a divisor
+    fn witness_extreme(
+        &self,
+        maximize: bool,
+        nonzero: bool,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        witness: &mut BTreeMap<String, Concrete>,
+    ) -> Result<bool, GraphError> {
+        if self.is_int(analyzer)? {
+            return Ok(false);
+        }
+
+        if let Some(tmp) = self.tmp_of(analyzer)? {
+            if let RangeOp::Cast = tmp.op {
+                return tmp
+                    .lhs
+                    .witness_extreme(maximize, nonzero, analyzer, arena, witness);
+            } else if nonzero {
+                return Ok(false);
+            }
+            let rhs_maximize = match tmp.op {
+                RangeOp::Add(_) | RangeOp::Mul(_) | RangeOp::Min | RangeOp::Max => maximize,
+                RangeOp::Sub(_) | RangeOp::Div(_) => !maximize,
+                _ => return Ok(false),
+            };
+            let Some(rhs) = tmp.rhs else {
+                return Ok(false);
+            };
+            let rhs_nonzero = matches!(tmp.op, RangeOp::Div(_));
+            return Ok(tmp
+                .lhs
+                .witness_extreme(maximize, false, analyzer, arena, witness)?
+                && rhs.witness_extreme(rhs_maximize, rhs_nonzero, analyzer, arena, witness)?);
+        }
+
+        // an assigned variable's range just references the assigned value, follow it through
+        let bound = if maximize {
+            self.range_max(analyzer)?
+        } else {
+            self.range_min(analyzer)?
+        };
+        if let Some(assigned) = bound.and_then(|bound| referenced_var(bound, analyzer, arena)) {
+            if assigned != *self {
+                return assigned.witness_extreme(maximize, nonzero, analyzer, arena, witness);
+            }
+        }
+
+        let extreme = if maximize {
+            self.evaled_range_max(analyzer, arena)?
+        } else {
+            self.evaled_range_min(analyzer, arena)?
+        };
+        let Some(mut val) = extreme
+            .and_then(|elem| elem.maybe_concrete())
+            .map(|c| c.val)
+        else {
+            return Ok(false);
+        };
+        if nonzero && val.is_zero() {
+            // the smallest nonzero value, if the range has one
+            let Some(one) = val.one() else {
+                return Ok(false);
+            };
+            let max = self
+                .evaled_range_max(analyzer, arena)?
+                .and_then(|elem| elem.maybe_concrete())
+                .and_then(|c| c.val.into_u256());
+            if maximize || max.map_or(true, |max| max.is_zero()) {
+                return Ok(false);
+            }
+            val = one;
+        }
+        // literals and constants can't be picked, and other values aren't the caller's to pick
+        if self.is_const(analyzer, arena)? {
+            return Ok(true);
+        } else if !self.is_fundamental(analyzer)? {
+            return Ok(false);
+        }
+        let name = self.display_name(analyzer)?;
+        match witness.get(&name) {
+            Some(existing) if *existing != val => Ok(false),
+            _ => {
+                witness.insert(name, val);
+                Ok(true)
+            }
+        }
+    }
+}
+
+/// If a range bound is (a cast of) a reference to a context variable, returns that variable
+fn referenced_var(
+    elem: Elem<Concrete>,
+    analyzer: &impl GraphBackend,
+    arena: &mut RangeArena<Elem<Concrete>>,
+) -> Option<ContextVarNode> {
+    match elem {
+        Elem::Arena(_) => referenced_var(elem.dearenaize_clone(arena), analyzer, arena),
+        Elem::Expr(RangeExpr {
+            lhs,
+            op: RangeOp::Cast,
+            ..
+        }) => referenced_var(*lhs, analyzer, arena),
+        Elem::Reference(Reference { idx, .. }) => match analyzer.node(idx) {
+            Node::ContextVar(_) => Some(idx.into()),
+            _ => None,
+        },
+        _ => None,
+    }
 }
@@ -2,6 +2,7 @@ use crate::{
     elem::Elem,
     nodes::{
         Builtin, Concrete, ContextNode, ContextVarNode, EnumNode, ErrorNode, StructNode, TyNode,
+        VarNode,
     },
     range::{
         elem::{RangeElem, RangeExpr, RangeOp},
@@ -251,6 +252,37 @@ impl ContextVarNode {
         }
     }
 
+    /// If this variable is a storage reference (i.e. `S storage s = x;`), the storage variable it
+    /// aliases as of this version. Reads and writes through the reference go to that variable.
+    /// Reassigning the reference (`s = y;`) points a later version of it elsewhere, so the most
+    /// recent alias at or before this version (including inherited ones) wins
+    pub fn storage_referent(&self, analyzer: &impl GraphBackend) -> Option<ContextVarNode> {
+        let mut current = *self;
+        loop {
+            let mut edges = analyzer
+                .graph()
+                .edges_directed(current.0.into(), Direction::Outgoing);
+            if let Some(referent) = edges
+                .clone()
+                .find(|edge| Edge::Context(ContextEdge::StorageAssign) == *edge.weight())
+            {
+                return Some(ContextVarNode::from(referent.target()));
+            }
+            current = match current.previous_version(analyzer) {
+                Some(prev) => prev,
+                None => edges
+                    .find(|edge| {
+                        matches!(
+                            edge.weight(),
+                            Edge::Context(ContextEdge::InheritedVariable)
+                                | Edge::Context(ContextEdge::InputVariable)
+                        )
+                    })
+                    .map(|edge| ContextVarNode::from(edge.target()))?,
+            };
+        }
+    }
+
     pub fn is_independent(&self, analyzer: &impl GraphBackend) -> Result<bool, GraphError> {
         Ok(self.dependent_on(analyzer, false)?.is_empty() && self.tmp_of(analyzer)?.is_none())
     }
@@ -310,6 +342,19 @@ impl ContextVarNode {
         underlying.ty.is_const(analyzer, arena)
     }
 
+    /// Whether this variable is a contract-level `constant` folded into the context
+    pub fn is_contract_constant(&self, analyzer: &impl GraphBackend) -> Result<bool, GraphError> {
+        let Some(var) = analyzer
+            .graph()
+            .edges_directed(self.first_version(analyzer).into(), Direction::Outgoing)
+            .find(|edge| *edge.weight() == Edge::Context(ContextEdge::ContractVariable))
+            .map(|edge| VarNode::from(edge.target()))
+        else {
+            return Ok(false);
+        };
+        var.is_constant(analyzer)
+    }
+
     pub fn is_symbolic(&self, analyzer: &impl GraphBackend) -> Result<bool, GraphError> {
         Ok(self.underlying(analyzer)?.is_symbolic)
     }
@@ -603,6 +648,14 @@ impl ContextVarNode {
         self.ty(analyzer)?.is_int(analyzer)
     }
 
+    pub fn is_address(&self, analyzer: &impl GraphBackend) -> Result<bool, GraphError> {
+        self.ty(analyzer)?.is_address(analyzer)
+    }
+
+    pub fn is_string(&self, analyzer: &impl GraphBackend) -> Result<bool, GraphError> {
+        self.ty(analyzer)?.is_string(analyzer)
+    }
+
     pub fn cast_exprs(
         &self,
         to_ty: &VarType,
@@ -630,7 +683,12 @@ impl ContextVarNode {
                 .range_min(analyzer)?
                 .unwrap()
                 .cast(to_range.min.clone())
-                .max((*self).range_max(analyzer)?.unwrap().cast(to_range.min));
+                .max(
+                    (*self)
+                        .range_max(analyzer)?
+                        .unwrap()
+                        .cast(to_range.min.clone()),
+                );
 
             if let Some(r) = self.ref_range(analyzer)? {
                 let zero = Elem::from(Concrete::from(U256::zero()));
@@ -666,6 +724,22 @@ impl ContextVarNode {
                             }
                         }
                     }
+                    (Builtin::Uint(from_size), Builtin::Uint(to_size)) if to_size < from_size => {
+                        // truncation wraps modulo 2**to_size, so if the range crosses a
+                        // multiple of it the result can be any value of the narrower type
+                        let min = self.evaled_range_min(analyzer, arena)?;
+                        let max = self.evaled_range_max(analyzer, arena)?;
+                        if let (Some(min), Some(max)) = (
+                            min.and_then(|min| min.maybe_concrete()?.val.uint_val()),
+                            max.and_then(|max| max.maybe_concrete()?.val.uint_val()),
+                        ) {
+                            let modulus = U256::from(1) << *to_size;
+                            if max.saturating_sub(min) >= modulus || min % modulus > max % modulus {
+                                min_expr = to_range.min.clone();
+                                max_expr = to_range.max.clone();
+                            }
+                        }
+                    }
                     _ => {}
                 }
             }
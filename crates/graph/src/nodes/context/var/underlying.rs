@@ -3,7 +3,7 @@ use crate::{
         BuiltInNode, Builtin, Concrete, ConcreteNode, ContextNode, ContextVarNode, ContractNode,
         EnumNode, Field, FunctionNode, FunctionParam, FunctionReturn, StructNode, TyNode,
     },
-    range::Range,
+    range::{Exclusions, Range},
     AnalyzerBackend, GraphBackend, Node, SolcRange, TypeNode, VarType,
 };
 
@@ -23,6 +23,8 @@ pub struct ContextVar {
     pub dep_on: Option<Vec<ContextVarNode>>,
     pub is_symbolic: bool,
     pub is_return: bool,
+    /// The source text of a number literal (i.e. `1 ether`), to show alongside its value
+    pub source_repr: Option<String>,
     pub ty: VarType,
 }
 
@@ -86,6 +88,7 @@ impl ContextVar {
             is_tmp: true,
             is_symbolic: lhs_cvar.is_symbolic(analyzer)? || rhs_cvar.is_symbolic(analyzer)?,
             is_return: false,
+            source_repr: None,
             tmp_of: Some(TmpConstruction::new(lhs_cvar, op, Some(rhs_cvar))),
             dep_on: {
                 let mut deps = lhs_cvar.dependent_on(analyzer, true)?;
@@ -117,6 +120,7 @@ impl ContextVar {
             dep_on: None,
             is_symbolic: false,
             is_return: false,
+            source_repr: None,
             ty: VarType::Concrete(concrete_node),
         })
     }
@@ -150,6 +154,7 @@ impl ContextVar {
         let mut new_tmp = self.clone();
         new_tmp.loc = Some(loc);
         new_tmp.is_tmp = true;
+        new_tmp.source_repr = None;
         new_tmp.name = format!("tmp{}({})", ctx.new_tmp(analyzer)?, self.name);
         new_tmp.display_name = format!("tmp_{}", self.display_name);
         Ok(new_tmp)
@@ -170,6 +175,7 @@ impl ContextVar {
             dep_on: None,
             is_symbolic: true,
             is_return: false,
+            source_repr: None,
             ty: VarType::User(
                 TypeNode::Contract(contract_node),
                 SolcRange::try_from_builtin(&Builtin::Address),
@@ -197,6 +203,7 @@ impl ContextVar {
             dep_on: None,
             is_symbolic: true,
             is_return: false,
+            source_repr: None,
             ty: VarType::User(TypeNode::Struct(struct_node), None),
         })
     }
@@ -221,6 +228,7 @@ impl ContextVar {
             dep_on: None,
             is_symbolic: true,
             is_return: false,
+            source_repr: None,
             ty: VarType::try_from_idx(analyzer, ty_node.0.into()).unwrap(),
         })
     }
@@ -240,6 +248,7 @@ impl ContextVar {
             dep_on: None,
             is_symbolic: false,
             is_return: false,
+            source_repr: None,
             ty: VarType::try_from_idx(analyzer, bn_node.into()).unwrap(),
         })
     }
@@ -400,7 +409,7 @@ impl ContextVar {
 
     pub fn set_range_exclusions(
         &mut self,
-        new_exclusions: Vec<Elem<Concrete>>,
+        new_exclusions: Exclusions,
         fallback_range: Option<SolcRange>,
     ) -> Result<(), GraphError> {
         match &mut self.ty {
@@ -409,10 +418,10 @@ impl ContextVar {
             | VarType::User(TypeNode::Ty(_), ref mut maybe_range)
             | VarType::BuiltIn(_, ref mut maybe_range) => {
                 if let Some(range) = maybe_range {
-                    range.set_range_exclusions(new_exclusions);
+                    range.set_range_exclusions(new_exclusions.into());
                     Ok(())
                 } else if let Some(mut fr) = fallback_range {
-                    fr.set_range_exclusions(new_exclusions);
+                    fr.set_range_exclusions(new_exclusions.into());
                     *maybe_range = Some(fr);
                     Ok(())
                 } else {
@@ -457,7 +466,7 @@ impl ContextVar {
 
     pub fn try_set_range_exclusions(
         &mut self,
-        new_exclusions: Vec<Elem<Concrete>>,
+        new_exclusions: Exclusions,
         fallback_range: Option<SolcRange>,
     ) -> bool {
         match &mut self.ty {
@@ -466,10 +475,10 @@ impl ContextVar {
             | VarType::User(TypeNode::Ty(_), ref mut maybe_range)
             | VarType::BuiltIn(_, ref mut maybe_range) => {
                 if let Some(range) = maybe_range {
-                    range.set_range_exclusions(new_exclusions);
+                    range.set_range_exclusions(new_exclusions.into());
                     true
                 } else if let Some(mut fr) = fallback_range {
-                    fr.set_range_exclusions(new_exclusions);
+                    fr.set_range_exclusions(new_exclusions.into());
                     *maybe_range = Some(fr);
                     true
                 } else {
@@ -536,6 +545,7 @@ impl ContextVar {
                 dep_on: None,
                 is_symbolic: true,
                 is_return: false,
+                source_repr: None,
                 ty,
             })
         } else {
@@ -564,6 +574,7 @@ impl ContextVar {
                 dep_on: None,
                 is_symbolic: true,
                 is_return: false,
+                source_repr: None,
                 ty,
             })
         } else {
@@ -589,6 +600,7 @@ impl ContextVar {
             dep_on: None,
             is_symbolic: true,
             is_return: false,
+            source_repr: None,
             ty: VarType::User(
                 TypeNode::Enum(enum_node),
                 Some(enum_node.range_from_variant(variant, analyzer)?),
@@ -615,6 +627,7 @@ impl ContextVar {
             dep_on: None,
             is_symbolic: index.underlying(analyzer)?.is_symbolic,
             is_return: false,
+            source_repr: None,
             ty: parent_var.dynamic_underlying_ty(analyzer)?,
         })
     }
@@ -633,6 +646,7 @@ impl ContextVar {
             dep_on: None,
             is_symbolic: false,
             is_return: false,
+            source_repr: None,
             ty: VarType::User(TypeNode::Func(func), None),
         })
     }
@@ -653,6 +667,7 @@ impl ContextVar {
                     dep_on: None,
                     is_symbolic: true,
                     is_return: false,
+                    source_repr: None,
                     ty,
                 })
             } else {
@@ -679,6 +694,7 @@ impl ContextVar {
                     dep_on: None,
                     is_symbolic: true,
                     is_return: true,
+                    source_repr: None,
                     ty,
                 })
             } else {
@@ -711,6 +727,7 @@ impl ContextVar {
                 dep_on: None,
                 is_symbolic: true,
                 is_return: true,
+                source_repr: None,
                 ty,
             }))
         } else {
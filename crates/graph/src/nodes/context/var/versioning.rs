@@ -286,4 +286,38 @@ impl ContextVarNode {
                 .next()
         }
     }
+
+    /// Marks this version as written (i.e. assigned to) rather than narrowed, so it no longer
+    /// holds the value of the version before it
+    pub fn mark_written(&self, analyzer: &mut impl GraphBackend) {
+        if let Some(prev) = self.previous_or_inherited_version(analyzer) {
+            analyzer.add_edge(*self, prev, Edge::Context(ContextEdge::Assign));
+        }
+    }
+
+    /// Whether this version was written rather than narrowed from the version before it
+    pub fn is_written(&self, analyzer: &impl GraphBackend) -> bool {
+        analyzer
+            .graph()
+            .edges_directed(self.0.into(), Direction::Outgoing)
+            .any(|edge| Edge::Context(ContextEdge::Assign) == *edge.weight())
+    }
+
+    /// Whether this version still holds the value of `earlier`, i.e. it is `earlier` or was only
+    /// narrowed from it
+    pub fn holds_value_of(&self, earlier: ContextVarNode, analyzer: &impl GraphBackend) -> bool {
+        let mut curr = *self;
+        loop {
+            if curr == earlier {
+                return true;
+            }
+            if curr.is_written(analyzer) {
+                return false;
+            }
+            match curr.previous_or_inherited_version(analyzer) {
+                Some(prev) => curr = prev,
+                None => return false,
+            }
+        }
+    }
 }
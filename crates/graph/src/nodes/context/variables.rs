@@ -252,6 +252,14 @@ impl ContextNode {
         &self.underlying(analyzer).unwrap().cache.vars
     }
 
+    /// Gets the temporary variables of a context, by display name
+    pub fn tmp_vars<'a>(
+        &self,
+        analyzer: &'a impl GraphBackend,
+    ) -> &'a BTreeMap<String, ContextVarNode> {
+        &self.underlying(analyzer).unwrap().cache.tmp_vars
+    }
+
     /// Gets all variables associated with a context
     pub fn all_vars(&self, analyzer: &impl GraphBackend) -> BTreeMap<String, ContextVarNode> {
         analyzer
@@ -1,10 +1,10 @@
 use crate::{
     nodes::{
-        Concrete, EnumNode, ErrorNode, FunctionNode, SourceUnitNode, SourceUnitPartNode,
-        StructNode, TyNode, VarNode,
+        Concrete, ContextVarNode, EnumNode, ErrorNode, FunctionNode, SourceUnitNode,
+        SourceUnitPartNode, StructNode, TyNode, VarNode,
     },
     range::elem::Elem,
-    AnalyzerBackend, AsDotStr, Edge, GraphBackend, Node,
+    AnalyzerBackend, AsDotStr, ContextEdge, Edge, GraphBackend, Node,
 };
 use shared::{GraphError, NodeIdx, RangeArena, Search};
 
@@ -190,6 +190,28 @@ impl ContractNode {
         ret
     }
 
+    /// Gets every write to storage made from this contract's functions as
+    /// (written storage variable, written value, location of the write)
+    pub fn storage_writes(
+        &self,
+        analyzer: &impl GraphBackend,
+    ) -> Vec<(ContextVarNode, ContextVarNode, Loc)> {
+        analyzer
+            .graph()
+            .edge_references()
+            .filter(|edge| *edge.weight() == Edge::Context(ContextEdge::StorageWrite))
+            .filter_map(|edge| {
+                let slot = ContextVarNode::from(edge.source());
+                let value = ContextVarNode::from(edge.target());
+                let func = slot.maybe_ctx(analyzer)?.associated_fn(analyzer).ok()?;
+                if func.maybe_slow_associated_contract(analyzer)? != *self {
+                    return None;
+                }
+                Some((slot, value, slot.loc(analyzer).ok()?))
+            })
+            .collect()
+    }
+
     pub fn funcs_mapping(
         &self,
         analyzer: &mut (impl Search + AnalyzerBackend),
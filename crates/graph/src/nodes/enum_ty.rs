@@ -62,28 +62,43 @@ impl EnumNode {
         Ok(self.underlying(analyzer)?.variants())
     }
 
+    /// The range of the enum's ordinals, `[0, variant_count - 1]`
     pub fn maybe_default_range(
         &self,
         analyzer: &impl GraphBackend,
     ) -> Result<Option<SolcRange>, GraphError> {
         let variants = self.variants(analyzer)?;
         if !variants.is_empty() {
-            let min = Concrete::from(variants.first().unwrap().clone()).into();
-            let max = Concrete::from(variants.last().unwrap().clone()).into();
+            let min = Concrete::from(U256::zero()).into();
+            let max = Concrete::from(U256::from(variants.len() - 1)).into();
             Ok(Some(SolcRange::new(min, max, vec![])))
         } else {
             Ok(None)
         }
     }
 
+    /// The ordinal of a variant. Errors with the enum's name and variants if it has no such variant
+    pub fn variant_ordinal(
+        &self,
+        variant: &str,
+        analyzer: &impl GraphBackend,
+    ) -> Result<usize, GraphError> {
+        let variants = self.variants(analyzer)?;
+        variants.iter().position(|v| v == variant).ok_or_else(|| {
+            GraphError::UnknownVariable(format!(
+                "Enum \"{}\" has no member \"{variant}\", available members: {}",
+                self.name(analyzer).unwrap_or_default(),
+                variants.join(", ")
+            ))
+        })
+    }
+
     pub fn range_from_variant(
         &self,
         variant: String,
         analyzer: &impl GraphBackend,
     ) -> Result<SolcRange, GraphError> {
-        let variants = self.variants(analyzer)?;
-        assert!(variants.contains(&variant));
-        let val = U256::from(variants.iter().position(|v| v == &variant).unwrap());
+        let val = U256::from(self.variant_ordinal(&variant, analyzer)?);
         let min = Concrete::from(val).into();
         let max = Concrete::from(val).into();
         Ok(SolcRange::new(min, max, vec![]))
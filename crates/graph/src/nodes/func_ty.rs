@@ -1,5 +1,7 @@
 use crate::{
-    nodes::{Concrete, ContextNode, ContractNode, SourceUnitNode, SourceUnitPartNode},
+    nodes::{
+        Concrete, ContextNode, ContextVarNode, ContractNode, SourceUnitNode, SourceUnitPartNode,
+    },
     range::elem::Elem,
     AnalyzerBackend, AsDotStr, ContextEdge, Edge, GraphBackend, Node, SolcRange, VarType,
 };
@@ -10,11 +12,12 @@ use petgraph::{visit::EdgeRef, Direction};
 use solang_parser::{
     helpers::CodeLocation,
     pt::{
-        Base, Expression, FunctionAttribute, FunctionDefinition, FunctionTy, Identifier, Loc,
-        Mutability, Parameter, ParameterList, Statement, Type, VariableDefinition, Visibility,
+        Base, CatchClause, Expression, FunctionAttribute, FunctionDefinition, FunctionTy,
+        Identifier, Loc, Mutability, Parameter, ParameterList, Statement, Type, VariableDefinition,
+        Visibility,
     },
 };
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 pub enum FuncVis {
     Pure,
@@ -185,6 +188,24 @@ impl FunctionNode {
         }
     }
 
+    /// The canonical signature of the function, i.e. `transfer(address,uint256)`, from which
+    /// its selector is derived
+    pub fn canonical_signature(&self, analyzer: &impl GraphBackend) -> Result<String, GraphError> {
+        let name = self
+            .prefix_only_name(analyzer)?
+            .ok_or_else(|| GraphError::NodeConfusion("Function has no signature".to_string()))?;
+        let params = self
+            .params(analyzer)
+            .iter()
+            .map(|param| {
+                VarType::try_from_idx(analyzer, param.ty(analyzer)?)
+                    .ok_or_else(|| GraphError::NodeConfusion("Non-typeable as type".to_string()))?
+                    .as_abi_string(analyzer)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(format!("{name}({})", params.join(",")))
+    }
+
     pub fn loc_specified_name(
         &self,
         analyzer: &mut impl AnalyzerBackend,
@@ -235,6 +256,52 @@ impl FunctionNode {
         }
     }
 
+    /// The cyclomatic complexity of the function's body: one plus its number of decision points.
+    /// `if`s, loops, `&&`s, `||`s, ternaries and `catch` clauses are each a decision point.
+    /// Functions without a body have a complexity of one
+    pub fn cyclomatic_complexity(&self, analyzer: &impl GraphBackend) -> Result<usize, GraphError> {
+        Ok(1 + self
+            .underlying(analyzer)?
+            .body
+            .as_ref()
+            .map_or(0, stmt_decision_points))
+    }
+
+    /// Gets the names of the storage variables this function may write on any of its paths,
+    /// including writes made by the functions it calls. Writes to an index or field of a storage
+    /// variable count as writes to the variable
+    pub fn written_storage(
+        &self,
+        analyzer: &mut impl AnalyzerBackend,
+    ) -> Result<BTreeSet<String>, GraphError> {
+        let Some(body_ctx) = self.maybe_body_ctx(analyzer) else {
+            return Ok(BTreeSet::default());
+        };
+        let mut ctxs = body_ctx.family_tree(analyzer)?;
+        ctxs.push(body_ctx);
+
+        analyzer
+            .graph()
+            .edge_references()
+            .filter(|edge| *edge.weight() == Edge::Context(ContextEdge::StorageWrite))
+            .map(|edge| ContextVarNode::from(edge.source()))
+            .filter(|slot| {
+                slot.maybe_ctx(analyzer)
+                    .is_some_and(|ctx| ctxs.contains(&ctx))
+            })
+            .map(|mut slot| {
+                // a write to `m[k]` or `s.field` writes the storage variable `m` or `s`
+                while let Some(parent) = slot
+                    .index_access_to_array(analyzer)
+                    .or_else(|| slot.struct_parent(analyzer))
+                {
+                    slot = parent;
+                }
+                slot.name(analyzer)
+            })
+            .collect()
+    }
+
     pub fn maybe_slow_associated_contract(
         &self,
         analyzer: &impl GraphBackend,
@@ -556,6 +623,14 @@ impl FunctionNode {
             .any(|attr| matches!(attr, FunctionAttribute::Mutability(Mutability::View(_)))))
     }
 
+    pub fn is_payable(&self, analyzer: &impl GraphBackend) -> Result<bool, GraphError> {
+        Ok(self
+            .underlying(analyzer)?
+            .attributes
+            .iter()
+            .any(|attr| matches!(attr, FunctionAttribute::Mutability(Mutability::Payable(_)))))
+    }
+
     pub fn visibility(&self, analyzer: &impl GraphBackend) -> Result<FuncVis, GraphError> {
         if self.is_pure(analyzer)? {
             Ok(FuncVis::Pure)
@@ -1094,3 +1169,82 @@ impl FunctionReturn {
         }
     }
 }
+
+/// The number of decision points in a statement, see [`FunctionNode::cyclomatic_complexity`]
+fn stmt_decision_points(stmt: &Statement) -> usize {
+    let exprs = |exprs: &[Expression]| exprs.iter().map(expr_decision_points).sum::<usize>();
+    match stmt {
+        Statement::Block { statements, .. } => statements.iter().map(stmt_decision_points).sum(),
+        Statement::If(_, cond, true_stmt, false_stmt) => {
+            1 + expr_decision_points(cond)
+                + stmt_decision_points(true_stmt)
+                + false_stmt.as_deref().map_or(0, stmt_decision_points)
+        }
+        Statement::While(_, cond, body) | Statement::DoWhile(_, body, cond) => {
+            1 + expr_decision_points(cond) + stmt_decision_points(body)
+        }
+        Statement::For(_, init, cond, post, body) => {
+            1 + [init, post, body]
+                .into_iter()
+                .flatten()
+                .map(|stmt| stmt_decision_points(stmt))
+                .sum::<usize>()
+                + cond.as_deref().map_or(0, expr_decision_points)
+        }
+        Statement::Try(_, expr, success, clauses) => {
+            clauses.len()
+                + expr_decision_points(expr)
+                + success
+                    .as_ref()
+                    .map_or(0, |(_, block)| stmt_decision_points(block))
+                + clauses
+                    .iter()
+                    .map(|clause| match clause {
+                        CatchClause::Simple(_, _, block) | CatchClause::Named(_, _, _, block) => {
+                            stmt_decision_points(block)
+                        }
+                    })
+                    .sum::<usize>()
+        }
+        Statement::Expression(_, expr) => expr_decision_points(expr),
+        Statement::VariableDefinition(_, _, init) => init.as_ref().map_or(0, expr_decision_points),
+        Statement::Return(_, expr) => expr.as_ref().map_or(0, expr_decision_points),
+        Statement::Revert(_, _, args) => exprs(args),
+        Statement::Emit(_, expr) => expr_decision_points(expr),
+        _ => 0,
+    }
+}
+
+/// The number of decision points in an expression, see [`FunctionNode::cyclomatic_complexity`]
+fn expr_decision_points(expr: &Expression) -> usize {
+    let exprs = |exprs: &[Expression]| exprs.iter().map(expr_decision_points).sum::<usize>();
+    match expr {
+        Expression::And(_, lhs, rhs) | Expression::Or(_, lhs, rhs) => {
+            1 + expr_decision_points(lhs) + expr_decision_points(rhs)
+        }
+        Expression::ConditionalOperator(_, cond, true_expr, false_expr) => {
+            1 + expr_decision_points(cond)
+                + expr_decision_points(true_expr)
+                + expr_decision_points(false_expr)
+        }
+        Expression::FunctionCall(_, func, args) => expr_decision_points(func) + exprs(args),
+        Expression::NamedFunctionCall(_, func, args) => {
+            expr_decision_points(func)
+                + args
+                    .iter()
+                    .map(|arg| expr_decision_points(&arg.expr))
+                    .sum::<usize>()
+        }
+        Expression::FunctionCallBlock(_, func, _) => expr_decision_points(func),
+        Expression::ArrayLiteral(_, elems) => exprs(elems),
+        Expression::ArraySlice(_, arr, start, end) => {
+            expr_decision_points(arr)
+                + start.as_deref().map_or(0, expr_decision_points)
+                + end.as_deref().map_or(0, expr_decision_points)
+        }
+        _ => {
+            let (lhs, rhs) = expr.components();
+            lhs.map_or(0, expr_decision_points) + rhs.map_or(0, expr_decision_points)
+        }
+    }
+}
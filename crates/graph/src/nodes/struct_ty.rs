@@ -66,6 +66,48 @@ impl StructNode {
             .find(|field_node| field_node.name(analyzer).unwrap() == ident.name)
     }
 
+    /// The storage slot (relative to the struct's first slot) and byte offset within that slot
+    /// of each field, following Solidity's packing rules: fields are packed into a slot in
+    /// declaration order until the next one doesn't fit, and structs and arrays always start a
+    /// new slot, as does the field after them
+    pub fn storage_layout(
+        &self,
+        analyzer: &impl GraphBackend,
+    ) -> Result<Vec<(FieldNode, usize, usize)>, GraphError> {
+        Ok(self.packed_fields(analyzer)?.0)
+    }
+
+    /// The number of storage slots the struct takes up
+    pub fn storage_slots(&self, analyzer: &impl GraphBackend) -> Result<usize, GraphError> {
+        Ok(self.packed_fields(analyzer)?.1)
+    }
+
+    fn packed_fields(
+        &self,
+        analyzer: &impl GraphBackend,
+    ) -> Result<(Vec<(FieldNode, usize, usize)>, usize), GraphError> {
+        let mut layout = vec![];
+        let (mut slot, mut offset) = (0, 0);
+        for field in self.fields(analyzer) {
+            let ty = field.ty(analyzer)?;
+            let (size, packable) = ty.storage_size(analyzer)?;
+            if offset > 0 && (!packable || offset + size > 32) {
+                slot += 1;
+                offset = 0;
+            }
+            layout.push((field, slot, offset));
+            if packable {
+                offset += size;
+            } else {
+                slot += size.div_ceil(32);
+            }
+        }
+        if offset > 0 {
+            slot += 1;
+        }
+        Ok((layout, slot))
+    }
+
     pub fn maybe_associated_contract(&self, analyzer: &impl GraphBackend) -> Option<ContractNode> {
         analyzer
             .graph()
@@ -203,6 +245,17 @@ impl FieldNode {
             .expect("Struct wasn't named")
             .to_string())
     }
+
+    /// Gets the field's type
+    pub fn ty(&self, analyzer: &impl GraphBackend) -> Result<VarType, GraphError> {
+        let ty = self.underlying(analyzer)?.ty;
+        VarType::try_from_idx(analyzer, ty).ok_or_else(|| {
+            GraphError::NodeConfusion(format!(
+                "Field \"{}\" has no type",
+                self.name(analyzer).unwrap_or_default()
+            ))
+        })
+    }
 }
 
 impl AsDotStr for FieldNode {
@@ -138,16 +138,30 @@ impl VarNode {
             .name)
     }
 
+    pub fn is_constant(&self, analyzer: &impl GraphBackend) -> Result<bool, GraphError> {
+        Ok(self
+            .underlying(analyzer)?
+            .attrs
+            .iter()
+            .any(|attr| matches!(attr, VariableAttribute::Constant(_))))
+    }
+
+    pub fn is_immutable(&self, analyzer: &impl GraphBackend) -> Result<bool, GraphError> {
+        Ok(self
+            .underlying(analyzer)?
+            .attrs
+            .iter()
+            .any(|attr| matches!(attr, VariableAttribute::Immutable(_))))
+    }
+
+    /// The value of a constant, or of an immutable initialized at its declaration (which can't
+    /// be reassigned in the constructor)
     pub fn const_value(
         &self,
         loc: Loc,
         analyzer: &impl GraphBackend,
     ) -> Result<Option<ContextVar>, GraphError> {
-        let attrs = &self.underlying(analyzer)?.attrs;
-        if attrs
-            .iter()
-            .any(|attr| matches!(attr, VariableAttribute::Constant(_)))
-        {
+        if self.is_constant(analyzer)? || self.is_immutable(analyzer)? {
             if let Some(init) = self.underlying(analyzer)?.initializer {
                 if let Some(ty) = VarType::try_from_idx(analyzer, init) {
                     return Ok(Some(ContextVar {
@@ -160,6 +174,7 @@ impl VarNode {
                         tmp_of: None,
                         is_symbolic: true,
                         is_return: false,
+                        source_repr: None,
                         ty,
                     }));
                 }
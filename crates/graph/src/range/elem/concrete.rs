@@ -205,6 +205,7 @@ impl RangeElem<Concrete> for RangeConcrete<Concrete> {
                     // two negatives
                     (Concrete::Int(_, s), Concrete::Int(_, o)) => Some(s.cmp(o)),
                     (Concrete::DynBytes(b0), Concrete::DynBytes(b1)) => Some(b0.cmp(b1)),
+                    (Concrete::String(s0), Concrete::String(s1)) => Some(s0.cmp(s1)),
                     _ => None,
                 }
             }
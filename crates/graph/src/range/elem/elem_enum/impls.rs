@@ -215,7 +215,7 @@ impl<T: Ord> Elem<T> {
 
     /// Creates a new range element that is one range element to the power of another
     pub fn pow(self, other: Self) -> Self {
-        let expr = RangeExpr::new(self, RangeOp::Exp, other);
+        let expr = RangeExpr::new(self, RangeOp::Exp(false), other);
         Elem::Expr(expr)
     }
 
@@ -436,6 +436,14 @@ impl Elem<Concrete> {
                 }
             }
             (Elem::Concrete(_), Elem::Reference(_)) => other.overlaps(self, eval, analyzer, arena),
+            (Elem::Arena(_), _) => match self.dearenaize_clone(arena) {
+                Elem::Arena(_) => Ok(None),
+                s => s.overlaps(other, eval, analyzer, arena),
+            },
+            (_, Elem::Arena(_)) => match other.dearenaize_clone(arena) {
+                Elem::Arena(_) => Ok(None),
+                o => self.overlaps(&o, eval, analyzer, arena),
+            },
             _ => Ok(None),
         }
     }
@@ -91,6 +91,7 @@ impl RangeElem<Concrete> for Elem<Concrete> {
                 }
             }
             (Self::Reference(a), Self::Reference(b)) => a.range_ord(b, arena),
+            (Self::ConcreteDyn(a), Self::ConcreteDyn(b)) => a.range_ord(b, arena),
             (Elem::Null, Elem::Null) => None,
             (_a, Elem::Null) => Some(std::cmp::Ordering::Greater),
             (Elem::Null, _a) => Some(std::cmp::Ordering::Less),
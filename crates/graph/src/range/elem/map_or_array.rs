@@ -39,6 +39,29 @@ pub struct RangeDyn<T> {
     pub loc: Loc,
 }
 
+/// Inserts an index's value, unless a later write to the same index is already present.
+/// Concrete unsigned indices of different sizes (i.e. a `uint8` literal and a `uint256`)
+/// are the same index
+pub(crate) fn insert_latest(
+    map: &mut BTreeMap<Elem<Concrete>, (Elem<Concrete>, usize)>,
+    idx: Elem<Concrete>,
+    val: (Elem<Concrete>, usize),
+) {
+    let same_index = |key: &Elem<Concrete>| match (key, &idx) {
+        (Elem::Concrete(a), Elem::Concrete(b)) => {
+            a.val == b.val || a.val.uint_val().is_some() && a.val.uint_val() == b.val.uint_val()
+        }
+        _ => *key == idx,
+    };
+    if let Some(existing) = map.keys().find(|key| same_index(key)).cloned() {
+        if map[&existing].1 > val.1 {
+            return;
+        }
+        map.remove(&existing);
+    }
+    map.insert(idx, val);
+}
+
 impl<T: std::cmp::PartialEq> PartialEq for RangeDyn<T> {
     fn eq(&self, other: &Self) -> bool {
         self.len == other.len && self.val == other.val && self.op_num == other.op_num
@@ -432,7 +455,8 @@ impl RangeElem<Concrete> for RangeDyn<Concrete> {
             val: {
                 let mut map = BTreeMap::default();
                 for (idx, val) in self.val.clone().into_iter() {
-                    map.insert(
+                    insert_latest(
+                        &mut map,
                         idx.flatten(maximize, analyzer, arena)?,
                         (val.0.flatten(maximize, analyzer, arena)?, val.1),
                     );
@@ -536,7 +560,11 @@ impl RangeElem<Concrete> for RangeDyn<Concrete> {
                     // We dont maximize the key so that any subsequent
                     // `get_index` can find potential values
                     let maximized = val.0.maximize(analyzer, arena)?;
-                    map.insert(idx.simplify_maximize(analyzer, arena)?, (maximized, val.1));
+                    insert_latest(
+                        &mut map,
+                        idx.simplify_maximize(analyzer, arena)?,
+                        (maximized, val.1),
+                    );
                 }
 
                 map
@@ -562,7 +590,11 @@ impl RangeElem<Concrete> for RangeDyn<Concrete> {
                     // We dont minimize the key so that any subsequent
                     // `get_index` can find potential values
                     let minimized = val.0.minimize(analyzer, arena)?;
-                    map.insert(idx.simplify_minimize(analyzer, arena)?, (minimized, val.1));
+                    insert_latest(
+                        &mut map,
+                        idx.simplify_minimize(analyzer, arena)?,
+                        (minimized, val.1),
+                    );
                 }
 
                 map
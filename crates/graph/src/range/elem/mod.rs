@@ -84,7 +84,7 @@ pub enum RangeOp {
     /// Bitwise Not
     BitNot,
     /// Exponentiation
-    Exp,
+    Exp(bool),
     /// Concatenation
     Concat,
     /// Memcopy
@@ -108,7 +108,7 @@ impl RangeOp {
             Sub(_i) => false,
             Div(_i) => false,
             Mod => false,
-            Exp => false,
+            Exp(_i) => false,
             Min => true,
             Max => true,
 
@@ -171,6 +171,11 @@ impl RangeOp {
         }
     }
 
+    /// Whether the operation results in a boolean, i.e. a comparison or logical operation
+    pub fn is_condition(&self) -> bool {
+        matches!(self, RangeOp::Not) || self.logical_inverse().is_some()
+    }
+
     /// Gets the logical inverse of a boolean operation
     pub fn logical_inverse(self) -> Option<Self> {
         use RangeOp::*;
@@ -213,7 +218,7 @@ impl ToString for RangeOp {
             Shl => "<<".to_string(),
             Shr => ">>".to_string(),
             Mod => "%".to_string(),
-            Exp => "**".to_string(),
+            Exp(..) => "**".to_string(),
             Min => "min".to_string(),
             Max => "max".to_string(),
             Lt => "<".to_string(),
@@ -0,0 +1,324 @@
+use crate::{
+    nodes::{Builtin, Concrete},
+    range::{elem::*, exec_traits::*},
+    GraphBackend, SolcRange,
+};
+
+use shared::RangeArena;
+
+use solang_parser::pt::Loc;
+
+use std::cmp::Ordering;
+
+/// The values excluded from a range (i.e. `x` in `require(x != 5)` has an exclusion of `5`)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub struct Exclusions(Vec<Elem<Concrete>>);
+
+impl From<Vec<Elem<Concrete>>> for Exclusions {
+    fn from(exclusions: Vec<Elem<Concrete>>) -> Self {
+        let mut new = Self::default();
+        exclusions.into_iter().for_each(|excl| {
+            new.add(excl);
+        });
+        new
+    }
+}
+
+impl From<Exclusions> for Vec<Elem<Concrete>> {
+    fn from(exclusions: Exclusions) -> Self {
+        exclusions.0
+    }
+}
+
+impl FromIterator<Elem<Concrete>> for Exclusions {
+    fn from_iter<I: IntoIterator<Item = Elem<Concrete>>>(iter: I) -> Self {
+        iter.into_iter().collect::<Vec<_>>().into()
+    }
+}
+
+impl IntoIterator for Exclusions {
+    type Item = Elem<Concrete>;
+    type IntoIter = std::vec::IntoIter<Elem<Concrete>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Exclusions {
+    type Item = &'a Elem<Concrete>;
+    type IntoIter = std::slice::Iter<'a, Elem<Concrete>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl Exclusions {
+    /// Adds an exclusion, returning whether it was not already present
+    pub fn add(&mut self, excl: Elem<Concrete>) -> bool {
+        if self.contains(&excl) {
+            false
+        } else {
+            self.0.push(excl);
+            true
+        }
+    }
+
+    /// Whether the element is already excluded
+    pub fn contains(&self, excl: &Elem<Concrete>) -> bool {
+        self.0.contains(excl)
+    }
+
+    /// Iterates over the exclusions in insertion (or, once normalized, ascending) order
+    pub fn iter(&self) -> std::slice::Iter<'_, Elem<Concrete>> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Resolves arenaized exclusions that point to concrete values, then sorts the concrete
+    /// exclusions in ascending order and removes duplicate values. Exclusions that are not
+    /// concrete are kept, after the concrete ones, in their original order
+    pub fn normalize(&mut self, arena: &mut RangeArena<Elem<Concrete>>) {
+        let (mut concretes, symbolics): (Vec<_>, Vec<_>) = std::mem::take(&mut self.0)
+            .into_iter()
+            .map(|excl| match excl {
+                Elem::Arena(_) => match excl.dearenaize_clone(arena) {
+                    concrete @ Elem::Concrete(_) => concrete,
+                    _ => excl,
+                },
+                _ => excl,
+            })
+            .partition(|excl| matches!(excl, Elem::Concrete(_)));
+        concretes.sort_by(|a, b| a.range_ord(b, arena).unwrap_or(Ordering::Equal));
+        concretes.dedup();
+        self.0 = concretes;
+        symbolics.into_iter().for_each(|excl| {
+            self.add(excl);
+        });
+    }
+
+    /// The concrete exclusions that fall within `[min, max]`, ascending and without duplicates
+    pub fn concrete_within(
+        &self,
+        min: &RangeConcrete<Concrete>,
+        max: &RangeConcrete<Concrete>,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Vec<RangeConcrete<Concrete>> {
+        let mut normalized = self.clone();
+        normalized.normalize(arena);
+        normalized
+            .0
+            .into_iter()
+            .filter_map(|excl| excl.maybe_concrete())
+            .filter(|excl| !concrete_lt(excl, min, arena) && !concrete_lt(max, excl, arena))
+            .collect()
+    }
+
+    /// Returns the sub-ranges of `[min, max]` that are not excluded, in ascending order. Only
+    /// concrete exclusions are considered
+    pub fn complement(
+        &self,
+        min: &RangeConcrete<Concrete>,
+        max: &RangeConcrete<Concrete>,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Vec<SolcRange> {
+        if concrete_lt(max, min, arena) {
+            return vec![];
+        }
+        let Some(one) = min
+            .val
+            .one()
+            .map(|one| RangeConcrete::new(one, Loc::Implicit))
+        else {
+            return vec![SolcRange::new(
+                Elem::Concrete(min.clone()),
+                Elem::Concrete(max.clone()),
+                Exclusions::default(),
+            )];
+        };
+
+        let mut complement = vec![];
+        let mut start = Some(min.clone());
+        for excl in self.concrete_within(min, max, arena) {
+            let Some(curr) = start.take() else {
+                break;
+            };
+            if concrete_lt(&curr, &excl, arena) {
+                if let Some(below) = excl.range_sub(&one) {
+                    complement.push(SolcRange::new(
+                        Elem::Concrete(curr),
+                        below,
+                        Exclusions::default(),
+                    ));
+                }
+            }
+            if excl != *max {
+                start = excl
+                    .range_add(&one)
+                    .and_then(|above| above.maybe_concrete());
+            }
+        }
+
+        if let Some(start) = start {
+            complement.push(SolcRange::new(
+                Elem::Concrete(start),
+                Elem::Concrete(max.clone()),
+                Exclusions::default(),
+            ));
+        }
+        complement
+    }
+
+    /// The exclusions as values of `builtin`, for a range converted to that type. Exclusions that
+    /// evaluate to a concrete value outside the type's range can never be hit and are dropped,
+    /// the others are cast. Symbolic exclusions are kept as is
+    pub fn cast_into(
+        &self,
+        builtin: &Builtin,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Self {
+        let Some((min, max)) = SolcRange::try_from_builtin(builtin)
+            .and_then(|range| Some((range.min.maybe_concrete()?, range.max.maybe_concrete()?)))
+        else {
+            return self.clone();
+        };
+        self.iter()
+            .filter_map(|excl| {
+                let (Ok(lo), Ok(hi)) = (
+                    excl.minimize(analyzer, arena),
+                    excl.maximize(analyzer, arena),
+                ) else {
+                    return Some(excl.clone());
+                };
+                let val = match (lo.maybe_concrete(), hi.maybe_concrete()) {
+                    (Some(lo), Some(hi)) if lo.val == hi.val => lo,
+                    _ => return Some(excl.clone()),
+                };
+                if concrete_lt(&val, &min, arena) || concrete_lt(&max, &val, arena) {
+                    return None;
+                }
+                let cast = val.val.cast(builtin.clone())?;
+                Some(Elem::Concrete(RangeConcrete::new(cast, val.loc)))
+            })
+            .collect()
+    }
+}
+
+fn concrete_lt(
+    a: &RangeConcrete<Concrete>,
+    b: &RangeConcrete<Concrete>,
+    arena: &mut RangeArena<Elem<Concrete>>,
+) -> bool {
+    matches!(
+        Elem::Concrete(a.clone()).range_ord(&Elem::Concrete(b.clone()), arena),
+        Some(Ordering::Less)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DummyGraph;
+    use ethers_core::types::U256;
+
+    fn rc(n: u64) -> RangeConcrete<Concrete> {
+        RangeConcrete::new(Concrete::from(U256::from(n)), Loc::Implicit)
+    }
+
+    fn elem(n: u64) -> Elem<Concrete> {
+        Elem::Concrete(rc(n))
+    }
+
+    fn bounds(range: &SolcRange) -> (Elem<Concrete>, Elem<Concrete>) {
+        (range.min.clone(), range.max.clone())
+    }
+
+    #[test]
+    fn add_dedups() {
+        let mut excls = Exclusions::default();
+        assert!(excls.add(elem(5)));
+        assert!(excls.add(elem(3)));
+        assert!(!excls.add(elem(5)));
+        assert_eq!(excls.len(), 2);
+    }
+
+    #[test]
+    fn contains_by_value() {
+        let excls = Exclusions::from(vec![elem(5)]);
+        assert!(excls.contains(&elem(5)));
+        assert!(!excls.contains(&elem(6)));
+    }
+
+    #[test]
+    fn iterates_in_insertion_order() {
+        let excls: Exclusions = vec![elem(9), elem(1), elem(4)].into_iter().collect();
+        assert_eq!(
+            excls.iter().cloned().collect::<Vec<_>>(),
+            vec![elem(9), elem(1), elem(4)]
+        );
+        assert_eq!((&excls).into_iter().count(), 3);
+    }
+
+    #[test]
+    fn normalize_sorts_and_resolves_arena() {
+        let mut arena = RangeArena::default();
+        arena.ranges.push(elem(2));
+        let mut excls = Exclusions::from(vec![elem(9), Elem::Arena(0), elem(4)]);
+        excls.normalize(&mut arena);
+        assert_eq!(
+            Vec::<Elem<Concrete>>::from(excls),
+            vec![elem(2), elem(4), elem(9)]
+        );
+
+        let mut excls = Exclusions::from(vec![elem(2), Elem::Arena(0)]);
+        excls.normalize(&mut arena);
+        assert_eq!(Vec::<Elem<Concrete>>::from(excls), vec![elem(2)]);
+    }
+
+    #[test]
+    fn complement_splits_around_exclusions() {
+        let mut arena = RangeArena::default();
+        let excls = Exclusions::from(vec![elem(7), elem(3), elem(50)]);
+        let complement = excls.complement(&rc(0), &rc(10), &mut arena);
+        assert_eq!(
+            complement.iter().map(bounds).collect::<Vec<_>>(),
+            vec![(elem(0), elem(2)), (elem(4), elem(6)), (elem(8), elem(10))]
+        );
+    }
+
+    #[test]
+    fn complement_excluding_bounds() {
+        let mut arena = RangeArena::default();
+        let excls = Exclusions::from(vec![elem(0), elem(10)]);
+        let complement = excls.complement(&rc(0), &rc(10), &mut arena);
+        assert_eq!(
+            complement.iter().map(bounds).collect::<Vec<_>>(),
+            vec![(elem(1), elem(9))]
+        );
+
+        let excls = Exclusions::from(vec![elem(5)]);
+        assert!(excls.complement(&rc(5), &rc(5), &mut arena).is_empty());
+    }
+
+    #[test]
+    fn cast_into_drops_out_of_range() {
+        let g = DummyGraph::default();
+        let mut arena = RangeArena::default();
+        let excls = Exclusions::from(vec![elem(7), elem(255), elem(256)]);
+        let cast = excls.cast_into(&Builtin::Uint(8), &g, &mut arena);
+        let u8_elem = |n: u64| {
+            Elem::Concrete(RangeConcrete::new(
+                Concrete::Uint(8, U256::from(n)),
+                Loc::Implicit,
+            ))
+        };
+        assert_eq!(cast, Exclusions::from(vec![u8_elem(7), u8_elem(255)]));
+    }
+}
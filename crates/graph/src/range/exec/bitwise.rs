@@ -287,6 +287,19 @@ pub fn exec_bit_and(
         _ => {}
     }
 
+    let is_const = |l: &Elem<_>, r: &Elem<_>, arena: &mut RangeArena<Elem<_>>| -> bool {
+        matches!(l.range_ord(r, arena), Some(std::cmp::Ordering::Equal))
+    };
+    if is_const(rhs_min, rhs_max, arena) {
+        if let Some(res) = exec_low_bits(lhs_min, lhs_max, rhs_min, maximize, arena) {
+            return Some(res);
+        }
+    } else if is_const(lhs_min, lhs_max, arena) {
+        if let Some(res) = exec_low_bits(rhs_min, rhs_max, lhs_min, maximize, arena) {
+            return Some(res);
+        }
+    }
+
     let mut candidates = vec![];
     let bit_and = |lhs: &Elem<_>, rhs: &Elem<_>, candidates: &mut Vec<Elem<Concrete>>| {
         if let Some(c) = lhs.range_bit_and(rhs) {
@@ -359,6 +372,52 @@ pub fn exec_bit_and(
     }
 }
 
+/// Bounds `x & mask` for a nonnegative `x` in `[min, max]` and a constant `mask` of the form
+/// <code>2<sup>n</sup> - 1</code>, which is also `x % 2^n`. The low bits of the bounds only bound
+/// the result if no multiple of <code>2<sup>n</sup></code> lies between them, otherwise it spans
+/// `[0, mask]`. Returns `None` if `x` may be negative or `mask` isn't such a mask.
+pub(crate) fn exec_low_bits(
+    min: &Elem<Concrete>,
+    max: &Elem<Concrete>,
+    mask: &Elem<Concrete>,
+    maximize: bool,
+    arena: &mut RangeArena<Elem<Concrete>>,
+) -> Option<Elem<Concrete>> {
+    let mask_val = mask.maybe_concrete()?.val;
+    let raw_mask = match mask_val {
+        Concrete::Uint(_, v) => v,
+        Concrete::Int(_, v) if !v.is_negative() => v.into_raw(),
+        _ => return None,
+    };
+    if raw_mask != U256::MAX && !(raw_mask & (raw_mask + 1)).is_zero() {
+        return None;
+    }
+
+    let zero = Elem::from(Concrete::from(U256::zero()));
+    if !matches!(
+        min.range_ord(&zero, arena),
+        Some(std::cmp::Ordering::Equal) | Some(std::cmp::Ordering::Greater)
+    ) {
+        return None;
+    }
+
+    let same_block = match (
+        min.maybe_concrete().and_then(|min| min.val.into_u256()),
+        max.maybe_concrete().and_then(|max| max.val.into_u256()),
+    ) {
+        (Some(min), Some(max)) => min & !raw_mask == max & !raw_mask,
+        _ => false,
+    };
+    if same_block {
+        let bound = if maximize { max } else { min };
+        bound.range_bit_and(mask)
+    } else if maximize {
+        Some(mask.clone())
+    } else {
+        Some(Elem::from(mask_val.u256_as_original(U256::zero())))
+    }
+}
+
 /// Executes a bitwise `or` given the minimum and maximum of each element. It returns either the _minimum_ bound or _maximum_ bound
 /// of the operation.
 ///
@@ -57,14 +57,44 @@ impl RangeExp<Concrete> for RangeConcrete<Concrete> {
             },
         }
     }
+
+    fn range_wrapping_exp(&self, other: &Self) -> Option<Elem<Concrete>> {
+        match (self.val.into_u256(), other.val.into_u256()) {
+            (Some(lhs_val), Some(rhs_val)) => {
+                let op_res = lhs_val.overflowing_pow(rhs_val).0;
+                let val = self.val.u256_as_original(op_res);
+                let rc = RangeConcrete::new(val, self.loc);
+                Some(rc.into())
+            }
+            _ => match (&self.val, &other.val.into_u256()) {
+                (Concrete::Int(lhs_size, neg_v), Some(val)) if val <= &U256::from(u32::MAX) => {
+                    let op_res = neg_v.overflowing_pow(val.as_u32()).0;
+                    let val = Concrete::Int(*lhs_size, op_res).size_wrap();
+                    let rc = RangeConcrete::new(val, self.loc);
+                    Some(rc.into())
+                }
+                _ => None,
+            },
+        }
+    }
 }
 
 impl RangeExp<Concrete> for Elem<Concrete> {
     fn range_exp(&self, other: &Self) -> Option<Elem<Concrete>> {
         match (self, other) {
             (Elem::Concrete(a), Elem::Concrete(b)) => a.range_exp(b),
-            (Elem::Concrete(a), _) if a.val.is_zero() => Some(Concrete::from(U256::from(1)).into()),
-            (_, Elem::Concrete(b)) if b.val.is_zero() => Some(other.clone()),
+            // x ** 0 == 1, including 0 ** 0
+            (_, Elem::Concrete(b)) if b.val.is_zero() => Some(Concrete::from(U256::one()).into()),
+            (Elem::Concrete(a), _) if a.val.is_one() => Some(self.clone()),
+            _ => None,
+        }
+    }
+
+    fn range_wrapping_exp(&self, other: &Self) -> Option<Elem<Concrete>> {
+        match (self, other) {
+            (Elem::Concrete(a), Elem::Concrete(b)) => a.range_wrapping_exp(b),
+            (_, Elem::Concrete(b)) if b.val.is_zero() => Some(Concrete::from(U256::one()).into()),
+            (Elem::Concrete(a), _) if a.val.is_one() => Some(self.clone()),
             _ => None,
         }
     }
@@ -73,24 +103,49 @@ impl RangeExp<Concrete> for Elem<Concrete> {
 /// Executes the `exponentiation` operation given the minimum and maximum of each element. It returns either the _minimum_ bound or _maximum_ bound
 /// of the operation.
 ///
-/// TODO: Add wrapping/unchecked version
+/// Checked exponentiation saturates at the type's bounds, as overflowing paths revert. If wrapping
+/// and any combination of the bounds overflows, the result may be anywhere in the type's range
 pub fn exec_exp(
     lhs_min: &Elem<Concrete>,
     lhs_max: &Elem<Concrete>,
     rhs_min: &Elem<Concrete>,
     rhs_max: &Elem<Concrete>,
     maximize: bool,
+    wrapping: bool,
     _analyzer: &impl GraphBackend,
     arena: &mut RangeArena<Elem<Concrete>>,
 ) -> Option<Elem<Concrete>> {
-    // TODO: Improve this
-    let candidates = vec![
-        lhs_min.range_exp(rhs_min),
-        lhs_min.range_exp(rhs_max),
-        lhs_max.range_exp(rhs_min),
-        lhs_max.range_exp(rhs_max),
+    let pairs = [
+        (lhs_min, rhs_min),
+        (lhs_min, rhs_max),
+        (lhs_max, rhs_min),
+        (lhs_max, rhs_max),
     ];
-    let mut candidates = candidates.into_iter().flatten().collect::<Vec<_>>();
+    if wrapping {
+        let may_overflow = pairs.iter().any(|(lhs, rhs)| {
+            match (lhs.range_exp(rhs), lhs.range_wrapping_exp(rhs)) {
+                (Some(saturated), Some(wrapped)) => !matches!(
+                    saturated.range_ord(&wrapped, arena),
+                    Some(std::cmp::Ordering::Equal)
+                ),
+                _ => false,
+            }
+        });
+        if may_overflow {
+            let c = lhs_max.maybe_concrete()?;
+            let bound = if maximize {
+                Concrete::max_of_type(&c.val)?
+            } else {
+                Concrete::min_of_type(&c.val)?
+            };
+            return Some(RangeConcrete::new(bound, c.loc).into());
+        }
+    }
+
+    let mut candidates = pairs
+        .into_iter()
+        .filter_map(|(lhs, rhs)| lhs.range_exp(rhs))
+        .collect::<Vec<_>>();
     candidates.sort_by(|a, b| match a.range_ord(b, arena) {
         Some(r) => r,
         _ => std::cmp::Ordering::Less,
@@ -185,17 +240,75 @@ mod tests {
         let rhs_min = rc_uint_sized(3).into();
         let rhs_max = rc_uint_sized(200).into();
 
-        let max_result = exec_exp(&lhs_min, &lhs_max, &rhs_min, &rhs_max, true, &g, &mut arena)
-            .unwrap()
-            .maybe_concrete()
-            .unwrap();
+        let max_result = exec_exp(
+            &lhs_min, &lhs_max, &rhs_min, &rhs_max, true, false, &g, &mut arena,
+        )
+        .unwrap()
+        .maybe_concrete()
+        .unwrap();
         assert_eq!(max_result.val, Concrete::Uint(8, U256::from(255)));
         let min_result = exec_exp(
-            &lhs_min, &lhs_max, &rhs_min, &rhs_max, false, &g, &mut arena,
+            &lhs_min, &lhs_max, &rhs_min, &rhs_max, false, false, &g, &mut arena,
         )
         .unwrap()
         .maybe_concrete()
         .unwrap();
         assert_eq!(min_result.val, Concrete::Uint(8, U256::from(8)));
     }
+
+    #[test]
+    fn zero_pow_zero() {
+        let x = RangeConcrete::new(Concrete::Uint(256, U256::zero()), Loc::Implicit);
+        let y = RangeConcrete::new(Concrete::Uint(256, U256::zero()), Loc::Implicit);
+        let result = x.range_exp(&y).unwrap().maybe_concrete_value().unwrap();
+        assert_eq!(result.val, Concrete::Uint(256, U256::from(1)));
+        let result = x
+            .range_wrapping_exp(&y)
+            .unwrap()
+            .maybe_concrete_value()
+            .unwrap();
+        assert_eq!(result.val, Concrete::Uint(256, U256::from(1)));
+    }
+
+    #[test]
+    fn sized_wrapping_uint_uint() {
+        let x = RangeConcrete::new(Concrete::Uint(8, U256::from(3)), Loc::Implicit);
+        let y = RangeConcrete::new(Concrete::Uint(8, U256::from(6)), Loc::Implicit);
+        let result = x
+            .range_wrapping_exp(&y)
+            .unwrap()
+            .maybe_concrete_value()
+            .unwrap();
+        // 729 % 256
+        assert_eq!(result.val, Concrete::Uint(8, U256::from(217)));
+    }
+
+    #[test]
+    fn exec_sized_uint_uint_wrapping() {
+        let g = DummyGraph::default();
+        let mut arena = Default::default();
+        let lhs_min = rc_uint_sized(2).into();
+        let lhs_max = rc_uint_sized(3).into();
+        let rhs_min = rc_uint_sized(2).into();
+        let rhs_max = rc_uint_sized(4).into();
+
+        // 3 ** 4 fits in a uint8, so nothing wraps
+        let max_result = exec_exp(
+            &lhs_min, &lhs_max, &rhs_min, &rhs_max, true, true, &g, &mut arena,
+        )
+        .unwrap()
+        .maybe_concrete()
+        .unwrap();
+        assert_eq!(max_result.val, Concrete::Uint(8, U256::from(81)));
+
+        // 3 ** 6 wraps, so the result could be anything
+        let rhs_max = rc_uint_sized(6).into();
+        let min_result = exec_exp(
+            &lhs_min, &lhs_max, &rhs_min, &rhs_max, false, true, &g, &mut arena,
+        )
+        .unwrap()
+        .maybe_concrete()
+        .unwrap();
+        assert_eq!(min_result.val, Concrete::Uint(8, U256::zero()));
+    }
 }
@@ -1,5 +1,5 @@
 use crate::nodes::Concrete;
-use crate::range::{elem::*, exec_traits::*};
+use crate::range::{elem::*, exec::bitwise::exec_low_bits, exec_traits::*};
 use crate::GraphBackend;
 
 use shared::RangeArena;
@@ -92,6 +92,15 @@ pub fn exec_mod(
         lhs_max.range_ord(&zero, arena),
         Some(std::cmp::Ordering::Equal) | Some(std::cmp::Ordering::Greater)
     );
+    // for a nonnegative lhs, `x % 2^n` is `x & (2^n - 1)`
+    if is_const(rhs_min, rhs_max, arena) {
+        if let Some(mask) = power_of_two_mask(rhs_min) {
+            if let Some(res) = exec_low_bits(lhs_min, lhs_max, &mask, maximize, arena) {
+                return Some(res);
+            }
+        }
+    }
+
     let mod_min_is_pos = matches!(
         rhs_min.range_ord(&zero, arena),
         Some(std::cmp::Ordering::Equal) | Some(std::cmp::Ordering::Greater)
@@ -165,9 +174,24 @@ pub fn exec_mod(
     }
 }
 
+/// `m - 1` if `m` is a positive power of two
+fn power_of_two_mask(m: &Elem<Concrete>) -> Option<Elem<Concrete>> {
+    let val = m.maybe_concrete()?.val;
+    let raw = match val {
+        Concrete::Uint(_, v) => v,
+        Concrete::Int(_, v) if v.is_positive() => v.into_raw(),
+        _ => return None,
+    };
+    if raw.is_zero() || !(raw & (raw - 1)).is_zero() {
+        return None;
+    }
+    Some(Elem::from(val.u256_as_original(raw - 1)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::range::exec::exec_bit_and;
     use crate::DummyGraph;
     use solang_parser::pt::Loc;
 
@@ -326,4 +350,83 @@ mod tests {
         .unwrap();
         assert_eq!(min_result.val, Concrete::Int(8, I256::from(-4i32)));
     }
+
+    #[test]
+    fn exec_pow2_mod_matches_bit_and() {
+        let g = DummyGraph::default();
+        let mut arena = Default::default();
+        let lhs_min = rc_uint256(0).into();
+        let lhs_max = Elem::from(Concrete::from(U256::MAX));
+        let modulus = rc_uint256(8).into();
+        let mask = rc_uint256(7).into();
+
+        for maximize in [false, true] {
+            let mod_result = exec_mod(
+                &lhs_min, &lhs_max, &modulus, &modulus, maximize, &g, &mut arena,
+            )
+            .unwrap()
+            .maybe_concrete()
+            .unwrap();
+            let and_result = exec_bit_and(&lhs_min, &lhs_max, &mask, &mask, maximize, &mut arena)
+                .unwrap()
+                .maybe_concrete()
+                .unwrap();
+            assert_eq!(mod_result.val, and_result.val);
+        }
+        let min_result = exec_mod(
+            &lhs_min, &lhs_max, &modulus, &modulus, false, &g, &mut arena,
+        )
+        .unwrap()
+        .maybe_concrete()
+        .unwrap();
+        assert_eq!(min_result.val, Concrete::Uint(256, U256::from(0)));
+        let max_result = exec_mod(&lhs_min, &lhs_max, &modulus, &modulus, true, &g, &mut arena)
+            .unwrap()
+            .maybe_concrete()
+            .unwrap();
+        assert_eq!(max_result.val, Concrete::Uint(256, U256::from(7)));
+    }
+
+    #[test]
+    fn exec_pow2_mod_across_multiple() {
+        let g = DummyGraph::default();
+        let mut arena = Default::default();
+        let modulus = rc_uint256(8).into();
+        let mask = rc_uint256(7).into();
+        let bounds = |lhs_min: u128, lhs_max: u128, arena: &mut RangeArena<Elem<Concrete>>| {
+            let (lhs_min, lhs_max) = (rc_uint256(lhs_min).into(), rc_uint256(lhs_max).into());
+            [false, true].map(|maximize| {
+                let mod_result =
+                    exec_mod(&lhs_min, &lhs_max, &modulus, &modulus, maximize, &g, arena)
+                        .unwrap()
+                        .maybe_concrete()
+                        .unwrap()
+                        .val;
+                let and_result = exec_bit_and(&lhs_min, &lhs_max, &mask, &mask, maximize, arena)
+                    .unwrap()
+                    .maybe_concrete()
+                    .unwrap()
+                    .val;
+                assert_eq!(mod_result, and_result);
+                mod_result
+            })
+        };
+
+        // 8 lies within [5, 9], so `x % 8` and `x & 7` can be 0
+        assert_eq!(
+            bounds(5, 9, &mut arena),
+            [
+                Concrete::Uint(256, U256::from(0)),
+                Concrete::Uint(256, U256::from(7))
+            ]
+        );
+        // [9, 14] lies within a single multiple of 8
+        assert_eq!(
+            bounds(9, 14, &mut arena),
+            [
+                Concrete::Uint(256, U256::from(1)),
+                Concrete::Uint(256, U256::from(6))
+            ]
+        );
+    }
 }
@@ -16,7 +16,7 @@ impl RangeMemSet<Concrete> for RangeDyn<Concrete> {
         let mut op_num = self.op_num;
         range.val.iter().for_each(|(k, (v, _))| {
             op_num += 1;
-            new_val.insert(k.clone(), (v.clone(), op_num));
+            insert_latest(&mut new_val, k.clone(), (v.clone(), op_num));
         });
 
         Some(Elem::ConcreteDyn(RangeDyn::new_w_op_nums(
@@ -21,6 +21,9 @@ impl RangeOrd<Concrete> for RangeConcrete<Concrete> {
                 (Concrete::Int(_lhs_size, l), Concrete::Int(_rhs_size, r)) => {
                     Some(RangeConcrete::new(Concrete::Bool(l == r), self.loc).into())
                 }
+                (Concrete::String(l), Concrete::String(r)) => {
+                    Some(RangeConcrete::new(Concrete::Bool(l == r), self.loc).into())
+                }
                 _ => None,
             },
         }
@@ -39,6 +42,9 @@ impl RangeOrd<Concrete> for RangeConcrete<Concrete> {
                 (Concrete::Int(_lhs_size, l), Concrete::Int(_rhs_size, r)) => {
                     Some(RangeConcrete::new(Concrete::Bool(l != r), self.loc).into())
                 }
+                (Concrete::String(l), Concrete::String(r)) => {
+                    Some(RangeConcrete::new(Concrete::Bool(l != r), self.loc).into())
+                }
                 _ => None,
             },
         }
@@ -85,6 +85,8 @@ pub trait RangeDiv<T, Rhs = Self> {
 pub trait RangeExp<T, Rhs = Self> {
     /// Perform exponentiation between two range elements
     fn range_exp(&self, other: &Rhs) -> Option<Elem<T>>;
+    /// Perform exponentiation between two range elements, wrapping on overflow
+    fn range_wrapping_exp(&self, other: &Rhs) -> Option<Elem<T>>;
 }
 
 pub trait RangeMul<T, Rhs = Self> {
@@ -10,11 +10,13 @@
 //!
 
 pub mod elem;
+mod exclusions;
 pub mod exec;
 pub mod exec_traits;
 pub mod range_string;
 mod range_trait;
 mod solc_range;
 
+pub use exclusions::*;
 pub use range_trait::*;
 pub use solc_range::*;
@@ -1,6 +1,6 @@
 use crate::{
     nodes::{Builtin, Concrete, ContextVarNode},
-    range::{elem::*, range_string::*, Range, RangeEval},
+    range::{elem::*, exec_traits::*, range_string::*, Exclusions, Range, RangeEval},
     AsDotStr, GraphBackend,
 };
 
@@ -15,7 +15,7 @@ use std::{borrow::Cow, collections::BTreeMap};
 pub struct FlattenedRange {
     pub min: Elem<Concrete>,
     pub max: Elem<Concrete>,
-    pub exclusions: Vec<Elem<Concrete>>,
+    pub exclusions: Exclusions,
 }
 
 impl From<FlattenedRange> for SolcRange {
@@ -30,7 +30,7 @@ pub struct SolcRange {
     pub min_cached: Option<usize>,
     pub max: Elem<Concrete>,
     pub max_cached: Option<usize>,
-    pub exclusions: Vec<Elem<Concrete>>,
+    pub exclusions: Exclusions,
     pub flattened: Option<FlattenedRange>,
 }
 
@@ -98,13 +98,17 @@ impl SolcRange {
         Ok(deps)
     }
 
-    pub fn new(min: Elem<Concrete>, max: Elem<Concrete>, exclusions: Vec<Elem<Concrete>>) -> Self {
+    pub fn new(
+        min: Elem<Concrete>,
+        max: Elem<Concrete>,
+        exclusions: impl Into<Exclusions>,
+    ) -> Self {
         Self {
             min,
             min_cached: None,
             max,
             max_cached: None,
-            exclusions,
+            exclusions: exclusions.into(),
             flattened: None,
         }
     }
@@ -365,7 +369,7 @@ impl SolcRange {
             RangeOp::Gte => &Self::gte_dyn,
             RangeOp::Eq => &Self::eq_dyn,
             RangeOp::Neq => &Self::neq_dyn,
-            RangeOp::Exp => &Self::exp_dyn,
+            RangeOp::Exp(..) => &Self::exp_dyn,
             RangeOp::BitAnd => &Self::bit_and_dyn,
             RangeOp::BitOr => &Self::bit_or_dyn,
             RangeOp::BitXor => &Self::bit_xor_dyn,
@@ -552,6 +556,124 @@ impl SolcRange {
 
         Ok(flat_range)
     }
+
+    /// Returns the sub-ranges of `type_domain` not covered by this range, in ascending order.
+    /// Concrete exclusions of this range that fall within it are returned as single-value ranges.
+    /// If either range does not evaluate to concrete bounds, or this range is empty, the whole
+    /// domain is returned
+    pub fn complement(
+        &self,
+        type_domain: &SolcRange,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Vec<SolcRange>, GraphError> {
+        let bounds = (
+            self.evaled_range_min(analyzer, arena)?.maybe_concrete(),
+            self.evaled_range_max(analyzer, arena)?.maybe_concrete(),
+            type_domain
+                .evaled_range_min(analyzer, arena)?
+                .maybe_concrete(),
+            type_domain
+                .evaled_range_max(analyzer, arena)?
+                .maybe_concrete(),
+        );
+        let (Some(min), Some(max), Some(dom_min), Some(dom_max)) = bounds else {
+            return Ok(vec![type_domain.clone()]);
+        };
+
+        let lt = |a: &RangeConcrete<Concrete>,
+                  b: &RangeConcrete<Concrete>,
+                  arena: &mut RangeArena<Elem<Concrete>>| {
+            matches!(
+                Elem::Concrete(a.clone()).range_ord(&Elem::Concrete(b.clone()), arena),
+                Some(std::cmp::Ordering::Less)
+            )
+        };
+
+        if lt(&max, &min, arena) || lt(&max, &dom_min, arena) || lt(&dom_max, &min, arena) {
+            return Ok(vec![type_domain.clone()]);
+        }
+
+        let Some(one) = min
+            .val
+            .one()
+            .map(|one| RangeConcrete::new(one, Loc::Implicit))
+        else {
+            return Ok(vec![type_domain.clone()]);
+        };
+
+        let mut complement = vec![];
+        if lt(&dom_min, &min, arena) {
+            if let Some(below) = min.range_sub(&one) {
+                complement.push(SolcRange::new(
+                    Elem::Concrete(dom_min),
+                    below.clone(),
+                    vec![],
+                ));
+            }
+        }
+
+        complement.extend(
+            self.exclusions
+                .concrete_within(&min, &max, arena)
+                .into_iter()
+                .map(|excl| {
+                    SolcRange::new(
+                        Elem::Concrete(excl.clone()),
+                        Elem::Concrete(excl),
+                        Exclusions::default(),
+                    )
+                }),
+        );
+
+        if lt(&max, &dom_max, arena) {
+            if let Some(above) = max.range_add(&one) {
+                complement.push(SolcRange::new(above, Elem::Concrete(dom_max), vec![]));
+            }
+        }
+
+        Ok(complement)
+    }
+
+    /// The intersection of this range with another: the tighter of the two minimums and the two
+    /// maximums, with the exclusions of both. Bounds that cannot be compared are combined
+    /// symbolically. Returns `None` if the intersection is empty, i.e. its minimum evaluates to
+    /// more than its maximum
+    pub fn intersect(
+        &self,
+        other: &SolcRange,
+        analyzer: &impl GraphBackend,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Option<SolcRange>, GraphError> {
+        let min = match self
+            .evaled_range_min(analyzer, arena)?
+            .range_ord(&other.evaled_range_min(analyzer, arena)?, arena)
+        {
+            Some(std::cmp::Ordering::Less) => other.min.clone(),
+            Some(_) => self.min.clone(),
+            None => self.min.clone().max(other.min.clone()),
+        };
+        let max = match self
+            .evaled_range_max(analyzer, arena)?
+            .range_ord(&other.evaled_range_max(analyzer, arena)?, arena)
+        {
+            Some(std::cmp::Ordering::Greater) => other.max.clone(),
+            Some(_) => self.max.clone(),
+            None => self.max.clone().min(other.max.clone()),
+        };
+
+        let mut exclusions = self.exclusions.clone();
+        other.exclusions.iter().for_each(|excl| {
+            exclusions.add(excl.clone());
+        });
+
+        let intersection = SolcRange::new(min, max, exclusions);
+        if intersection.unsat(analyzer, arena) {
+            Ok(None)
+        } else {
+            Ok(Some(intersection))
+        }
+    }
 }
 
 impl Range<Concrete> for SolcRange {
@@ -640,7 +762,7 @@ impl Range<Concrete> for SolcRange {
     }
 
     fn range_exclusions(&self) -> Vec<Self::ElemTy> {
-        self.exclusions.clone().into_iter().collect()
+        self.exclusions.clone().into()
     }
     fn set_range_min(&mut self, new: Self::ElemTy) {
         self.min_cached = None;
@@ -654,12 +776,10 @@ impl Range<Concrete> for SolcRange {
     }
 
     fn add_range_exclusion(&mut self, new: Elem<Concrete>) {
-        if !self.exclusions.contains(&new) {
-            self.exclusions.push(new);
-        }
+        self.exclusions.add(new);
     }
     fn set_range_exclusions(&mut self, new: Vec<Elem<Concrete>>) {
-        self.exclusions = new;
+        self.exclusions = new.into();
     }
 
     fn filter_min_recursion(
@@ -858,3 +978,159 @@ impl RangeEval<Concrete, Elem<Concrete>> for FlattenedRange {
         <FlattenedRange as Into<SolcRange>>::into(self.clone()).overlaps(&other, analyzer, arena)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DummyGraph;
+
+    fn uint_range(min: u64, max: u64) -> SolcRange {
+        SolcRange::new(
+            Elem::from(Concrete::from(U256::from(min))),
+            Elem::from(Concrete::from(U256::from(max))),
+            vec![],
+        )
+    }
+
+    fn concrete_bounds(
+        range: &SolcRange,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> (Concrete, Concrete) {
+        let g = DummyGraph::default();
+        (
+            range
+                .evaled_range_min(&g, arena)
+                .unwrap()
+                .maybe_concrete()
+                .unwrap()
+                .val,
+            range
+                .evaled_range_max(&g, arena)
+                .unwrap()
+                .maybe_concrete()
+                .unwrap()
+                .val,
+        )
+    }
+
+    #[test]
+    fn complement_within_domain() {
+        let g = DummyGraph::default();
+        let mut arena = Default::default();
+        let complement = uint_range(3, 7)
+            .complement(&uint_range(0, 10), &g, &mut arena)
+            .unwrap();
+        let bounds = complement
+            .iter()
+            .map(|range| concrete_bounds(range, &mut arena))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bounds,
+            vec![
+                (Concrete::from(U256::from(0)), Concrete::from(U256::from(2))),
+                (
+                    Concrete::from(U256::from(8)),
+                    Concrete::from(U256::from(10))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn complement_includes_exclusions() {
+        let g = DummyGraph::default();
+        let mut arena = Default::default();
+        let mut range = uint_range(0, 7);
+        range.add_range_exclusion(Elem::from(Concrete::from(U256::from(5))));
+        let complement = range
+            .complement(&uint_range(0, 10), &g, &mut arena)
+            .unwrap();
+        let bounds = complement
+            .iter()
+            .map(|range| concrete_bounds(range, &mut arena))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bounds,
+            vec![
+                (Concrete::from(U256::from(5)), Concrete::from(U256::from(5))),
+                (
+                    Concrete::from(U256::from(8)),
+                    Concrete::from(U256::from(10))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn complement_of_empty_range() {
+        let g = DummyGraph::default();
+        let mut arena = Default::default();
+        let complement = uint_range(7, 3)
+            .complement(&uint_range(0, 10), &g, &mut arena)
+            .unwrap();
+        let bounds = complement
+            .iter()
+            .map(|range| concrete_bounds(range, &mut arena))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bounds,
+            vec![(
+                Concrete::from(U256::from(0)),
+                Concrete::from(U256::from(10))
+            )]
+        );
+    }
+
+    #[test]
+    fn intersect_overlapping() {
+        let g = DummyGraph::default();
+        let mut arena = Default::default();
+        let mut lhs = uint_range(0, 10);
+        lhs.add_range_exclusion(Elem::from(Concrete::from(U256::from(6))));
+        let mut rhs = uint_range(5, 20);
+        rhs.add_range_exclusion(Elem::from(Concrete::from(U256::from(8))));
+        let intersection = lhs.intersect(&rhs, &g, &mut arena).unwrap().unwrap();
+        assert_eq!(
+            concrete_bounds(&intersection, &mut arena),
+            (
+                Concrete::from(U256::from(5)),
+                Concrete::from(U256::from(10))
+            )
+        );
+        assert_eq!(
+            intersection.range_exclusions(),
+            vec![
+                Elem::from(Concrete::from(U256::from(6))),
+                Elem::from(Concrete::from(U256::from(8)))
+            ]
+        );
+    }
+
+    #[test]
+    fn intersect_disjoint() {
+        let g = DummyGraph::default();
+        let mut arena = Default::default();
+        let intersection = uint_range(0, 3)
+            .intersect(&uint_range(5, 9), &g, &mut arena)
+            .unwrap();
+        assert!(intersection.is_none());
+    }
+
+    #[test]
+    fn intersect_nested() {
+        let g = DummyGraph::default();
+        let mut arena = Default::default();
+        let outer = uint_range(0, 100);
+        let inner = uint_range(10, 20);
+        for (lhs, rhs) in [(&outer, &inner), (&inner, &outer)] {
+            let intersection = lhs.intersect(rhs, &g, &mut arena).unwrap().unwrap();
+            assert_eq!(
+                concrete_bounds(&intersection, &mut arena),
+                (
+                    Concrete::from(U256::from(10)),
+                    Concrete::from(U256::from(20))
+                )
+            );
+        }
+    }
+}
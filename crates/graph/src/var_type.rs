@@ -497,7 +497,7 @@ impl VarType {
             Self::User(TypeNode::Ty(ty), _) => {
                 BuiltInNode::from(ty.underlying(analyzer)?.ty).zero_range(analyzer)
             }
-            Self::BuiltIn(bn, None) => bn.zero_range(analyzer),
+            Self::BuiltIn(bn, _) => bn.zero_range(analyzer),
             Self::Concrete(cnode) => Ok(cnode.underlying(analyzer)?.as_builtin().zero_range()),
             _ => Ok(None),
         }
@@ -781,6 +781,46 @@ impl VarType {
         }
     }
 
+    /// The canonical ABI name of the type, as used in function signatures: contracts are
+    /// `address`, enums are `uint8`, structs are tuples of their fields and user defined value
+    /// types are their underlying type
+    pub fn as_abi_string(&self, analyzer: &impl GraphBackend) -> Result<String, GraphError> {
+        match self {
+            VarType::User(TypeNode::Contract(_), _) => Ok("address".to_string()),
+            VarType::User(TypeNode::Enum(_), _) => Ok("uint8".to_string()),
+            VarType::User(TypeNode::Struct(strukt), _) => Ok(format!(
+                "({})",
+                strukt
+                    .fields(analyzer)
+                    .iter()
+                    .map(|field| field.ty(analyzer)?.as_abi_string(analyzer))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(",")
+            )),
+            VarType::User(TypeNode::Ty(ty), _) => {
+                VarType::try_from_idx(analyzer, ty.underlying(analyzer)?.ty)
+                    .ok_or_else(|| GraphError::NodeConfusion("Non-typeable as type".to_string()))?
+                    .as_abi_string(analyzer)
+            }
+            VarType::User(TypeNode::Func(_), _) => Ok("function".to_string()),
+            VarType::BuiltIn(bn, _) => match bn.underlying(analyzer)? {
+                Builtin::Array(v_ty) => Ok(format!(
+                    "{}[]",
+                    v_ty.unresolved_as_resolved(analyzer)?
+                        .as_abi_string(analyzer)?
+                )),
+                Builtin::SizedArray(s, v_ty) => Ok(format!(
+                    "{}[{}]",
+                    v_ty.unresolved_as_resolved(analyzer)?
+                        .as_abi_string(analyzer)?,
+                    s
+                )),
+                _ => self.as_string(analyzer),
+            },
+            _ => self.as_string(analyzer),
+        }
+    }
+
     pub fn is_int(&self, analyzer: &impl GraphBackend) -> Result<bool, GraphError> {
         match self {
             VarType::BuiltIn(bn, _) => Ok(bn.underlying(analyzer)?.is_int()),
@@ -789,6 +829,22 @@ impl VarType {
         }
     }
 
+    pub fn is_address(&self, analyzer: &impl GraphBackend) -> Result<bool, GraphError> {
+        match self {
+            VarType::BuiltIn(bn, _) => Ok(bn.underlying(analyzer)?.is_address()),
+            VarType::Concrete(c) => Ok(c.underlying(analyzer)?.as_builtin().is_address()),
+            _ => Ok(false),
+        }
+    }
+
+    pub fn is_string(&self, analyzer: &impl GraphBackend) -> Result<bool, GraphError> {
+        match self {
+            VarType::BuiltIn(bn, _) => Ok(bn.underlying(analyzer)?.is_string()),
+            VarType::Concrete(c) => Ok(c.underlying(analyzer)?.as_builtin().is_string()),
+            _ => Ok(false),
+        }
+    }
+
     pub fn as_builtin(&self, analyzer: &impl GraphBackend) -> Result<Builtin, GraphError> {
         match self {
             VarType::BuiltIn(bn, _) => Ok(bn.underlying(analyzer)?.clone()),
@@ -806,6 +862,67 @@ impl VarType {
             None
         }
     }
+
+    /// The number of bytes a value of this type takes up in storage, and whether it can be packed
+    /// into a slot with its neighbours. Structs and static arrays are never packed and take up
+    /// whole slots, while mappings, dynamic arrays, strings and bytes take up a single slot
+    pub fn storage_size(&self, analyzer: &impl GraphBackend) -> Result<(usize, bool), GraphError> {
+        match self {
+            VarType::User(TypeNode::Contract(_), _) => Ok((20, true)),
+            VarType::User(TypeNode::Enum(enum_node), _) => {
+                let size = if enum_node.variants(analyzer)?.len() <= 256 {
+                    1
+                } else {
+                    2
+                };
+                Ok((size, true))
+            }
+            VarType::User(TypeNode::Struct(struct_node), _) => {
+                Ok((32 * struct_node.storage_slots(analyzer)?, false))
+            }
+            VarType::User(TypeNode::Ty(ty_node), _) => {
+                let ty = ty_node.underlying(analyzer)?.ty;
+                VarType::try_from_idx(analyzer, ty)
+                    .ok_or_else(|| {
+                        GraphError::NodeConfusion(format!(
+                            "User defined type \"{}\" has no underlying type",
+                            ty_node.name(analyzer).unwrap_or_default()
+                        ))
+                    })?
+                    .storage_size(analyzer)
+            }
+            VarType::User(TypeNode::Func(_), _) => Ok((24, true)),
+            VarType::User(e, _) => Err(GraphError::NodeConfusion(format!(
+                "Type cannot be stored in storage: {e:?}"
+            ))),
+            VarType::BuiltIn(..) | VarType::Concrete(_) => match self.as_builtin(analyzer)? {
+                Builtin::Address | Builtin::AddressPayable | Builtin::Payable => Ok((20, true)),
+                Builtin::Bool => Ok((1, true)),
+                Builtin::Int(size) | Builtin::Uint(size) => Ok((size as usize / 8, true)),
+                Builtin::Bytes(size) => Ok((size as usize, true)),
+                Builtin::Func(..) => Ok((24, true)),
+                Builtin::SizedArray(len, elem_ty) => {
+                    let len = if len > U256::from(usize::MAX) {
+                        usize::MAX
+                    } else {
+                        len.as_usize()
+                    };
+                    let (elem_size, packable) = elem_ty.storage_size(analyzer)?;
+                    let slots = if packable {
+                        len.div_ceil(32 / elem_size)
+                    } else {
+                        len.saturating_mul(elem_size.div_ceil(32))
+                    };
+                    Ok((slots.saturating_mul(32), false))
+                }
+                Builtin::String
+                | Builtin::DynamicBytes
+                | Builtin::Array(_)
+                | Builtin::Mapping(..)
+                | Builtin::Rational => Ok((32, false)),
+            },
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
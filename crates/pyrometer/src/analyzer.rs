@@ -4,7 +4,7 @@ use graph::elem::Elem;
 use graph::{nodes::*, ContextEdge, Edge, Node, VarType};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use shared::{AnalyzerLike, ApplyStats, GraphLike, NodeIdx, Search};
+use shared::{AnalyzerLike, ApplyStats, GraphLike, JoinStrategy, NodeIdx, Search};
 use shared::{ExprErr, IntoExprErr, RangeArena, USE_DEBUG_SITE};
 use solc_expressions::StatementParser;
 use tokio::runtime::Runtime;
@@ -12,14 +12,14 @@ use tracing::{error, trace, warn};
 
 use ahash::AHashMap;
 use ariadne::{Cache, Color, Config, Fmt, Label, Report, ReportKind, Source, Span};
-use petgraph::{graph::*, Directed};
+use petgraph::{graph::*, visit::EdgeRef, Directed};
 use serde_json::Value;
 use solang_parser::{
     diagnostics::Diagnostic,
     helpers::CodeLocation,
     pt::{
         ContractDefinition, ContractPart, EnumDefinition, ErrorDefinition, Expression,
-        FunctionDefinition, FunctionTy, Identifier, Import, SourceUnit, SourceUnitPart,
+        FunctionDefinition, FunctionTy, Identifier, Import, Loc, SourceUnit, SourceUnitPart,
         StructDefinition, TypeDefinition, Using, UsingList, VariableDefinition,
     },
 };
@@ -29,6 +29,7 @@ use std::{
     collections::BTreeMap,
     fs,
     path::{Path, PathBuf},
+    time::{Duration, Instant},
 };
 
 /// A path to either a single solidity file or a Solc Standard JSON file
@@ -132,6 +133,23 @@ pub struct Analyzer {
     pub max_depth: usize,
     /// The maximum number of forks throughout the lifetime of the analysis.
     pub max_width: usize,
+    /// How `ContextNode::joined_var_range` joins variable ranges where the branches of a fork
+    /// rejoin
+    pub join_strategy: JoinStrategy,
+    /// Whether to track linear equalities between variables to narrow related ranges
+    pub track_relations: bool,
+    /// The maximum nesting depth of struct fields assigned field by field (i.e. `a = b` for
+    /// structs assigns `a.inner.x = b.inner.x` at a depth of 2)
+    pub max_struct_depth: usize,
+    /// The maximum number of times a function is inlined into its own call stack. Deeper
+    /// recursive calls are not inlined, and their returns take the full range of their types
+    pub max_recursion_depth: usize,
+    /// The maximum number of nodes analyzing a single function may add to the graph, if any. Once
+    /// a function adds more, it is killed with a diagnostic rather than running out of memory
+    pub max_nodes: Option<usize>,
+    /// Whether implicit integer narrowing that could lose information (i.e. a `uint256` assigned
+    /// to a `uint128`) is an error rather than silently clamped
+    pub strict_casts: bool,
     /// Dummy function used during parsing to attach contexts to for more complex first-pass parsing (i.e. before `final_pass`)
     pub parse_fn: FunctionNode,
     /// Whether to force a panic on first error encountered
@@ -146,6 +164,11 @@ pub struct Analyzer {
     pub handled_funcs: Vec<FunctionNode>,
     /// Target Context to debug
     pub minimize_debug: Option<String>,
+    /// Whether to record how long analyzing each function takes into `function_timings`
+    pub time_functions: bool,
+    /// How long analyzing each function took, by contract qualified function name. Only
+    /// populated if `time_functions` is set
+    pub function_timings: BTreeMap<String, Duration>,
 }
 
 impl Default for Analyzer {
@@ -170,6 +193,12 @@ impl Default for Analyzer {
             expr_errs: Default::default(),
             max_depth: 200,
             max_width: 2_i32.pow(14) as usize, // 14 splits == 16384 contexts
+            join_strategy: JoinStrategy::default(),
+            track_relations: false,
+            max_struct_depth: 32,
+            max_recursion_depth: 4,
+            max_nodes: None,
+            strict_casts: false,
             parse_fn: NodeIdx::from(0).into(),
             debug_panic: false,
             fn_calls_fns: Default::default(),
@@ -183,6 +212,8 @@ impl Default for Analyzer {
                 },
             },
             handled_funcs: Vec::default(),
+            time_functions: false,
+            function_timings: Default::default(),
             minimize_debug: None,
         };
         a.builtin_fn_inputs = builtin_fns::builtin_fns_inputs(&mut a);
@@ -353,6 +384,20 @@ impl Analyzer {
             })
     }
 
+    /// Returns every context that was killed during analysis, along with how and where it was
+    /// killed, in the order the contexts were created
+    pub fn killed_contexts(&self) -> Vec<(ContextNode, KilledKind, Loc)> {
+        self.graph
+            .node_indices()
+            .filter_map(|idx| match self.graph.node_weight(idx) {
+                Some(Node::Context(c)) => c
+                    .killed
+                    .map(|(loc, kind)| (ContextNode::from(idx), kind, loc)),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn complicated_parse(
         &mut self,
         arena: &mut RangeArena<Elem<Concrete>>,
@@ -433,18 +478,24 @@ impl Analyzer {
     }
 
     pub fn update_with_solc_json(&mut self, path_to_json: &PathBuf) {
-        self.root = Root::SolcJSON(path_to_json.clone());
-
-        // iterate over the Solc JSON and add all the sources
         let json_file = fs::read_to_string(path_to_json)
             .unwrap_or_else(|_| panic!("Solc JSON file not found: {}", path_to_json.display()));
-        let solc_json: Value = serde_json::from_str(&json_file).unwrap();
+        self.update_with_solc_json_str(path_to_json, &json_file);
+    }
+
+    /// Adds the sources and remappings of a Solc Standard JSON input, where `path_to_json` is
+    /// only used to identify the input. The sources are not parsed
+    pub fn update_with_solc_json_str(&mut self, path_to_json: &Path, json: &str) {
+        self.root = Root::SolcJSON(path_to_json.to_path_buf());
+
+        // iterate over the Solc JSON and add all the sources
+        let solc_json: Value = serde_json::from_str(json).unwrap();
         let sources = solc_json["sources"].as_object().unwrap();
         for (name, value_obj) in sources {
             // value_obj is a Value with a `content` field -> save the `content` field's solidity string
             let sol_source = value_obj.as_object().unwrap()["content"].as_str().unwrap();
             // create SourcePath with the path to the JSON and the name of the source
-            let source_path = SourcePath::SolcJSON(path_to_json.clone(), name.to_owned());
+            let source_path = SourcePath::SolcJSON(path_to_json.to_path_buf(), name.to_owned());
             // Don't know the solang file no yet, so set it to None
             let source = (source_path.clone(), sol_source.to_owned(), None, None);
             self.sources.push(source);
@@ -459,16 +510,70 @@ impl Analyzer {
                 // split the remapping string into two parts
                 let remapping = remapping.as_str().unwrap();
                 let remapping = remapping.split_once('=').expect("Invalid remapping");
-                // remapping.0 is the name of the remapping
+                // remapping.0 is the name of the remapping, possibly behind a `context:` that
+                // limits which files it applies to. The context is dropped, applying it everywhere
+                let name = remapping
+                    .0
+                    .split_once(':')
+                    .map_or(remapping.0, |(_context, name)| name);
                 // remapping.1 is the path of the remapping
-                self.remappings.push((
-                    remapping.0.to_owned().to_string(),
-                    remapping.1.to_owned().to_string(),
-                ));
+                self.remappings
+                    .push((name.to_string(), remapping.1.to_owned().to_string()));
             }
         }
     }
 
+    /// Parses every source of a Solc Standard JSON input, resolving imports between them from
+    /// their embedded contents rather than the filesystem. Returns the source unit of each
+    /// source, in the order of the input's `sources`
+    pub fn parse_solc_json(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        path_to_json: &Path,
+        json: &str,
+    ) -> Vec<NodeIdx> {
+        let first = self.sources.len();
+        self.update_with_solc_json_str(path_to_json, json);
+        let paths = self.sources[first..]
+            .iter()
+            .map(|(path, ..)| path.clone())
+            .collect::<Vec<_>>();
+
+        let entries = paths
+            .iter()
+            .filter_map(|path| {
+                let idx = self
+                    .sources
+                    .iter()
+                    .position(|(source_path, ..)| source_path == path)?;
+                if let (_, _, Some(_), entry) = &self.sources[idx] {
+                    // already parsed as an import of an earlier source
+                    return *entry;
+                }
+                // `parse` adds the source back with its file number
+                let (_, sol, ..) = self.sources.remove(idx);
+                if self
+                    .sources
+                    .iter()
+                    .any(|(_, _, file_no, _)| file_no.is_some())
+                {
+                    self.file_no += 1;
+                }
+                let entry = self.parse(arena, &sol, path, false);
+                if let Some((.., optional_entry)) = self
+                    .sources
+                    .iter_mut()
+                    .find(|(source_path, ..)| source_path == path)
+                {
+                    *optional_entry = entry;
+                }
+                entry
+            })
+            .collect();
+        self.final_pass(arena);
+        entries
+    }
+
     pub fn print_errors(
         &self,
         file_mapping: &'_ BTreeMap<usize, String>,
@@ -627,11 +732,12 @@ impl Analyzer {
             //     }
             // });
 
-            final_pass_item.funcs.into_iter().for_each(|func| {
+            // constructors go first so that functions reading immutables see the values they set
+            let mut funcs = final_pass_item.funcs;
+            funcs.sort_by_key(|func| !func.is_constructor(self).unwrap_or(false));
+            funcs.into_iter().for_each(|func| {
                 if !self.handled_funcs.contains(&func) {
-                    if let Some(body) = &func.underlying(self).unwrap().body.clone() {
-                        self.parse_ctx_statement(arena, body, false, Some(func));
-                    }
+                    self.analyze_func_body(arena, func);
                 }
             });
 
@@ -639,6 +745,240 @@ impl Analyzer {
         });
     }
 
+    /// Builds the contexts of a function's body, recording how long it took if `time_functions`
+    /// is set
+    fn analyze_func_body(&mut self, arena: &mut RangeArena<Elem<Concrete>>, func: FunctionNode) {
+        if let Some(body) = &func.underlying(self).unwrap().body.clone() {
+            let start = self.time_functions.then(Instant::now);
+            self.parse_ctx_statement(arena, body, false, Some(func));
+            if let Some(start) = start {
+                let name = func.loc_specified_name(self).unwrap();
+                *self.function_timings.entry(name).or_default() += start.elapsed();
+            }
+        }
+    }
+
+    /// Re-analyzes an already parsed source after its contents changed to `src`, reusing the
+    /// analysis of everything it doesn't affect. The functions of the source are re-analyzed, as
+    /// are functions of other sources whose analysis called into them. Returns the re-analyzed
+    /// functions.
+    ///
+    /// Only changes within function bodies can be handled incrementally, and the locations of
+    /// everything after an edited body are shifted by the change in its length. Returns `None` if
+    /// the source is unknown, doesn't parse, or changed outside of its function bodies (even
+    /// just in whitespace), in which case the whole project needs to be parsed again
+    pub fn reanalyze_source(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        current_path: &SourcePath,
+        src: &str,
+    ) -> Option<Vec<FunctionNode>> {
+        let source_idx = self
+            .sources
+            .iter()
+            .position(|(path, _, file_no, _)| path == current_path && file_no.is_some())?;
+        let (_, old_src, file_no, _) = self.sources[source_idx].clone();
+        let file_no = file_no?;
+        let (old_unit, _) = solang_parser::parse(&old_src, file_no).ok()?;
+        let (new_unit, _) = solang_parser::parse(src, file_no).ok()?;
+
+        let old_defs = func_defs(&old_unit);
+        let new_defs = func_defs(&new_unit);
+        if skeleton(&old_src, &old_defs) != skeleton(src, &new_defs) {
+            return None;
+        }
+
+        // with everything outside of the bodies unchanged, functions are identified by their
+        // contract and header
+        let new_defs_by_header = new_defs
+            .iter()
+            .map(|(con, def)| ((con.clone(), header(src, def)), *def))
+            .collect::<BTreeMap<_, _>>();
+        let funcs = self
+            .graph
+            .node_indices()
+            .filter(|idx| matches!(self.node(*idx), Node::Function(_)))
+            .map(FunctionNode::from)
+            .collect::<Vec<_>>();
+        let mut changed = vec![];
+        for func in funcs {
+            let underlying = func.underlying(self).unwrap().clone();
+            if underlying.loc.try_file_no() != Some(file_no) {
+                continue;
+            }
+            let key = (
+                func.maybe_associated_contract(self)
+                    .map(|con| con.name(self).unwrap()),
+                header_of(&old_src, underlying.loc, underlying.body.as_ref()),
+            );
+            if let Some(def) = new_defs_by_header.get(&key) {
+                changed.push((func, *def));
+            }
+        }
+        let changed_funcs = changed.iter().map(|(func, _)| *func).collect::<Vec<_>>();
+
+        // functions elsewhere whose analysis inlined or applied a call into this source are
+        // stale too, as are the functions calling those
+        let ctxs = self
+            .graph
+            .node_indices()
+            .filter(|idx| matches!(self.node(*idx), Node::Context(_)))
+            .map(ContextNode::from)
+            .collect::<Vec<_>>();
+        let mut stale = changed_funcs.clone();
+        let mut callers = vec![];
+        loop {
+            let mut new_callers = vec![];
+            ctxs.iter().for_each(|ctx| {
+                let underlying = ctx.underlying(self).unwrap();
+                let mut parent = if underlying.applies.iter().any(|func| stale.contains(func)) {
+                    Some(*ctx)
+                } else if ctx
+                    .associated_fn(self)
+                    .is_ok_and(|func| stale.contains(&func))
+                {
+                    underlying.parent_ctx
+                } else {
+                    None
+                };
+                while let Some(ctx) = parent {
+                    if let Ok(func) = ctx.associated_fn(self) {
+                        if !stale.contains(&func) && !new_callers.contains(&func) {
+                            new_callers.push(func);
+                        }
+                    }
+                    parent = ctx.underlying(self).unwrap().parent_ctx;
+                }
+            });
+            if new_callers.is_empty() {
+                break;
+            }
+            stale.extend(new_callers.iter().copied());
+            callers.extend(new_callers);
+        }
+
+        self.sources[source_idx].1 = src.to_string();
+        self.shift_locs(file_no, &body_locs(&old_defs), &body_locs(&new_defs));
+        changed.into_iter().for_each(|(func, def)| {
+            self.update_func_def(arena, func, def);
+        });
+
+        let mut reanalyzed = changed_funcs;
+        reanalyzed.sort_by_key(|func| !func.is_constructor(self).unwrap_or(false));
+        reanalyzed.extend(callers);
+        reanalyzed.retain(|func| func.underlying(self).unwrap().body.is_some());
+        reanalyzed.iter().for_each(|func| {
+            self.detach_body_ctx(*func);
+            self.handled_funcs.retain(|handled| handled != func);
+            self.analyze_func_body(arena, *func);
+        });
+        Some(reanalyzed)
+    }
+
+    /// Replaces the definition of a function, including the locations of its parameters and
+    /// returns, with a new version of it that has the same header
+    fn update_func_def(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        func: FunctionNode,
+        def: &FunctionDefinition,
+    ) {
+        let params = func.params(self);
+        let rets = func.returns(arena, self);
+        let underlying = func.underlying_mut(self).unwrap();
+        underlying.loc = def.loc;
+        underlying.name_loc = def.name_loc;
+        underlying.attributes = def.attributes.clone();
+        underlying.body = def.body.clone();
+        underlying.params = def.params.clone();
+        underlying.returns = def.returns.clone();
+
+        params
+            .into_iter()
+            .zip(def.params.iter().filter_map(|(_, param)| param.as_ref()))
+            .for_each(|(param, new)| {
+                if let Node::FunctionParam(param) = self.node_mut(param) {
+                    param.loc = new.loc;
+                    param.name = new.name.clone();
+                }
+            });
+        rets.into_iter()
+            .zip(def.returns.iter().filter_map(|(_, ret)| ret.as_ref()))
+            .for_each(|(ret, new)| {
+                if let Node::FunctionReturn(ret) = self.node_mut(ret) {
+                    ret.loc = new.loc;
+                    ret.name = new.name.clone();
+                }
+            });
+    }
+
+    /// Moves every location in a source that lies outside of its function bodies to where it is
+    /// after the bodies changed from `old_bodies` to `new_bodies`, which are in source order.
+    /// Locations within the old bodies belong to contexts that are about to be re-analyzed, or to
+    /// the functions themselves, which get their new definitions, so they are left as they are
+    fn shift_locs(&mut self, file_no: usize, old_bodies: &[Loc], new_bodies: &[Loc]) {
+        let shift = |offset: usize| {
+            if old_bodies
+                .iter()
+                .any(|body| body.start() < offset && offset < body.end())
+            {
+                return offset;
+            }
+            old_bodies
+                .iter()
+                .zip(new_bodies)
+                .rfind(|(old, _)| old.end() <= offset)
+                .map_or(offset, |(old, new)| offset - old.end() + new.end())
+        };
+        let shift_loc = |loc: &mut Loc| {
+            if let Loc::File(no, start, end) = loc {
+                if *no == file_no {
+                    *start = shift(*start);
+                    *end = shift(*end);
+                }
+            }
+        };
+
+        let nodes = self.graph.node_indices().collect::<Vec<_>>();
+        nodes.into_iter().for_each(|idx| match self.node_mut(idx) {
+            Node::Context(ctx) => shift_loc(&mut ctx.loc),
+            Node::ContextVar(var) => var.loc.iter_mut().for_each(shift_loc),
+            Node::Contract(con) => shift_loc(&mut con.loc),
+            Node::Function(func) => {
+                shift_loc(&mut func.loc);
+                shift_loc(&mut func.name_loc);
+            }
+            Node::FunctionParam(param) => shift_loc(&mut param.loc),
+            Node::FunctionReturn(ret) => shift_loc(&mut ret.loc),
+            Node::Struct(strukt) => shift_loc(&mut strukt.loc),
+            Node::Enum(enu) => shift_loc(&mut enu.loc),
+            Node::Error(err) => shift_loc(&mut err.loc),
+            Node::ErrorParam(param) => shift_loc(&mut param.loc),
+            Node::Field(field) => shift_loc(&mut field.loc),
+            Node::Var(var) => shift_loc(&mut var.loc),
+            Node::Ty(ty) => shift_loc(&mut ty.loc),
+            Node::Unresolved(ident) => shift_loc(&mut ident.loc),
+            _ => {}
+        });
+    }
+
+    /// Disconnects the body context of a function so that its body can be analyzed again.
+    ///
+    /// The old context tree is left in the graph, unreachable from the function: `Graph` shifts
+    /// node indices on removal, which would invalidate the indices held by ranges and caches, so
+    /// its nodes are leaked until the next full parse
+    fn detach_body_ctx(&mut self, func: FunctionNode) {
+        while let Some(edge) = self
+            .graph
+            .edges_directed(func.into(), petgraph::Direction::Incoming)
+            .find(|edge| *edge.weight() == Edge::Context(ContextEdge::Context))
+            .map(|edge| edge.id())
+        {
+            self.graph.remove_edge(edge);
+        }
+        func.underlying_mut(self).unwrap().cache.body_ctx = None;
+    }
+
     #[tracing::instrument(level = "trace", skip_all)]
     pub fn parse_source_unit(
         &mut self,
@@ -1606,6 +1946,65 @@ pub fn print_diagnostics_report(
     Ok(())
 }
 
+/// Every function definition in a source unit along with the name of its contract, if any
+fn func_defs(source_unit: &SourceUnit) -> Vec<(Option<String>, &FunctionDefinition)> {
+    source_unit
+        .0
+        .iter()
+        .flat_map(|part| match part {
+            SourceUnitPart::FunctionDefinition(def) => vec![(None, &**def)],
+            SourceUnitPart::ContractDefinition(con) => con
+                .parts
+                .iter()
+                .filter_map(|part| match part {
+                    ContractPart::FunctionDefinition(def) => {
+                        Some((con.name.as_ref().map(|name| name.name.clone()), &**def))
+                    }
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        })
+        .collect()
+}
+
+/// The source of a function definition up to its body, with whitespace normalized
+fn header(src: &str, def: &FunctionDefinition) -> String {
+    header_of(src, def.loc, def.body.as_ref())
+}
+
+fn header_of(src: &str, loc: Loc, body: Option<&solang_parser::pt::Statement>) -> String {
+    let end = body.map_or(loc.end(), |body| body.loc().start().min(loc.end()));
+    normalize_whitespace(src.get(loc.start()..end).unwrap_or_default())
+}
+
+/// The locations of the function bodies in a source, in source order
+fn body_locs(defs: &[(Option<String>, &FunctionDefinition)]) -> Vec<Loc> {
+    let mut bodies = defs
+        .iter()
+        .filter_map(|(_, def)| def.body.as_ref().map(|body| body.loc()))
+        .collect::<Vec<_>>();
+    bodies.sort_by_key(|loc| loc.start());
+    bodies
+}
+
+/// The source with every function body removed. Whitespace is kept as is so that every location
+/// outside of the bodies can be mapped between two sources with the same skeleton
+fn skeleton(src: &str, defs: &[(Option<String>, &FunctionDefinition)]) -> String {
+    let mut out = String::with_capacity(src.len());
+    let mut pos = 0;
+    body_locs(defs).into_iter().for_each(|loc| {
+        out.push_str(&src[pos..loc.start()]);
+        pos = loc.end();
+    });
+    out.push_str(&src[pos..]);
+    out
+}
+
+fn normalize_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
 /// Normalize the path by resolving the `.` and `..` components in order to do path comparison.
 ///
 /// This is used instead of `std::fs::canonicalize()` in cases where the path is not present on the filesystem (e.g. in the case of a Solc Standard JSON)
@@ -9,8 +9,8 @@ use graph::{
     AnalyzerBackend, Edge, GraphBackend, Node, RepresentationInvariant, TypeNode, VarType,
 };
 use shared::{
-    AnalyzerLike, ApplyStats, ExprErr, GraphError, GraphLike, IntoExprErr, NodeIdx, RangeArena,
-    RepresentationErr,
+    AnalyzerLike, ApplyStats, ExprErr, GraphError, GraphLike, IntoExprErr, JoinStrategy, NodeIdx,
+    RangeArena, RepresentationErr,
 };
 
 use ahash::AHashMap;
@@ -66,6 +66,30 @@ impl AnalyzerLike for Analyzer {
         self.max_width
     }
 
+    fn join_strategy(&self) -> JoinStrategy {
+        self.join_strategy
+    }
+
+    fn track_relations(&self) -> bool {
+        self.track_relations
+    }
+
+    fn max_struct_depth(&self) -> usize {
+        self.max_struct_depth
+    }
+
+    fn max_recursion_depth(&self) -> usize {
+        self.max_recursion_depth
+    }
+
+    fn max_nodes(&self) -> Option<usize> {
+        self.max_nodes
+    }
+
+    fn strict_casts(&self) -> bool {
+        self.strict_casts
+    }
+
     fn minimize_err(&mut self, ctx: ContextNode) -> String {
         let genesis = ctx.genesis(self).unwrap();
         let mut family_tree = genesis.family_tree(self).unwrap();
@@ -0,0 +1,2966 @@
+use analyzers::{
+    bounds::{range_parts, RangePart},
+    ConstantCmpAnalyzer, FunctionVarsBoundAnalysis, FunctionVarsBoundAnalyzer, GuardAnalyzer,
+    IndexBoundsAnalyzer, OrderedPairs, OverflowAnalyzer, RangeChange, RangeDiffer, ReportConfig,
+    ReportConfigBuilder, ReportDisplay, RevertAnalyzer, TmpFilter, UninitReadAnalyzer,
+    VarBoundAnalyzer,
+};
+use ariadne::{ReportKind, Span};
+use ethers_core::types::{Address, I256, U256};
+use graph::{
+    elem::{Elem, RangeElem},
+    nodes::{Builtin, Concrete, ContextNode, GuardKind, KilledKind, MemoryLayout, RangeKind},
+    range_string::ToRangeString,
+};
+use pyrometer::{Analyzer, SourcePath};
+use shared::{GraphError, JoinStrategy, RangeArena};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::env;
+use std::path::PathBuf;
+mod helpers;
+use helpers::*;
+
+#[test]
+fn test_balance_tracks_value_transfers() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/balance_killed.sol");
+    let sol = include_str!("./test_data/balance_killed.sol");
+    let mut killed = killed_fns(path_str, sol);
+    killed.sort();
+    assert_eq!(killed, vec!["receivedTooLittle()", "sentTooMuch(address)"]);
+}
+
+#[test]
+fn test_this_balance_range() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/this_balance.sol");
+    let sol = include_str!("./test_data/this_balance.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    // the value sent to a payable entry point is part of the balance, including when it is read
+    // by an internal function it calls
+    for (name, expected) in [
+        ("payIn", "[ 5, 2**256 - 1 ]"),
+        ("viaHelper", "[ 5, 2**256 - 1 ]"),
+        ("helperBalance", "[ 0, 2**256 - 1 ]"),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, name).body_ctx(&mut analyzer);
+        let leaves = ctx.successful_edges(&analyzer).unwrap();
+        let ret = leaves
+            .first()
+            .unwrap_or(&ctx)
+            .return_nodes(&analyzer)
+            .unwrap()[0]
+            .1;
+        assert_eq!(
+            ret.range_string(&mut analyzer, &mut arena)
+                .unwrap()
+                .unwrap(),
+            expected,
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn test_markdown_report() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/report.sol");
+    let sol = include_str!("./test_data/report.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str.clone(), sol);
+    let file_mapping = file_mapping(&analyzer);
+    let sources = BTreeMap::from([(path_str.clone(), sol.to_string())]);
+    let ctx = func_by_name(&analyzer, entry, "bounded").body_ctx(&mut analyzer);
+    let md = analyzer
+        .bounds_for_all(&mut arena, &file_mapping, ctx, ReportConfig::default())
+        .to_markdown(&analyzer, &mut arena, &sources);
+
+    assert!(md.contains("| variable | definition loc | min | max | exclusions |"));
+    assert!(md.contains(&format!("| `x` | {path_str}:4:22 | 0 | 99 | uint256(50) |")));
+    assert!(md.contains("```solidity\n"));
+    assert!(md.contains("require(x < 100);"));
+}
+
+#[test]
+fn test_witness_max() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/witness.sol");
+    let sol = include_str!("./test_data/witness.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let ctx = func_by_name(&analyzer, entry, "add").body_ctx(&mut analyzer);
+    let z = ctx
+        .var_by_name_or_recurse(&analyzer, "z")
+        .unwrap()
+        .unwrap()
+        .latest_version(&analyzer);
+    let witness = z.witness_max(&analyzer, &mut arena).unwrap().unwrap();
+
+    assert_eq!(
+        witness,
+        BTreeMap::from([
+            ("a".to_string(), Concrete::Uint(256, U256::from(99))),
+            ("b".to_string(), Concrete::Uint(256, U256::from(49))),
+        ])
+    );
+
+    // the divisor is minimized, but to a nonzero value
+    let ctx = func_by_name(&analyzer, entry, "div").body_ctx(&mut analyzer);
+    let live = ctx
+        .all_edges(&analyzer)
+        .unwrap()
+        .into_iter()
+        .find(|leaf| !leaf.is_killed(&analyzer).unwrap())
+        .unwrap();
+    let z = live
+        .var_by_name_or_recurse(&analyzer, "z")
+        .unwrap()
+        .unwrap()
+        .latest_version(&analyzer);
+    let witness = z.witness_max(&analyzer, &mut arena).unwrap().unwrap();
+    assert_eq!(
+        witness,
+        BTreeMap::from([
+            ("a".to_string(), Concrete::Uint(256, U256::from(99))),
+            ("b".to_string(), Concrete::Uint(256, U256::from(1))),
+        ])
+    );
+
+    // signed arithmetic isn't monotone, so no witness is given
+    let ctx = func_by_name(&analyzer, entry, "signedAdd").body_ctx(&mut analyzer);
+    let z = ctx
+        .var_by_name_or_recurse(&analyzer, "z")
+        .unwrap()
+        .unwrap()
+        .latest_version(&analyzer);
+    assert!(z.witness_max(&analyzer, &mut arena).unwrap().is_none());
+}
+
+#[test]
+fn test_final_range() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/report.sol");
+    let sol = include_str!("./test_data/report.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let ctx = func_by_name(&analyzer, entry, "bounded").body_ctx(&mut analyzer);
+
+    let range = analyzer.final_range(ctx, "x", &mut arena).unwrap();
+    assert_eq!(
+        range.min.maybe_concrete().unwrap().val,
+        Concrete::Uint(256, U256::from(0))
+    );
+    assert_eq!(
+        range.max.maybe_concrete().unwrap().val,
+        Concrete::Uint(256, U256::from(99))
+    );
+
+    assert!(analyzer.final_range(ctx, "missing", &mut arena).is_none());
+}
+
+#[test]
+fn test_emit_arg_ranges() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/emit.sol");
+    let sol = include_str!("./test_data/emit.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let ctx = func_by_name(&analyzer, entry, "emitBounded").body_ctx(&mut analyzer);
+
+    let emits = ctx.emits(&analyzer).unwrap();
+    assert_eq!(emits.len(), 1);
+    let (_, event_name, args) = &emits[0];
+    assert_eq!(event_name, "Bounded");
+    assert_eq!(args.len(), 1);
+    let min = args[0].evaled_range_min(&analyzer, &mut arena).unwrap();
+    let max = args[0].evaled_range_max(&analyzer, &mut arena).unwrap();
+    assert_eq!(
+        min.unwrap().maybe_concrete().unwrap().val,
+        Concrete::Uint(256, U256::from(11))
+    );
+    assert_eq!(
+        max.unwrap().maybe_concrete().unwrap().val,
+        Concrete::Uint(256, U256::from(19))
+    );
+}
+
+#[test]
+fn test_preserve_constant_names() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/constants.sol");
+    let sol = include_str!("./test_data/constants.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let ctx = func_by_name(&analyzer, entry, "capped").body_ctx(&mut analyzer);
+    let y = ctx
+        .var_by_name_or_recurse(&analyzer, "y")
+        .unwrap()
+        .unwrap()
+        .latest_version(&analyzer);
+    let range = y.range(&analyzer).unwrap().unwrap();
+
+    let folded = range_parts(&analyzer, &mut arena, &ReportConfig::default(), &range).0;
+    assert_eq!(folded[0], RangePart::Equal("1000".to_string()));
+
+    let config = ReportConfig {
+        preserve_constant_names: true,
+        ..Default::default()
+    };
+    let named = range_parts(&analyzer, &mut arena, &config, &range).0;
+    assert_eq!(named[0], RangePart::Equal("MAX_SUPPLY".to_string()));
+}
+
+#[test]
+fn test_hex_values() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/hex_values.sol");
+    let sol = include_str!("./test_data/hex_values.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let ctx = func_by_name(&analyzer, entry, "values").body_ctx(&mut analyzer);
+    let hex_config = ReportConfig::builder().hex_values(true).build();
+
+    for (var, plain, hex) in [
+        (
+            "a",
+            "0x5b38da6a701c568545dcfcb03fcb875f56beddc4",
+            "0x5B38Da6a701c568545dCfcB03FcB875f56beddC4",
+        ),
+        ("b", "0xdeadbeef", "0xdeadbeef"),
+        // numbers are never rendered as hex, a hex literal's text is shown beside its value
+        ("c", "16 (0x10)", "16 (0x10)"),
+    ] {
+        let cvar = ctx
+            .var_by_name_or_recurse(&analyzer, var)
+            .unwrap()
+            .unwrap()
+            .latest_version(&analyzer);
+        let range = cvar.range(&analyzer).unwrap().unwrap();
+
+        let default = range_parts(&analyzer, &mut arena, &ReportConfig::default(), &range).0;
+        assert_eq!(default[0], RangePart::Equal(plain.to_string()));
+        let hexed = range_parts(&analyzer, &mut arena, &hex_config, &range).0;
+        assert_eq!(hexed[0], RangePart::Equal(hex.to_string()));
+    }
+}
+
+#[test]
+fn test_bounds_for_unknown_var_errors() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/report.sol");
+    let sol = include_str!("./test_data/report.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+    let ctx = func_by_name(&analyzer, entry, "bounded").body_ctx(&mut analyzer);
+
+    let known = analyzer.bounds_for_var_in_family_tree(
+        &mut arena,
+        &file_mapping,
+        vec![ctx],
+        "x".to_string(),
+        ReportConfig::default(),
+    );
+    assert_eq!(known.unwrap().var_display_name, "x");
+
+    let unknown = analyzer.bounds_for_var_in_family_tree(
+        &mut arena,
+        &file_mapping,
+        vec![ctx],
+        "missing".to_string(),
+        ReportConfig::default(),
+    );
+    assert!(matches!(unknown, Err(GraphError::UnknownVariable(_))));
+}
+
+#[test]
+fn test_safe_math_add_narrows_result() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/safe_math.sol");
+    let sol = include_str!("./test_data/safe_math.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let ctx = func_by_name(&analyzer, entry, "safeAdd").body_ctx(&mut analyzer);
+    assert!(!ctx.is_killed(&analyzer).unwrap());
+    let c = ctx
+        .var_by_name_or_recurse(&analyzer, "c")
+        .unwrap()
+        .unwrap()
+        .latest_version(&analyzer);
+
+    // the unchecked add could wrap below `a`, but the wrapper's require rules that out
+    let min = c.evaled_range_min(&analyzer, &mut arena).unwrap().unwrap();
+    let max = c.evaled_range_max(&analyzer, &mut arena).unwrap().unwrap();
+    assert_eq!(
+        min.maybe_concrete().unwrap().val,
+        Concrete::Uint(256, U256::from(10))
+    );
+    assert_eq!(
+        max.maybe_concrete().unwrap().val,
+        Concrete::Uint(256, U256::MAX)
+    );
+}
+
+#[test]
+fn test_storage_writes() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/storage_writes.sol");
+    let sol = include_str!("./test_data/storage_writes.sol");
+    let (analyzer, _arena, entry) = parse_analyzer(path_str, sol);
+    let contract = func_by_name(&analyzer, entry, "setA")
+        .maybe_slow_associated_contract(&analyzer)
+        .unwrap();
+
+    let mut writes = contract
+        .storage_writes(&analyzer)
+        .into_iter()
+        .map(|(slot, value, _loc)| {
+            (
+                slot.display_name(&analyzer).unwrap(),
+                value.display_name(&analyzer).unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    writes.sort();
+    assert_eq!(
+        writes,
+        vec![
+            ("a".to_string(), "x".to_string()),
+            ("b".to_string(), "y".to_string())
+        ]
+    );
+}
+
+#[test]
+fn test_do_while_first_iteration() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/do_while.sol");
+    let sol = include_str!("./test_data/do_while.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let ctx = func_by_name(&analyzer, entry, "runsOnce").body_ctx(&mut analyzer);
+    assert!(!ctx.is_killed(&analyzer).unwrap());
+    let x = ctx
+        .var_by_name_or_recurse(&analyzer, "x")
+        .unwrap()
+        .unwrap()
+        .latest_version(&analyzer);
+    let min = x.evaled_range_min(&analyzer, &mut arena).unwrap().unwrap();
+    let max = x.evaled_range_max(&analyzer, &mut arena).unwrap().unwrap();
+    assert_eq!(
+        min.maybe_concrete().unwrap().val,
+        Concrete::Uint(256, U256::from(5))
+    );
+    assert_eq!(
+        max.maybe_concrete().unwrap().val,
+        Concrete::Uint(256, U256::from(5))
+    );
+}
+
+#[test]
+fn test_loop_keeps_read_only_vars() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/loop_invariant.sol");
+    let sol = include_str!("./test_data/loop_invariant.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let ctx = func_by_name(&analyzer, entry, "counter").body_ctx(&mut analyzer);
+    let post_loop = ctx.all_edges(&analyzer).unwrap();
+    assert!(!post_loop.is_empty());
+    for ctx in post_loop {
+        let n = ctx
+            .var_by_name_or_recurse(&analyzer, "n")
+            .unwrap()
+            .unwrap()
+            .latest_version(&analyzer);
+        assert_eq!(
+            n.range_string(&mut analyzer, &mut arena).unwrap().unwrap(),
+            "[ 10, 10 ]"
+        );
+
+        let count = ctx
+            .var_by_name_or_recurse(&analyzer, "count")
+            .unwrap()
+            .unwrap()
+            .latest_version(&analyzer);
+        assert_eq!(
+            count
+                .range_string(&mut analyzer, &mut arena)
+                .unwrap()
+                .unwrap(),
+            "[ 0, 2**256 - 1 ]"
+        );
+    }
+}
+
+#[test]
+fn test_msg_value_follows_payability() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/msg_value.sol");
+    let sol = include_str!("./test_data/msg_value.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let ctx = func_by_name(&analyzer, entry, "nonPayable").body_ctx(&mut analyzer);
+    let v = ctx
+        .var_by_name_or_recurse(&analyzer, "v")
+        .unwrap()
+        .unwrap()
+        .latest_version(&analyzer);
+    assert_eq!(
+        v.range_string(&mut analyzer, &mut arena).unwrap().unwrap(),
+        "[ 0, 0 ]"
+    );
+
+    let ctx = func_by_name(&analyzer, entry, "payableFn").body_ctx(&mut analyzer);
+    let v = ctx
+        .var_by_name_or_recurse(&analyzer, "v")
+        .unwrap()
+        .unwrap()
+        .latest_version(&analyzer);
+    assert_eq!(
+        v.range_string(&mut analyzer, &mut arena).unwrap().unwrap(),
+        "[ 0, 2**256 - 1 ]"
+    );
+}
+
+#[test]
+fn test_unbounded_loop_reports_widened() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/loop_invariant.sol");
+    let sol = include_str!("./test_data/loop_invariant.sol");
+    let (mut analyzer, _arena, entry) = parse_analyzer(path_str, sol);
+
+    // `count < m` has no constant bound, so there is no estimate. `i < 3` from 0 runs 3 times,
+    // and the do-while's first iteration is interpreted before the loop, so it counts towards
+    // both the estimate and the interpretations
+    for (func, iterations, interpreted, msg) in [
+        (
+            "counter",
+            None,
+            1,
+            "Loop approximated: body interpreted 1 time(s), then variables mutated in the loop were widened",
+        ),
+        (
+            "bounded",
+            Some(3),
+            1,
+            "Loop approximated: runs at most 3 time(s), body interpreted 1 time(s), then variables mutated in the loop were widened",
+        ),
+        (
+            "doWhile",
+            Some(3),
+            2,
+            "Loop approximated: runs at most 3 time(s), body interpreted 2 time(s), then variables mutated in the loop were widened",
+        ),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let leaves = ctx.all_edges(&analyzer).unwrap();
+        assert!(!leaves.is_empty());
+        for leaf in leaves {
+            let loops = leaf.widened_loops(&analyzer).unwrap();
+            assert_eq!(loops.len(), 1, "{func}");
+            let (loop_ctx, info) = loops[0];
+            assert_eq!(loop_ctx.loop_info(&analyzer).unwrap(), Some(info));
+            assert!(info.widened);
+            assert_eq!(info.iterations, iterations, "{func}");
+            assert_eq!(info.interpreted, interpreted, "{func}");
+            assert_eq!(info.analysis_str(), msg);
+        }
+        assert_eq!(ctx.loop_info(&analyzer).unwrap(), None);
+    }
+}
+
+#[test]
+fn test_always_reverts_diagnostic() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/always_reverts.sol");
+    let sol = include_str!("./test_data/always_reverts.sol");
+    let (mut analyzer, _arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+
+    let ctx = func_by_name(&analyzer, entry, "bothBranchesRevert").body_ctx(&mut analyzer);
+    let diagnostic = analyzer
+        .always_reverts(&file_mapping, ctx)
+        .unwrap()
+        .expect("function should always revert");
+    assert_eq!(diagnostic.func_name, "bothBranchesRevert(uint256)");
+    assert!(!diagnostic.revert_locs.is_empty());
+
+    let ctx = func_by_name(&analyzer, entry, "oneBranchReturns").body_ctx(&mut analyzer);
+    assert!(analyzer
+        .always_reverts(&file_mapping, ctx)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn test_ternary_index_bounds() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/ternary_index.sol");
+    let sol = include_str!("./test_data/ternary_index.sol");
+    let (mut analyzer, _arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+
+    let ctx = func_by_name(&analyzer, entry, "inBounds").body_ctx(&mut analyzer);
+    assert!(analyzer.index_bounds(&file_mapping, ctx).unwrap().is_none());
+
+    let ctx = func_by_name(&analyzer, entry, "maybeOutOfBounds").body_ctx(&mut analyzer);
+    let diagnostic = analyzer
+        .index_bounds(&file_mapping, ctx)
+        .unwrap()
+        .expect("index may be out of bounds");
+    assert_eq!(diagnostic.accesses.len(), 1);
+}
+
+#[test]
+fn test_guarded_index_bounds() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/guarded_index.sol");
+    let sol = include_str!("./test_data/guarded_index.sol");
+    let (mut analyzer, _arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+
+    // the path already requires the index to be less than the length
+    for func in ["guarded", "required"] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        assert!(
+            analyzer.index_bounds(&file_mapping, ctx).unwrap().is_none(),
+            "{func}"
+        );
+    }
+
+    // excluding only the largest index leaves every other large one out of bounds
+    for func in ["excluded", "unguarded"] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let diagnostic = analyzer
+            .index_bounds(&file_mapping, ctx)
+            .unwrap()
+            .expect("index may be out of bounds");
+        let counterexample = diagnostic.counterexample.expect("no counterexample");
+        let i = counterexample["i"].uint_val().unwrap();
+        let len = counterexample["arr.length"].uint_val().unwrap();
+        assert!(i >= len, "{func}: {counterexample:?}");
+        assert!(i != U256::MAX || func == "unguarded", "{counterexample:?}");
+    }
+}
+
+#[test]
+fn test_ctx_range_diff() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/ctx_diff.sol");
+    let sol = include_str!("./test_data/ctx_diff.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let ctx = func_by_name(&analyzer, entry, "f").body_ctx(&mut analyzer);
+    let true_branch = ctx
+        .all_edges(&analyzer)
+        .unwrap()
+        .into_iter()
+        .find(|leaf| leaf.path(&analyzer).contains("true"))
+        .unwrap();
+
+    let diffs = analyzer
+        .diff_ctx_ranges(ctx, true_branch, &mut arena)
+        .unwrap();
+    let change = |name: &str| {
+        diffs
+            .iter()
+            .find(|diff| diff.var_name == name)
+            .unwrap()
+            .change
+    };
+    assert_eq!(change("x"), RangeChange::Narrowed);
+    assert_eq!(change("y"), RangeChange::Unchanged);
+
+    let diffs = analyzer
+        .diff_ctx_ranges(true_branch, ctx, &mut arena)
+        .unwrap();
+    let x = diffs.iter().find(|diff| diff.var_name == "x").unwrap();
+    assert_eq!(x.change, RangeChange::Widened);
+}
+
+#[test]
+fn test_branch_join_strategies() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/join_strategy.sol");
+    let sol = include_str!("./test_data/join_strategy.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let ctx = func_by_name(&analyzer, entry, "f").body_ctx(&mut analyzer);
+    let elem = |n: u64| Elem::from(Concrete::from(U256::from(n)));
+
+    // forks are not merged during analysis, the strategy only applies to queries of the range
+    // where the branches rejoin
+    assert_eq!(analyzer.join_strategy, JoinStrategy::Union);
+    let joined = ctx
+        .joined_var_range("x", &analyzer, &mut arena)
+        .unwrap()
+        .unwrap();
+    assert_eq!((joined.min, joined.max), (elem(5), elem(10)));
+
+    analyzer.join_strategy = JoinStrategy::WidenToType;
+    let joined = ctx
+        .joined_var_range("x", &analyzer, &mut arena)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        (joined.min, joined.max),
+        (elem(0), Elem::from(Concrete::from(U256::MAX)))
+    );
+
+    // so analyzed ranges, like those of ternary branches, don't depend on the strategy
+    let path_str = format!("{manifest_dir}/tests/test_data/ternary.sol");
+    let sol = include_str!("./test_data/ternary.sol");
+    let widening = Analyzer {
+        join_strategy: JoinStrategy::WidenToType,
+        ..Default::default()
+    };
+    let (mut analyzer, mut arena, entry) = parse_with_analyzer(widening, path_str, sol);
+    let ctx = func_by_name(&analyzer, entry, "symbolicCond").body_ctx(&mut analyzer);
+    let branches = ctx.all_edges(&analyzer).unwrap();
+    for (branch, expected) in branches.into_iter().zip(["[ 11, 49 ]", "[ 100, 100 ]"]) {
+        let y = branch
+            .var_by_name_or_recurse(&analyzer, "y")
+            .unwrap()
+            .unwrap()
+            .latest_version_or_inherited_in_ctx(branch, &analyzer);
+        assert_eq!(
+            y.range_string(&mut analyzer, &mut arena).unwrap().unwrap(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_ordered_pairs() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/ordered_pairs.sol");
+    let sol = include_str!("./test_data/ordered_pairs.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let ctx = func_by_name(&analyzer, entry, "f").body_ctx(&mut analyzer);
+    let pairs = analyzer
+        .ordered_pairs(ctx, &mut arena)
+        .unwrap()
+        .into_iter()
+        .map(|(a, ord, b)| {
+            let (a, b) = (a.name(&analyzer).unwrap(), b.name(&analyzer).unwrap());
+            // normalize to `lesser <= greater`
+            match ord {
+                std::cmp::Ordering::Greater => (b, a),
+                _ => (a, b),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // from `require(lo <= hi)`
+    assert!(pairs.contains(&("lo".to_string(), "hi".to_string())));
+    // from concrete assignments
+    assert!(pairs.contains(&("small".to_string(), "big".to_string())));
+    assert!(!pairs.iter().any(|(a, b)| a == "other" || b == "other"));
+
+    // `hi` no longer holds the value `lo` was required to be at most
+    let ctx = func_by_name(&analyzer, entry, "reassigned").body_ctx(&mut analyzer);
+    let pairs = analyzer
+        .ordered_pairs(ctx, &mut arena)
+        .unwrap()
+        .into_iter()
+        .map(|(a, ord, b)| (a.name(&analyzer).unwrap(), ord, b.name(&analyzer).unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        pairs,
+        vec![("hi".to_string(), std::cmp::Ordering::Less, "lo".to_string())]
+    );
+}
+
+#[test]
+fn test_bytes_index_bounds() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/bytes_index.sol");
+    let sol = include_str!("./test_data/bytes_index.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+
+    let ctx = func_by_name(&analyzer, entry, "inBounds").body_ctx(&mut analyzer);
+    assert!(analyzer.index_bounds(&file_mapping, ctx).unwrap().is_none());
+    let x = ctx
+        .var_by_name_or_recurse(&analyzer, "x")
+        .unwrap()
+        .unwrap()
+        .latest_version(&analyzer);
+    assert_eq!(
+        x.ty(&analyzer).unwrap().as_string(&analyzer).unwrap(),
+        "bytes1"
+    );
+    assert_eq!(
+        x.range_string(&mut analyzer, &mut arena).unwrap().unwrap(),
+        "[ 0xbb, 0xbb ]"
+    );
+
+    let ctx = func_by_name(&analyzer, entry, "maybeOutOfBounds").body_ctx(&mut analyzer);
+    let diagnostic = analyzer
+        .index_bounds(&file_mapping, ctx)
+        .unwrap()
+        .expect("index may be out of bounds");
+    assert_eq!(diagnostic.accesses.len(), 1);
+}
+
+#[test]
+fn test_overflow_counterexample() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/overflow.sol");
+    let sol = include_str!("./test_data/overflow.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+
+    let ctx = func_by_name(&analyzer, entry, "boundedAdd").body_ctx(&mut analyzer);
+    assert!(analyzer
+        .overflows(&file_mapping, ctx, &mut arena)
+        .unwrap()
+        .is_none());
+
+    let ctx = func_by_name(&analyzer, entry, "add").body_ctx(&mut analyzer);
+    let diagnostic = analyzer
+        .overflows(&file_mapping, ctx, &mut arena)
+        .unwrap()
+        .expect("addition may overflow");
+    assert_eq!(diagnostic.overflows.len(), 1);
+
+    let counterexample = diagnostic.counterexample.expect("no counterexample");
+    let a = counterexample["a"].uint_val().unwrap();
+    let b = counterexample["b"].uint_val().unwrap();
+    assert!(a.checked_add(b).is_none());
+
+    // the counterexample respects the path's constraints
+    let ctx = func_by_name(&analyzer, entry, "excludedAdd").body_ctx(&mut analyzer);
+    let diagnostic = analyzer
+        .overflows(&file_mapping, ctx, &mut arena)
+        .unwrap()
+        .expect("addition may overflow");
+    let counterexample = diagnostic.counterexample.expect("no counterexample");
+    let a = counterexample["a"].uint_val().unwrap();
+    let b = counterexample["b"].uint_val().unwrap();
+    assert_ne!(a, U256::MAX);
+    assert!(a.checked_add(b).is_none(), "{counterexample:?}");
+
+    // `n` is excluded from being zero on the path that subtracts from it
+    let ctx = func_by_name(&analyzer, entry, "guardedSub").body_ctx(&mut analyzer);
+    assert!(analyzer
+        .overflows(&file_mapping, ctx, &mut arena)
+        .unwrap()
+        .is_none());
+
+    // the literal operand is not part of the counterexample
+    let ctx = func_by_name(&analyzer, entry, "increment").body_ctx(&mut analyzer);
+    let diagnostic = analyzer
+        .overflows(&file_mapping, ctx, &mut arena)
+        .unwrap()
+        .expect("increment may overflow");
+    let counterexample = diagnostic.counterexample.expect("no counterexample");
+    assert_eq!(
+        counterexample.keys().collect::<Vec<_>>(),
+        vec!["count"],
+        "{counterexample:?}"
+    );
+    assert_eq!(counterexample["count"].uint_val(), Some(U256::MAX));
+}
+
+#[test]
+fn test_chained_assignment() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/chained_assign.sol");
+    let sol = include_str!("./test_data/chained_assign.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let ctx = func_by_name(&analyzer, entry, "chained").body_ctx(&mut analyzer);
+    for name in ["a", "b", "c"] {
+        let var = ctx
+            .var_by_name_or_recurse(&analyzer, name)
+            .unwrap()
+            .unwrap()
+            .latest_version(&analyzer);
+        assert_eq!(
+            var.range_string(&mut analyzer, &mut arena)
+                .unwrap()
+                .unwrap(),
+            "[ 5, 5 ]",
+            "{name}"
+        );
+    }
+
+    let ctx = func_by_name(&analyzer, entry, "chainedSymbolic").body_ctx(&mut analyzer);
+    let a = ctx
+        .var_by_name_or_recurse(&analyzer, "a")
+        .unwrap()
+        .unwrap()
+        .latest_version(&analyzer);
+    assert_eq!(
+        a.range_string(&mut analyzer, &mut arena).unwrap().unwrap(),
+        "[ 0, 9 ]"
+    );
+}
+
+#[test]
+fn test_only_meaningful_changes() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/meaningful_changes.sol");
+    let sol = include_str!("./test_data/meaningful_changes.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+    let ctx = func_by_name(&analyzer, entry, "reassign").body_ctx(&mut analyzer);
+
+    // `d = hex"aabb"` re-versions `d` with a structurally different but equal range
+    let mut bounds = |report_config| {
+        analyzer
+            .bounds_for_var_in_family_tree(
+                &mut arena,
+                &file_mapping,
+                vec![ctx],
+                "d".to_string(),
+                report_config,
+            )
+            .unwrap()
+    };
+    let all = bounds(ReportConfig::default());
+    let meaningful = bounds(ReportConfig {
+        only_meaningful_changes: true,
+        ..Default::default()
+    });
+
+    let decl_span = &all.bound_changes[0].0;
+    assert!(all.bound_changes.iter().any(|(span, _)| span != decl_span));
+    assert_eq!(meaningful.bound_changes.len(), all.bound_changes.len() - 1);
+    assert!(meaningful
+        .bound_changes
+        .iter()
+        .all(|(span, _)| span == decl_span));
+}
+
+#[test]
+fn test_struct_storage_layout() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/struct_packing.sol");
+    let sol = include_str!("./test_data/struct_packing.sol");
+    let (analyzer, _arena, entry) = parse_analyzer(path_str, sol);
+
+    let layout = |name| {
+        let strukt = struct_by_name(&analyzer, entry, name);
+        let fields = strukt
+            .storage_layout(&analyzer)
+            .unwrap()
+            .into_iter()
+            .map(|(field, slot, offset)| (field.name(&analyzer).unwrap(), slot, offset))
+            .collect::<Vec<_>>();
+        (fields, strukt.storage_slots(&analyzer).unwrap())
+    };
+
+    let (fields, slots) = layout("Packed");
+    assert_eq!(
+        fields,
+        vec![
+            ("a".to_string(), 0, 0),
+            ("b".to_string(), 0, 16),
+            ("c".to_string(), 0, 24),
+        ]
+    );
+    assert_eq!(slots, 1);
+
+    // a full slot field and a nested struct each start a new slot, as does the field after them
+    let (fields, slots) = layout("Spilled");
+    assert_eq!(
+        fields,
+        vec![
+            ("a".to_string(), 0, 0),
+            ("b".to_string(), 1, 0),
+            ("c".to_string(), 2, 0),
+            ("d".to_string(), 3, 0),
+            ("e".to_string(), 4, 0),
+        ]
+    );
+    assert_eq!(slots, 5);
+}
+
+#[test]
+fn test_increment_expression_values() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/increment.sol");
+    let sol = include_str!("./test_data/increment.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    for (func, x_range, i_range) in [
+        ("postIncrement", "[ 5, 5 ]", "[ 6, 6 ]"),
+        ("preIncrement", "[ 6, 6 ]", "[ 6, 6 ]"),
+        ("postDecrement", "[ 5, 5 ]", "[ 4, 4 ]"),
+        ("preDecrement", "[ 4, 4 ]", "[ 4, 4 ]"),
+        ("postIncrementAssign", "[ 5, 5 ]", "[ 6, 6 ]"),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        for (name, expected) in [("x", x_range), ("i", i_range)] {
+            let var = ctx
+                .var_by_name_or_recurse(&analyzer, name)
+                .unwrap()
+                .unwrap()
+                .latest_version(&analyzer);
+            assert_eq!(
+                var.range_string(&mut analyzer, &mut arena)
+                    .unwrap()
+                    .unwrap(),
+                expected,
+                "{func}: {name}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_range_history() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/range_history.sol");
+    let sol = include_str!("./test_data/range_history.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+    let ctx = func_by_name(&analyzer, entry, "history").body_ctx(&mut analyzer);
+    let x = ctx.var_by_name_or_recurse(&analyzer, "x").unwrap().unwrap();
+
+    let history = analyzer.range_history(x).unwrap();
+
+    // one entry per version, in order: the parameter, its read and narrowing by the `require`,
+    // the self-assignment, the compound assignment with its overflow check, and the return
+    assert_eq!(history.len(), 11);
+    let srcs = history
+        .iter()
+        .map(|(_, span, _)| &sol[span.0.start()..span.0.end()])
+        .collect::<Vec<_>>();
+    assert_eq!(
+        srcs,
+        vec![
+            "uint256 x",
+            "x",
+            "x < 100",
+            "x",
+            "x = x",
+            "x = x",
+            "x += 1",
+            "x += 1",
+            "x += 1",
+            "x",
+            "return x",
+        ]
+    );
+    assert!(history
+        .iter()
+        .enumerate()
+        .all(|(i, (version, _, _))| i == *version));
+
+    // unlike the bound changes, versions without a range change are kept
+    let analysis = analyzer
+        .bounds_for_var_in_family_tree(
+            &mut arena,
+            &file_mapping,
+            vec![ctx],
+            "x".to_string(),
+            ReportConfig::default(),
+        )
+        .unwrap();
+    assert_eq!(analysis.bound_changes.len(), 2);
+}
+
+#[test]
+fn test_show_cause_expr() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/range_history.sol");
+    let sol = include_str!("./test_data/range_history.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+    let sources = analyzer
+        .sources
+        .iter()
+        .map(|(source_path, sol, _, _)| {
+            (
+                source_path.path_to_solidity_source().display().to_string(),
+                sol.to_string(),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    let ctx = func_by_name(&analyzer, entry, "history").body_ctx(&mut analyzer);
+
+    let analysis = analyzer
+        .bounds_for_var_in_family_tree(
+            &mut arena,
+            &file_mapping,
+            vec![ctx],
+            "x".to_string(),
+            ReportConfig {
+                show_cause_expr: true,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+        .with_cause_exprs(&sources);
+
+    let labels = analysis
+        .labels(&analyzer, &mut arena)
+        .iter()
+        .map(|label| format!("{label:?}"))
+        .collect::<Vec<_>>();
+    assert!(labels
+        .iter()
+        .any(|label| label.contains("(from `x < 100`)")));
+    assert!(labels.iter().any(|label| label.contains("(from `x += 1`)")));
+}
+
+#[test]
+fn test_new_dyn_bytes_length() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/new_bytes.sol");
+    let sol = include_str!("./test_data/new_bytes.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let mut range_of = |func: &str, var: &str| {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let cvar = ctx
+            .var_by_name_or_recurse(&analyzer, var)
+            .unwrap()
+            .unwrap()
+            .latest_version(&analyzer);
+        cvar.range_string(&mut analyzer, &mut arena)
+            .unwrap()
+            .unwrap()
+    };
+
+    assert_eq!(range_of("concrete", "len"), "[ 5, 5 ]");
+    assert_eq!(range_of("symbolic", "len"), "[ 1, 3 ]");
+    assert_eq!(
+        range_of("str", "s"),
+        "[ {len: 1, indices: {}}, {len: 3, indices: {}} ]"
+    );
+}
+
+#[test]
+fn test_new_alloc_zeroed() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/new_alloc.sol");
+    let sol = include_str!("./test_data/new_alloc.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let mut range_of = |func: &str, var: &str| {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let cvar = ctx
+            .var_by_name_or_recurse(&analyzer, var)
+            .unwrap()
+            .unwrap()
+            .latest_version(&analyzer);
+        cvar.range_string(&mut analyzer, &mut arena)
+            .unwrap()
+            .unwrap()
+    };
+
+    assert_eq!(range_of("fixedBytes", "len"), "[ 5, 5 ]");
+    assert_eq!(range_of("fixedBytes", "first"), "[ 0x00, 0x00 ]");
+    assert_eq!(range_of("fixedBytes", "last"), "[ 0x00, 0x00 ]");
+    assert_eq!(range_of("rangedArray", "len"), "[ 3, 9 ]");
+    assert_eq!(range_of("rangedArray", "elem"), "[ 0, 0 ]");
+    assert_eq!(range_of("writeThenRead", "written"), "[ 7, 7 ]");
+    assert_eq!(range_of("writeThenRead", "untouched"), "[ 0, 0 ]");
+}
+
+#[test]
+fn test_killed_ctx_reasons() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/kill_reason.sol");
+    let sol = include_str!("./test_data/kill_reason.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+
+    for (func, func_name, killed_at) in [
+        ("direct", "direct()", "x > 10"),
+        // the failing require in `check` is reported at the call in `nested`
+        ("nested", "nested()", "check"),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let analysis =
+            analyzer.bounds_for_all(&mut arena, &file_mapping, ctx, ReportConfig::default());
+
+        assert_eq!(analysis.killed_ctxs.len(), 1);
+        let kill = &analysis.killed_ctxs[0];
+        assert_eq!(kill.func_name, func_name);
+        assert_eq!(kill.kind, KilledKind::Revert);
+        let (start, end) = (kill.loc.1.start(), kill.loc.1.end());
+        assert_eq!(&sol[start..end], killed_at);
+
+        let labels = analysis
+            .killed_ctx_labels()
+            .iter()
+            .map(|label| format!("{label:?}"))
+            .collect::<Vec<_>>();
+        assert!(labels[0].contains(&format!("killed in {func_name}")));
+        assert!(labels[0].contains(KilledKind::Revert.analysis_str()));
+        assert!(analysis.killed_ctxs_report().is_some());
+    }
+}
+
+#[test]
+fn test_uninit_local_reads() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/uninit_read.sol");
+    let sol = include_str!("./test_data/uninit_read.sol");
+    let (mut analyzer, _arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+
+    // `x` is read in `x + 1` before it is assigned
+    let ctx = func_by_name(&analyzer, entry, "readBeforeAssign").body_ctx(&mut analyzer);
+    let advisory = analyzer.uninit_reads(&file_mapping, ctx).unwrap().unwrap();
+    assert_eq!(advisory.reads.len(), 1);
+    let (span, name, ty) = &advisory.reads[0];
+    assert_eq!((name.as_str(), ty.as_str()), ("x", "uint256"));
+    let read_at = sol.find("x + 1").unwrap();
+    assert_eq!(span.1.start(), read_at);
+
+    // only the path that skips the assignment reads the default
+    let ctx = func_by_name(&analyzer, entry, "readOnOnePath").body_ctx(&mut analyzer);
+    let advisory = analyzer.uninit_reads(&file_mapping, ctx).unwrap().unwrap();
+    assert_eq!(advisory.reads.len(), 1);
+    let read_at = sol.rfind("return x").unwrap() + "return ".len();
+    assert_eq!(advisory.reads[0].0 .1.start(), read_at);
+
+    // every read follows an assignment, including a tuple assignment
+    let ctx = func_by_name(&analyzer, entry, "assignThenRead").body_ctx(&mut analyzer);
+    assert!(analyzer.uninit_reads(&file_mapping, ctx).unwrap().is_none());
+}
+
+#[test]
+fn test_relational_tracking() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/relational.sol");
+    let sol = include_str!("./test_data/relational.sol");
+
+    for track_relations in [false, true] {
+        let analyzer = pyrometer::Analyzer {
+            track_relations,
+            ..Default::default()
+        };
+        let (mut analyzer, mut arena, entry) = parse_with_analyzer(analyzer, path_str.clone(), sol);
+
+        // the range at the end of every successful path, which must agree
+        let mut range_of = |func: &str, var: &str| {
+            let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+            let mut leaves = ctx.successful_edges(&analyzer).unwrap();
+            if leaves.is_empty() {
+                leaves.push(ctx);
+            }
+            let ranges = leaves
+                .into_iter()
+                .map(|leaf| {
+                    let cvar = leaf
+                        .var_by_name_or_recurse(&analyzer, var)
+                        .unwrap()
+                        .unwrap()
+                        .latest_version(&analyzer);
+                    cvar.range_string(&mut analyzer, &mut arena)
+                        .unwrap()
+                        .unwrap()
+                })
+                .collect::<BTreeSet<_>>();
+            assert_eq!(ranges.len(), 1, "{func}: {var} differs across paths");
+            ranges.into_iter().next().unwrap()
+        };
+
+        assert_eq!(range_of("sum", "a"), "[ 0, 10 ]");
+        assert_eq!(range_of("swapped", "d"), "[ 0, 10 ]");
+        if track_relations {
+            assert_eq!(range_of("swapped", "c"), "[ 90, 100 ]");
+            assert_eq!(range_of("sum", "b"), "[ 90, 100 ]");
+            assert_eq!(range_of("sum", "c"), "[ 90, 100 ]");
+            assert_eq!(range_of("assigned", "c"), "[ 90, 100 ]");
+            // unchecked sums may wrap, a deleted variable no longer equals its old value and
+            // storage reset by a summarized call no longer equals what was written before it
+            assert_eq!(range_of("unchecked_sum", "c"), "[ 0, 2**256 - 1 ]");
+            assert_eq!(range_of("deleted", "c"), "[ 0, 0 ]");
+            assert_eq!(range_of("stored", "c"), "[ 0, 2**256 - 1 ]");
+            // `a` changed in the loop, so it no longer relates to `b`
+            assert_eq!(range_of("looped", "c"), "[ 0, 2**256 - 1 ]");
+        } else {
+            assert_eq!(range_of("sum", "b"), "[ 0, 2**256 - 1 ]");
+            assert_eq!(range_of("assigned", "c"), "[ 0, 100 ]");
+        }
+    }
+}
+
+#[test]
+fn test_array_literal_indices() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/array_literal.sol");
+    let sol = include_str!("./test_data/array_literal.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let mut range_of = |func: &str, var: &str| {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let cvar = ctx
+            .var_by_name_or_recurse(&analyzer, var)
+            .unwrap()
+            .unwrap()
+            .latest_version(&analyzer);
+        cvar.range_string(&mut analyzer, &mut arena)
+            .unwrap()
+            .unwrap()
+    };
+
+    assert_eq!(
+        range_of("flat", "x"),
+        "[ {len: 3, indices: {0: 1, 1: 2, 2: 3}}, {len: 3, indices: {0: 1, 1: 2, 2: 3}} ]"
+    );
+    assert_eq!(range_of("flat", "a"), "[ 1, 1 ]");
+    assert_eq!(range_of("flat", "b"), "[ 3, 3 ]");
+    assert_eq!(range_of("nested", "c"), "[ 2, 2 ]");
+    assert_eq!(range_of("nested", "d"), "[ 3, 3 ]");
+    assert_eq!(range_of("mixed", "e"), "[ 1, 1 ]");
+    assert_eq!(range_of("mixed", "f"), "[ 256, 256 ]");
+}
+
+#[test]
+fn test_failable_guard_severity() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/guards.sol");
+    let sol = include_str!("./test_data/guards.sol");
+    let (mut analyzer, _arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+
+    let ctx = func_by_name(&analyzer, entry, "checked").body_ctx(&mut analyzer);
+    let analyses = analyzer.failable_guards(&file_mapping, ctx).unwrap();
+    assert_eq!(analyses.len(), 2);
+
+    // guards whose conditions already hold are not failable
+    let (asserts, requires) = (&analyses[0], &analyses[1]);
+    assert_eq!(asserts.kind, GuardKind::Assert);
+    assert_eq!(asserts.guards.len(), 1);
+    assert_eq!(
+        asserts.guards[0].1.start(),
+        sol.find("assert(x < 100)").unwrap()
+    );
+    assert_eq!(requires.kind, GuardKind::Require);
+    assert_eq!(requires.guards.len(), 1);
+    assert_eq!(
+        requires.guards[0].1.start(),
+        sol.find("require(x > 10)").unwrap()
+    );
+
+    // a failable assert is likely a bug, a failable require is expected input validation
+    assert_eq!(asserts.report_kind(), ReportKind::Warning);
+    assert_eq!(requires.report_kind(), ReportKind::Advice);
+}
+
+#[test]
+fn test_written_storage() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/written_storage.sol");
+    let sol = include_str!("./test_data/written_storage.sol");
+    let (mut analyzer, _arena, entry) = parse_analyzer(path_str, sol);
+
+    // each of `a` and `b` is written on one branch, `c` is only read
+    let written = func_by_name(&analyzer, entry, "write")
+        .written_storage(&mut analyzer)
+        .unwrap();
+    assert_eq!(
+        written,
+        ["a", "b"]
+            .into_iter()
+            .map(String::from)
+            .collect::<BTreeSet<_>>()
+    );
+
+    // a mapping write in a called function counts as a write to the mapping
+    let written = func_by_name(&analyzer, entry, "viaCall")
+        .written_storage(&mut analyzer)
+        .unwrap();
+    assert_eq!(written, BTreeSet::from(["m".to_string()]));
+}
+
+#[test]
+fn test_export_dot() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/dot_export.sol");
+    let sol = include_str!("./test_data/dot_export.sol");
+    let (mut analyzer, _arena, entry) = parse_analyzer(path_str, sol);
+    let ctx = func_by_name(&analyzer, entry, "f").body_ctx(&mut analyzer);
+    let dot = ctx.export_dot(&analyzer).unwrap();
+
+    assert!(dot.starts_with("digraph \"f(uint256)\" {"));
+    assert!(dot.ends_with('}'));
+    let lines = dot.lines().collect::<Vec<_>>();
+    let nodes = lines
+        .iter()
+        .filter(|line| line.ends_with("];") && !line.contains("->"));
+    let edges = lines
+        .iter()
+        .filter(|line| line.contains(" -> "))
+        .collect::<Vec<_>>();
+    // the body context plus every version of `x`, `y` and the temporaries
+    assert_eq!(nodes.count(), 14);
+    assert_eq!(edges.len(), 16);
+    assert_eq!(
+        lines
+            .iter()
+            .filter(|line| line.contains("shape=box"))
+            .count(),
+        1
+    );
+    assert!(edges.iter().any(|line| line.contains("[label=\"Return\"]")));
+    assert!(edges
+        .iter()
+        .any(|line| line.contains("[label=\"CalldataVariable\"]")));
+}
+
+#[test]
+fn test_range_kind() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/range_kind.sol");
+    let sol = include_str!("./test_data/range_kind.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let mut kind_of_x = |func: &str| {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        // `empty` reverts in a subcontext of the second `require`
+        let ctx = ctx
+            .family_tree(&analyzer)
+            .unwrap()
+            .into_iter()
+            .find(|ctx| ctx.is_killed(&analyzer).unwrap())
+            .unwrap_or(ctx);
+        ctx.var_by_name(&analyzer, "x")
+            .unwrap()
+            .latest_version_or_inherited_in_ctx(ctx, &analyzer)
+            .range_kind(&analyzer, &mut arena)
+            .unwrap()
+    };
+
+    assert_eq!(
+        kind_of_x("singleton"),
+        RangeKind::Singleton(Concrete::from(U256::from(5)))
+    );
+    assert_eq!(kind_of_x("narrowed"), RangeKind::Bounded);
+    assert_eq!(kind_of_x("full"), RangeKind::Unbounded);
+    assert_eq!(kind_of_x("empty"), RangeKind::Empty);
+}
+
+#[test]
+fn test_ternary_joins_arms() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/ternary.sol");
+    let sol = include_str!("./test_data/ternary.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    // a literal condition only evaluates the live arm, without forking
+    let ctx = func_by_name(&analyzer, entry, "constantCond").body_ctx(&mut analyzer);
+    assert!(ctx.subcontexts(&analyzer).is_empty());
+    let y = ctx
+        .var_by_name(&analyzer, "y")
+        .unwrap()
+        .latest_version(&analyzer);
+    assert_eq!(
+        y.range_string(&mut analyzer, &mut arena).unwrap().unwrap(),
+        "[ 100, 100 ]"
+    );
+
+    // a symbolic condition forks, and each branch keeps its own arm, narrowed by the branch's
+    // side of the condition and cast to the arms' common type. The union of the arms is the
+    // joined value
+    for (func, expected, joined) in [
+        ("symbolicCond", ["[ 11, 49 ]", "[ 100, 100 ]"], (11, 100)),
+        ("literals", ["[ 1, 1 ]", "[ 300, 300 ]"], (1, 300)),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let branches = ctx.all_edges(&analyzer).unwrap();
+        assert_eq!(branches.len(), 2);
+        for (branch, expected) in branches.into_iter().zip(expected) {
+            let y = branch
+                .var_by_name_or_recurse(&analyzer, "y")
+                .unwrap()
+                .unwrap()
+                .latest_version_or_inherited_in_ctx(branch, &analyzer);
+            assert_eq!(
+                y.range_string(&mut analyzer, &mut arena).unwrap().unwrap(),
+                expected,
+                "{func}"
+            );
+            assert_eq!(
+                y.ty(&analyzer).unwrap().as_string(&analyzer).unwrap(),
+                "uint256"
+            );
+        }
+        let range = ctx
+            .joined_var_range("y", &analyzer, &mut arena)
+            .unwrap()
+            .unwrap();
+        let val = |elem: Elem<Concrete>| elem.maybe_concrete().unwrap().val.into_u256().unwrap();
+        assert_eq!(
+            (val(range.min), val(range.max)),
+            (U256::from(joined.0), U256::from(joined.1)),
+            "{func}"
+        );
+    }
+
+    // the true branch's result is `x` itself, so constraining the result narrows `x`, and the
+    // false branch's `5 < 3` kills it
+    let ctx = func_by_name(&analyzer, entry, "requireOnResult").body_ctx(&mut analyzer);
+    let live = ctx.successful_edges(&analyzer).unwrap();
+    assert_eq!(live.len(), 1);
+    let x = live[0]
+        .var_by_name_or_recurse(&analyzer, "x")
+        .unwrap()
+        .unwrap()
+        .latest_version_or_inherited_in_ctx(live[0], &analyzer);
+    assert_eq!(
+        x.range_string(&mut analyzer, &mut arena).unwrap().unwrap(),
+        "[ 0, 2 ]"
+    );
+}
+
+#[test]
+fn test_low_level_catch() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/try_catch.sol");
+    let sol = include_str!("./test_data/try_catch.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let ctx = func_by_name(&analyzer, entry, "lowLevel").body_ctx(&mut analyzer);
+
+    // the successful call plus one branch per catch clause
+    let branches = ctx.all_edges(&analyzer).unwrap();
+    assert_eq!(branches.len(), 4);
+
+    let mut low_level = branches
+        .iter()
+        .filter_map(|branch| branch.var_by_name(&analyzer, "lowLevelData"));
+    let data = low_level.next().unwrap();
+    assert!(low_level.next().is_none());
+    assert!(data.is_symbolic(&analyzer).unwrap());
+    assert_eq!(
+        data.ty(&analyzer).unwrap().as_string(&analyzer).unwrap(),
+        "bytes"
+    );
+
+    let len = data
+        .maybe_ctx(&analyzer)
+        .unwrap()
+        .var_by_name(&analyzer, "lowLevelData.length")
+        .unwrap();
+    assert_eq!(
+        len.range_string(&mut analyzer, &mut arena)
+            .unwrap()
+            .unwrap(),
+        "[ 0, 2**256 - 1 ]"
+    );
+}
+
+#[test]
+fn test_cyclomatic_complexity() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/complexity.sol");
+    let sol = include_str!("./test_data/complexity.sol");
+    let (analyzer, _arena, entry) = parse_analyzer(path_str, sol);
+
+    let complexity = |func: &str| {
+        func_by_name(&analyzer, entry, func)
+            .cyclomatic_complexity(&analyzer)
+            .unwrap()
+    };
+    // two ifs and a loop
+    assert_eq!(complexity("branchy"), 4);
+    assert_eq!(complexity("straight"), 1);
+    // a short-circuit and a ternary
+    assert_eq!(complexity("conditions"), 3);
+}
+
+#[test]
+fn test_tuple_assign_from_call() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/tuple_assign.sol");
+    let sol = include_str!("./test_data/tuple_assign.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let mut range_of = |func: &str, name: &str| {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        // the call to `pair` resumes the function in a subcontext
+        let ctx = ctx
+            .family_tree(&analyzer)
+            .unwrap()
+            .into_iter()
+            .find(|ctx| ctx.subcontexts(&analyzer).is_empty())
+            .unwrap_or(ctx);
+        let var = ctx
+            .var_by_name_or_recurse(&analyzer, name)
+            .unwrap()
+            .unwrap()
+            .latest_version_or_inherited_in_ctx(ctx, &analyzer);
+        var.range_string(&mut analyzer, &mut arena)
+            .unwrap()
+            .unwrap()
+    };
+
+    assert_eq!(range_of("declare", "x"), "[ 1, 1 ]");
+    assert_eq!(range_of("declare", "y"), "[ 2, 2 ]");
+    // the parenthesized call is a one element tuple wrapping both returns
+    assert_eq!(range_of("assign", "a"), "[ 1, 1 ]");
+    assert_eq!(range_of("assign", "b"), "[ 2, 2 ]");
+}
+
+#[test]
+fn test_require_narrows_comparisons() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/require_narrowing.sol");
+    let sol = include_str!("./test_data/require_narrowing.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let mut x_after = |func: &str| {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        assert!(!ctx.is_killed(&analyzer).unwrap(), "{func} reverted");
+        let x = ctx
+            .var_by_name_or_recurse(&analyzer, "x")
+            .unwrap()
+            .unwrap()
+            .latest_version_or_inherited_in_ctx(ctx, &analyzer);
+        let exclusions = x.ref_range(&analyzer).unwrap().unwrap().exclusions.len();
+        let range = x.range_string(&mut analyzer, &mut arena).unwrap().unwrap();
+        (range, exclusions)
+    };
+
+    for (funcs, expected) in [
+        (["gt", "gtFlipped"], "[ 6, 2**256 - 1 ]"),
+        (["gte", "gteFlipped"], "[ 5, 2**256 - 1 ]"),
+        (["lt", "ltFlipped"], "[ 0, 4 ]"),
+        (["lte", "lteFlipped"], "[ 0, 5 ]"),
+        (["eq", "eqFlipped"], "[ 5, 5 ]"),
+    ] {
+        for func in funcs {
+            assert_eq!(x_after(func), (expected.to_string(), 0), "{func}");
+        }
+    }
+    // `!=` excludes the value instead of splitting the range
+    for func in ["neq", "neqFlipped", "neqMin"] {
+        assert_eq!(
+            x_after(func),
+            ("[ 0, 2**256 - 1 ]".to_string(), 1),
+            "{func}"
+        );
+    }
+    assert_eq!(x_after("signedGt").0, "[ -4, 2**255 - 1 ]");
+    assert_eq!(x_after("signedLt").0, "[ -1 * 2**255, -6 ]");
+    assert_eq!(x_after("ltMax").0, "[ 0, 254 ]");
+}
+
+#[test]
+fn test_fixed_bytes_index() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/fixed_bytes_index.sol");
+    let sol = include_str!("./test_data/fixed_bytes_index.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let mut x_in = |func: &str| {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        ctx.var_by_name(&analyzer, "x")
+            .unwrap()
+            .latest_version_or_inherited_in_ctx(ctx, &analyzer)
+            .range_string(&mut analyzer, &mut arena)
+            .unwrap()
+            .unwrap()
+    };
+    assert_eq!(x_in("constIndex"), "[ 0x56, 0x56 ]");
+    // a symbolic index can be any byte of the value
+    assert_eq!(x_in("symbolicIndex"), "[ 0x12, 0x78 ]");
+    assert_eq!(x_in("symbolicBytes"), "[ 0x00, 0xff ]");
+
+    let ctx = func_by_name(&analyzer, entry, "outOfBounds").body_ctx(&mut analyzer);
+    assert_eq!(
+        ctx.killed_loc(&analyzer).unwrap().map(|(_, kind)| kind),
+        Some(KilledKind::Revert)
+    );
+}
+
+#[test]
+fn test_csv_export() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/bounds_csv.sol");
+    let sol = include_str!("./test_data/bounds_csv.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str.clone(), sol);
+    let file_mapping = file_mapping(&analyzer);
+    let ctx = func_by_name(&analyzer, entry, "clamp").body_ctx(&mut analyzer);
+    let analysis = analyzer.bounds_for_all(&mut arena, &file_mapping, ctx, ReportConfig::default());
+    let csv = FunctionVarsBoundAnalysis::to_csv(&[analysis], &analyzer, &mut arena);
+
+    // splits a line into fields per RFC 4180: quoted fields may contain commas and `""` quotes
+    fn parse_line(line: &str) -> Vec<String> {
+        let mut fields = vec![String::new()];
+        let mut chars = line.chars().peekable();
+        let mut quoted = false;
+        while let Some(c) = chars.next() {
+            match (c, quoted) {
+                ('"', true) if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    fields.last_mut().unwrap().push('"');
+                }
+                ('"', _) => quoted = !quoted,
+                (',', false) => fields.push(String::new()),
+                (c, _) => fields.last_mut().unwrap().push(c),
+            }
+        }
+        fields
+    }
+
+    let rows = csv.lines().map(parse_line).collect::<Vec<_>>();
+    assert_eq!(
+        rows[0],
+        [
+            "function",
+            "variable",
+            "def_file",
+            "def_start",
+            "def_end",
+            "min",
+            "max"
+        ]
+    );
+    assert!(rows.len() > 1);
+    assert!(rows.iter().all(|row| row.len() == 7));
+
+    // the function path contains a comma, so it is quoted and survives the round trip
+    let y = rows.iter().find(|row| row[1] == "y").unwrap();
+    assert_eq!(y[0], "clamp(uint256, uint256)");
+    assert_eq!(y[2], path_str);
+    assert_eq!(
+        &sol[y[3].parse::<usize>().unwrap()..y[4].parse().unwrap()],
+        "uint256 y"
+    );
+    assert_eq!(y[5], "8");
+}
+
+#[test]
+fn test_assign_casts_exclusions() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/cast_exclusions.sol");
+    let sol = include_str!("./test_data/cast_exclusions.sol");
+    let (mut analyzer, _arena, entry) = parse_analyzer(path_str, sol);
+    let ctx = func_by_name(&analyzer, entry, "narrow").body_ctx(&mut analyzer);
+    let y = ctx
+        .var_by_name(&analyzer, "y")
+        .unwrap()
+        .latest_version_or_inherited_in_ctx(ctx, &analyzer);
+
+    // `256` can't be a `uint8` so it is dropped, `7` is kept as a `uint8`
+    let exclusions = y
+        .ref_range(&analyzer)
+        .unwrap()
+        .unwrap()
+        .exclusions
+        .iter()
+        .map(|excl| excl.maybe_concrete().map(|excl| excl.val))
+        .collect::<Vec<_>>();
+    assert_eq!(exclusions, vec![Some(Concrete::Uint(8, U256::from(7)))]);
+}
+
+#[test]
+fn test_address_casts() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/address_casts.sol");
+    let sol = include_str!("./test_data/address_casts.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let mut final_var = |func: &str, var: &str| {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        ctx.var_by_name(&analyzer, var)
+            .unwrap()
+            .latest_version_or_inherited_in_ctx(ctx, &analyzer)
+    };
+    let a = final_var("zero", "a");
+    let b = final_var("fromUint", "b");
+    let p = final_var("pay", "p");
+
+    let mut one = [0u8; 20];
+    one[19] = 1;
+    for (var, expected) in [(a, Address::zero()), (b, Address::from(one))] {
+        for bound in [
+            var.evaled_range_min(&analyzer, &mut arena),
+            var.evaled_range_max(&analyzer, &mut arena),
+        ] {
+            assert_eq!(
+                bound.unwrap().unwrap().maybe_concrete().unwrap().val,
+                Concrete::Address(expected)
+            );
+        }
+    }
+
+    // `payable(x)` is an `address payable` (displayed as an `address`) that keeps the range of `x`
+    let ctx = func_by_name(&analyzer, entry, "pay").body_ctx(&mut analyzer);
+    let cast = ctx.tmp_vars(&analyzer)["address(x)"];
+    assert_eq!(
+        cast.ty(&analyzer).unwrap().as_builtin(&analyzer).unwrap(),
+        Builtin::AddressPayable
+    );
+    let mut sixteen = [0u8; 20];
+    sixteen[19] = 0x10;
+    for bound in [
+        p.evaled_range_min(&analyzer, &mut arena),
+        p.evaled_range_max(&analyzer, &mut arena),
+    ] {
+        assert_eq!(
+            bound.unwrap().unwrap().maybe_concrete().unwrap().val,
+            Concrete::Address(Address::from(sixteen))
+        );
+    }
+}
+
+#[test]
+fn test_delete_resets_to_zero() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/delete.sol");
+    let sol = include_str!("./test_data/delete.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let ctx = func_by_name(&analyzer, entry, "deleteUint").body_ctx(&mut analyzer);
+    let a = ctx
+        .var_by_name(&analyzer, "a")
+        .unwrap()
+        .latest_version_or_inherited_in_ctx(ctx, &analyzer);
+    assert_eq!(
+        a.range_string(&mut analyzer, &mut arena).unwrap().unwrap(),
+        "[ 0, 0 ]"
+    );
+
+    let ctx = func_by_name(&analyzer, entry, "deleteStruct").body_ctx(&mut analyzer);
+    let p = ctx
+        .var_by_name(&analyzer, "p")
+        .unwrap()
+        .latest_version_or_inherited_in_ctx(ctx, &analyzer);
+    for field in ["x", "y"] {
+        let field = p.field_of_struct(field, &analyzer).unwrap().unwrap();
+        assert_eq!(
+            field
+                .range_string(&mut analyzer, &mut arena)
+                .unwrap()
+                .unwrap(),
+            "[ 0, 0 ]"
+        );
+    }
+
+    // solc rejects deleting a mapping, so it is ignored rather than an error
+    let ctx = func_by_name(&analyzer, entry, "deleteMapping").body_ctx(&mut analyzer);
+    assert!(!ctx.is_killed(&analyzer).unwrap());
+    assert!(analyzer.expr_errs.is_empty());
+}
+
+#[test]
+fn test_max_struct_depth() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/nested_structs.sol");
+    let sol = include_str!("./test_data/nested_structs.sol");
+
+    // five levels of structs assign field by field within the default depth
+    let (mut analyzer, _arena, entry) = parse_analyzer(path_str.clone(), sol);
+    let ctx = func_by_name(&analyzer, entry, "copy").body_ctx(&mut analyzer);
+    let b = ctx
+        .var_by_name(&analyzer, "b")
+        .unwrap()
+        .latest_version_or_inherited_in_ctx(ctx, &analyzer);
+    let v = ["inner", "inner", "inner", "inner", "v"]
+        .into_iter()
+        .fold(b, |cvar, field| {
+            cvar.field_of_struct(field, &analyzer).unwrap().unwrap()
+        });
+    assert_eq!(
+        v.display_name(&analyzer).unwrap(),
+        "b.inner.inner.inner.inner.v"
+    );
+    // the innermost field was written by the assignment
+    assert!(v.previous_version(&analyzer).is_some());
+
+    // past the max depth the assignment errors rather than recursing further
+    let mut analyzer = Analyzer {
+        max_struct_depth: 3,
+        ..Default::default()
+    };
+    let current_path = SourcePath::SolidityFile(PathBuf::from(path_str));
+    let _ = analyzer.parse(&mut Default::default(), sol, &current_path, true);
+    assert!(analyzer.expr_errs.iter().any(|err| err
+        .to_string()
+        .contains("Struct fields nested deeper than 3")));
+}
+
+#[test]
+fn test_max_nodes() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/max_nodes.sol");
+    let sol = include_str!("./test_data/max_nodes.sol");
+    let current_path = SourcePath::SolidityFile(PathBuf::from(path_str));
+
+    let mut analyzer = Analyzer::default();
+    let _ = analyzer.parse(&mut Default::default(), sol, &current_path, true);
+    assert!(analyzer.expr_errs.is_empty());
+    let unlimited = analyzer.graph.node_count();
+
+    // a limit partway through the function kills it with a diagnostic instead of continuing
+    let mut analyzer = Analyzer {
+        max_nodes: Some(200),
+        ..Default::default()
+    };
+    let entry = analyzer
+        .parse(&mut Default::default(), sol, &current_path, true)
+        .unwrap();
+    assert!(analyzer.graph.node_count() < unlimited);
+    assert!(analyzer
+        .expr_errs
+        .iter()
+        .any(|err| err.to_string().contains("Node count limit reached")));
+    let too_large = |analyzer: &mut Analyzer, func: &str| {
+        let ctx = func_by_name(analyzer, entry, func).body_ctx(analyzer);
+        std::iter::once(ctx)
+            .chain(ctx.all_edges(analyzer).unwrap())
+            .any(|ctx| {
+                matches!(
+                    ctx.killed_loc(analyzer).unwrap(),
+                    Some((_, KilledKind::TooLarge))
+                )
+            })
+    };
+    assert!(too_large(&mut analyzer, "busy"));
+    // the limit is on the nodes each function adds, not on the nodes earlier functions left
+    assert!(!too_large(&mut analyzer, "small"));
+}
+
+#[test]
+fn test_max_recursion_depth() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/recursion.sol");
+    let sol = include_str!("./test_data/recursion.sol");
+
+    let range_of = |max_recursion_depth: usize, func: &str, var: &str| {
+        let analyzer = Analyzer {
+            max_recursion_depth,
+            ..Default::default()
+        };
+        let (mut analyzer, mut arena, entry) = parse_with_analyzer(analyzer, path_str.clone(), sol);
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        ctx.successful_edges(&analyzer)
+            .unwrap()
+            .into_iter()
+            .map(|leaf| {
+                leaf.var_by_name_or_recurse(&analyzer, var)
+                    .unwrap()
+                    .unwrap()
+                    .latest_version(&analyzer)
+                    .range_string(&mut analyzer, &mut arena)
+                    .unwrap()
+                    .unwrap()
+            })
+            .collect::<BTreeSet<_>>()
+    };
+
+    // every call is inlined within the limit
+    assert_eq!(
+        range_of(2, "factOfOne", "x"),
+        BTreeSet::from(["[ 1, 1 ]".to_string()])
+    );
+    assert_eq!(
+        range_of(1, "oddOne", "odd"),
+        BTreeSet::from(["[ true, true ]".to_string()])
+    );
+
+    // past the limit a call returns the full range of its return type
+    assert_eq!(
+        range_of(1, "factOfOne", "x"),
+        BTreeSet::from(["[ 0, 2**256 - 1 ]".to_string()])
+    );
+    assert_eq!(
+        range_of(1, "evenTwo", "even"),
+        BTreeSet::from(["[ false, true ]".to_string()])
+    );
+    // and the storage it may write is reset, so later reads don't see stale values
+    assert_eq!(
+        range_of(1, "storageWrite", "total"),
+        BTreeSet::from(["[ 7, 7 ]".to_string()])
+    );
+}
+
+#[test]
+fn test_strict_casts() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/narrowing_cast.sol");
+    let sol = include_str!("./test_data/narrowing_cast.sol");
+
+    // by default narrowing a `uint256` into a `uint128` clamps it to the narrower type
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str.clone(), sol);
+    let ctx = func_by_name(&analyzer, entry, "narrow").body_ctx(&mut analyzer);
+    let y = ctx
+        .var_by_name_or_recurse(&analyzer, "y")
+        .unwrap()
+        .unwrap()
+        .latest_version(&analyzer);
+    let max = y.evaled_range_max(&analyzer, &mut arena).unwrap().unwrap();
+    assert_eq!(
+        max.maybe_concrete().unwrap().val,
+        Concrete::Uint(128, U256::from(u128::MAX))
+    );
+
+    // with strict casts the lossy narrowing errors, but one whose value always fits does not
+    let mut analyzer = Analyzer {
+        strict_casts: true,
+        ..Default::default()
+    };
+    let current_path = SourcePath::SolidityFile(PathBuf::from(path_str));
+    let _ = analyzer.parse(&mut Default::default(), sol, &current_path, true);
+    let narrowing_errs = analyzer
+        .expr_errs
+        .iter()
+        .filter(|err| err.to_string().contains("could lose information"))
+        .collect::<Vec<_>>();
+    assert_eq!(narrowing_errs.len(), 1);
+    assert!(narrowing_errs[0]
+        .to_string()
+        .contains("of type uint256 to y of type uint128"));
+}
+
+#[test]
+fn test_explicit_narrowing_cast() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/explicit_narrowing_cast.sol");
+    let sol = include_str!("./test_data/explicit_narrowing_cast.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    for (func, expected) in [
+        // `[0, 300]` crosses 256, so every `uint8` is possible
+        ("wide", "[ 0, 255 ]"),
+        ("fits", "[ 0, 200 ]"),
+        // `[256, 300]` truncates without wrapping past 255
+        ("wraps", "[ 0, 44 ]"),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let y = ctx
+            .var_by_name(&analyzer, "y")
+            .unwrap()
+            .latest_version_or_inherited_in_ctx(ctx, &analyzer);
+        assert_eq!(
+            y.range_string(&mut analyzer, &mut arena).unwrap().unwrap(),
+            expected,
+            "{func}"
+        );
+    }
+}
+
+#[test]
+fn test_is_satisfiable() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/satisfiable.sol");
+    let sol = include_str!("./test_data/satisfiable.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    for (func, satisfiable) in [
+        ("contradiction", false),
+        ("feasible", true),
+        ("excluded", false),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        assert_eq!(
+            ctx.is_satisfiable(&analyzer, &mut arena).unwrap(),
+            satisfiable,
+            "{func}"
+        );
+    }
+
+    // a path that reaches a `revert` or divides by zero is feasible
+    for (func, kind) in [
+        ("reverting", KilledKind::Revert),
+        ("dividing", KilledKind::DivisionByZero),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let killed = ctx
+            .all_edges(&analyzer)
+            .unwrap()
+            .into_iter()
+            .filter(|leaf| matches!(leaf.killed_loc(&analyzer).unwrap(), Some((_, k)) if k == kind))
+            .collect::<Vec<_>>();
+        assert!(!killed.is_empty(), "{func}");
+        for leaf in killed {
+            assert!(
+                leaf.is_satisfiable(&analyzer, &mut arena).unwrap(),
+                "{func}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_type_bounds() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/type_bounds.sol");
+    let sol = include_str!("./test_data/type_bounds.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    // `type(T).max` and `.min` fold to constants of the type
+    let ctx = func_by_name(&analyzer, entry, "bounds").body_ctx(&mut analyzer);
+    for (name, val) in [
+        ("u16Max", Concrete::Uint(16, U256::from(65535))),
+        ("u8Min", Concrete::Uint(8, U256::zero())),
+        ("i8Min", Concrete::Int(8, I256::from(-128))),
+        ("i8Max", Concrete::Int(8, I256::from(127))),
+    ] {
+        let var = ctx
+            .var_by_name_or_recurse(&analyzer, name)
+            .unwrap()
+            .unwrap()
+            .latest_version(&analyzer);
+        assert!(var.is_const(&analyzer, &mut arena).unwrap(), "{name}");
+        let min = var
+            .evaled_range_min(&analyzer, &mut arena)
+            .unwrap()
+            .unwrap();
+        assert_eq!(min.maybe_concrete().unwrap().val, val, "{name}");
+    }
+
+    // the code of a contract is bytes of an unknown length
+    let ctx = func_by_name(&analyzer, entry, "code").body_ctx(&mut analyzer);
+    for name in ["creation", "runtime"] {
+        let var = ctx
+            .var_by_name_or_recurse(&analyzer, name)
+            .unwrap()
+            .unwrap()
+            .latest_version(&analyzer);
+        let layout = ctx.memory_layout(var, &analyzer, &mut arena).unwrap();
+        assert_eq!(layout.len_min, Some(U256::zero()), "{name}");
+        assert_eq!(layout.len_max, Some(U256::MAX), "{name}");
+    }
+}
+
+#[test]
+fn test_literal_source_repr() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/literal_source.sol");
+    let sol = include_str!("./test_data/literal_source.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+    let ctx = func_by_name(&analyzer, entry, "units").body_ctx(&mut analyzer);
+    let analysis = analyzer.bounds_for_all(&mut arena, &file_mapping, ctx, ReportConfig::default());
+
+    let labels = |name: &str, arena: &mut _| {
+        let var = analysis
+            .vars_by_ctx
+            .values()
+            .flatten()
+            .find(|analysis| analysis.var_display_name == name)
+            .unwrap();
+        var.labels(&analyzer, arena)
+            .iter()
+            .map(|label| format!("{label:?}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    assert!(labels("amount", &mut arena).contains("1000000000000000000 (1 ether)"));
+    assert!(labels("scaled", &mut arena).contains("1500000000000000000 (15e17)"));
+    // the literal's text is shown as written
+    assert!(labels("separated", &mut arena).contains("1000000000000000000000 (1_000 ether)"));
+    assert!(labels("exponent", &mut arena).contains("1000000000000000000 (1e18)"));
+    assert!(labels("hex", &mut arena).contains("255 (0xFF)"));
+    // a literal written as its value isn't repeated
+    assert!(!labels("plain", &mut arena).contains("== 5 ("));
+    // a value derived from a literal doesn't show the literal's text
+    let wrapped = labels("wrapped", &mut arena);
+    assert!(wrapped.contains("== 44"));
+    assert!(!wrapped.contains("(300)"));
+}
+
+#[test]
+fn test_tuple_assign_arity_mismatch() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/tuple_arity.sol");
+    let sol = include_str!("./test_data/tuple_arity.sol");
+    let mut analyzer = Analyzer::default();
+    let current_path = SourcePath::SolidityFile(PathBuf::from(path_str));
+    let _ = analyzer.parse(&mut Default::default(), sol, &current_path, true);
+    assert!(analyzer.expr_errs.iter().any(|err| err
+        .to_string()
+        .contains("Tuple assignment arity mismatch: 2 variables assigned 3 values")));
+}
+
+#[test]
+fn test_function_timings() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/do_while.sol");
+    let sol = include_str!("./test_data/do_while.sol");
+    let current_path = SourcePath::SolidityFile(PathBuf::from(path_str));
+
+    // nothing is recorded unless timing is enabled
+    let mut analyzer = Analyzer::default();
+    let _ = analyzer.parse(&mut Default::default(), sol, &current_path, true);
+    assert!(analyzer.function_timings.is_empty());
+
+    let mut analyzer = Analyzer {
+        time_functions: true,
+        ..Default::default()
+    };
+    let _ = analyzer.parse(&mut Default::default(), sol, &current_path, true);
+    assert!(analyzer
+        .function_timings
+        .keys()
+        .any(|func| func.starts_with("DoWhile.runsOnce")));
+}
+
+#[test]
+fn test_short_circuit() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/short_circuit.sol");
+    let sol = include_str!("./test_data/short_circuit.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let range_of = |analyzer: &mut Analyzer,
+                    arena: &mut RangeArena<Elem<Concrete>>,
+                    ctx: ContextNode,
+                    name: &str| {
+        let var = ctx
+            .var_by_name_or_recurse(analyzer, name)
+            .unwrap()
+            .unwrap()
+            .latest_version_or_inherited_in_ctx(ctx, analyzer);
+        var.range_string(analyzer, arena).unwrap().unwrap()
+    };
+
+    // the side where the lhs decides the result never evaluates the rhs's assignment to `y`
+    for (func, decided_x, rhs_x) in [
+        ("andSideEffect", "[ 0, 5 ]", "[ 6, 2**256 - 1 ]"),
+        ("orSideEffect", "[ 6, 2**256 - 1 ]", "[ 0, 5 ]"),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let [decided, rhs] = ctx.all_edges(&analyzer).unwrap()[..] else {
+            panic!("{func} did not fork");
+        };
+        assert_eq!(
+            range_of(&mut analyzer, &mut arena, decided, "x"),
+            decided_x,
+            "{func}"
+        );
+        assert_eq!(
+            range_of(&mut analyzer, &mut arena, decided, "y"),
+            "[ 0, 0 ]",
+            "{func}"
+        );
+        assert_eq!(
+            range_of(&mut analyzer, &mut arena, rhs, "x"),
+            rhs_x,
+            "{func}"
+        );
+        assert_eq!(
+            range_of(&mut analyzer, &mut arena, rhs, "y"),
+            "[ 1, 1 ]",
+            "{func}"
+        );
+    }
+
+    // an lhs that is already known doesn't fork
+    let ctx = func_by_name(&analyzer, entry, "knownLhs").body_ctx(&mut analyzer);
+    assert!(ctx.all_edges(&analyzer).unwrap().is_empty());
+    assert_eq!(range_of(&mut analyzer, &mut arena, ctx, "y"), "[ 0, 0 ]");
+    assert_eq!(
+        range_of(&mut analyzer, &mut arena, ctx, "c"),
+        "[ false, false ]"
+    );
+}
+
+#[test]
+fn test_report_config_builder() {
+    let config = ReportConfig::builder()
+        .show_tmps(TmpFilter::ConditionsOnly)
+        .show_reverts(true)
+        .build();
+    assert_eq!(config.show_tmps, TmpFilter::ConditionsOnly);
+    assert!(config.show_reverts);
+    assert_eq!(
+        config,
+        ReportConfig {
+            show_tmps: TmpFilter::ConditionsOnly,
+            show_reverts: true,
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        ReportConfigBuilder::default().build(),
+        ReportConfig::default()
+    );
+}
+
+#[test]
+fn test_immutables() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/immutables.sol");
+    let sol = include_str!("./test_data/immutables.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    for (func, var, bounds) in [
+        ("fee", "f", "[ 30, 30 ]"),
+        ("readDeclared", "d", "[ 7, 7 ]"),
+        // set to either value depending on the constructor's branch
+        ("readConditional", "c", "[ 10, 100 ]"),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let cvar = ctx
+            .var_by_name_or_recurse(&analyzer, var)
+            .unwrap()
+            .unwrap()
+            .latest_version(&analyzer);
+        let range = cvar.range_string(&mut analyzer, &mut arena).unwrap();
+        assert_eq!(range.as_deref(), Some(bounds), "{func}");
+    }
+}
+
+#[test]
+fn test_exponentiation() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/exponentiation.sol");
+    let sol = include_str!("./test_data/exponentiation.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    for (func, bounds) in [
+        ("constantPower", "[ 81, 81 ]"),
+        ("rangedBase", "[ 8, 1000 ]"),
+        ("zeroPowZero", "[ 1, 1 ]"),
+        // 3 ** 6 wraps in a uint8, so any value is possible
+        ("wrapping", "[ 0, 255 ]"),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let x = ctx
+            .var_by_name_or_recurse(&analyzer, "x")
+            .unwrap()
+            .unwrap()
+            .latest_version(&analyzer);
+        let range = x.range_string(&mut analyzer, &mut arena).unwrap();
+        assert_eq!(range.as_deref(), Some(bounds), "{func}");
+    }
+}
+
+#[test]
+fn test_constant_cmps() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/constant_cmp.sol");
+    let sol = include_str!("./test_data/constant_cmp.sol");
+    let (mut analyzer, _arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+
+    for (func, cmp, val) in [
+        ("alwaysTrue", "x >= 0", true),
+        ("alwaysFalse", "x < 0", false),
+        // the first require already implies the second
+        ("decidedByRequire", "x > 5", true),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let advisory = analyzer
+            .constant_cmps(&file_mapping, ctx)
+            .unwrap()
+            .unwrap_or_else(|| panic!("no constant comparison in {func}"));
+        assert_eq!(advisory.cmps.len(), 1, "{func}");
+        let (span, found) = &advisory.cmps[0];
+        assert_eq!(span.1.start(), sol.rfind(cmp).unwrap(), "{func}");
+        assert_eq!(*found, val, "{func}");
+    }
+
+    let ctx = func_by_name(&analyzer, entry, "undecided").body_ctx(&mut analyzer);
+    assert!(analyzer
+        .constant_cmps(&file_mapping, ctx)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn test_killed_contexts() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/killed_contexts.sol");
+    let sol = include_str!("./test_data/killed_contexts.sol");
+    let (analyzer, _arena, _entry) = parse_analyzer(path_str, sol);
+
+    let killed = analyzer.killed_contexts();
+    assert_eq!(killed.len(), 1);
+    let (ctx, kind, loc) = killed[0];
+    assert_eq!(kind, KilledKind::Revert);
+    assert_eq!(ctx.killed_loc(&analyzer).unwrap(), Some((loc, kind)));
+    assert_eq!(&sol[loc.start()..loc.end()], "revert()");
+}
+
+#[test]
+fn test_bytes_length() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/bytes_length.sol");
+    let sol = include_str!("./test_data/bytes_length.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    for (func, expected) in [
+        ("constHex", "[ 2, 2 ]"),
+        ("literalLen", "[ 2, 2 ]"),
+        // trailing zero bytes still count towards the length
+        ("trailingZero", "[ 2, 2 ]"),
+        ("dynLen", "[ 0, 9 ]"),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let len = ctx
+            .var_by_name_or_recurse(&analyzer, "len")
+            .unwrap()
+            .unwrap()
+            .latest_version(&analyzer);
+        assert_eq!(
+            len.range_string(&mut analyzer, &mut arena)
+                .unwrap()
+                .unwrap(),
+            expected,
+            "{func}"
+        );
+    }
+
+    // `string` has no `length` member
+    let path_str = format!("{manifest_dir}/tests/test_data/string_length.sol");
+    let sol = include_str!("./test_data/string_length.sol");
+    let mut analyzer = Analyzer::default();
+    let current_path = SourcePath::SolidityFile(PathBuf::from(path_str));
+    let _ = analyzer.parse(&mut Default::default(), sol, &current_path, true);
+    assert!(analyzer
+        .expr_errs
+        .iter()
+        .any(|err| err.to_string().contains("`string` has no `length` member")));
+}
+
+#[test]
+fn test_multi_source_reports() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/multi_source/Main.sol");
+    let sol = include_str!("./test_data/multi_source/Main.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+    let srcs = analyzer
+        .sources
+        .iter()
+        .filter_map(|(_, sol, o_file_no, _)| Some(((*o_file_no)?, sol.clone())))
+        .collect::<BTreeMap<_, _>>();
+    assert_eq!(srcs.len(), 2);
+
+    // `deposit` in `Main.sol` is bounded by the `require` in the inherited `Limits.sol`
+    let ctx = func_by_name(&analyzer, entry, "deposit").body_ctx(&mut analyzer);
+    let config = ReportConfig::builder().show_initial_bounds(true).build();
+    let analysis = analyzer.bounds_for_all(&mut arena, &file_mapping, ctx, config);
+    let mut out = vec![];
+    analysis
+        .as_cli_compat(&file_mapping)
+        .write_multi_source_reports(&mut out, &file_mapping, &srcs, &analyzer, &mut arena)
+        .unwrap();
+    let out = String::from_utf8(out).unwrap();
+    // the subcontext's report has labels in both files, rendered from their own sources
+    assert!(out.contains("multi_source/Main.sol:"));
+    assert!(out.contains("multi_source/Limits.sol:"));
+    assert!(out.contains("\"amount\" ∈"));
+    assert!(out.contains("\"Limits.checkLimit(uint256).0\""));
+}
+
+#[test]
+fn test_bounds_report_snapshot() {
+    let sol = r#"
+contract Snapshot {
+    function twoVars(uint256 x, uint256 y) public pure {
+        require(x > 10);
+        require(y < 100);
+    }
+}
+"#;
+    // a relative path keeps the rendered file names independent of the checkout location
+    let (mut analyzer, mut arena, entry) = parse_analyzer("snapshot.sol".to_string(), sol);
+    let ctx = func_by_name(&analyzer, entry, "twoVars").body_ctx(&mut analyzer);
+    let report = render_bounds_report(&analyzer, &mut arena, ctx, ReportConfig::default());
+    assert_snapshot("bounds_two_vars", &report);
+}
+
+#[test]
+fn test_var_dependencies() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/dependencies.sol");
+    let sol = include_str!("./test_data/dependencies.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let ctx = func_by_name(&analyzer, entry, "add").body_ctx(&mut analyzer);
+    let mut dep_names = |var: &str| {
+        ctx.var_by_name(&analyzer, var)
+            .unwrap()
+            .latest_version_or_inherited_in_ctx(ctx, &analyzer)
+            .dependencies(&analyzer, &mut arena)
+            .unwrap()
+            .into_iter()
+            .filter(|dep| !dep.is_tmp(&analyzer).unwrap())
+            .map(|dep| dep.display_name(&analyzer).unwrap())
+            .collect::<BTreeSet<_>>()
+    };
+    assert_eq!(dep_names("c"), BTreeSet::from(["a".into(), "b".into()]));
+    // dependencies are followed through `c` to what it derives from, and `d` is never involved
+    assert_eq!(dep_names("e"), BTreeSet::from(["a".into(), "b".into()]));
+}
+
+#[test]
+fn test_function_selectors() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/selectors.sol");
+    let sol = include_str!("./test_data/selectors.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    for (func, expected) in [
+        ("local", [0x2f, 0xbe, 0xbd, 0x38]),
+        ("iface", [0xa9, 0x05, 0x9c, 0xbb]),
+        ("contractParam", [0x09, 0x5e, 0xa7, 0xb3]),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let s = ctx
+            .var_by_name(&analyzer, "s")
+            .unwrap()
+            .latest_version_or_inherited_in_ctx(ctx, &analyzer);
+        for bound in [
+            s.evaled_range_min(&analyzer, &mut arena),
+            s.evaled_range_max(&analyzer, &mut arena),
+        ] {
+            assert_eq!(
+                bound.unwrap().unwrap().maybe_concrete().unwrap().val,
+                Concrete::from(expected),
+                "{func}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_free_functions() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/free_functions.sol");
+    let sol = include_str!("./test_data/free_functions.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    // file level functions are analyzed like contract functions, and callable from contracts
+    for (func, expected) in [
+        ("add", "[ 0, 2**256 - 1 ]"),
+        ("addSmall", "[ 0, 510 ]"),
+        ("callsFree", "[ 3, 3 ]"),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let rets = ctx.return_nodes(&analyzer).unwrap();
+        assert_eq!(rets.len(), 1, "{func}");
+        assert_eq!(
+            rets[0]
+                .1
+                .range_string(&mut analyzer, &mut arena)
+                .unwrap()
+                .unwrap(),
+            expected,
+            "{func}"
+        );
+    }
+}
+
+#[test]
+fn test_reanalyze_source() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/incremental/Main.sol");
+    let sol = include_str!("./test_data/incremental/Main.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let (lib_path, lib_src) = analyzer
+        .sources
+        .iter()
+        .find(|(path, ..)| path.path_to_solidity_source().ends_with("Lib.sol"))
+        .map(|(path, src, ..)| (path.clone(), src.clone()))
+        .unwrap();
+    let standalone_ctx = func_by_name(&analyzer, entry, "standalone").body_ctx(&mut analyzer);
+    let lib = func_by_name(&analyzer, entry, "libOnly")
+        .maybe_associated_contract(&mut analyzer)
+        .unwrap();
+    let old_lib_loc = lib.underlying(&analyzer).unwrap().loc;
+
+    // a header change can't be handled incrementally, nor can whitespace outside of the bodies
+    let renamed = lib_src.replace("libOnly(uint256 x)", "libOnly(uint128 x)");
+    assert!(analyzer
+        .reanalyze_source(&mut arena, &lib_path, &renamed)
+        .is_none());
+    let spaced = lib_src.replace("contract Lib {", "contract  Lib {");
+    assert!(analyzer
+        .reanalyze_source(&mut arena, &lib_path, &spaced)
+        .is_none());
+
+    // a body change re-runs the changed file's functions and `useDouble`, which calls into it
+    analyzer.time_functions = true;
+    let doubled = lib_src.replace("x * 2", "x *   3");
+    let reanalyzed = analyzer
+        .reanalyze_source(&mut arena, &lib_path, &doubled)
+        .unwrap()
+        .into_iter()
+        .map(|func| func.name(&analyzer).unwrap())
+        .collect::<BTreeSet<_>>();
+    let expected = BTreeSet::from([
+        "double(uint256)".to_string(),
+        "libOnly(uint256)".to_string(),
+        "useDouble()".to_string(),
+    ]);
+    assert_eq!(reanalyzed, expected);
+    let timed = analyzer
+        .function_timings
+        .keys()
+        .cloned()
+        .collect::<BTreeSet<_>>();
+    assert_eq!(
+        timed,
+        BTreeSet::from([
+            "double(uint256)".to_string(),
+            "Lib.libOnly(uint256)".to_string(),
+            "Main.useDouble()".to_string(),
+        ])
+    );
+
+    let ctx = func_by_name(&analyzer, entry, "useDouble").body_ctx(&mut analyzer);
+    let rets = ctx.return_nodes(&analyzer).unwrap();
+    assert_eq!(
+        rets[0]
+            .1
+            .range_string(&mut analyzer, &mut arena)
+            .unwrap()
+            .unwrap(),
+        "[ 9, 9 ]"
+    );
+    // the untouched function keeps its analysis
+    assert_eq!(
+        func_by_name(&analyzer, entry, "standalone").body_ctx(&mut analyzer),
+        standalone_ctx
+    );
+    // locations after the edited body moved with it
+    let lib_loc = lib.underlying(&analyzer).unwrap().loc;
+    assert_eq!(lib_loc.start(), doubled.find("contract Lib").unwrap());
+    assert_eq!(lib_loc.start(), old_lib_loc.start() + 2);
+    assert_eq!(lib_loc.end(), old_lib_loc.end() + 2);
+}
+
+#[test]
+fn test_constant_hashes() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/hashes.sol");
+    let sol = include_str!("./test_data/hashes.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let expected = [
+        (
+            "emptyKeccak",
+            "0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+        ),
+        (
+            "emptySha",
+            "0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        ),
+        ("emptyRipemd", "0x9c1185a5c5e9fc54612808977ee8f548b2258d31"),
+    ];
+    for (name, hash) in expected {
+        let ctx = func_by_name(&analyzer, entry, name).body_ctx(&mut analyzer);
+        let ret = ctx.return_nodes(&analyzer).unwrap()[0].1;
+        let val = ret
+            .evaled_range_min(&analyzer, &mut arena)
+            .unwrap()
+            .unwrap()
+            .maybe_concrete()
+            .unwrap()
+            .val;
+        assert_eq!(val.as_display_hex().unwrap(), hash, "{name}");
+    }
+
+    // hashes of symbolic inputs are unknown
+    let ctx = func_by_name(&analyzer, entry, "symbolic").body_ctx(&mut analyzer);
+    let ret = ctx.return_nodes(&analyzer).unwrap()[0].1;
+    assert!(!ret.is_const(&analyzer, &mut arena).unwrap());
+    assert_eq!(
+        ret.ty(&analyzer).unwrap().as_string(&analyzer).unwrap(),
+        "bytes32"
+    );
+}
+
+#[test]
+fn test_storage_pointers() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/storage_pointer.sol");
+    let sol = include_str!("./test_data/storage_pointer.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    for (func, expected) in [
+        ("writeThrough", "[ 5, 5 ]"),
+        ("writeThroughMapping", "[ 7, 7 ]"),
+        ("readThrough", "[ 9, 9 ]"),
+        // a memory copy does not alias the storage it was copied from
+        ("memoryCopy", "[ 1, 1 ]"),
+        // writes through a reference in a loop widen the storage it points to
+        ("loopWrite", "[ 0, 2**256 - 1 ]"),
+        ("loopWriteInner", "[ 0, 2**256 - 1 ]"),
+        // reassigning the reference points it elsewhere instead of writing through it
+        ("reassigned", "[ 5, 5 ]"),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let mut leaves = ctx.all_edges(&analyzer).unwrap();
+        if leaves.is_empty() {
+            leaves.push(ctx);
+        }
+        for ctx in leaves {
+            let a = ctx
+                .var_by_name_or_recurse(&analyzer, "a")
+                .unwrap()
+                .unwrap()
+                .latest_version(&analyzer);
+            assert_eq!(
+                a.range_string(&mut analyzer, &mut arena).unwrap().unwrap(),
+                expected,
+                "{func}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_eval_bounds_override() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/eval_override.sol");
+    let sol = include_str!("./test_data/eval_override.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+    let ctx = func_by_name(&analyzer, entry, "offset").body_ctx(&mut analyzer);
+    let analysis = analyzer.bounds_for_all(&mut arena, &file_mapping, ctx, ReportConfig::default());
+
+    let z_labels = |analysis: &FunctionVarsBoundAnalysis, arena: &mut _| {
+        let z = analysis
+            .vars_by_ctx
+            .values()
+            .flatten()
+            .find(|analysis| analysis.var_display_name == "z")
+            .unwrap();
+        z.labels(&analyzer, arena)
+            .iter()
+            .map(|label| format!("{label:?}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let evaled = z_labels(&analysis, &mut arena);
+    let symbolic = z_labels(
+        &analysis.with_eval_overrides(&BTreeMap::from([("z".to_string(), false)])),
+        &mut arena,
+    );
+    // the evaluated bounds are numbers, the symbolic ones are in terms of `x`
+    assert!(evaled.contains("14") && !evaled.contains("x + 5"));
+    assert!(symbolic.contains("(x + 5)"));
+}
+
+#[test]
+fn test_loop_widens_to_threshold() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/loop_threshold.sol");
+    let sol = include_str!("./test_data/loop_threshold.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    for (func, expected) in [
+        ("bounded", "[ 0, 100 ]"),
+        ("stepped", "[ 1, 128 ]"),
+        ("countdown", "[ 10, 50 ]"),
+        ("unbounded", "[ 0, n ]"),
+        ("offset", "[ 5, max{n, 5} ]"),
+        ("countdownTo", "[ min{n, 50}, 50 ]"),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let post_loop = ctx.all_edges(&analyzer).unwrap();
+        assert!(!post_loop.is_empty());
+        for ctx in post_loop {
+            let i = ctx
+                .var_by_name_or_recurse(&analyzer, "i")
+                .unwrap()
+                .unwrap()
+                .latest_version(&analyzer);
+            // the bounds as set, since a bound like `n` evaluates to its own full range
+            let range = format!(
+                "[ {}, {} ]",
+                i.range_min(&analyzer)
+                    .unwrap()
+                    .unwrap()
+                    .to_range_string(false, &analyzer, &mut arena)
+                    .s,
+                i.range_max(&analyzer)
+                    .unwrap()
+                    .unwrap()
+                    .to_range_string(true, &analyzer, &mut arena)
+                    .s
+            );
+            assert_eq!(range, expected, "{func}");
+        }
+    }
+}
+
+#[test]
+fn test_const_string_cmp_folds() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/string_cmp.sol");
+    let sol = include_str!("./test_data/string_cmp.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    for (func, expected) in [
+        ("equal", "[ true, true ]"),
+        ("unequal", "[ false, false ]"),
+        ("notEqual", "[ true, true ]"),
+    ] {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let res = ctx
+            .var_by_name_or_recurse(&analyzer, "res")
+            .unwrap()
+            .unwrap()
+            .latest_version(&analyzer);
+        assert_eq!(
+            res.range_string(&mut analyzer, &mut arena)
+                .unwrap()
+                .unwrap(),
+            expected,
+            "{func}"
+        );
+    }
+}
+
+#[test]
+fn test_div_by_zero() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/div_by_zero.sol");
+    let sol = include_str!("./test_data/div_by_zero.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let ctx = func_by_name(&analyzer, entry, "constZero").body_ctx(&mut analyzer);
+    assert_eq!(
+        ctx.killed_loc(&analyzer).unwrap().map(|(_, kind)| kind),
+        Some(KilledKind::DivisionByZero)
+    );
+
+    // a divisor in `[0, 5]` forks into a reverting path and a path where it is nonzero
+    let ctx = func_by_name(&analyzer, entry, "maybeZero").body_ctx(&mut analyzer);
+    let leaves = ctx.all_edges(&analyzer).unwrap();
+    assert_eq!(leaves.len(), 2);
+    let (killed, live): (Vec<_>, Vec<_>) = leaves
+        .into_iter()
+        .partition(|leaf| leaf.is_killed(&analyzer).unwrap());
+    assert_eq!(killed.len(), 1);
+    assert_eq!(
+        killed[0]
+            .killed_loc(&analyzer)
+            .unwrap()
+            .map(|(_, kind)| kind),
+        Some(KilledKind::DivisionByZero)
+    );
+    let d = live[0]
+        .var_by_name_or_recurse(&analyzer, "d")
+        .unwrap()
+        .unwrap()
+        .latest_version(&analyzer);
+    let exclusions = d.ref_range(&analyzer).unwrap().unwrap().exclusions.clone();
+    let excluded = exclusions
+        .iter()
+        .map(|elem| {
+            elem.minimize(&analyzer, &mut arena)
+                .unwrap()
+                .maybe_concrete()
+                .unwrap()
+                .val
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(excluded, vec![Concrete::Uint(256, U256::zero())]);
+    let r = live[0]
+        .var_by_name_or_recurse(&analyzer, "r")
+        .unwrap()
+        .unwrap()
+        .latest_version(&analyzer);
+    assert_eq!(
+        r.range_string(&mut analyzer, &mut arena).unwrap().unwrap(),
+        "[ 0, 4 ]"
+    );
+}
+
+#[test]
+fn test_assembly_widens_touched_vars() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/assembly_widen.sol");
+    let sol = include_str!("./test_data/assembly_widen.sol");
+    // `blobhash` isn't supported, so parse without asserting there were no errors
+    let mut analyzer = Analyzer::default();
+    let mut arena = Default::default();
+    let current_path = SourcePath::SolidityFile(PathBuf::from(path_str));
+    let entry = analyzer
+        .parse(&mut arena, sol, &current_path, true)
+        .unwrap();
+    assert!(analyzer
+        .expr_errs
+        .iter()
+        .all(|err| err.to_string().contains("blobhash")));
+
+    let mut final_range = |func: &str, var: &str| {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let leaf = ctx.all_edges(&analyzer).unwrap().pop().unwrap_or(ctx);
+        leaf.var_by_name_or_recurse(&analyzer, var)
+            .unwrap()
+            .unwrap()
+            .latest_version(&analyzer)
+            .range_string(&mut analyzer, &mut arena)
+            .unwrap()
+            .unwrap()
+    };
+    // assigned in a yul loop body
+    assert_eq!(final_range("yulLoop", "x"), "[ 0, 2**256 - 1 ]");
+    // memory written by a copy
+    assert!(final_range("copied", "b").contains("2**256 - 1"));
+    // assigned from and referenced by an unsupported builtin
+    assert_eq!(final_range("unhandled", "x"), "[ 0, 2**256 - 1 ]");
+    assert_eq!(final_range("unhandled", "y"), "[ 0, 2**256 - 1 ]");
+}
+
+#[test]
+fn test_parse_standard_json() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path = PathBuf::from(format!("{manifest_dir}/tests/test_data/standard_json.json"));
+    let json = include_str!("./test_data/standard_json.json");
+    let mut analyzer = Analyzer::default();
+    let mut arena = Default::default();
+    let entries = analyzer.parse_solc_json(&mut arena, &path, json);
+    assert!(analyzer.expr_errs.is_empty());
+    // `Main.sol` imports `Lib.sol` through a remapping with a context, so `Lib.sol` is only
+    // parsed once
+    assert_eq!(entries.len(), 2);
+    assert_eq!(
+        analyzer
+            .sources
+            .iter()
+            .filter_map(|(_, _, file_no, _)| *file_no)
+            .collect::<BTreeSet<_>>()
+            .len(),
+        2
+    );
+
+    let entry = analyzer.entry;
+    let ctx = func_by_name(&analyzer, entry, "run").body_ctx(&mut analyzer);
+    let y = ctx
+        .var_by_name_or_recurse(&analyzer, "y")
+        .unwrap()
+        .unwrap()
+        .latest_version(&analyzer);
+    assert_eq!(
+        y.range_string(&mut analyzer, &mut arena).unwrap().unwrap(),
+        "[ 6, 6 ]"
+    );
+    let ctx = func_by_name(&analyzer, entry, "incr").body_ctx(&mut analyzer);
+    assert!(!ctx.return_nodes(&analyzer).unwrap().is_empty());
+}
+
+#[test]
+fn test_memory_layout() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/memory_layout.sol");
+    let sol = include_str!("./test_data/memory_layout.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let ctx = func_by_name(&analyzer, entry, "known").body_ctx(&mut analyzer);
+    let b = ctx.var_by_name_or_recurse(&analyzer, "b").unwrap().unwrap();
+    assert_eq!(
+        ctx.memory_layout(b, &analyzer, &mut arena).unwrap(),
+        MemoryLayout {
+            len_min: Some(U256::from(2)),
+            len_max: Some(U256::from(2)),
+            indices: BTreeMap::from([(U256::zero(), 0x7b), (U256::one(), 0xff)]),
+        }
+    );
+
+    let ctx = func_by_name(&analyzer, entry, "symbolic").body_ctx(&mut analyzer);
+    let b = ctx.var_by_name_or_recurse(&analyzer, "b").unwrap().unwrap();
+    assert_eq!(
+        ctx.memory_layout(b, &analyzer, &mut arena).unwrap(),
+        MemoryLayout {
+            len_min: Some(U256::zero()),
+            len_max: Some(U256::MAX),
+            indices: BTreeMap::default(),
+        }
+    );
+}
+
+#[test]
+fn test_tmp_filter() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/tmp_filter.sol");
+    let sol = include_str!("./test_data/tmp_filter.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+    let ctx = func_by_name(&analyzer, entry, "f").body_ctx(&mut analyzer);
+
+    let mut shown_tmps = |show_tmps: TmpFilter| {
+        let report_config = ReportConfig {
+            show_tmps,
+            show_symbolics: false,
+            ..Default::default()
+        };
+        let analysis = analyzer.bounds_for_all(&mut arena, &file_mapping, ctx, report_config);
+        analysis
+            .vars_by_ctx
+            .values()
+            .flatten()
+            .map(|var| var.var_display_name.clone())
+            .filter(|name| name.starts_with('('))
+            .collect::<BTreeSet<_>>()
+    };
+
+    assert!(shown_tmps(TmpFilter::None).is_empty());
+    let conditions = shown_tmps(TmpFilter::ConditionsOnly);
+    assert!(conditions.contains("(b > 10)"));
+    assert!(!conditions.contains("(a * 2)"));
+    assert!(!conditions.contains("((a * 2) + 1)"));
+    let all = shown_tmps(TmpFilter::All);
+    assert!(all.contains("(b > 10)"));
+    assert!(all.contains("(a * 2)"));
+}
+
+#[test]
+fn test_mapping_value_range() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/mapping_values.sol");
+    let sol = include_str!("./test_data/mapping_values.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+
+    let mut value_range = |func: &str, key: Option<u64>| {
+        let ctx = func_by_name(&analyzer, entry, func).body_ctx(&mut analyzer);
+        let balances = ctx
+            .var_by_name_or_recurse(&analyzer, "balances")
+            .unwrap()
+            .unwrap();
+        let range = ctx
+            .mapping_value_range(
+                balances,
+                key.map(|key| Concrete::from(U256::from(key))),
+                &analyzer,
+                &mut arena,
+            )
+            .unwrap()
+            .unwrap();
+        let as_u64 = |elem: Elem<Concrete>| elem.maybe_concrete().unwrap().val.uint_val().unwrap();
+        (as_u64(range.min).as_u64(), as_u64(range.max).as_u64())
+    };
+
+    assert_eq!(value_range("constKeys", Some(1)), (5, 5));
+    assert_eq!(value_range("constKeys", Some(2)), (7, 7));
+    // never written, so the zero value
+    assert_eq!(value_range("constKeys", Some(3)), (0, 0));
+    assert_eq!(value_range("constKeys", None), (5, 7));
+
+    // the symbolic key may have overwritten either key
+    assert_eq!(value_range("symbolicKey", Some(1)), (0, 99));
+    assert_eq!(value_range("symbolicKey", Some(3)), (0, 99));
+    assert_eq!(value_range("symbolicKey", None), (0, 99));
+}
+
+#[test]
+fn test_bound_changes_collapse_same_loc() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/loop_same_loc.sol");
+    let sol = include_str!("./test_data/loop_same_loc.sol");
+    let (mut analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    let file_mapping = file_mapping(&analyzer);
+    let ctx = func_by_name(&analyzer, entry, "incr").body_ctx(&mut analyzer);
+    let report_config = ReportConfig {
+        show_all_lines: true,
+        ..Default::default()
+    };
+    let analysis = analyzer.bounds_for_all(&mut arena, &file_mapping, ctx, report_config);
+    let x = analysis
+        .vars_by_ctx
+        .values()
+        .flatten()
+        .find(|var| var.var_name == "x")
+        .unwrap();
+
+    // the loop body advances `x` several times at `x += 1`
+    let start = sol.find("x += 1").unwrap();
+    let end = start + "x += 1".len();
+    let at_incr = x
+        .bound_changes
+        .iter()
+        .filter(|(span, _)| span.start() == start && span.end() == end)
+        .count();
+    assert_eq!(at_incr, 1);
+    assert!(x
+        .bound_changes
+        .windows(2)
+        .all(|changes| changes[0].0 != changes[1].0));
+}
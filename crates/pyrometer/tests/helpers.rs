@@ -1,13 +1,16 @@
+#![allow(dead_code)]
 use analyzers::FunctionVarsBoundAnalyzer;
 use analyzers::ReportConfig;
 use analyzers::ReportDisplay;
+use analyzers::TmpFilter;
 use ariadne::sources;
 use graph::{
     elem::Elem,
-    nodes::{Concrete, FunctionNode},
+    nodes::{Concrete, ContextNode, FunctionNode, KilledKind, StructNode},
     Edge,
 };
 use pyrometer::{Analyzer, SourcePath};
+use shared::ExprErr;
 use shared::NodeIdx;
 use shared::RangeArena;
 use shared::Search;
@@ -29,6 +32,170 @@ pub fn assert_no_parse_errors(path_str: String) {
     );
 }
 
+pub fn parse_analyzer(
+    path_str: String,
+    sol: &str,
+) -> (Analyzer, RangeArena<Elem<Concrete>>, NodeIdx) {
+    parse_with_analyzer(Analyzer::default(), path_str, sol)
+}
+
+/// Like [`parse_analyzer`], but with a preconfigured analyzer
+pub fn parse_with_analyzer(
+    mut analyzer: Analyzer,
+    path_str: String,
+    sol: &str,
+) -> (Analyzer, RangeArena<Elem<Concrete>>, NodeIdx) {
+    let mut arena = Default::default();
+    let current_path = SourcePath::SolidityFile(PathBuf::from(path_str));
+    let entry = analyzer
+        .parse(&mut arena, sol, &current_path, true)
+        .unwrap();
+    assert!(
+        analyzer.expr_errs.is_empty(),
+        "Analyzer encountered parse errors"
+    );
+    (analyzer, arena, entry)
+}
+
+pub fn func_by_name(analyzer: &Analyzer, entry: NodeIdx, name: &str) -> FunctionNode {
+    analyzer
+        .search_children(entry, &Edge::Func)
+        .into_iter()
+        .map(FunctionNode::from)
+        .find(|func| {
+            func.name(analyzer)
+                .unwrap()
+                .starts_with(&format!("{name}("))
+        })
+        .unwrap_or_else(|| panic!("No function named {name}"))
+}
+
+pub fn struct_by_name(analyzer: &Analyzer, entry: NodeIdx, name: &str) -> StructNode {
+    analyzer
+        .search_children(entry, &Edge::Struct)
+        .into_iter()
+        .map(StructNode::from)
+        .find(|strukt| strukt.name(analyzer).unwrap() == name)
+        .unwrap_or_else(|| panic!("No struct named {name}"))
+}
+
+pub fn file_mapping(analyzer: &Analyzer) -> BTreeMap<usize, String> {
+    analyzer
+        .sources
+        .iter()
+        .filter_map(|(source_path, _sol, o_file_no, _o_entry)| {
+            Some((
+                (*o_file_no)?,
+                source_path.path_to_solidity_source().display().to_string(),
+            ))
+        })
+        .collect()
+}
+
+/// Renders the bound reports of `ctx` as plain text, with the terminal color codes stripped
+pub fn render_bounds_report(
+    analyzer: &Analyzer,
+    arena: &mut RangeArena<Elem<Concrete>>,
+    ctx: ContextNode,
+    config: ReportConfig,
+) -> String {
+    let file_mapping = file_mapping(analyzer);
+    let srcs = analyzer
+        .sources
+        .iter()
+        .filter_map(|(_, sol, o_file_no, _)| Some(((*o_file_no)?, sol.clone())))
+        .collect::<BTreeMap<_, _>>();
+    let mut out = vec![];
+    analyzer
+        .bounds_for_all(arena, &file_mapping, ctx, config)
+        .as_cli_compat(&file_mapping)
+        .write_multi_source_reports(&mut out, &file_mapping, &srcs, analyzer, arena)
+        .unwrap();
+    strip_ansi(&String::from_utf8(out).unwrap())
+}
+
+/// Removes ANSI escape sequences (i.e. colors) from `s`
+pub fn strip_ansi(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            out.push(c);
+            continue;
+        }
+        // skip the `[` and parameters up to and including the final letter
+        for c in chars.by_ref() {
+            if c.is_ascii_alphabetic() {
+                break;
+            }
+        }
+    }
+    out
+}
+
+/// Compares `actual` against the snapshot stored in `tests/snapshots/{name}.snap`. Run with
+/// `UPDATE_SNAPSHOTS=1` to write (or overwrite) the snapshot instead
+pub fn assert_snapshot(name: &str, actual: &str) {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path = PathBuf::from(format!("{manifest_dir}/tests/snapshots/{name}.snap"));
+    if std::env::var("UPDATE_SNAPSHOTS").is_ok() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "Missing snapshot {}, run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        )
+    });
+    assert_eq!(
+        expected, actual,
+        "Snapshot {name} changed, run with UPDATE_SNAPSHOTS=1 to accept the new output"
+    );
+}
+
+pub fn parse_errs(path_str: String, sol: &str) -> Vec<ExprErr> {
+    let mut analyzer = Analyzer::default();
+    let mut arena_base = Default::default();
+    let arena = &mut arena_base;
+    let current_path = SourcePath::SolidityFile(PathBuf::from(path_str));
+    let _ = analyzer.parse(arena, sol, &current_path, true);
+    analyzer.expr_errs
+}
+
+pub fn killed_fns(path_str: String, sol: &str) -> Vec<String> {
+    let mut analyzer = Analyzer::default();
+    let mut arena_base = Default::default();
+    let arena = &mut arena_base;
+    let current_path = SourcePath::SolidityFile(PathBuf::from(path_str));
+    let entry = analyzer.parse(arena, sol, &current_path, true).unwrap();
+    assert!(
+        analyzer.expr_errs.is_empty(),
+        "Analyzer encountered parse errors"
+    );
+
+    let funcs = analyzer.search_children(entry, &Edge::Func);
+    funcs
+        .into_iter()
+        .filter_map(|func| {
+            let func = FunctionNode::from(func);
+            let ctx = func.maybe_body_ctx(&mut analyzer)?;
+            let killed = ctx.killed_loc(&analyzer).unwrap().is_some()
+                || ctx
+                    .all_edges(&analyzer)
+                    .unwrap()
+                    .iter()
+                    .any(|subctx| subctx.killed_loc(&analyzer).unwrap().is_some());
+            if killed {
+                Some(func.name(&analyzer).unwrap())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
 pub fn assert_no_ctx_killed(path_str: String, sol: &str) {
     let mut analyzer = Analyzer::default();
     let mut arena_base = Default::default();
@@ -50,10 +217,17 @@ pub fn remapping_assert_no_ctx_killed(path_str: String, remapping_file: String,
     no_ctx_killed(analyzer, arena, entry);
 }
 
-pub fn no_ctx_killed(
+pub fn no_ctx_killed(analyzer: Analyzer, arena: &mut RangeArena<Elem<Concrete>>, entry: NodeIdx) {
+    no_ctx_killed_except(analyzer, arena, entry, &[]);
+}
+
+/// Like [`no_ctx_killed`], except that each function in `expected_kills` must have a forked
+/// subcontext killed with the given kind, i.e. the zero side of a possibly zero divisor
+pub fn no_ctx_killed_except(
     mut analyzer: Analyzer,
     arena: &mut RangeArena<Elem<Concrete>>,
     entry: NodeIdx,
+    expected_kills: &[(&str, KilledKind)],
 ) {
     assert!(
         analyzer.expr_errs.is_empty(),
@@ -63,7 +237,7 @@ pub fn no_ctx_killed(
     let config = ReportConfig {
         eval_bounds: true,
         simplify_bounds: false,
-        show_tmps: true,
+        show_tmps: TmpFilter::All,
         show_consts: true,
         show_symbolics: true,
         show_initial_bounds: true,
@@ -71,6 +245,10 @@ pub fn no_ctx_killed(
         show_reverts: true,
         show_unreachables: true,
         show_nonreverts: true,
+        preserve_constant_names: false,
+        only_meaningful_changes: false,
+        show_cause_expr: false,
+        hex_values: false,
     };
     let mut file_mapping: BTreeMap<usize, String> = BTreeMap::new();
     let mut src_map: HashMap<String, String> = HashMap::new();
@@ -88,9 +266,11 @@ pub fn no_ctx_killed(
     }
     let mut source_map = sources(src_map);
 
+    let mut seen_kills = vec![];
     let funcs = analyzer.search_children(entry, &Edge::Func);
     for func in funcs.into_iter() {
-        if let Some(ctx) = FunctionNode::from(func).maybe_body_ctx(&mut analyzer) {
+        let func = FunctionNode::from(func);
+        if let Some(ctx) = func.maybe_body_ctx(&mut analyzer) {
             if ctx.killed_loc(&analyzer).unwrap().is_some() {
                 analyzer
                     .bounds_for_all(arena, &file_mapping, ctx, config)
@@ -98,8 +278,16 @@ pub fn no_ctx_killed(
                     .print_reports(&mut source_map, &analyzer, arena);
                 panic!("Killed context in test");
             }
+            let name = func.name(&analyzer).unwrap();
             ctx.all_edges(&analyzer).unwrap().iter().for_each(|subctx| {
-                if subctx.killed_loc(&analyzer).unwrap().is_some() {
+                if let Some((_, kind)) = subctx.killed_loc(&analyzer).unwrap() {
+                    let expected = expected_kills.iter().any(|(func, expected)| {
+                        name.starts_with(&format!("{func}(")) && *expected == kind
+                    });
+                    if expected && subctx.underlying(&analyzer).unwrap().is_fork {
+                        seen_kills.push((name.clone(), kind));
+                        return;
+                    }
                     analyzer
                         .bounds_for_all(arena, &file_mapping, *subctx, config)
                         .as_cli_compat(&file_mapping)
@@ -109,4 +297,13 @@ pub fn no_ctx_killed(
             });
         }
     }
+
+    for (func, kind) in expected_kills {
+        assert!(
+            seen_kills
+                .iter()
+                .any(|(name, seen)| name.starts_with(&format!("{func}(")) && seen == kind),
+            "Expected a forked context of {func} killed with {kind:?}"
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use graph::nodes::KilledKind;
 use std::env;
 mod helpers;
 use helpers::*;
@@ -10,6 +11,14 @@ fn test_bitwise() {
     assert_no_ctx_killed(path_str, sol);
 }
 
+#[test]
+fn test_balance() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/balance.sol");
+    let sol = include_str!("./test_data/balance.sol");
+    assert_no_ctx_killed(path_str, sol);
+}
+
 #[test]
 fn test_cast() {
     let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
@@ -63,7 +72,21 @@ fn test_math() {
     let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
     let path_str = format!("{manifest_dir}/tests/test_data/math.sol");
     let sol = include_str!("./test_data/math.sol");
-    assert_no_ctx_killed(path_str, sol);
+    let (analyzer, mut arena, entry) = parse_analyzer(path_str, sol);
+    // dividing by a symbolic divisor forks off a side where it is zero, which reverts
+    let zero_divisor = KilledKind::DivisionByZero;
+    no_ctx_killed_except(
+        analyzer,
+        &mut arena,
+        entry,
+        &[
+            ("div", zero_divisor),
+            ("int_div", zero_divisor),
+            ("rmod", zero_divisor),
+            ("int_rmod", zero_divisor),
+            ("symbUncheckedMul", zero_divisor),
+        ],
+    );
 }
 
 #[test]
@@ -184,3 +207,11 @@ fn test_repros() {
         assert_no_parse_errors(path_str);
     }
 }
+
+#[test]
+fn test_do_while() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/do_while.sol");
+    let sol = include_str!("./test_data/do_while.sol");
+    assert_no_ctx_killed(path_str, sol);
+}
@@ -0,0 +1,31 @@
+use std::env;
+mod helpers;
+use helpers::*;
+
+#[test]
+fn test_struct_assign_field_mismatch() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/struct_mismatch.sol");
+    let sol = include_str!("./test_data/struct_mismatch.sol");
+    let errs = parse_errs(path_str, sol);
+    assert!(
+        errs.iter().any(|err| err.msg()
+            == "Struct types mismatched - cannot assign struct \"B\" { x, z } to struct \"A\" { x, y }: missing fields: [y], unexpected fields: [z]"),
+        "Expected a struct mismatch error, got: {errs:?}"
+    );
+}
+
+#[test]
+fn test_literal_downcast() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let path_str = format!("{manifest_dir}/tests/test_data/literal_downcast.sol");
+    let sol = include_str!("./test_data/literal_downcast.sol");
+    let errs = parse_errs(path_str, sol);
+    let msgs = errs.iter().map(|err| err.msg()).collect::<Vec<_>>();
+    // only `300` doesn't fit, `200` does
+    assert_eq!(
+        msgs,
+        vec!["Literal 300 does not fit into the assigned type uint8"],
+        "{errs:?}"
+    );
+}
@@ -0,0 +1,14 @@
+contract AddressCasts {
+    function zero() public {
+        address a = address(0);
+    }
+
+    function fromUint() public {
+        address b = address(uint160(0x01));
+    }
+
+    function pay(address x) public {
+        require(x == address(0x10));
+        address payable p = payable(x);
+    }
+}
@@ -0,0 +1,17 @@
+contract AlwaysReverts {
+    function bothBranchesRevert(uint256 x) public pure returns (uint256) {
+        if (x > 5) {
+            revert("too big");
+        } else {
+            require(x > 10);
+        }
+        return x;
+    }
+
+    function oneBranchReturns(uint256 x) public pure returns (uint256) {
+        if (x > 5) {
+            revert("too big");
+        }
+        return x;
+    }
+}
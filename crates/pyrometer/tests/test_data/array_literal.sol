@@ -0,0 +1,19 @@
+contract ArrayLiteral {
+    function flat() public pure {
+        uint8[3] memory x = [1, 2, 3];
+        uint8 a = x[0];
+        uint8 b = x[2];
+    }
+
+    function nested() public pure {
+        uint8[2][2] memory m = [[1, 2], [3, 4]];
+        uint8 c = m[0][1];
+        uint8 d = m[1][0];
+    }
+
+    function mixed() public pure {
+        uint16[2] memory y = [1, 256];
+        uint16 e = y[0];
+        uint16 f = y[1];
+    }
+}
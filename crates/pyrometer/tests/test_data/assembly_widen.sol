@@ -0,0 +1,32 @@
+contract AssemblyWiden {
+    function yulLoop() public returns (uint256) {
+        uint256 x = 5;
+        assembly {
+            for {
+                let i := 0
+            } lt(i, 10) {
+                i := add(i, 1)
+            } {
+                x := add(x, 1)
+            }
+        }
+        return x;
+    }
+
+    function copied() public returns (bytes memory) {
+        bytes memory b = hex"01";
+        assembly {
+            calldatacopy(add(b, 32), 0, 1)
+        }
+        return b;
+    }
+
+    function unhandled(uint256 y) public returns (uint256) {
+        uint256 x = 5;
+        require(y < 10);
+        assembly {
+            x := blobhash(y)
+        }
+        return x + y;
+    }
+}
@@ -0,0 +1,24 @@
+contract SelfBalance {
+    function received() public payable returns (uint256) {
+        require(msg.value == 5);
+        uint256 bal = address(this).balance;
+        require(bal >= 5);
+        return bal;
+    }
+
+    function sent(address payable to) public returns (uint256, uint256) {
+        uint256 before = address(this).balance;
+        to.transfer(10);
+        uint256 afterwards = address(this).balance;
+        require(afterwards <= type(uint256).max - 10);
+        return (before, afterwards);
+    }
+
+    function yulSelfBalance() public returns (uint256 bal) {
+        payable(msg.sender).transfer(1 ether);
+        assembly {
+            bal := selfbalance()
+        }
+        require(bal <= type(uint256).max - 1 ether);
+    }
+}
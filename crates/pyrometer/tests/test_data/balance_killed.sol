@@ -0,0 +1,12 @@
+contract SelfBalanceKilled {
+    function receivedTooLittle() public payable {
+        require(msg.value == 5);
+        uint256 bal = address(this).balance;
+        require(bal < 5);
+    }
+
+    function sentTooMuch(address payable to) public {
+        to.transfer(10);
+        require(address(this).balance > type(uint256).max - 10);
+    }
+}
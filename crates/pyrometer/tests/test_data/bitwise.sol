@@ -25,6 +25,20 @@ contract BitAnd {
         require(bit_and(50, 500) == 48);
     }
 
+    function bit_and_low_bits(uint256 x) public {
+        require(x >= 5);
+        require(x <= 9);
+        require(x & 7 == 0);
+        require(x % 8 == 0);
+    }
+
+    function bit_and_low_bits_max(uint256 x) public {
+        require(x >= 5);
+        require(x <= 9);
+        require(x & 7 == 7);
+        require(x % 8 == 7);
+    }
+
     function int_bit_and(int256 x, int256 y) public returns (int256) {
         return x & y;
     }
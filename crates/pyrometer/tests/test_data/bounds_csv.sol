@@ -0,0 +1,7 @@
+contract BoundsCsv {
+    function clamp(uint256 x, uint256 y) public returns (uint256) {
+        require(x < 100);
+        require(y > 7);
+        return x + y;
+    }
+}
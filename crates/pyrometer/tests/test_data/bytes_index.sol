@@ -0,0 +1,14 @@
+contract BytesIndex {
+    function inBounds() public pure returns (bytes1) {
+        bytes memory b = hex"aabbcc";
+        bytes1 x = b[1];
+        return x;
+    }
+
+    function maybeOutOfBounds(uint256 i) public pure returns (bytes1) {
+        bytes memory b = hex"aabbcc";
+        require(i < 5);
+        bytes1 x = b[i];
+        return x;
+    }
+}
@@ -0,0 +1,24 @@
+contract BytesLength {
+    function constHex() public pure returns (uint256) {
+        bytes memory b = hex"7bff";
+        uint256 len = b.length;
+        return len;
+    }
+
+    function literalLen() public pure returns (uint256) {
+        uint256 len = hex"7bff".length;
+        return len;
+    }
+
+    function trailingZero() public pure returns (uint256) {
+        uint256 len = hex"7b00".length;
+        return len;
+    }
+
+    function dynLen(bytes memory b) public pure returns (uint256) {
+        require(b.length < 10);
+        bytes memory c = b;
+        uint256 len = c.length;
+        return len;
+    }
+}
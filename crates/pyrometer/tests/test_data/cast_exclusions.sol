@@ -0,0 +1,9 @@
+contract CastExclusions {
+    function narrow(uint256 x) public returns (uint8) {
+        require(x != 256);
+        require(x != 7);
+        // solc requires an explicit cast here, the analyzer converts implicitly
+        uint8 y = x;
+        return y;
+    }
+}
@@ -0,0 +1,16 @@
+contract ChainedAssign {
+    uint[] arr;
+
+    function chained() public pure {
+        uint a;
+        uint b;
+        uint c = 5;
+        a = b = c;
+    }
+
+    function chainedSymbolic(uint c) public {
+        require(c < 10);
+        uint a;
+        a = arr[0] = c;
+    }
+}
@@ -0,0 +1,25 @@
+contract Complexity {
+    function branchy(uint256 x) public returns (uint256) {
+        uint256 total;
+        if (x > 10) {
+            total = 1;
+        }
+        for (uint256 i; i < 3; i++) {
+            total += i;
+        }
+        if (x == 5) {
+            total = 2;
+        } else {
+            total = 3;
+        }
+        return total;
+    }
+
+    function straight(uint256 x) public returns (uint256) {
+        return x + 1;
+    }
+
+    function conditions(uint256 x) public returns (uint256) {
+        return x > 1 && x < 10 ? x : 0;
+    }
+}
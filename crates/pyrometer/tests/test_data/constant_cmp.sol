@@ -0,0 +1,22 @@
+contract ConstantCmp {
+    function alwaysTrue(uint256 x) public returns (bool) {
+        bool b = x >= 0;
+        return b;
+    }
+
+    function alwaysFalse(uint256 x) public {
+        if (x < 0) {
+            x = 1;
+        }
+    }
+
+    function undecided(uint256 x) public returns (bool) {
+        bool b = x > 5;
+        return b;
+    }
+
+    function decidedByRequire(uint256 x) public {
+        require(x > 10);
+        require(x > 5);
+    }
+}
@@ -0,0 +1,8 @@
+contract Constants {
+    uint256 constant MAX_SUPPLY = 1000;
+
+    function capped() public returns (uint256) {
+        uint256 y = MAX_SUPPLY;
+        return y;
+    }
+}
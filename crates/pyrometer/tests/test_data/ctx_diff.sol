@@ -0,0 +1,8 @@
+contract CtxDiff {
+    function f(uint256 x, uint256 y) public pure returns (uint256) {
+        if (x > 10) {
+            return x + y;
+        }
+        return y;
+    }
+}
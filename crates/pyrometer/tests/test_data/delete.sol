@@ -0,0 +1,26 @@
+contract Delete {
+    struct Point {
+        uint256 x;
+        uint256 y;
+    }
+
+    mapping(uint256 => uint256) balances;
+
+    function deleteUint(uint256 a) public returns (uint256) {
+        require(a > 10);
+        delete a;
+        return a;
+    }
+
+    function deleteStruct(uint256 a, uint256 b) public returns (Point memory) {
+        require(a > 10);
+        require(b > 20);
+        Point memory p = Point(a, b);
+        delete p;
+        return p;
+    }
+
+    function deleteMapping() public {
+        delete balances;
+    }
+}
@@ -0,0 +1,6 @@
+contract Dependencies {
+    function add(uint256 a, uint256 b, uint256 d) public pure {
+        uint256 c = a + b;
+        uint256 e = c * 2;
+    }
+}
@@ -0,0 +1,12 @@
+contract DivByZero {
+    function constZero(uint256 x) public returns (uint256) {
+        uint256 d = 0;
+        return x / d;
+    }
+
+    function maybeZero(uint256 x, uint256 d) public returns (uint256) {
+        require(d <= 5);
+        uint256 r = x % d;
+        return r;
+    }
+}
@@ -0,0 +1,18 @@
+contract DoWhile {
+    function runsOnce() public returns (uint256) {
+        uint256 x = 1;
+        do {
+            x = x + 4;
+        } while (false);
+        require(x == 5);
+        return x;
+    }
+
+    function widens(uint256 n) public returns (uint256) {
+        uint256 x = 1;
+        do {
+            x = x + 1;
+        } while (x < n);
+        return x;
+    }
+}
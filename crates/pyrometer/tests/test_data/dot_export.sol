@@ -0,0 +1,6 @@
+contract DotExport {
+    function f(uint256 x) public returns (uint256) {
+        uint256 y = x + 1;
+        return y;
+    }
+}
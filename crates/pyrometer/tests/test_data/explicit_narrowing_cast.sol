@@ -0,0 +1,20 @@
+contract ExplicitNarrowing {
+    function wide(uint256 x) public pure returns (uint8) {
+        require(x <= 300);
+        uint8 y = uint8(x);
+        return y;
+    }
+
+    function fits(uint256 x) public pure returns (uint8) {
+        require(x <= 200);
+        uint8 y = uint8(x);
+        return y;
+    }
+
+    function wraps(uint256 x) public pure returns (uint8) {
+        require(x >= 256);
+        require(x <= 300);
+        uint8 y = uint8(x);
+        return y;
+    }
+}
@@ -0,0 +1,27 @@
+contract Exponentiation {
+    function constantPower() public returns (uint256) {
+        uint256 x = 3 ** 4;
+        return x;
+    }
+
+    function rangedBase(uint256 a) public returns (uint256) {
+        require(a >= 2 && a <= 10);
+        uint256 x = a ** 3;
+        return x;
+    }
+
+    function zeroPowZero() public returns (uint256) {
+        uint256 zero = 0;
+        uint256 x = zero ** zero;
+        return x;
+    }
+
+    function wrapping(uint8 a) public returns (uint8) {
+        require(a >= 2 && a <= 3);
+        uint8 x;
+        unchecked {
+            x = a ** 6;
+        }
+        return x;
+    }
+}
@@ -0,0 +1,24 @@
+contract FixedBytesIndex {
+    function constIndex() public pure returns (bytes1) {
+        bytes4 b = 0x78561234;
+        bytes1 x = b[1];
+        return x;
+    }
+
+    function outOfBounds() public pure returns (bytes1) {
+        bytes4 b = 0x78561234;
+        bytes1 x = b[4];
+        return x;
+    }
+
+    function symbolicIndex(uint256 i) public pure returns (bytes1) {
+        bytes4 b = 0x78561234;
+        bytes1 x = b[i];
+        return x;
+    }
+
+    function symbolicBytes(bytes4 b) public pure returns (bytes1) {
+        bytes1 x = b[2];
+        return x;
+    }
+}
@@ -0,0 +1,14 @@
+function add(uint256 a, uint256 b) pure returns (uint256) {
+    return a + b;
+}
+
+function addSmall(uint8 a, uint8 b) pure returns (uint16) {
+    return uint16(a) + uint16(b);
+}
+
+contract UsesFree {
+    function callsFree() public pure returns (uint256) {
+        uint256 c = add(1, 2);
+        return c;
+    }
+}
@@ -0,0 +1,26 @@
+contract GuardedIndex {
+    uint256[] arr;
+
+    function guarded(uint256 i) public view returns (uint256) {
+        if (i < arr.length) {
+            return arr[i];
+        }
+        return 0;
+    }
+
+    function required(uint256[] memory a, uint256 i) public pure returns (uint256) {
+        require(i < a.length);
+        return a[i];
+    }
+
+    function excluded(uint256 i) public view returns (uint256) {
+        if (i != type(uint256).max) {
+            return arr[i];
+        }
+        return 0;
+    }
+
+    function unguarded(uint256 i) public view returns (uint256) {
+        return arr[i];
+    }
+}
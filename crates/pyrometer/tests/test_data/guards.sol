@@ -0,0 +1,10 @@
+contract Guards {
+    function checked(uint256 x) public pure returns (uint256) {
+        require(x > 10);
+        // already implied by the require
+        assert(x != 5);
+        assert(x < 100);
+        require(x >= 11);
+        return x;
+    }
+}
@@ -0,0 +1,17 @@
+contract Hashes {
+    function emptyKeccak() public pure returns (bytes32) {
+        return keccak256(hex"");
+    }
+
+    function emptySha() public pure returns (bytes32) {
+        return sha256(hex"");
+    }
+
+    function emptyRipemd() public pure returns (bytes20) {
+        return ripemd160(hex"");
+    }
+
+    function symbolic(bytes memory b) public pure returns (bytes32) {
+        return sha256(b);
+    }
+}
@@ -0,0 +1,7 @@
+contract HexValues {
+    function values() public pure {
+        address a = 0x5B38Da6a701c568545dCfcB03FcB875f56beddC4;
+        bytes4 b = hex"deadbeef";
+        uint256 c = 0x10;
+    }
+}
@@ -0,0 +1,28 @@
+contract Immutables {
+    uint256 constant FEE = 30;
+    uint256 immutable declared = 7;
+    uint256 immutable conditional;
+
+    constructor(bool high) {
+        if (high) {
+            conditional = 100;
+        } else {
+            conditional = 10;
+        }
+    }
+
+    function fee() public returns (uint256) {
+        uint256 f = FEE;
+        return f;
+    }
+
+    function readDeclared() public returns (uint256) {
+        uint256 d = declared;
+        return d;
+    }
+
+    function readConditional() public returns (uint256) {
+        uint256 c = conditional;
+        return c;
+    }
+}
@@ -0,0 +1,32 @@
+contract Increment {
+    function postIncrement() public pure returns (uint256, uint256) {
+        uint256 i = 5;
+        uint256 x = i++;
+        return (x, i);
+    }
+
+    function preIncrement() public pure returns (uint256, uint256) {
+        uint256 i = 5;
+        uint256 x = ++i;
+        return (x, i);
+    }
+
+    function postDecrement() public pure returns (uint256, uint256) {
+        uint256 i = 5;
+        uint256 x = i--;
+        return (x, i);
+    }
+
+    function preDecrement() public pure returns (uint256, uint256) {
+        uint256 i = 5;
+        uint256 x = --i;
+        return (x, i);
+    }
+
+    function postIncrementAssign() public pure returns (uint256, uint256) {
+        uint256 i = 5;
+        uint256 x;
+        x = i++;
+        return (x, i);
+    }
+}
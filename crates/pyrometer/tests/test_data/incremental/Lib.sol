@@ -0,0 +1,9 @@
+function double(uint256 x) pure returns (uint256) {
+    return x * 2;
+}
+
+contract Lib {
+    function libOnly(uint256 x) public pure returns (uint256) {
+        return x + 1;
+    }
+}
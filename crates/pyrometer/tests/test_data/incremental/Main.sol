@@ -0,0 +1,11 @@
+import "./Lib.sol";
+
+contract Main {
+    function standalone(uint256 y) public pure returns (uint256) {
+        return y;
+    }
+
+    function useDouble() public pure returns (uint256) {
+        return double(3);
+    }
+}
@@ -0,0 +1,11 @@
+contract JoinStrategy {
+    function f(bool b) public pure returns (uint256) {
+        uint256 x;
+        if (b) {
+            x = 5;
+        } else {
+            x = 10;
+        }
+        return x;
+    }
+}
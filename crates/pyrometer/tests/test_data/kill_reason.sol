@@ -0,0 +1,14 @@
+contract KillReason {
+    function check(uint256 x) internal pure {
+        require(x > 10);
+    }
+
+    function direct() public pure {
+        uint256 x = 5;
+        require(x > 10);
+    }
+
+    function nested() public pure {
+        check(5);
+    }
+}
@@ -0,0 +1,8 @@
+contract KilledContexts {
+    function withdraw(uint256 amount, uint256 balance) public pure returns (uint256) {
+        if (amount > balance) {
+            revert();
+        }
+        return balance - amount;
+    }
+}
@@ -0,0 +1,11 @@
+contract LiteralDowncast {
+    function tooLarge() public pure {
+        uint8 x;
+        x = 300;
+    }
+
+    function fits() public pure {
+        uint8 y;
+        y = 200;
+    }
+}
@@ -0,0 +1,12 @@
+contract LiteralSource {
+    function units() public pure returns (uint256, uint256, uint256) {
+        uint256 amount = 1 ether;
+        uint256 plain = 5;
+        uint256 scaled = 15e17;
+        uint256 separated = 1_000 ether;
+        uint256 exponent = 1e18;
+        uint256 hex = 0xFF;
+        uint8 wrapped = uint8(uint256(300));
+        return (amount, plain, scaled);
+    }
+}
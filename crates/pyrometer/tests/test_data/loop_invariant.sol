@@ -0,0 +1,26 @@
+contract LoopInvariant {
+    function counter(uint256 m) public returns (uint256, uint256) {
+        uint256 n = 10;
+        uint256 count = 0;
+        while (count < m) {
+            count = count + n;
+        }
+        return (n, count);
+    }
+
+    function bounded() public returns (uint256) {
+        uint256 x = 0;
+        for (uint256 i = 0; i < 3; i++) {
+            x += 1;
+        }
+        return x;
+    }
+
+    function doWhile() public returns (uint256) {
+        uint256 i = 0;
+        do {
+            i++;
+        } while (i < 3);
+        return i;
+    }
+}
@@ -0,0 +1,8 @@
+contract LoopSameLoc {
+    function incr(uint256 x) public returns (uint256) {
+        for (uint256 i; i < 10; i++) {
+            x += 1;
+        }
+        return x;
+    }
+}
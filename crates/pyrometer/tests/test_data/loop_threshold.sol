@@ -0,0 +1,47 @@
+contract LoopThreshold {
+    function bounded() public returns (uint256) {
+        uint256 i;
+        for (i = 0; i < 100; i++) {}
+        return i;
+    }
+
+    function stepped() public returns (uint256) {
+        uint256 i = 1;
+        while (i <= 100) {
+            i += 3;
+        }
+        return i;
+    }
+
+    function countdown() public returns (uint256) {
+        uint256 i = 50;
+        while (i > 10) {
+            i--;
+        }
+        return i;
+    }
+
+    function unbounded(uint256 n) public returns (uint256) {
+        uint256 i = 0;
+        while (i < n) {
+            i++;
+        }
+        return i;
+    }
+
+    function offset(uint256 n) public returns (uint256) {
+        uint256 i = 5;
+        while (i < n) {
+            i++;
+        }
+        return i;
+    }
+
+    function countdownTo(uint256 n) public returns (uint256) {
+        uint256 i = 50;
+        while (n < i) {
+            i--;
+        }
+        return i;
+    }
+}
@@ -0,0 +1,14 @@
+contract MappingValues {
+    mapping(uint256 => uint256) balances;
+
+    function constKeys() public {
+        balances[1] = 5;
+        balances[2] = 7;
+    }
+
+    function symbolicKey(uint256 k, uint256 v) public {
+        balances[1] = 5;
+        require(v < 100);
+        balances[k] = v;
+    }
+}
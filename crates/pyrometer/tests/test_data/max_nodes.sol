@@ -0,0 +1,25 @@
+contract MaxNodes {
+    uint256 total;
+
+    function busy(uint256 x, uint256 y) public returns (uint256) {
+        uint256 a = x + y;
+        if (a > 10) {
+            a = a * 2;
+        } else {
+            a = a + 7;
+        }
+        if (y > x) {
+            total += y - x;
+        } else {
+            total += x - y;
+        }
+        for (uint256 i; i < 3; i++) {
+            a += i;
+        }
+        return a + total;
+    }
+
+    function small(uint256 x) public pure returns (uint256) {
+        return x + 1;
+    }
+}
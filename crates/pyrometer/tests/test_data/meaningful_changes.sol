@@ -0,0 +1,7 @@
+contract MeaningfulChanges {
+    function reassign() public pure returns (bytes memory) {
+        bytes memory d = hex"aabb";
+        d = hex"aabb";
+        return d;
+    }
+}
@@ -0,0 +1,10 @@
+contract MemoryLayout {
+    function known() public returns (bytes memory) {
+        bytes memory b = hex"7bff";
+        return b;
+    }
+
+    function symbolic(bytes memory b) public returns (bytes memory) {
+        return b;
+    }
+}
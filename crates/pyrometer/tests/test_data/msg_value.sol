@@ -0,0 +1,11 @@
+contract MsgValue {
+    function nonPayable() public returns (uint256) {
+        uint256 v = msg.value;
+        return v;
+    }
+
+    function payableFn() public payable returns (uint256) {
+        uint256 v = msg.value;
+        return v;
+    }
+}
@@ -0,0 +1,8 @@
+contract Limits {
+    uint256 public total;
+
+    function checkLimit(uint256 x) internal pure returns (uint256) {
+        require(x < 100);
+        return x;
+    }
+}
@@ -0,0 +1,8 @@
+import "./Limits.sol";
+
+contract Main is Limits {
+    function deposit(uint256 amount) public returns (uint256) {
+        total = checkLimit(amount);
+        return total;
+    }
+}
@@ -0,0 +1,14 @@
+contract NarrowingCast {
+    function narrow(uint256 x) public pure returns (uint128) {
+        uint128 y;
+        y = x;
+        return y;
+    }
+
+    function narrowInRange(uint256 x) public pure returns (uint128) {
+        require(x < 100);
+        uint128 y;
+        y = x;
+        return y;
+    }
+}
@@ -0,0 +1,30 @@
+contract NestedStructs {
+    struct L5 {
+        uint256 v;
+    }
+
+    struct L4 {
+        L5 inner;
+        uint256 v;
+    }
+
+    struct L3 {
+        L4 inner;
+        uint256 v;
+    }
+
+    struct L2 {
+        L3 inner;
+        uint256 v;
+    }
+
+    struct L1 {
+        L2 inner;
+        uint256 v;
+    }
+
+    function copy(L1 memory a) public pure returns (L1 memory) {
+        L1 memory b = a;
+        return b;
+    }
+}
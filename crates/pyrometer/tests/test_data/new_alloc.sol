@@ -0,0 +1,22 @@
+contract NewAlloc {
+    function fixedBytes() public pure {
+        bytes memory b = new bytes(5);
+        uint256 len = b.length;
+        bytes1 first = b[0];
+        bytes1 last = b[4];
+    }
+
+    function rangedArray(uint256 n) public pure {
+        require(n > 2 && n < 10);
+        uint256[] memory arr = new uint256[](n);
+        uint256 len = arr.length;
+        uint256 elem = arr[2];
+    }
+
+    function writeThenRead() public pure {
+        uint256[] memory arr = new uint256[](3);
+        arr[1] = 7;
+        uint256 written = arr[1];
+        uint256 untouched = arr[2];
+    }
+}
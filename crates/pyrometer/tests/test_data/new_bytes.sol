@@ -0,0 +1,20 @@
+contract NewBytes {
+    function concrete() public pure returns (uint256) {
+        bytes memory b = new bytes(5);
+        uint256 len = b.length;
+        return len;
+    }
+
+    function symbolic(uint256 n) public pure returns (uint256) {
+        require(n >= 1 && n <= 3);
+        bytes memory b = new bytes(n);
+        uint256 len = b.length;
+        return len;
+    }
+
+    function str(uint256 n) public pure returns (uint256) {
+        require(n >= 1 && n <= 3);
+        string memory s = new string(n);
+        return bytes(s).length;
+    }
+}
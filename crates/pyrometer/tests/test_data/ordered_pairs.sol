@@ -0,0 +1,15 @@
+contract OrderedPairs {
+    function f(uint256 lo, uint256 hi, uint256 other) public pure returns (uint256) {
+        require(lo <= hi);
+        uint256 mid = lo + (hi - lo) / 2;
+        uint256 small = 5;
+        uint256 big = small + 10;
+        return mid + other + big;
+    }
+
+    function reassigned(uint256 lo, uint256 hi) public pure returns (uint256) {
+        require(lo <= hi);
+        hi = 0;
+        return lo + hi;
+    }
+}
@@ -0,0 +1,27 @@
+contract Overflow {
+    uint256 count;
+
+    function add(uint256 a, uint256 b) public pure returns (uint256) {
+        return a + b;
+    }
+
+    function boundedAdd(uint256 a, uint256 b) public pure returns (uint256) {
+        require(a < 100);
+        require(b < 100);
+        return a + b;
+    }
+
+    function excludedAdd(uint256 a, uint256 b) public pure returns (uint256) {
+        require(a != type(uint256).max);
+        return a + b;
+    }
+
+    function guardedSub(uint256 n) public pure returns (uint256) {
+        if (n == 0) return 1;
+        return n - 1;
+    }
+
+    function increment() public {
+        count += 1;
+    }
+}
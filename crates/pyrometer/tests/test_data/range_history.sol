@@ -0,0 +1,8 @@
+contract RangeHistory {
+    function history(uint256 x) public pure returns (uint256) {
+        require(x < 100);
+        x = x;
+        x += 1;
+        return x;
+    }
+}
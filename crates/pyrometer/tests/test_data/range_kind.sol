@@ -0,0 +1,21 @@
+contract RangeKind {
+    function singleton() public returns (uint256) {
+        uint256 x = 5;
+        return x;
+    }
+
+    function narrowed(uint256 x) public returns (uint256) {
+        require(x < 100);
+        return x;
+    }
+
+    function full(uint256 x) public returns (uint256) {
+        return x;
+    }
+
+    function empty(uint256 x) public returns (uint256) {
+        require(x > 10);
+        require(x < 5);
+        return x;
+    }
+}
@@ -0,0 +1,53 @@
+contract Recursion {
+    uint256 total;
+
+    function fact(uint256 n) internal returns (uint256) {
+        if (n == 0) {
+            return 1;
+        }
+        return n * fact(n - 1);
+    }
+
+    function isEven(uint256 n) internal returns (bool) {
+        if (n == 0) {
+            return true;
+        }
+        return isOdd(n - 1);
+    }
+
+    function isOdd(uint256 n) internal returns (bool) {
+        if (n == 0) {
+            return false;
+        }
+        return isEven(n - 1);
+    }
+
+    function factOfOne() public returns (uint256) {
+        uint256 x = fact(1);
+        return x;
+    }
+
+    function oddOne() public returns (bool) {
+        bool odd = isOdd(1);
+        return odd;
+    }
+
+    function evenTwo() public returns (bool) {
+        bool even = isEven(2);
+        return even;
+    }
+
+    function rec(uint256 n) internal {
+        if (n == 2) {
+            total = 7;
+            return;
+        }
+        rec(n + 1);
+    }
+
+    function storageWrite() public {
+        total = 1;
+        rec(0);
+        require(total == 7);
+    }
+}
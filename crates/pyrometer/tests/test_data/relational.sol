@@ -0,0 +1,59 @@
+contract Relational {
+    uint256 s;
+
+    function sum(uint256 a, uint256 b) public pure {
+        require(a + b == 100);
+        require(a <= 10);
+        uint256 c = b;
+    }
+
+    function assigned(uint256 a) public pure {
+        uint256 b = 100 - a;
+        require(a <= 10);
+        uint256 c = b;
+    }
+
+    function unchecked_sum(uint256 a, uint256 b) public pure {
+        unchecked {
+            require(a + b == 100);
+        }
+        require(a <= 10);
+        uint256 c = b;
+    }
+
+    function deleted(uint256 a) public pure {
+        uint256 b = 100 - a;
+        delete b;
+        require(a <= 10);
+        uint256 c = b;
+    }
+
+    function recurse() internal {
+        recurse();
+    }
+
+    function stored(uint256 a) public {
+        s = 100 - a;
+        recurse();
+        require(a <= 10);
+        uint256 c = s;
+    }
+
+    function swapped(uint256 a, uint256 b) public pure {
+        require(a + b == 100);
+        require(a <= 10);
+        (a, b) = (b, a);
+        require(b <= 50);
+        uint256 c = a;
+        uint256 d = b;
+    }
+
+    function looped(uint256 a, uint256 b, uint256 n) public pure {
+        require(a + b == 100);
+        for (uint256 i; i < n; i++) {
+            a -= 1;
+        }
+        require(a <= 10);
+        uint256 c = b;
+    }
+}
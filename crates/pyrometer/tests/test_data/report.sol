@@ -0,0 +1,11 @@
+contract Report {
+    uint256 storageVar;
+
+    function bounded(uint256 x) public returns (uint256) {
+        require(x < 100);
+        require(x != 50);
+        uint256 y = x + 10;
+        storageVar = y;
+        return y;
+    }
+}
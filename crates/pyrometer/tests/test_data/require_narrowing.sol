@@ -0,0 +1,65 @@
+contract RequireNarrowing {
+    function gt(uint256 x) public {
+        require(x > 5);
+    }
+
+    function gtFlipped(uint256 x) public {
+        require(5 < x);
+    }
+
+    function gte(uint256 x) public {
+        require(x >= 5);
+    }
+
+    function gteFlipped(uint256 x) public {
+        require(5 <= x);
+    }
+
+    function lt(uint256 x) public {
+        require(x < 5);
+    }
+
+    function ltFlipped(uint256 x) public {
+        require(5 > x);
+    }
+
+    function lte(uint256 x) public {
+        require(x <= 5);
+    }
+
+    function lteFlipped(uint256 x) public {
+        require(5 >= x);
+    }
+
+    function eq(uint256 x) public {
+        require(x == 5);
+    }
+
+    function eqFlipped(uint256 x) public {
+        require(5 == x);
+    }
+
+    function neq(uint256 x) public {
+        require(x != 5);
+    }
+
+    function neqFlipped(uint256 x) public {
+        require(5 != x);
+    }
+
+    function signedGt(int256 x) public {
+        require(x > -5);
+    }
+
+    function signedLt(int256 x) public {
+        require(-5 > x);
+    }
+
+    function neqMin(uint256 x) public {
+        require(x != 0);
+    }
+
+    function ltMax(uint8 x) public {
+        require(x < 255);
+    }
+}
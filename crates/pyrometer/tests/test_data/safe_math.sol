@@ -0,0 +1,21 @@
+library SafeMath {
+    function add(uint256 a, uint256 b) internal pure returns (uint256) {
+        uint256 c;
+        unchecked {
+            c = a + b;
+        }
+        require(c >= a, "SafeMath: addition overflow");
+        return c;
+    }
+}
+
+contract SafeMathUser {
+    using SafeMath for uint256;
+
+    function safeAdd(uint256 a, uint256 b) public returns (uint256) {
+        require(a >= 10);
+        require(a <= 20);
+        uint256 c = a.add(b);
+        return c;
+    }
+}
@@ -0,0 +1,27 @@
+contract Satisfiable {
+    function contradiction(uint256 x) public pure {
+        require(x > 5);
+        require(x < 3);
+    }
+
+    function feasible(uint256 x) public pure {
+        require(x > 5);
+        require(x < 10);
+    }
+
+    function excluded(uint256 x) public pure {
+        require(x >= 5);
+        require(x <= 5);
+        require(x != 5);
+    }
+
+    function reverting(uint256 x) public pure {
+        if (x > 5) {
+            revert();
+        }
+    }
+
+    function dividing(uint256 x, uint256 y) public pure returns (uint256) {
+        return x / y;
+    }
+}
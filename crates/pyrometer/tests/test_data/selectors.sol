@@ -0,0 +1,25 @@
+interface IToken {
+    function transfer(address to, uint256 amount) external returns (bool);
+}
+
+contract Selectors {
+    function foo(uint256 x) public {}
+
+    function approve(IToken spender, uint256 amount) public {}
+
+    function local() public view returns (bytes4) {
+        bytes4 s = this.foo.selector;
+        return s;
+    }
+
+    function iface() public pure returns (bytes4) {
+        bytes4 s = IToken.transfer.selector;
+        return s;
+    }
+
+    function contractParam() public view returns (bytes4) {
+        // the selector uses the canonical `approve(address,uint256)`
+        bytes4 s = this.approve.selector;
+        return s;
+    }
+}
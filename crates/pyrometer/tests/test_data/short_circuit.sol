@@ -0,0 +1,19 @@
+contract ShortCircuit {
+    function andSideEffect(uint256 x) public returns (bool) {
+        uint256 y = 0;
+        bool c = x > 5 && (y = 1) == 1;
+        return c;
+    }
+
+    function orSideEffect(uint256 x) public returns (bool) {
+        uint256 y = 0;
+        bool c = x > 5 || (y = 1) == 1;
+        return c;
+    }
+
+    function knownLhs() public returns (bool) {
+        uint256 y = 0;
+        bool c = y > 5 && (y = 1) == 1;
+        return c;
+    }
+}
@@ -0,0 +1,63 @@
+contract StoragePointer {
+    struct Position {
+        uint256 amount;
+        uint256 debt;
+    }
+
+    Position pos;
+    Position other;
+    mapping(uint256 => Position) positions;
+
+    function writeThrough() public {
+        Position storage p = pos;
+        p.amount = 5;
+        uint256 a = pos.amount;
+    }
+
+    function writeThroughMapping(uint256 id) public {
+        Position storage p = positions[id];
+        p.debt = 7;
+        uint256 a = positions[id].debt;
+    }
+
+    function readThrough() public {
+        pos.amount = 9;
+        Position storage p = pos;
+        uint256 a = p.amount;
+    }
+
+    function memoryCopy() public {
+        pos.amount = 1;
+        Position memory m = pos;
+        m.amount = 5;
+        uint256 a = pos.amount;
+    }
+
+    function loopWrite(uint256 n) public {
+        pos.amount = 5;
+        Position storage p = pos;
+        for (uint256 i; i < n; i++) {
+            p.amount = i;
+        }
+        uint256 a = pos.amount;
+    }
+
+    function loopWriteInner(uint256 n) public {
+        positions[1].debt = 5;
+        for (uint256 i; i < n; i++) {
+            Position storage p = positions[1];
+            p.debt = i;
+        }
+        uint256 a = positions[1].debt;
+    }
+
+    function reassigned() public {
+        pos.amount = 1;
+        other.amount = 2;
+        Position storage p = pos;
+        p = other;
+        p.amount = 5;
+        require(pos.amount == 1);
+        uint256 a = other.amount;
+    }
+}
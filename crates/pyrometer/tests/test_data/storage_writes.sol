@@ -0,0 +1,12 @@
+contract Setters {
+    uint256 a;
+    uint256 b;
+
+    function setA(uint256 x) public {
+        a = x;
+    }
+
+    function setB(uint256 y) public {
+        b = y;
+    }
+}
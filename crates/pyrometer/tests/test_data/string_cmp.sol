@@ -0,0 +1,16 @@
+contract StringCmp {
+    function equal() public returns (bool) {
+        bool res = "abc" == "abc";
+        return res;
+    }
+
+    function unequal() public returns (bool) {
+        bool res = "abc" == "abd";
+        return res;
+    }
+
+    function notEqual() public returns (bool) {
+        bool res = "abc" != "abd";
+        return res;
+    }
+}
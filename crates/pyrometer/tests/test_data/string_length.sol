@@ -0,0 +1,5 @@
+contract StringLength {
+    function strLen(string memory s) public pure returns (uint256) {
+        return s.length;
+    }
+}
@@ -0,0 +1,15 @@
+contract StructMismatch {
+    struct A {
+        uint256 x;
+        uint256 y;
+    }
+
+    struct B {
+        uint256 x;
+        uint256 z;
+    }
+
+    function mismatched(A memory a, B memory b) public pure {
+        a = b;
+    }
+}
@@ -0,0 +1,18 @@
+contract StructPacking {
+    struct Packed {
+        uint128 a;
+        uint64 b;
+        uint64 c;
+    }
+
+    struct Spilled {
+        uint64 a;
+        uint256 b;
+        bool c;
+        Packed d;
+        address e;
+    }
+
+    Packed packed;
+    Spilled spilled;
+}
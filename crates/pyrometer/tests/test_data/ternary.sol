@@ -0,0 +1,22 @@
+contract Ternary {
+    function constantCond(uint256 x) public returns (uint256) {
+        uint256 y = false ? x : 100;
+        return y;
+    }
+
+    function symbolicCond(uint256 x) public returns (uint256) {
+        require(x < 50);
+        uint256 y = x > 10 ? x : 100;
+        return y;
+    }
+
+    function literals(bool c) public returns (uint256) {
+        uint256 y = c ? 1 : 300;
+        return y;
+    }
+
+    function requireOnResult(bool c, uint256 x) public returns (uint256) {
+        require((c ? x : 5) < 3);
+        return x;
+    }
+}
@@ -0,0 +1,11 @@
+contract TernaryIndex {
+    uint256[2] arr;
+
+    function inBounds(bool c) public view returns (uint256) {
+        return arr[c ? 0 : 1];
+    }
+
+    function maybeOutOfBounds(bool c) public view returns (uint256) {
+        return arr[c ? 0 : 2];
+    }
+}
@@ -0,0 +1,15 @@
+contract ThisBalance {
+    function payIn() public payable returns (uint256) {
+        require(msg.value == 5);
+        return address(this).balance;
+    }
+
+    function viaHelper() public payable returns (uint256) {
+        require(msg.value == 5);
+        return helperBalance();
+    }
+
+    function helperBalance() internal returns (uint256) {
+        return address(this).balance;
+    }
+}
@@ -0,0 +1,9 @@
+contract TmpFilter {
+    function f(uint256 a) public returns (uint256) {
+        uint256 b = a * 2 + 1;
+        if (b > 10) {
+            return b;
+        }
+        return 0;
+    }
+}
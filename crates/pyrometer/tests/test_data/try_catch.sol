@@ -0,0 +1,18 @@
+interface IOracle {
+    function price() external returns (uint256);
+}
+
+contract TryCatch {
+    function lowLevel(IOracle oracle) public returns (uint256) {
+        try oracle.price() returns (uint256 p) {
+            return p;
+        } catch Error(string memory reason) {
+            return 1;
+        } catch Panic(uint256 code) {
+            return 2;
+        } catch (bytes memory lowLevelData) {
+            uint256 len = lowLevelData.length;
+            return len;
+        }
+    }
+}
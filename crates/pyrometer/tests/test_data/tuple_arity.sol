@@ -0,0 +1,7 @@
+contract TupleArity {
+    function mismatch(uint256 a, uint256 b, uint256 c) public pure {
+        uint256 x;
+        uint256 y;
+        (x, y) = (a, b, c);
+    }
+}
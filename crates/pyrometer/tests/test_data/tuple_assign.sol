@@ -0,0 +1,17 @@
+contract TupleAssign {
+    function pair() public returns (uint256, uint256) {
+        return (1, 2);
+    }
+
+    function declare() public returns (uint256, uint256) {
+        (uint256 x, uint256 y) = pair();
+        return (x, y);
+    }
+
+    function assign() public returns (uint256, uint256) {
+        uint256 a;
+        uint256 b;
+        (a, b) = (pair());
+        return (a, b);
+    }
+}
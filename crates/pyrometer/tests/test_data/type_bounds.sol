@@ -0,0 +1,15 @@
+contract TypeBounds {
+    function bounds() public pure {
+        uint16 u16Max = type(uint16).max;
+        uint8 u8Min = type(uint8).min;
+        int8 i8Min = type(int8).min;
+        int8 i8Max = type(int8).max;
+    }
+
+    function code() public pure {
+        bytes memory creation = type(Other).creationCode;
+        bytes memory runtime = type(Other).runtimeCode;
+    }
+}
+
+contract Other {}
@@ -0,0 +1,26 @@
+contract UninitRead {
+    function readBeforeAssign() public pure returns (uint256) {
+        uint256 x;
+        uint256 y = x + 1;
+        x = y;
+        return x;
+    }
+
+    function assignThenRead(uint256 a) public pure returns (uint256 ret) {
+        uint256 x;
+        x = a;
+        bool b;
+        (b, x) = (true, x + 1);
+        if (b) {
+            ret = x;
+        }
+    }
+
+    function readOnOnePath(bool flag) public pure returns (uint256) {
+        uint256 x;
+        if (flag) {
+            x = 1;
+        }
+        return x;
+    }
+}
@@ -0,0 +1,19 @@
+contract Witness {
+    function add(uint256 a, uint256 b) public returns (uint256 z) {
+        require(a < 100);
+        require(b < 50);
+        z = a + b;
+    }
+
+    function div(uint256 a, uint256 b) public returns (uint256 z) {
+        require(a < 100);
+        require(b < 50);
+        z = a / b;
+    }
+
+    function signedAdd(int256 a, int256 b) public returns (int256 z) {
+        require(a < 100);
+        require(b < 50);
+        z = a + b;
+    }
+}
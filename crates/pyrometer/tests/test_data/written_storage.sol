@@ -0,0 +1,23 @@
+contract StorageWrites {
+    uint256 a;
+    uint256 b;
+    uint256 c;
+    mapping(uint256 => uint256) m;
+
+    function write(bool flag, uint256 x) public {
+        if (flag) {
+            a = x;
+        } else {
+            b = x;
+        }
+        uint256 read = c;
+    }
+
+    function viaCall(uint256 k) public {
+        setM(k);
+    }
+
+    function setM(uint256 k) internal {
+        m[k] = 1;
+    }
+}
@@ -4,6 +4,18 @@ use ahash::AHashMap;
 
 use std::collections::BTreeMap;
 
+/// How a variable's range is computed by queries of the range where the branches of a fork rejoin.
+/// Forks are never merged during analysis, each branch is analyzed to its end, so this does not
+/// change any analyzed range
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum JoinStrategy {
+    /// The smallest range containing every branch's range (sound)
+    #[default]
+    Union,
+    /// The full range of the variable's type (conservative, but cheap)
+    WidenToType,
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct ApplyStats {
     pub pure_no_children_applies: ApplyStat,
@@ -129,6 +141,21 @@ pub trait AnalyzerLike: GraphLike {
     fn max_depth(&self) -> usize;
     /// Returns the configured max fork width
     fn max_width(&self) -> usize;
+    /// Returns the configured strategy for joining branch ranges
+    fn join_strategy(&self) -> JoinStrategy;
+    /// Returns whether linear equalities between variables (i.e. `a + b == 100`) are tracked so
+    /// that narrowing one variable narrows the others. More precise, but slower
+    fn track_relations(&self) -> bool;
+    /// Returns the configured max nesting depth of struct fields assigned field by field
+    fn max_struct_depth(&self) -> usize;
+    /// Returns the configured max number of times a function is inlined into its own call stack
+    fn max_recursion_depth(&self) -> usize;
+    /// Returns the configured max number of nodes analyzing a single function may add to the
+    /// graph, if any. Analysis of a function is aborted once it adds more
+    fn max_nodes(&self) -> Option<usize>;
+    /// Returns whether implicit integer narrowing that could lose information is an error rather
+    /// than silently clamped
+    fn strict_casts(&self) -> bool;
     fn user_types(&self) -> &AHashMap<String, Vec<NodeIdx>>;
     fn user_types_mut(&mut self) -> &mut AHashMap<String, Vec<NodeIdx>>;
     fn parse_expr(
@@ -80,6 +80,8 @@ pub enum GraphError {
     MaxStackDepthReached(String),
     /// Fork width limit reached
     MaxStackWidthReached(String),
+    /// Graph node count limit reached
+    MaxNodeCountReached(String),
     /// Tried to set the subcontext of a context that already had a subcontext
     ChildRedefinition(String),
     /// Tried to update a variable that is in an old context
@@ -223,6 +225,7 @@ impl ExprErr {
             ExprErr::GraphError(_, GraphError::NodeConfusion(msg), ..) => msg,
             ExprErr::GraphError(_, GraphError::MaxStackDepthReached(msg), ..) => msg,
             ExprErr::GraphError(_, GraphError::MaxStackWidthReached(msg), ..) => msg,
+            ExprErr::GraphError(_, GraphError::MaxNodeCountReached(msg), ..) => msg,
             ExprErr::GraphError(_, GraphError::ChildRedefinition(msg), ..) => msg,
             ExprErr::GraphError(_, GraphError::DetachedVariable(msg), ..) => msg,
             ExprErr::GraphError(_, GraphError::VariableUpdateInOldContext(msg), ..) => msg,
@@ -262,6 +265,7 @@ impl ExprErr {
             ExprErr::GraphError(_, GraphError::NodeConfusion(_), ..) => "Graph IR Error: Node type confusion. This is potentially a bug. Please report it at https://github.com/nascentxyz/pyrometer",
             ExprErr::GraphError(_, GraphError::MaxStackDepthReached(_), ..) => "Max call depth reached - either recursion or loop",
             ExprErr::GraphError(_, GraphError::MaxStackWidthReached(_), ..) => "TODO: Max fork width reached - Need to widen variables and remove contexts",
+            ExprErr::GraphError(_, GraphError::MaxNodeCountReached(_), ..) => "Max node count reached - the function is too large to analyze",
             ExprErr::GraphError(_, GraphError::ChildRedefinition(_), ..) => "Graph IR Error: Child redefintion. This is potentially a bug. Please report it at https://github.com/nascentxyz/pyrometer",
             ExprErr::GraphError(_, GraphError::DetachedVariable(_), ..) => "Graph IR Error: Detached Variable. This is potentially a bug. Please report it at https://github.com/nascentxyz/pyrometer",
             ExprErr::GraphError(_, GraphError::VariableUpdateInOldContext(_), ..) => "Graph IR Error: Variable update in an old context. This is potentially a bug. Please report it at https://github.com/nascentxyz/pyrometer",
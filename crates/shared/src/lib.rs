@@ -2,11 +2,13 @@ mod analyzer_like;
 mod error;
 pub mod gas;
 mod graph_like;
+mod loc;
 mod search;
 
 pub use analyzer_like::*;
 pub use error::*;
 pub use graph_like::*;
+pub use loc::*;
 pub use search::*;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
@@ -0,0 +1,50 @@
+use solang_parser::pt::Loc;
+
+/// Merges source locations into one spanning all of them, i.e. the parts of a multi-part literal
+/// like `hex"12" hex"34"`. Returns `None` if there are no locations or they are from different
+/// source files. Locations that aren't in a file only merge with identical locations
+pub fn merge_locs(locs: &[Loc]) -> Option<Loc> {
+    let (first, rest) = locs.split_first()?;
+    rest.iter()
+        .try_fold(*first, |merged, loc| match (merged, *loc) {
+            (Loc::File(file, start, end), Loc::File(other_file, other_start, other_end))
+                if file == other_file =>
+            {
+                Some(Loc::File(file, start.min(other_start), end.max(other_end)))
+            }
+            (merged, loc) if merged == loc => Some(merged),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_empty() {
+        assert_eq!(merge_locs(&[]), None);
+    }
+
+    #[test]
+    fn merge_single() {
+        assert_eq!(merge_locs(&[Loc::File(0, 3, 7)]), Some(Loc::File(0, 3, 7)));
+        assert_eq!(merge_locs(&[Loc::Implicit]), Some(Loc::Implicit));
+    }
+
+    #[test]
+    fn merge_same_file() {
+        let locs = [
+            Loc::File(1, 10, 15),
+            Loc::File(1, 2, 8),
+            Loc::File(1, 16, 20),
+        ];
+        assert_eq!(merge_locs(&locs), Some(Loc::File(1, 2, 20)));
+    }
+
+    #[test]
+    fn merge_cross_file() {
+        assert_eq!(merge_locs(&[Loc::File(0, 0, 5), Loc::File(1, 6, 10)]), None);
+        assert_eq!(merge_locs(&[Loc::File(0, 0, 5), Loc::Implicit]), None);
+    }
+}
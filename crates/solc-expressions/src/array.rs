@@ -1,11 +1,14 @@
 use crate::{require::Require, variable::Variable, ContextBuilder, ExpressionParser, ListAccess};
 
 use graph::{
-    elem::{Elem, RangeDyn, RangeOp},
-    nodes::{Builtin, Concrete, ContextNode, ContextVar, ContextVarNode, ExprRet, TmpConstruction},
+    elem::{Elem, RangeDyn, RangeElem, RangeOp},
+    nodes::{
+        Builtin, Concrete, ContextNode, ContextVar, ContextVarNode, ExprRet, OobIndex,
+        TmpConstruction,
+    },
     AnalyzerBackend, ContextEdge, Edge, Node, VarType,
 };
-use shared::{ExprErr, IntoExprErr, RangeArena};
+use shared::{ExprErr, IntoExprErr, NodeIdx, RangeArena};
 
 use solang_parser::{
     helpers::CodeLocation,
@@ -80,6 +83,30 @@ pub trait Array: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
         }
     }
 
+    /// Gets the type of a fixed size array, i.e. `uint8[3]`, where the length is a literal
+    fn sized_array_ty(
+        &mut self,
+        ctx: ContextNode,
+        loc: Loc,
+        inner_ty: NodeIdx,
+        len: ContextVarNode,
+    ) -> Result<(), ExprErr> {
+        let Some(var_type) = VarType::try_from_idx(self, inner_ty) else {
+            return Err(ExprErr::ArrayTy(
+                loc,
+                "Expected to be able to convert to a var type from an index to determine array type".to_string(),
+            ));
+        };
+        let Some(len) = len.as_concrete(self).into_expr_err(loc)?.uint_val() else {
+            return Err(ExprErr::ArrayTy(
+                loc,
+                "Fixed size array length must be a non-negative integer literal".to_string(),
+            ));
+        };
+        let idx = self.builtin_or_add(Builtin::SizedArray(len, var_type));
+        ctx.push_expr(ExprRet::Single(idx), self).into_expr_err(loc)
+    }
+
     /// Indexes into an array
     #[tracing::instrument(level = "trace", skip_all)]
     fn index_into_array(
@@ -140,6 +167,9 @@ pub trait Array: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
             (ExprRet::CtxKilled(kind), _) => {
                 ctx.kill(self, loc, kind).into_expr_err(loc)
             }
+            (ExprRet::Single(parent), ExprRet::SingleLiteral(index)) if !matches!(self.node(parent), Node::ContextVar(_)) => {
+                self.sized_array_ty(ctx, loc, parent, index.into())
+            }
             (ExprRet::Single(parent), ExprRet::Single(index)) | (ExprRet::Single(parent), ExprRet::SingleLiteral(index)) => {
                 let index = ContextVarNode::from(index).latest_version_or_inherited_in_ctx(ctx, self);
                 let parent = ContextVarNode::from(parent).latest_version_or_inherited_in_ctx(ctx, self);
@@ -150,6 +180,35 @@ pub trait Array: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
         }
     }
 
+    /// Whether the index's range reaches the length's range, i.e. the access may be out of
+    /// bounds, without regard to how the two relate. If either range doesn't evaluate to a
+    /// concrete value, the access is conservatively considered possibly out of bounds. A
+    /// conditional index (`arr[c ? i : j]`) forks the context, so each candidate index is checked
+    /// against the length on its own path
+    fn index_may_exceed_len(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        index: ContextVarNode,
+        len: ContextVarNode,
+    ) -> Result<bool, ExprErr> {
+        let idx_max = index
+            .evaled_range_max(self, arena)
+            .into_expr_err(loc)?
+            .and_then(|max| max.maybe_concrete());
+        let len_min = len
+            .evaled_range_min(self, arena)
+            .into_expr_err(loc)?
+            .and_then(|min| min.maybe_concrete());
+        let (Some(idx_max), Some(len_min)) = (idx_max, len_min) else {
+            return Ok(true);
+        };
+        Ok(!matches!(
+            Elem::Concrete(idx_max).range_ord(&Elem::Concrete(len_min), arena),
+            Some(std::cmp::Ordering::Less)
+        ))
+    }
+
     fn index_into_array_raw(
         &mut self,
         arena: &mut RangeArena<Elem<Concrete>>,
@@ -169,6 +228,50 @@ pub trait Array: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                 .get_length(arena, ctx, loc, parent, true)?
                 .unwrap()
                 .latest_version_or_inherited_in_ctx(ctx, self);
+            let idx = idx.latest_version_or_inherited_in_ctx(ctx, self);
+            if self.index_may_exceed_len(arena, loc, idx, len_var)? {
+                // the access is guarded if the path rules out every counterexample near the
+                // extreme one, i.e. inside `if (i < arr.length)`, but not inside
+                // `if (i != type(uint256).max)`
+                let (counterexample, admitted) = match idx
+                    .witness_max_over(len_var, self, arena)
+                    .into_expr_err(loc)?
+                {
+                    Some(candidate) => {
+                        let counterexample = ctx
+                            .admitted_witness(
+                                candidate,
+                                self,
+                                arena,
+                                |assignment, analyzer, arena| {
+                                    let (Some(idx), Some(len)) = (
+                                        idx.eval_with(assignment, analyzer, arena)?,
+                                        len_var.eval_with(assignment, analyzer, arena)?,
+                                    ) else {
+                                        return Ok(true);
+                                    };
+                                    Ok(!matches!(
+                                        Elem::from(idx).range_ord(&Elem::from(len), arena),
+                                        Some(std::cmp::Ordering::Less)
+                                    ))
+                                },
+                            )
+                            .into_expr_err(loc)?;
+                        let admitted = counterexample.is_some();
+                        (counterexample, admitted)
+                    }
+                    None => (None, true),
+                };
+                if admitted {
+                    let oob_index = OobIndex {
+                        loc,
+                        index: idx,
+                        len: len_var,
+                        counterexample,
+                    };
+                    ctx.add_oob_index(oob_index, self).into_expr_err(loc)?;
+                }
+            }
             self.require(
                 arena,
                 len_var.latest_version_or_inherited_in_ctx(ctx, self),
@@ -225,6 +328,7 @@ pub trait Array: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                 },
                 is_symbolic: true,
                 is_return: false,
+                source_repr: None,
                 ty,
             };
 
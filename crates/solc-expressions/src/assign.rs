@@ -1,13 +1,20 @@
-use crate::{array::Array, variable::Variable, ContextBuilder, ExpressionParser, ListAccess};
+use crate::{
+    array::Array, min_int_size, min_uint_size, variable::Variable, ContextBuilder,
+    ExpressionParser, ListAccess, Relations,
+};
 
 use graph::{
     elem::{Elem, RangeElem},
-    nodes::{Concrete, ContextNode, ContextVarNode, ExprRet},
+    nodes::{Builtin, Concrete, ContextNode, ContextVarNode, ExprRet},
     AnalyzerBackend, ContextEdge, Edge,
 };
 
 use shared::{ExprErr, GraphError, IntoExprErr, RangeArena};
-use solang_parser::pt::{Expression, Loc};
+
+use ethers_core::types::I256;
+use solang_parser::pt::{Expression, Identifier, Loc, Parameter};
+
+use std::collections::BTreeSet;
 
 impl<T> Assign for T where T: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {}
 /// Handles assignments
@@ -22,6 +29,16 @@ pub trait Assign: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized
         rhs_expr: &Expression,
         ctx: ContextNode,
     ) -> Result<(), ExprErr> {
+        if let Expression::Variable(ident) = lhs_expr {
+            if let Some(reference) = ctx
+                .var_by_name_or_recurse(self, &ident.name)
+                .into_expr_err(loc)?
+                .filter(|var| var.storage_referent(self).is_some())
+            {
+                return self.repoint_storage_ref(arena, loc, reference, rhs_expr, ctx);
+            }
+        }
+
         self.parse_ctx_expr(arena, lhs_expr, ctx)?;
         self.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, loc| {
             let Some(lhs_paths) = ctx.pop_expr_latest(loc, analyzer).into_expr_err(loc)? else {
@@ -36,6 +53,13 @@ pub trait Assign: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized
                 return Ok(());
             }
 
+            // looking up an assigned variable isn't a read of it
+            let assigned = assigned_idents(lhs_expr);
+            for ident in &assigned {
+                ctx.undo_uninit_read(ident.loc, &ident.name, analyzer)
+                    .into_expr_err(loc)?;
+            }
+
             ctx.push_expr(lhs_paths, analyzer).into_expr_err(loc)?;
             analyzer.parse_ctx_expr(arena, rhs_expr, ctx)?;
             analyzer.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, loc| {
@@ -56,12 +80,54 @@ pub trait Assign: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized
                     return Ok(());
                 }
                 analyzer.match_assign_sides(arena, ctx, loc, &lhs_paths, &rhs_paths)?;
+                for ident in &assigned {
+                    ctx.remove_uninit_local(&ident.name, analyzer)
+                        .into_expr_err(loc)?;
+                }
                 Ok(())
             })
         })
     }
 
-    /// Match on the [`ExprRet`]s of an assignment expression
+    /// Assigns to a storage reference itself (`p = b;` for `S storage p`), which points it at
+    /// other storage instead of writing through it
+    fn repoint_storage_ref(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        reference: ContextVarNode,
+        rhs_expr: &Expression,
+        ctx: ContextNode,
+    ) -> Result<(), ExprErr> {
+        self.parse_ctx_expr(arena, rhs_expr, ctx)?;
+        self.apply_to_edges(ctx, loc, arena, &|analyzer, _arena, ctx, loc| {
+            let Some(rhs_paths) = ctx.pop_expr_latest(loc, analyzer).into_expr_err(loc)? else {
+                return Err(ExprErr::NoRhs(
+                    loc,
+                    "Assign operation had no right hand side".to_string(),
+                ));
+            };
+            let rhs_paths = rhs_paths.flatten();
+            let ExprRet::Single(rhs) = rhs_paths else {
+                return ctx.push_expr(rhs_paths, analyzer).into_expr_err(loc);
+            };
+            let rhs = ContextVarNode::from(rhs).latest_version_or_inherited_in_ctx(ctx, analyzer);
+            let referent = rhs.storage_referent(analyzer).unwrap_or(rhs);
+            let reference = reference.latest_version_or_inherited_in_ctx(ctx, analyzer);
+            let new_reference = analyzer.advance_var_in_ctx(reference, loc, ctx)?;
+            analyzer.add_edge(
+                new_reference,
+                referent,
+                Edge::Context(ContextEdge::StorageAssign),
+            );
+            ctx.push_expr(ExprRet::Single(new_reference.into()), analyzer)
+                .into_expr_err(loc)
+        })
+    }
+
+    /// Match on the [`ExprRet`]s of an assignment expression. Each assigned variable is pushed to
+    /// the context, so in a chained assignment (`a = b = c`) the inner assignment's result is the
+    /// right hand side of the outer one
     fn match_assign_sides(
         &mut self,
         arena: &mut RangeArena<Elem<Concrete>>,
@@ -81,6 +147,7 @@ pub trait Assign: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized
                     ContextVarNode::from(*lhs).latest_version_or_inherited_in_ctx(ctx, self);
                 let rhs_cvar =
                     ContextVarNode::from(*rhs).latest_version_or_inherited_in_ctx(ctx, self);
+                self.check_literal_fits(loc, lhs_cvar, rhs_cvar)?;
                 ctx.push_expr(self.assign(arena, loc, lhs_cvar, rhs_cvar, ctx)?, self)
                     .into_expr_err(loc)?;
                 Ok(())
@@ -97,6 +164,8 @@ pub trait Assign: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized
             (l @ ExprRet::Single(_), ExprRet::Multi(rhs_sides)) => rhs_sides
                 .iter()
                 .try_for_each(|expr_ret| self.match_assign_sides(arena, ctx, loc, l, expr_ret)),
+            // a call returning multiple values arrives as a `Multi`, so a single rhs here is a
+            // value broadcast to every lhs
             (ExprRet::Multi(lhs_sides), r @ ExprRet::Single(_) | r @ ExprRet::SingleLiteral(_)) => {
                 lhs_sides
                     .iter()
@@ -105,21 +174,138 @@ pub trait Assign: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized
             (ExprRet::Multi(lhs_sides), ExprRet::Multi(rhs_sides)) => {
                 // try to zip sides if they are the same length
                 if lhs_sides.len() == rhs_sides.len() {
+                    let rhs_sides = self.snapshot_reassigned(ctx, loc, lhs_sides, rhs_sides)?;
                     lhs_sides.iter().zip(rhs_sides.iter()).try_for_each(
                         |(lhs_expr_ret, rhs_expr_ret)| {
                             self.match_assign_sides(arena, ctx, loc, lhs_expr_ret, rhs_expr_ret)
                         },
                     )
+                } else if let [inner @ ExprRet::Multi(_)] = &rhs_sides[..] {
+                    // a multi-return call collapsed into a one element tuple, i.e. `(a, b) = (f())`
+                    self.match_assign_sides(arena, ctx, loc, lhs_paths, inner)
                 } else {
-                    rhs_sides.iter().try_for_each(|rhs_expr_ret| {
-                        self.match_assign_sides(arena, ctx, loc, lhs_paths, rhs_expr_ret)
-                    })
+                    Err(ExprErr::ParseError(
+                        loc,
+                        format!(
+                            "Tuple assignment arity mismatch: {} variables assigned {} values",
+                            lhs_sides.len(),
+                            rhs_sides.len()
+                        ),
+                    ))
                 }
             }
             (e, f) => todo!("any: {:?} {:?}", e, f),
         }
     }
 
+    /// Copies each right hand side of a tuple assignment that is also assigned by it into a
+    /// temporary. The whole right hand side is evaluated before any assignment, so in
+    /// `(a, b) = (b, a)`, `b` must get the value `a` had before `a` was assigned
+    fn snapshot_reassigned(
+        &mut self,
+        ctx: ContextNode,
+        loc: Loc,
+        lhs_sides: &[ExprRet],
+        rhs_sides: &[ExprRet],
+    ) -> Result<Vec<ExprRet>, ExprErr> {
+        let mut assigned = BTreeSet::new();
+        for lhs in lhs_sides {
+            if let ExprRet::Single(lhs) = lhs {
+                assigned.insert(ContextVarNode::from(*lhs).name(self).into_expr_err(loc)?);
+            }
+        }
+        rhs_sides
+            .iter()
+            .map(|rhs| {
+                let ExprRet::Single(rhs) = rhs else {
+                    return Ok(rhs.clone());
+                };
+                let rhs_cvar =
+                    ContextVarNode::from(*rhs).latest_version_or_inherited_in_ctx(ctx, self);
+                if !assigned.contains(&rhs_cvar.name(self).into_expr_err(loc)?) {
+                    return Ok(ExprRet::Single(*rhs));
+                }
+                let snapshot = rhs_cvar.as_tmp(loc, ctx, self).into_expr_err(loc)?;
+                Ok(ExprRet::Single(snapshot.into()))
+            })
+            .collect()
+    }
+
+    /// Errors if an integer literal does not fit the integer type it is assigned to (i.e. `300`
+    /// into a `uint8`), which solc rejects instead of truncating
+    fn check_literal_fits(
+        &self,
+        loc: Loc,
+        lhs_cvar: ContextVarNode,
+        rhs_cvar: ContextVarNode,
+    ) -> Result<(), ExprErr> {
+        let Ok(lhs_ty) = lhs_cvar.ty(self).into_expr_err(loc)?.as_builtin(self) else {
+            return Ok(());
+        };
+        let Ok(literal) = rhs_cvar.as_concrete(self) else {
+            return Ok(());
+        };
+        if concrete_fits(&lhs_ty, &literal) {
+            return Ok(());
+        }
+        Err(ExprErr::ParseError(
+            loc,
+            format!(
+                "Literal {} does not fit into the assigned type {}",
+                literal.as_human_string(),
+                lhs_ty.as_string(self).into_expr_err(loc)?
+            ),
+        ))
+    }
+
+    /// In strict casts mode, errors if assigning `rhs_cvar` to `lhs_cvar` implicitly narrows an
+    /// integer in a way that could lose information (i.e. a `uint256` into a `uint128`), which
+    /// solc rejects. Otherwise the value is silently clamped by the cast
+    fn check_narrowing_cast(
+        &self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        lhs_cvar: ContextVarNode,
+        rhs_cvar: ContextVarNode,
+    ) -> Result<(), ExprErr> {
+        if !self.strict_casts() {
+            return Ok(());
+        }
+        let (Ok(lhs_ty), Ok(rhs_ty)) = (
+            lhs_cvar.ty(self).into_expr_err(loc)?.as_builtin(self),
+            rhs_cvar.ty(self).into_expr_err(loc)?.as_builtin(self),
+        ) else {
+            return Ok(());
+        };
+        if !matches!(lhs_ty, Builtin::Uint(_) | Builtin::Int(_))
+            || !matches!(rhs_ty, Builtin::Uint(_) | Builtin::Int(_))
+            || rhs_ty.implicitly_castable_to(&lhs_ty)
+        {
+            return Ok(());
+        }
+        // a narrower type is fine if every value the rhs can take fits
+        let bound_fits = |bound: Option<Elem<Concrete>>| {
+            bound
+                .and_then(|bound| bound.maybe_concrete())
+                .is_some_and(|bound| concrete_fits(&lhs_ty, &bound.val))
+        };
+        if bound_fits(rhs_cvar.evaled_range_min(self, arena).into_expr_err(loc)?)
+            && bound_fits(rhs_cvar.evaled_range_max(self, arena).into_expr_err(loc)?)
+        {
+            return Ok(());
+        }
+        Err(ExprErr::VarBadType(
+            loc,
+            format!(
+                "Implicitly narrowing {} of type {} to {} of type {} could lose information",
+                rhs_cvar.display_name(self).into_expr_err(loc)?,
+                rhs_ty.as_string(self).into_expr_err(loc)?,
+                lhs_cvar.display_name(self).into_expr_err(loc)?,
+                lhs_ty.as_string(self).into_expr_err(loc)?
+            ),
+        ))
+    }
+
     /// Perform an assignment
     fn assign(
         &mut self,
@@ -129,12 +315,45 @@ pub trait Assign: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized
         rhs_cvar: ContextVarNode,
         ctx: ContextNode,
     ) -> Result<ExprRet, ExprErr> {
+        self.assign_at_depth(arena, loc, lhs_cvar, rhs_cvar, ctx, 0)
+    }
+
+    /// Perform an assignment of a variable nested `depth` struct fields deep, erroring instead of
+    /// recursing past the analyzer's max struct depth
+    fn assign_at_depth(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        lhs_cvar: ContextVarNode,
+        rhs_cvar: ContextVarNode,
+        ctx: ContextNode,
+        depth: usize,
+    ) -> Result<ExprRet, ExprErr> {
+        if depth > self.max_struct_depth() {
+            return Err(ExprErr::GraphError(
+                loc,
+                GraphError::MaxStackDepthReached(format!(
+                    "Struct fields nested deeper than {} while assigning {}",
+                    self.max_struct_depth(),
+                    lhs_cvar.display_name(self).into_expr_err(loc)?
+                )),
+            ));
+        }
         tracing::trace!(
             "assigning: {} to {}",
             rhs_cvar.display_name(self).unwrap(),
             lhs_cvar.display_name(self).unwrap(),
         );
 
+        self.check_struct_assign_fields(loc, lhs_cvar, rhs_cvar)?;
+        self.check_narrowing_cast(arena, loc, lhs_cvar, rhs_cvar)?;
+
+        // `cast_from` converts the rhs in place, dropping its exclusions, so take them beforehand
+        let converted = !lhs_cvar.ty_eq(&rhs_cvar, self).into_expr_err(loc)?;
+        let rhs_exclusions = rhs_cvar
+            .ref_range(self)
+            .into_expr_err(loc)?
+            .map(|range| range.exclusions.clone());
         rhs_cvar
             .cast_from(&lhs_cvar, self, arena)
             .into_expr_err(loc)?;
@@ -166,6 +385,7 @@ pub trait Assign: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized
             )?
         };
 
+        new_lhs.mark_written(self);
         new_lhs.underlying_mut(self).into_expr_err(loc)?.tmp_of =
             rhs_cvar.tmp_of(self).into_expr_err(loc)?;
 
@@ -237,13 +457,25 @@ pub trait Assign: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized
             let _ = new_lhs.try_set_range_min(self, arena, new_lower_bound);
             let _ = new_lhs.try_set_range_max(self, arena, new_upper_bound);
         }
-        if let Some(rhs_range) = rhs_cvar.ref_range(self).into_expr_err(loc)? {
+        if let Some(mut exclusions) = rhs_exclusions {
+            if converted {
+                if let Ok(builtin) = lhs_cvar.ty(self).into_expr_err(loc)?.as_builtin(self) {
+                    exclusions = exclusions.cast_into(&builtin, self, arena);
+                }
+            }
+            exclusions.normalize(arena);
             let res = new_lhs
-                .try_set_range_exclusions(self, rhs_range.exclusions.clone())
+                .try_set_range_exclusions(self, exclusions)
                 .into_expr_err(loc);
             let _ = self.add_if_err(res);
         }
 
+        let lhs_name = lhs_cvar.name(self).into_expr_err(loc)?;
+        ctx.forget_linear_eqs(&lhs_name, self).into_expr_err(loc)?;
+        if lhs_cvar.ty_eq(&rhs_cvar, self).into_expr_err(loc)? {
+            self.record_linear_eq(arena, ctx, loc, new_lhs, rhs_cvar)?;
+        }
+
         if rhs_cvar.is_indexable(self).into_expr_err(loc)? {
             // rhs is indexable. get the length attribute, create a new length for the lhs,
             // and perform assign
@@ -289,18 +521,19 @@ pub trait Assign: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized
                         .unwrap();
                     field_name == r_field_name
                 }) {
-                    let _ = self.assign(
+                    let _ = self.assign_at_depth(
                         arena,
                         loc,
                         field.latest_version_or_inherited_in_ctx(ctx, self),
                         matching_field.latest_version_or_inherited_in_ctx(ctx, self),
                         ctx,
+                        depth + 1,
                     )?;
                     Ok(())
                 } else {
                     Err(ExprErr::ParseError(
                         loc,
-                        "Missing fields for struct assignment".to_string(),
+                        format!("Struct types mismatched - could not find field \"{field_name}\""),
                     ))
                 }
             })?;
@@ -338,4 +571,105 @@ pub trait Assign: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized
         )?;
         Ok(ExprRet::Single(new_lhs.into()))
     }
+
+    /// Ensures two struct-typed variables have the same set of field names before assigning one
+    /// to the other, naming both struct types and the field differences if they do not
+    fn check_struct_assign_fields(
+        &mut self,
+        loc: Loc,
+        lhs_cvar: ContextVarNode,
+        rhs_cvar: ContextVarNode,
+    ) -> Result<(), ExprErr> {
+        let (Some(lhs_struct), Some(rhs_struct)) = (
+            lhs_cvar.maybe_struct(self).into_expr_err(loc)?,
+            rhs_cvar.maybe_struct(self).into_expr_err(loc)?,
+        ) else {
+            return Ok(());
+        };
+
+        if lhs_struct == rhs_struct {
+            return Ok(());
+        }
+
+        let lhs_fields = lhs_struct
+            .fields(self)
+            .iter()
+            .map(|field| field.name(self))
+            .collect::<Result<Vec<_>, _>>()
+            .into_expr_err(loc)?;
+        let rhs_fields = rhs_struct
+            .fields(self)
+            .iter()
+            .map(|field| field.name(self))
+            .collect::<Result<Vec<_>, _>>()
+            .into_expr_err(loc)?;
+
+        let lhs_set = lhs_fields.iter().collect::<BTreeSet<_>>();
+        let rhs_set = rhs_fields.iter().collect::<BTreeSet<_>>();
+        if lhs_set == rhs_set
+            && lhs_set.len() == lhs_fields.len()
+            && rhs_set.len() == rhs_fields.len()
+        {
+            return Ok(());
+        }
+
+        let missing = lhs_set
+            .difference(&rhs_set)
+            .cloned()
+            .cloned()
+            .collect::<Vec<_>>();
+        let extra = rhs_set
+            .difference(&lhs_set)
+            .cloned()
+            .cloned()
+            .collect::<Vec<_>>();
+        Err(ExprErr::ParseError(
+            loc,
+            format!(
+                "Struct types mismatched - cannot assign struct \"{}\" {{ {} }} to struct \"{}\" {{ {} }}: missing fields: [{}], unexpected fields: [{}]",
+                rhs_struct.name(self).into_expr_err(loc)?,
+                rhs_fields.join(", "),
+                lhs_struct.name(self).into_expr_err(loc)?,
+                lhs_fields.join(", "),
+                missing.join(", "),
+                extra.join(", "),
+            ),
+        ))
+    }
+}
+
+/// Whether a concrete integer value fits into an integer type without truncation
+fn concrete_fits(ty: &Builtin, val: &Concrete) -> bool {
+    match (ty, val) {
+        (Builtin::Uint(size), Concrete::Uint(_, val)) => min_uint_size(*val) <= *size,
+        (Builtin::Uint(size), Concrete::Int(_, val)) => {
+            !val.is_negative() && min_uint_size(val.into_raw()) <= *size
+        }
+        (Builtin::Int(size), Concrete::Int(_, val)) => min_int_size(*val) <= *size,
+        (Builtin::Int(size), Concrete::Uint(_, val)) => {
+            I256::try_from(*val).is_ok_and(|val| min_int_size(val) <= *size)
+        }
+        _ => true,
+    }
+}
+
+/// The variables directly assigned to by an assignment's left hand side, i.e. `a` in `a = 1` or
+/// `a` and `b` in `(a, b) = (1, 2)`
+fn assigned_idents(lhs_expr: &Expression) -> Vec<&Identifier> {
+    match lhs_expr {
+        Expression::Variable(ident) => vec![ident],
+        Expression::Parenthesis(_, inner) => assigned_idents(inner),
+        Expression::List(_, params) => params
+            .iter()
+            .filter_map(|(_, param)| match param {
+                Some(Parameter {
+                    ty: Expression::Variable(ident),
+                    name: None,
+                    ..
+                }) => Some(ident),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
 }
@@ -2,16 +2,23 @@ use crate::{require::Require, variable::Variable, ContextBuilder, ExpressionPars
 
 use graph::{
     elem::*,
+    exec_traits::{RangeAdd, RangeSub},
     nodes::{
-        Concrete, ContextNode, ContextVar, ContextVarNode, ExprRet, KilledKind, TmpConstruction,
+        Concrete, Context, ContextNode, ContextVar, ContextVarNode, ExprRet, KilledKind,
+        TmpConstruction,
     },
-    AnalyzerBackend, ContextEdge, Edge, Node,
+    AnalyzerBackend, ContextEdge, Edge, Node, Range,
 };
-use shared::{ExprErr, IntoExprErr, RangeArena};
+use shared::{ExprErr, GraphError, IntoExprErr, NodeIdx, RangeArena};
 
 use ethers_core::types::U256;
 use solang_parser::pt::{Expression, Loc};
 
+use std::cmp::Ordering;
+
+/// A concrete minimum and maximum
+type ConcreteBounds = (RangeConcrete<Concrete>, RangeConcrete<Concrete>);
+
 impl<T> BinOp for T where T: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {}
 /// Handles binary operations (`+`, `-`, `/`, etc.)
 pub trait BinOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
@@ -81,12 +88,7 @@ pub trait BinOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                     ContextVarNode::from(*rhs).latest_version_or_inherited_in_ctx(ctx, self);
                 lhs_cvar.try_increase_size(self, arena).into_expr_err(loc)?;
                 rhs_cvar.try_increase_size(self, arena).into_expr_err(loc)?;
-                ctx.push_expr(
-                    self.op(arena, loc, lhs_cvar, rhs_cvar, ctx, op, assign)?,
-                    self,
-                )
-                .into_expr_err(loc)?;
-                Ok(())
+                self.op_and_push(arena, loc, lhs_cvar, rhs_cvar, ctx, op, assign)
             }
             (ExprRet::SingleLiteral(lhs), ExprRet::Single(rhs)) => {
                 ContextVarNode::from(*lhs)
@@ -96,12 +98,7 @@ pub trait BinOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                     ContextVarNode::from(*lhs).latest_version_or_inherited_in_ctx(ctx, self);
                 let rhs_cvar =
                     ContextVarNode::from(*rhs).latest_version_or_inherited_in_ctx(ctx, self);
-                ctx.push_expr(
-                    self.op(arena, loc, lhs_cvar, rhs_cvar, ctx, op, assign)?,
-                    self,
-                )
-                .into_expr_err(loc)?;
-                Ok(())
+                self.op_and_push(arena, loc, lhs_cvar, rhs_cvar, ctx, op, assign)
             }
             (ExprRet::Single(lhs), ExprRet::SingleLiteral(rhs)) => {
                 ContextVarNode::from(*rhs)
@@ -111,24 +108,14 @@ pub trait BinOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                     ContextVarNode::from(*lhs).latest_version_or_inherited_in_ctx(ctx, self);
                 let rhs_cvar =
                     ContextVarNode::from(*rhs).latest_version_or_inherited_in_ctx(ctx, self);
-                ctx.push_expr(
-                    self.op(arena, loc, lhs_cvar, rhs_cvar, ctx, op, assign)?,
-                    self,
-                )
-                .into_expr_err(loc)?;
-                Ok(())
+                self.op_and_push(arena, loc, lhs_cvar, rhs_cvar, ctx, op, assign)
             }
             (ExprRet::Single(lhs), ExprRet::Single(rhs)) => {
                 let lhs_cvar =
                     ContextVarNode::from(*lhs).latest_version_or_inherited_in_ctx(ctx, self);
                 let rhs_cvar =
                     ContextVarNode::from(*rhs).latest_version_or_inherited_in_ctx(ctx, self);
-                ctx.push_expr(
-                    self.op(arena, loc, lhs_cvar, rhs_cvar, ctx, op, assign)?,
-                    self,
-                )
-                .into_expr_err(loc)?;
-                Ok(())
+                self.op_and_push(arena, loc, lhs_cvar, rhs_cvar, ctx, op, assign)
             }
             (lhs @ ExprRet::Single(..), ExprRet::Multi(rhs_sides)) => {
                 rhs_sides
@@ -157,6 +144,121 @@ pub trait BinOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
         }
     }
 
+    /// Executes a binary operation and pushes its result. A division or modulo whose divisor may
+    /// be zero forks the context into a path where the divisor is zero, which reverts, and a path
+    /// where it is nonzero, which continues with the result
+    #[allow(clippy::too_many_arguments)]
+    fn op_and_push(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        lhs_cvar: ContextVarNode,
+        rhs_cvar: ContextVarNode,
+        ctx: ContextNode,
+        op: RangeOp,
+        assign: bool,
+    ) -> Result<(), ExprErr> {
+        if !matches!(op, RangeOp::Div(..) | RangeOp::Mod)
+            || !self.divisor_may_be_zero(arena, loc, rhs_cvar)?
+        {
+            let res = self.op(arena, loc, lhs_cvar, rhs_cvar, ctx, op, assign)?;
+            return ctx.push_expr(res, self).into_expr_err(loc);
+        }
+
+        let (zero_ctx, nonzero_ctx) = self.fork_divisor(loc, ctx)?;
+        zero_ctx
+            .kill(self, loc, KilledKind::DivisionByZero)
+            .into_expr_err(loc)?;
+        let lhs_cvar = lhs_cvar.latest_version_or_inherited_in_ctx(nonzero_ctx, self);
+        let rhs_cvar = rhs_cvar.latest_version_or_inherited_in_ctx(nonzero_ctx, self);
+        let res = self.op(arena, loc, lhs_cvar, rhs_cvar, nonzero_ctx, op, assign)?;
+        nonzero_ctx.push_expr(res, self).into_expr_err(loc)
+    }
+
+    /// Whether a divisor's range includes zero without being exactly zero
+    fn divisor_may_be_zero(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        rhs: ContextVarNode,
+    ) -> Result<bool, ExprErr> {
+        let Some(zero) = rhs.ty_zero_concrete(self).into_expr_err(loc)? else {
+            return Ok(false);
+        };
+        let zero = Elem::from(zero);
+        let (Some(min), Some(max)) = (
+            rhs.evaled_range_min(self, arena).into_expr_err(loc)?,
+            rhs.evaled_range_max(self, arena).into_expr_err(loc)?,
+        ) else {
+            return Ok(false);
+        };
+        if min.maybe_concrete().is_none() || max.maybe_concrete().is_none() {
+            return Ok(false);
+        }
+        let min_ord = min.range_ord(&zero, arena);
+        let max_ord = max.range_ord(&zero, arena);
+        Ok(matches!(
+            (min_ord, max_ord),
+            (
+                Some(Ordering::Less | Ordering::Equal),
+                Some(Ordering::Greater)
+            ) | (Some(Ordering::Less), Some(Ordering::Equal))
+        ))
+    }
+
+    /// Forks the context into a subcontext where a divisor is zero and one where it is nonzero
+    fn fork_divisor(
+        &mut self,
+        loc: Loc,
+        ctx: ContextNode,
+    ) -> Result<(ContextNode, ContextNode), ExprErr> {
+        let zero_ctx = Context::new_subctx(
+            ctx,
+            None,
+            loc,
+            Some("zero_divisor"),
+            None,
+            false,
+            self,
+            None,
+        )
+        .into_expr_err(loc)?;
+        let zero_ctx = ContextNode::from(self.add_node(Node::Context(zero_ctx)));
+        let nonzero_ctx = Context::new_subctx(
+            ctx,
+            None,
+            loc,
+            Some("nonzero_divisor"),
+            None,
+            false,
+            self,
+            None,
+        )
+        .into_expr_err(loc)?;
+        let nonzero_ctx = ContextNode::from(self.add_node(Node::Context(nonzero_ctx)));
+        ctx.set_child_fork(zero_ctx, nonzero_ctx, self)
+            .into_expr_err(loc)?;
+        zero_ctx
+            .set_continuation_ctx(self, ctx, "fork_zero_divisor")
+            .into_expr_err(loc)?;
+        nonzero_ctx
+            .set_continuation_ctx(self, ctx, "fork_nonzero_divisor")
+            .into_expr_err(loc)?;
+        let ctx_fork = self.add_node(Node::ContextFork);
+        self.add_edge(ctx_fork, ctx, Edge::Context(ContextEdge::ContextFork));
+        self.add_edge(
+            NodeIdx::from(zero_ctx.0),
+            ctx_fork,
+            Edge::Context(ContextEdge::Subcontext),
+        );
+        self.add_edge(
+            NodeIdx::from(nonzero_ctx.0),
+            ctx_fork,
+            Edge::Context(ContextEdge::Subcontext),
+        );
+        Ok((zero_ctx, nonzero_ctx))
+    }
+
     /// Execute a binary operation after parsing the expressions
     #[tracing::instrument(level = "trace", skip_all)]
     fn op(
@@ -178,12 +280,19 @@ pub trait BinOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
         );
 
         let unchecked = match op {
-            RangeOp::Add(u) | RangeOp::Sub(u) | RangeOp::Mul(u) | RangeOp::Div(u) => u,
+            RangeOp::Add(u)
+            | RangeOp::Sub(u)
+            | RangeOp::Mul(u)
+            | RangeOp::Div(u)
+            | RangeOp::Exp(u) => u,
             _ => false,
         };
 
         let new_lhs = if assign {
+            let lhs_name = lhs_cvar.name(self).into_expr_err(loc)?;
+            ctx.forget_linear_eqs(&lhs_name, self).into_expr_err(loc)?;
             let new = self.advance_var_in_ctx_forcible(lhs_cvar, loc, ctx, true)?;
+            new.mark_written(self);
             let underlying = new.underlying_mut(self).into_expr_err(loc)?;
             underlying.tmp_of = Some(TmpConstruction::new(lhs_cvar, op, Some(rhs_cvar)));
 
@@ -248,15 +357,26 @@ pub trait BinOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
             true,
         )?;
 
+        // division by zero reverts even in an unchecked block
+        if matches!(op, RangeOp::Div(..) | RangeOp::Mod) {
+            if let Some(killed) =
+                self.checked_require_mod_div(arena, lhs_cvar, new_rhs, loc, ctx)?
+            {
+                return Ok(killed);
+            }
+        }
+
         if !unchecked {
+            if matches!(
+                op,
+                RangeOp::Add(..) | RangeOp::Sub(..) | RangeOp::Mul(..) | RangeOp::Exp(..)
+            ) && self.op_may_overflow(arena, loc, lhs_cvar, new_rhs, new_lhs, op)?
+            {
+                ctx.add_possible_overflow(loc, new_lhs, self)
+                    .into_expr_err(loc)?;
+            }
+
             match op {
-                RangeOp::Div(..) | RangeOp::Mod => {
-                    if let Some(killed) =
-                        self.checked_require_mod_div(arena, lhs_cvar, new_rhs, loc, ctx)?
-                    {
-                        return Ok(killed);
-                    }
-                }
                 RangeOp::Sub(..) => {
                     if let Some(killed) =
                         self.checked_require_sub(arena, lhs_cvar, new_lhs, new_rhs, loc, ctx)?
@@ -278,7 +398,7 @@ pub trait BinOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                         return Ok(killed);
                     }
                 }
-                RangeOp::Exp => {
+                RangeOp::Exp(..) => {
                     if let Some(killed) =
                         self.checked_require_exp(arena, lhs_cvar, new_lhs, new_rhs, loc, ctx)?
                     {
@@ -294,6 +414,110 @@ pub trait BinOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
         ))
     }
 
+    /// Whether a checked operation may overflow the result's type, by applying the operation to
+    /// every combination of the operands' concrete bounds, with bounds that are excluded from an
+    /// operand's range (i.e. `n` after `if (n == 0) return;`) moved inwards past the exclusion.
+    /// If a bound doesn't evaluate to a concrete value, the operation is assumed not to overflow
+    fn op_may_overflow(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        lhs: ContextVarNode,
+        rhs: ContextVarNode,
+        result: ContextVarNode,
+        op: RangeOp,
+    ) -> Result<bool, ExprErr> {
+        let Ok(builtin) = result.ty(self).into_expr_err(loc)?.as_builtin(self) else {
+            return Ok(false);
+        };
+        let mut bounds = vec![];
+        for cvar in [lhs, rhs] {
+            let Some((min, max)) = self.unexcluded_bounds(arena, cvar).into_expr_err(loc)? else {
+                return Ok(false);
+            };
+            bounds.push([min.val, max.val]);
+        }
+
+        Ok(bounds[0].iter().any(|l| {
+            l.clone()
+                .cast(builtin.clone())
+                .is_some_and(|l| bounds[1].iter().any(|r| l.overflows_with(op, r)))
+        }))
+    }
+
+    /// The concrete minimum and maximum of a variable's range that aren't excluded from it.
+    /// Returns `None` if either bound (or an exclusion around one) isn't concrete, or every
+    /// value in the range is excluded
+    fn unexcluded_bounds(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        cvar: ContextVarNode,
+    ) -> Result<Option<ConcreteBounds>, GraphError> {
+        let min = cvar.evaled_range_min(self, arena)?;
+        let max = cvar.evaled_range_max(self, arena)?;
+        let (Some(mut min), Some(mut max)) = (
+            min.and_then(|min| min.maybe_concrete()),
+            max.and_then(|max| max.maybe_concrete()),
+        ) else {
+            return Ok(None);
+        };
+        let Some(range) = cvar.ref_range(self)? else {
+            return Ok(Some((min, max)));
+        };
+        let mut excls = vec![];
+        for excl in range.range_exclusions() {
+            let excl_min = excl.minimize(self, arena)?.maybe_concrete();
+            let excl_max = excl.maximize(self, arena)?.maybe_concrete();
+            if let (Some(excl_min), Some(excl_max)) = (excl_min, excl_max) {
+                excls.push((excl_min, excl_max));
+            }
+        }
+
+        let contains = |excl: &ConcreteBounds,
+                        val: &RangeConcrete<Concrete>,
+                        arena: &mut RangeArena<Elem<Concrete>>| {
+            matches!(
+                excl.0.range_ord(val, arena),
+                Some(Ordering::Less | Ordering::Equal)
+            ) && matches!(
+                val.range_ord(&excl.1, arena),
+                Some(Ordering::Less | Ordering::Equal)
+            )
+        };
+        // each pass moves a bound past at least one exclusion, so this settles within as many
+        // passes as there are exclusions
+        for _ in 0..=excls.len() {
+            let mut moved = false;
+            for excl in excls.iter() {
+                let min_excluded = contains(excl, &min, arena);
+                let max_excluded = contains(excl, &max, arena);
+                if min_excluded && max_excluded {
+                    return Ok(None);
+                }
+                let Some(one) = min.val.one().map(|one| RangeConcrete::new(one, min.loc)) else {
+                    return Ok(None);
+                };
+                if min_excluded {
+                    let Some(next) = excl.1.range_add(&one).and_then(|e| e.maybe_concrete()) else {
+                        return Ok(None);
+                    };
+                    min = next;
+                    moved = true;
+                } else if max_excluded {
+                    let Some(prev) = excl.0.range_sub(&one).and_then(|e| e.maybe_concrete()) else {
+                        return Ok(None);
+                    };
+                    max = prev;
+                    moved = true;
+                }
+            }
+            if !moved {
+                break;
+            }
+        }
+        Ok(Some((min, max)))
+    }
+
     #[tracing::instrument(level = "trace", skip_all)]
     fn bit_not(
         &mut self,
@@ -362,6 +586,7 @@ pub trait BinOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                     dep_on: Some(lhs_cvar.dependent_on(self, true).into_expr_err(loc)?),
                     is_symbolic: lhs_cvar.is_symbolic(self).into_expr_err(loc)?,
                     is_return: false,
+                    source_repr: None,
                     ty: lhs_cvar.underlying(self).into_expr_err(loc)?.ty.clone(),
                 };
 
@@ -417,10 +642,12 @@ pub trait BinOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                 .expect("No range?")
                 .range_eq(&Elem::from(Concrete::from(U256::zero())), arena)
         {
-            let res = ctx.kill(self, loc, KilledKind::Revert).into_expr_err(loc);
+            let res = ctx
+                .kill(self, loc, KilledKind::DivisionByZero)
+                .into_expr_err(loc);
             let _ = self.add_if_err(res);
 
-            return Ok(Some(ExprRet::CtxKilled(KilledKind::Revert)));
+            return Ok(Some(ExprRet::CtxKilled(KilledKind::DivisionByZero)));
         }
 
         // otherwise, require rhs != 0
@@ -440,7 +667,7 @@ pub trait BinOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
             )?
             .is_none()
         {
-            return Ok(Some(ExprRet::CtxKilled(KilledKind::Revert)));
+            return Ok(Some(ExprRet::CtxKilled(KilledKind::DivisionByZero)));
         }
         Ok(None)
     }
@@ -1,4 +1,4 @@
-use crate::{ContextBuilder, ExpressionParser};
+use crate::{require::Require, ContextBuilder, ExpressionParser, Literal};
 
 use graph::{
     elem::*,
@@ -13,9 +13,9 @@ use shared::{ExprErr, GraphError, IntoExprErr, RangeArena};
 use solang_parser::pt::{Expression, Loc};
 use std::cmp::Ordering;
 
-impl<T> Cmp for T where T: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {}
+impl<T> Cmp for T where T: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Require + Sized {}
 /// Handles comparator operations, i.e: `!`
-pub trait Cmp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
+pub trait Cmp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Require + Sized {
     #[tracing::instrument(level = "trace", skip_all)]
     fn not(
         &mut self,
@@ -82,6 +82,7 @@ pub trait Cmp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                     dep_on: Some(lhs_cvar.dependent_on(self, true).into_expr_err(loc)?),
                     is_symbolic: lhs_cvar.is_symbolic(self).into_expr_err(loc)?,
                     is_return: false,
+                    source_repr: None,
                     ty: VarType::BuiltIn(
                         BuiltInNode::from(self.builtin_or_add(Builtin::Bool)),
                         Some(range),
@@ -177,6 +178,9 @@ pub trait Cmp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                     ContextVarNode::from(*rhs).latest_version_or_inherited_in_ctx(ctx, self);
                 lhs_cvar.try_increase_size(self, arena).into_expr_err(loc)?;
                 rhs_cvar.try_increase_size(self, arena).into_expr_err(loc)?;
+                if self.fold_literal_cmp(arena, ctx, loc, lhs_cvar, op, rhs_cvar)? {
+                    return Ok(());
+                }
                 self.cmp_inner(
                     arena,
                     ctx,
@@ -201,6 +205,7 @@ pub trait Cmp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                     op.to_string(),
                     rhs_cvar.display_name(self).unwrap()
                 );
+                self.note_constant_cmp(arena, ctx, loc, lhs_cvar, op, rhs_cvar)?;
                 let range = {
                     let elem = Elem::Expr(RangeExpr::new(
                         Elem::from(lhs_cvar),
@@ -241,6 +246,7 @@ pub trait Cmp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                             .is_symbolic(self)
                             .into_expr_err(loc)?,
                     is_return: false,
+                    source_repr: None,
                     tmp_of: Some(TmpConstruction::new(lhs_cvar, op, Some(rhs_cvar))),
                     dep_on: {
                         let mut deps = lhs_cvar.dependent_on(self, true).into_expr_err(loc)?;
@@ -1,11 +1,14 @@
 use crate::{require::Require, ContextBuilder, ExpressionParser, StatementParser};
 
 use graph::{
-    elem::Elem,
-    nodes::{Concrete, Context, ContextNode},
-    AnalyzerBackend, ContextEdge, Edge, Node,
+    elem::{Elem, RangeOp},
+    nodes::{
+        BuiltInNode, Builtin, Concrete, ConcreteNode, Context, ContextNode, ContextVar,
+        ContextVarNode, ExprRet,
+    },
+    AnalyzerBackend, ContextEdge, Edge, Node, VarType,
 };
-use shared::{ExprErr, IntoExprErr, NodeIdx, RangeArena};
+use shared::{ExprErr, GraphError, IntoExprErr, NodeIdx, RangeArena};
 
 use solang_parser::pt::CodeLocation;
 use solang_parser::pt::{Expression, Loc, Statement};
@@ -159,7 +162,9 @@ pub trait CondOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Requir
 
     /// Handles a conditional expression like `if .. else ..`
     /// When we have a conditional operator, we create a fork in the context. One side of the fork is
-    /// if the expression is true, the other is if it is false.
+    /// if the expression is true, the other is if it is false. Each side's result is then cast to
+    /// the sides' common type, keeping the range its side's constraints give it. If the condition
+    /// is a boolean literal, only the live side is evaluated, without forking.
     #[tracing::instrument(level = "trace", skip_all)]
     fn cond_op_expr(
         &mut self,
@@ -171,6 +176,11 @@ pub trait CondOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Requir
         ctx: ContextNode,
     ) -> Result<(), ExprErr> {
         tracing::trace!("conditional operator");
+        if let Some(cond) = literal_cond(if_expr) {
+            let live_expr = if cond { true_expr } else { false_expr };
+            return self.parse_ctx_expr(arena, live_expr, ctx);
+        }
+
         self.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, loc| {
             let tctx =
                 Context::new_subctx(ctx, None, loc, Some("true"), None, false, analyzer, None)
@@ -209,10 +219,253 @@ pub trait CondOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Requir
             analyzer.false_fork_if_cvar(arena, if_expr.clone(), false_subctx)?;
             analyzer.apply_to_edges(false_subctx, loc, arena, &|analyzer, arena, ctx, _loc| {
                 analyzer.parse_ctx_expr(arena, false_expr, ctx)
+            })?;
+
+            analyzer.join_cond_op_arms(loc, ctx)
+        })
+    }
+
+    /// Handles `&&` and `||`, which short circuit: the rhs is only evaluated where the lhs doesn't
+    /// decide the result (`false` for `&&` and `true` for `||`). Unless the lhs's value is known,
+    /// the context is forked into a side where the lhs has the deciding value and is the result,
+    /// and a side where it has the other value and the rhs is evaluated as the result
+    #[tracing::instrument(level = "trace", skip_all)]
+    fn short_circuit_expr(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        lhs_expr: &Expression,
+        rhs_expr: &Expression,
+        deciding: bool,
+        ctx: ContextNode,
+    ) -> Result<(), ExprErr> {
+        self.parse_ctx_expr(arena, lhs_expr, ctx)?;
+        self.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, loc| {
+            let Some(lhs_paths) = ctx.pop_expr_latest(loc, analyzer).into_expr_err(loc)? else {
+                return Err(ExprErr::NoLhs(
+                    loc,
+                    "Short circuiting operation had no left hand side".to_string(),
+                ));
+            };
+            let lhs_paths = lhs_paths.flatten();
+            if lhs_paths.is_killed() {
+                return ctx.push_expr(lhs_paths, analyzer).into_expr_err(loc);
+            }
+            let lhs = ContextVarNode::from(lhs_paths.expect_single().into_expr_err(loc)?);
+
+            match lhs.evaled_range_min(analyzer, arena).into_expr_err(loc)? {
+                Some(min)
+                    if lhs.is_const(analyzer, arena).into_expr_err(loc)?
+                        && min.maybe_concrete().is_some() =>
+                {
+                    if min.maybe_concrete().unwrap().val == Concrete::Bool(deciding) {
+                        return ctx.push_expr(lhs_paths, analyzer).into_expr_err(loc);
+                    }
+                    return analyzer.parse_ctx_expr(arena, rhs_expr, ctx);
+                }
+                _ => {}
+            }
+
+            // each side narrows the lhs, which must be inherited per side rather than versioned
+            // globally, so it needs to belong to the context
+            if lhs.maybe_ctx(analyzer).is_none() {
+                ctx.add_var(lhs, analyzer).into_expr_err(loc)?;
+                analyzer.add_edge(lhs, ctx, Edge::Context(ContextEdge::Variable));
+            }
+
+            let decided_ctx =
+                Context::new_subctx(ctx, None, loc, Some("decided"), None, false, analyzer, None)
+                    .into_expr_err(loc)?;
+            let decided_ctx = ContextNode::from(analyzer.add_node(Node::Context(decided_ctx)));
+            let rhs_ctx =
+                Context::new_subctx(ctx, None, loc, Some("rhs"), None, false, analyzer, None)
+                    .into_expr_err(loc)?;
+            let rhs_ctx = ContextNode::from(analyzer.add_node(Node::Context(rhs_ctx)));
+            ctx.set_child_fork(decided_ctx, rhs_ctx, analyzer)
+                .into_expr_err(loc)?;
+            decided_ctx
+                .set_continuation_ctx(analyzer, ctx, "fork_decided")
+                .into_expr_err(loc)?;
+            rhs_ctx
+                .set_continuation_ctx(analyzer, ctx, "fork_rhs")
+                .into_expr_err(loc)?;
+            let ctx_fork = analyzer.add_node(Node::ContextFork);
+            analyzer.add_edge(ctx_fork, ctx, Edge::Context(ContextEdge::ContextFork));
+            analyzer.add_edge(
+                NodeIdx::from(decided_ctx.0),
+                ctx_fork,
+                Edge::Context(ContextEdge::Subcontext),
+            );
+            analyzer.add_edge(
+                NodeIdx::from(rhs_ctx.0),
+                ctx_fork,
+                Edge::Context(ContextEdge::Subcontext),
+            );
+
+            analyzer.require_cvar_is(arena, decided_ctx, loc, lhs, deciding)?;
+            analyzer.apply_to_edges(decided_ctx, loc, arena, &|analyzer, _arena, ctx, loc| {
+                let lhs = lhs.latest_version_or_inherited_in_ctx(ctx, analyzer);
+                ctx.push_expr(ExprRet::Single(lhs.into()), analyzer)
+                    .into_expr_err(loc)
+            })?;
+
+            analyzer.require_cvar_is(arena, rhs_ctx, loc, lhs, !deciding)?;
+            analyzer.apply_to_edges(rhs_ctx, loc, arena, &|analyzer, arena, ctx, _loc| {
+                analyzer.parse_ctx_expr(arena, rhs_expr, ctx)
             })
         })
     }
 
+    /// Requires that a boolean variable has a value, narrowing the operands of the comparison it
+    /// was the result of (i.e. `x` in `x > 5`)
+    fn require_cvar_is(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        ctx: ContextNode,
+        loc: Loc,
+        cvar: ContextVarNode,
+        val: bool,
+    ) -> Result<(), ExprErr> {
+        if let Some(tmp) = cvar.tmp_of(self).into_expr_err(loc)? {
+            let op = if val {
+                Some(tmp.op)
+            } else {
+                tmp.op.logical_inverse()
+            };
+            if let (Some((op, _inv_op, pair)), Some(rhs)) =
+                (op.and_then(RangeOp::require_parts), tmp.rhs)
+            {
+                self.handle_require_inner(
+                    arena,
+                    ctx,
+                    loc,
+                    &ExprRet::Single(tmp.lhs.into()),
+                    &ExprRet::Single(rhs.into()),
+                    op,
+                    op,
+                    pair,
+                )?;
+            }
+        }
+        if ctx.is_killed(self).into_expr_err(loc)? {
+            return Ok(());
+        }
+
+        let cnode = ConcreteNode::from(self.add_node(Node::Concrete(Concrete::Bool(val))));
+        let tmp_val =
+            ContextVar::new_from_concrete(Loc::Implicit, ctx, cnode, self).into_expr_err(loc)?;
+        let tmp_val = self.add_node(Node::ContextVar(tmp_val));
+        ctx.add_var(tmp_val.into(), self).into_expr_err(loc)?;
+        self.add_edge(tmp_val, ctx, Edge::Context(ContextEdge::Variable));
+        self.handle_require_inner(
+            arena,
+            ctx,
+            loc,
+            &ExprRet::Single(cvar.into()),
+            &ExprRet::Single(tmp_val),
+            RangeOp::Eq,
+            RangeOp::Eq,
+            (RangeOp::Neq, RangeOp::Eq),
+        )
+    }
+
+    /// Casts the result of every live branch under a conditional expression's fork to the
+    /// branches' common type. Each branch keeps its own result, so its range is the union of the
+    /// arms intersected with that branch's path constraints, and the union itself is the joined
+    /// value (see [`ContextNode::joined_var_range`]). Does nothing unless each live branch
+    /// resulted in a single variable
+    fn join_cond_op_arms(&mut self, loc: Loc, ctx: ContextNode) -> Result<(), ExprErr> {
+        let mut arms = vec![];
+        for branch in ctx.all_edges(self).into_expr_err(loc)? {
+            if branch.is_killed(self).into_expr_err(loc)? {
+                continue;
+            }
+            let Some(ret) = branch.pop_expr_latest(loc, self).into_expr_err(loc)? else {
+                continue;
+            };
+            arms.push((branch, ret));
+        }
+
+        let cvars = arms
+            .iter()
+            .filter_map(|(_, ret)| match ret {
+                ExprRet::Single(idx) | ExprRet::SingleLiteral(idx) => {
+                    Some(ContextVarNode::from(*idx))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let ty = if !cvars.is_empty() && cvars.len() == arms.len() {
+            self.cond_op_arms_ty(&cvars)
+        } else {
+            None
+        };
+        let Some(ty) = ty else {
+            return arms
+                .into_iter()
+                .try_for_each(|(branch, ret)| branch.push_expr(ret, self).into_expr_err(loc));
+        };
+
+        // the result is only a literal if every arm is one
+        let literal = arms
+            .iter()
+            .all(|(_, ret)| matches!(ret, ExprRet::SingleLiteral(_)));
+        for (branch, cvar) in arms.into_iter().map(|(branch, _)| branch).zip(cvars) {
+            let result =
+                if cvar.ty(self).into_expr_err(loc)?.as_builtin(self).ok() == Some(ty.clone()) {
+                    cvar
+                } else {
+                    let cast = |elem: Elem<Concrete>| match elem.maybe_concrete() {
+                        Some(rc) => rc
+                            .val
+                            .clone()
+                            .cast(ty.clone())
+                            .map(Elem::from)
+                            .unwrap_or(elem),
+                        None => elem,
+                    };
+                    let range = cvar.ref_range(self).into_expr_err(loc)?.map(|range| {
+                        let mut range = range.into_owned();
+                        range.min = cast(range.min);
+                        range.max = cast(range.max);
+                        range
+                    });
+                    let new_var = cvar
+                        .as_cast_tmp(loc, branch, ty.clone(), self)
+                        .into_expr_err(loc)?;
+                    let ty_node = BuiltInNode::from(self.builtin_or_add(ty.clone()));
+                    new_var.underlying_mut(self).into_expr_err(loc)?.ty =
+                        VarType::BuiltIn(ty_node, range);
+                    new_var
+                };
+            let ret = if literal {
+                ExprRet::SingleLiteral(result.into())
+            } else {
+                ExprRet::Single(result.into())
+            };
+            branch.push_expr(ret, self).into_expr_err(loc)?;
+        }
+        Ok(())
+    }
+
+    /// The type of the joined result of a conditional expression's branches: the branch type
+    /// every other branch implicitly casts to
+    fn cond_op_arms_ty(&self, cvars: &[ContextVarNode]) -> Option<Builtin> {
+        let builtins = cvars
+            .iter()
+            .map(|cvar| cvar.ty(self)?.as_builtin(self))
+            .collect::<Result<Vec<_>, GraphError>>()
+            .ok()?;
+        builtins
+            .iter()
+            .find(|ty| {
+                builtins
+                    .iter()
+                    .all(|other| other.implicitly_castable_to(ty))
+            })
+            .cloned()
+    }
+
     /// Creates the true_fork cvar (updates bounds assuming its true)
     fn true_fork_if_cvar(
         &mut self,
@@ -240,9 +493,24 @@ pub trait CondOp: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Requir
     ) -> Result<(), ExprErr> {
         let loc = if_expr.loc();
         let inv_if_expr = self.inverse_expr(if_expr);
+        // a comparison is inverted in place, so any value recorded for it is the inverse's
+        let inverted_cmp = !matches!(inv_if_expr, Expression::Not(..));
         self.apply_to_edges(false_fork_ctx, loc, arena, &|analyzer, arena, ctx, _loc| {
             analyzer.handle_require(arena, &[inv_if_expr.clone()], ctx)?;
+            if inverted_cmp {
+                ctx.negate_constant_cmps(loc, analyzer).into_expr_err(loc)?;
+            }
             Ok(())
         })
     }
 }
+
+/// The value of a condition that is a boolean literal, possibly parenthesized or negated
+fn literal_cond(expr: &Expression) -> Option<bool> {
+    match expr {
+        Expression::BoolLiteral(_, val) => Some(*val),
+        Expression::Parenthesis(_, inner) => literal_cond(inner),
+        Expression::Not(_, inner) => literal_cond(inner).map(|val| !val),
+        _ => None,
+    }
+}
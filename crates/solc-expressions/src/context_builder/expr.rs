@@ -1,6 +1,8 @@
 use crate::{
     context_builder::ContextBuilder,
-    func_call::{func_caller::FuncCaller, intrinsic_call::IntrinsicFuncCaller},
+    func_call::{
+        func_caller::FuncCaller, helper::CallerHelper, intrinsic_call::IntrinsicFuncCaller,
+    },
     variable::Variable,
     ExprTyParser,
 };
@@ -176,9 +178,15 @@ pub trait ExpressionParser:
             UnaryPlus(_loc, e) => todo!("UnaryPlus unexpected rhs: {e:?}"),
 
             // Binary ops
-            Power(loc, lhs_expr, rhs_expr) => {
-                self.op_expr(arena, *loc, lhs_expr, rhs_expr, ctx, RangeOp::Exp, false)
-            }
+            Power(loc, lhs_expr, rhs_expr) => self.op_expr(
+                arena,
+                *loc,
+                lhs_expr,
+                rhs_expr,
+                ctx,
+                RangeOp::Exp(ctx.unchecked(self).into_expr_err(*loc)?),
+                false,
+            ),
             Add(loc, lhs_expr, rhs_expr) => self.op_expr(
                 arena,
                 *loc,
@@ -308,10 +316,26 @@ pub trait ExpressionParser:
                 *loc,
                 "Array slicing not currently supported".to_string(),
             )),
-            ArrayLiteral(loc, _) => Err(ExprErr::Todo(
-                *loc,
-                "Array literal not currently supported".to_string(),
-            )),
+            ArrayLiteral(loc, exprs) => {
+                self.parse_inputs(arena, ctx, *loc, exprs)?;
+                self.apply_to_edges(ctx, *loc, arena, &|analyzer, arena, ctx, loc| {
+                    let Some(ret) = ctx.pop_expr_latest(loc, analyzer).into_expr_err(loc)? else {
+                        return Err(ExprErr::NoRhs(
+                            loc,
+                            "Array literal had no elements".to_string(),
+                        ));
+                    };
+                    if matches!(ret, ExprRet::CtxKilled(_)) {
+                        ctx.push_expr(ret, analyzer).into_expr_err(loc)?;
+                        return Ok(());
+                    }
+                    let elems = match ret {
+                        ExprRet::Multi(elems) => elems,
+                        elem => vec![elem],
+                    };
+                    analyzer.array_literal(arena, ctx, loc, elems)
+                })
+            }
 
             // Comparator
             Equal(loc, lhs, rhs) => self.cmp(arena, *loc, lhs, RangeOp::Eq, rhs, ctx),
@@ -323,8 +347,8 @@ pub trait ExpressionParser:
 
             // Logical
             Not(loc, expr) => self.not(arena, *loc, expr, ctx),
-            And(loc, lhs, rhs) => self.cmp(arena, *loc, lhs, RangeOp::And, rhs, ctx),
-            Or(loc, lhs, rhs) => self.cmp(arena, *loc, lhs, RangeOp::Or, rhs, ctx),
+            And(loc, lhs, rhs) => self.short_circuit_expr(arena, *loc, lhs, rhs, false, ctx),
+            Or(loc, lhs, rhs) => self.short_circuit_expr(arena, *loc, lhs, rhs, true, ctx),
 
             // Function calls
             FunctionCallBlock(loc, _func_expr, _input_exprs) => {
@@ -384,6 +408,29 @@ pub trait ExpressionParser:
             }
 
             Delete(loc, expr) => {
+                /// Resets a variable, and for a struct each of its fields, to its type's zero value
+                fn delete_var(
+                    ctx: ContextNode,
+                    loc: Loc,
+                    analyzer: &mut impl AnalyzerBackend<Expr = Expression, ExprErr = ExprErr>,
+                    cvar: ContextVarNode,
+                ) -> Result<(), ExprErr> {
+                    if cvar.is_mapping(analyzer).into_expr_err(loc)? {
+                        // solc rejects this, so there is nothing to model
+                        tracing::warn!("`delete` cannot be applied to a mapping, ignoring it");
+                        return Ok(());
+                    }
+                    let fields = cvar.struct_to_fields(analyzer).into_expr_err(loc)?;
+                    let name = cvar.name(analyzer).into_expr_err(loc)?;
+                    ctx.forget_linear_eqs(&name, analyzer).into_expr_err(loc)?;
+                    let mut new_var = analyzer.advance_var_in_ctx(cvar, loc, ctx)?;
+                    new_var.mark_written(analyzer);
+                    new_var.sol_delete_range(analyzer).into_expr_err(loc)?;
+                    fields
+                        .into_iter()
+                        .try_for_each(|field| delete_var(ctx, loc, analyzer, field))
+                }
+
                 fn delete_match(
                     ctx: ContextNode,
                     loc: &Loc,
@@ -395,9 +442,7 @@ pub trait ExpressionParser:
                             let _ = ctx.kill(analyzer, *loc, kind);
                         }
                         ExprRet::Single(cvar) | ExprRet::SingleLiteral(cvar) => {
-                            let mut new_var =
-                                analyzer.advance_var_in_ctx(cvar.into(), *loc, ctx).unwrap();
-                            let res = new_var.sol_delete_range(analyzer).into_expr_err(*loc);
+                            let res = delete_var(ctx, *loc, analyzer, cvar.into());
                             let _ = analyzer.add_if_err(res);
                         }
                         ExprRet::Multi(inner) => {
@@ -1,8 +1,9 @@
 use crate::{
     context_builder::ContextBuilder,
-    func_call::{func_caller::FuncCaller, modifier::ModifierCaller},
+    func_call::{func_caller::FuncCaller, helper::CallerHelper, modifier::ModifierCaller},
     loops::Looper,
-    yul::YulBuilder,
+    try_catch::TryCatch,
+    yul::{yul_stmt_vars, YulBuilder},
     ExpressionParser, TestCommandRunner,
 };
 
@@ -14,7 +15,7 @@ use graph::{
     },
     AnalyzerBackend, ContextEdge, Edge, Node,
 };
-use shared::{ExprErr, IntoExprErr, NodeIdx, RangeArena};
+use shared::{ExprErr, GraphError, IntoExprErr, NodeIdx, RangeArena};
 
 use petgraph::{visit::EdgeRef, Direction};
 use solang_parser::{
@@ -97,6 +98,26 @@ pub trait StatementParser:
         if let Some(ctx) = parent_ctx {
             if let Node::Context(_) = self.node(ctx) {
                 let c = ContextNode::from(ctx.into());
+                if let Some(max_nodes) = self.max_nodes() {
+                    // only count the nodes this function added, earlier functions aren't its cost
+                    let added = c.underlying(self).map_or(0, |underlying| {
+                        self.graph()
+                            .node_count()
+                            .saturating_sub(underlying.entry_node_count)
+                    });
+                    if added > max_nodes {
+                        if !c.is_killed(self).unwrap_or(true) {
+                            let _ = c.kill(self, stmt.loc(), KilledKind::TooLarge);
+                            self.add_expr_err(ExprErr::GraphError(
+                                stmt.loc(),
+                                GraphError::MaxNodeCountReached(format!(
+                                    "Node count limit reached: the function added {added} > {max_nodes} nodes"
+                                )),
+                            ));
+                        }
+                        return;
+                    }
+                }
                 let res = self.is_representation_ok(arena).into_expr_err(stmt.loc());
                 if let Some(errs) = self.add_if_err(res) {
                     if !errs.is_empty() {
@@ -142,7 +163,7 @@ pub trait StatementParser:
                         mods_set = fn_node.modifiers_set;
                         entry_loc = Some(fn_node.loc);
                         tracing::trace!("creating genesis context for function");
-                        let ctx = Context::new(
+                        let mut ctx = Context::new(
                             FunctionNode::from(parent.into()),
                             self.add_if_err(
                                 FunctionNode::from(parent.into())
@@ -152,6 +173,7 @@ pub trait StatementParser:
                             .unwrap(),
                             *loc,
                         );
+                        ctx.entry_node_count = self.graph().node_count();
                         let ctx_node = self.add_node(Node::Context(ctx));
                         self.add_edge(ctx_node, parent, Edge::Context(ContextEdge::Context));
 
@@ -544,7 +566,7 @@ pub trait StatementParser:
                         *loc,
                         arena,
                         &|analyzer, arena, ctx, loc| {
-                            analyzer.while_loop(arena, loc, ctx, while_expr, while_stmt)
+                            analyzer.do_while_loop(arena, loc, ctx, while_expr, while_stmt)
                         },
                     );
                     let _ = self.widen_if_limit_hit(parent.into().into(), res);
@@ -570,6 +592,7 @@ pub trait StatementParser:
                         .expect("No context for variable definition?")
                         .into(),
                 );
+                let errs = self.expr_errs().len();
                 let res = self.apply_to_edges(ctx, *loc, arena, &|analyzer, arena, ctx, _loc| {
                     analyzer.parse_ctx_yul_statement(
                         arena,
@@ -578,7 +601,24 @@ pub trait StatementParser:
                     );
                     Ok(())
                 });
-                let _ = self.widen_if_limit_hit(ctx, res);
+                if self.widen_if_limit_hit(ctx, res) {
+                    return;
+                }
+                if self.expr_errs().len() > errs {
+                    // part of the block couldn't be followed, so don't trust any bounds of
+                    // variables it touches
+                    let (mut assigned, mut referenced) = Default::default();
+                    yul_block
+                        .statements
+                        .iter()
+                        .for_each(|stmt| yul_stmt_vars(stmt, &mut assigned, &mut referenced));
+                    assigned.append(&mut referenced);
+                    let res =
+                        self.apply_to_edges(ctx, *loc, arena, &|analyzer, arena, ctx, loc| {
+                            analyzer.widen_yul_vars(arena, ctx, loc, &assigned)
+                        });
+                    let _ = self.add_if_err(res);
+                }
             }
             Return(loc, maybe_ret_expr) => {
                 tracing::trace!("parsing return");
@@ -641,8 +681,57 @@ pub trait StatementParser:
                 tracing::trace!("parsing named revert");
                 todo!("revert named args")
             }
-            Emit(_loc, _emit_expr) => {}
-            Try(_loc, _try_expr, _maybe_returns, _clauses) => {}
+            Emit(loc, emit_expr) => {
+                tracing::trace!("parsing emit");
+                let (
+                    Some(parent),
+                    solang_parser::pt::Expression::FunctionCall(_, event_expr, args),
+                ) = (parent_ctx, emit_expr)
+                else {
+                    return;
+                };
+                let event_name = match &**event_expr {
+                    solang_parser::pt::Expression::Variable(ident)
+                    | solang_parser::pt::Expression::MemberAccess(_, _, ident) => {
+                        ident.name.clone()
+                    }
+                    other => other.to_string(),
+                };
+                let ctx = ContextNode::from(parent.into());
+                let res = self.parse_inputs(arena, ctx, *loc, args);
+                if self.widen_if_limit_hit(ctx, res) {
+                    return;
+                }
+                let res = self.apply_to_edges(ctx, *loc, arena, &|analyzer, _arena, ctx, loc| {
+                    let Some(ret) = ctx.pop_expr_latest(loc, analyzer).into_expr_err(loc)? else {
+                        return Err(ExprErr::NoRhs(
+                            loc,
+                            "Emit did not have associated arguments".to_string(),
+                        ));
+                    };
+                    if ret.is_killed() {
+                        ctx.push_expr(ret, analyzer).into_expr_err(loc)?;
+                        return Ok(());
+                    }
+                    let args = ret
+                        .as_flat_vec()
+                        .into_iter()
+                        .map(|idx| {
+                            ContextVarNode::from(idx)
+                                .latest_version_or_inherited_in_ctx(ctx, analyzer)
+                        })
+                        .collect();
+                    ctx.add_emit(loc, event_name.clone(), args, analyzer)
+                        .into_expr_err(loc)
+                });
+                let _ = self.add_if_err(res);
+            }
+            Try(loc, try_expr, maybe_success, clauses) => {
+                tracing::trace!("parsing try, {try_expr:?}");
+                let ctx = ContextNode::from(parent_ctx.expect("Dangling try statement").into());
+                let res = self.try_catch_stmt(arena, *loc, ctx, try_expr, maybe_success, clauses);
+                let _ = self.widen_if_limit_hit(ctx, res);
+            }
             Error(_loc) => {}
         }
     }
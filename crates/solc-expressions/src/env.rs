@@ -1,12 +1,13 @@
-use crate::{func_call::helper::CallerHelper, func_call::modifier::ModifierCaller};
+use crate::{func_call::helper::CallerHelper, func_call::modifier::ModifierCaller, BinOp};
 
 use graph::{
-    elem::Elem,
-    nodes::{Builtin, Concrete, ContextNode, ContextVar, ExprRet},
+    elem::{Elem, RangeOp},
+    nodes::{Builtin, Concrete, ContextNode, ContextVar, ContextVarNode, ExprRet},
     AnalyzerBackend, ContextEdge, Edge, Node,
 };
-use shared::{ExprErr, IntoExprErr, RangeArena, StorageLocation};
+use shared::{ExprErr, GraphError, IntoExprErr, RangeArena, StorageLocation};
 
+use ethers_core::types::U256;
 use solang_parser::pt::{Expression, Identifier, Loc};
 
 impl<T> Env for T where T: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {}
@@ -304,6 +305,18 @@ pub trait Env: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                     .latest_version_or_inherited_in_ctx(ctx, self)
                     .into(),
             ))
+        } else if let Some(caller_var) = self.caller_msg_var(ctx, &name).into_expr_err(loc)? {
+            let mut var = caller_var.underlying(self).into_expr_err(loc)?.clone();
+            var.loc = Some(loc);
+            let cvar = self.add_node(Node::ContextVar(var));
+            ctx.add_var(cvar.into(), self).into_expr_err(loc)?;
+            self.add_edge(cvar, ctx, Edge::Context(ContextEdge::Variable));
+            self.add_edge(
+                cvar,
+                caller_var,
+                Edge::Context(ContextEdge::InheritedVariable),
+            );
+            Ok(ExprRet::Single(cvar))
         } else {
             let (node, name) = match ident_name {
                 "data" => {
@@ -380,6 +393,13 @@ pub trait Env: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                             self.add_node(Node::Concrete(c)).into(),
                             "msg.value".to_string(),
                         )
+                    } else if self.rejects_value(ctx).into_expr_err(loc)? {
+                        // a call with value into a non-payable entry point reverts
+                        let c = Concrete::from(U256::zero());
+                        (
+                            self.add_node(Node::Concrete(c)).into(),
+                            "msg.value".to_string(),
+                        )
                     } else {
                         let node = self.builtin_or_add(Builtin::Uint(256));
                         let mut var = ContextVar::new_from_builtin(loc, node.into(), self)
@@ -476,4 +496,131 @@ pub trait Env: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
             Ok(ExprRet::Single(cvar))
         }
     }
+
+    /// Finds a `msg` variable already read by an internal caller of this context's function.
+    /// Internal calls share the caller's `msg`, so the search stops at external calls
+    fn caller_msg_var(
+        &self,
+        ctx: ContextNode,
+        name: &str,
+    ) -> Result<Option<ContextVarNode>, GraphError> {
+        let mut curr = ctx;
+        while let Some(parent) = curr.underlying(self)?.parent_ctx {
+            if curr.is_ext_fn(self)? {
+                return Ok(None);
+            }
+            if let Some(var) = parent.var_by_name(self, name) {
+                return Ok(Some(var.latest_version_or_inherited_in_ctx(parent, self)));
+            }
+            curr = parent;
+        }
+        Ok(None)
+    }
+
+    /// Whether the function that was entered to reach this context rejects calls with value, i.e.
+    /// it is a non-payable public, external or constructor function. Internal functions inherit
+    /// `msg.value` from whichever entry point called them, so they are never considered rejecting
+    fn rejects_value(&mut self, ctx: ContextNode) -> Result<bool, GraphError> {
+        let func = ctx.first_ancestor(self)?.associated_fn(self)?;
+        Ok(!func.is_payable(self)?
+            && (func.is_public_or_ext(self)? || func.is_constructor(self)?))
+    }
+
+    /// Gets the latest version of `address(this).balance` in the context, creating it if it
+    /// hasn't been read yet. If the entry function is payable, the value sent with the call is
+    /// already part of the balance
+    fn this_balance_access(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        ctx: ContextNode,
+    ) -> Result<ExprRet, ExprErr> {
+        let name = "address(this).balance";
+        if let Some(balance) = ctx.var_by_name_or_recurse(self, name).into_expr_err(loc)? {
+            return Ok(ExprRet::Single(
+                balance.latest_version_or_inherited_in_ctx(ctx, self).into(),
+            ));
+        }
+
+        let node = self.builtin_or_add(Builtin::Uint(256));
+        let mut var = ContextVar::new_from_builtin(loc, node.into(), self).into_expr_err(loc)?;
+        var.name = name.to_string();
+        var.display_name = name.to_string();
+        var.is_tmp = false;
+        var.is_symbolic = true;
+        let balance = ContextVarNode::from(self.add_node(Node::ContextVar(var)));
+        ctx.add_var(balance, self).into_expr_err(loc)?;
+        self.add_edge(balance, ctx, Edge::Context(ContextEdge::Variable));
+
+        // internal functions see the value sent to whichever entry point called them
+        if ctx
+            .first_ancestor(self)
+            .into_expr_err(loc)?
+            .associated_fn(self)
+            .into_expr_err(loc)?
+            .is_payable(self)
+            .into_expr_err(loc)?
+        {
+            let ExprRet::Single(value) = self.msg_access(loc, ctx, "value")? else {
+                return Err(ExprErr::NoRhs(
+                    loc,
+                    "Could not get msg.value for balance".to_string(),
+                ));
+            };
+            let ret = self.op(
+                arena,
+                loc,
+                balance,
+                ContextVarNode::from(value),
+                ctx,
+                RangeOp::Add(false),
+                true,
+            )?;
+            if matches!(ret, ExprRet::CtxKilled(_)) {
+                return Ok(ret);
+            }
+        }
+
+        Ok(ExprRet::Single(
+            balance.latest_version_or_inherited_in_ctx(ctx, self).into(),
+        ))
+    }
+
+    /// Models sending `value` wei out of this contract (i.e. `addr.transfer(value)`), reducing
+    /// `address(this).balance`. Sending more than the balance kills the context
+    fn this_balance_transfer_out(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        ctx: ContextNode,
+        value: ExprRet,
+    ) -> Result<ExprRet, ExprErr> {
+        let balance = match self.this_balance_access(arena, loc, ctx)? {
+            ExprRet::Single(balance) => ContextVarNode::from(balance),
+            killed => return Ok(killed),
+        };
+        let value = match value {
+            ExprRet::Single(value) => ContextVarNode::from(value),
+            ExprRet::SingleLiteral(value) => {
+                let value = ContextVarNode::from(value);
+                value.cast_from(&balance, self, arena).into_expr_err(loc)?;
+                value
+            }
+            e => {
+                return Err(ExprErr::UnhandledExprRet(
+                    loc,
+                    format!("Expected a single value to transfer, got: {e:?}"),
+                ))
+            }
+        };
+        self.op(
+            arena,
+            loc,
+            balance,
+            value.latest_version_or_inherited_in_ctx(ctx, self),
+            ctx,
+            RangeOp::Sub(false),
+            true,
+        )
+    }
 }
@@ -3,7 +3,8 @@
 use crate::{
     func_call::apply::FuncApplier, func_call::modifier::ModifierCaller, helper::CallerHelper,
     internal_call::InternalFuncCaller, intrinsic_call::IntrinsicFuncCaller,
-    namespaced_call::NameSpaceFuncCaller, ContextBuilder, ExpressionParser, StatementParser,
+    namespaced_call::NameSpaceFuncCaller, variable::Variable, ContextBuilder, ExpressionParser,
+    StatementParser,
 };
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -454,6 +455,14 @@ pub trait FuncCaller:
         let renamed_inputs =
             self.map_inputs_to_params(arena, loc, entry_call, params, inputs, callee_ctx)?;
 
+        if !entry_call
+            && ctx.recursion_depth(func_node, self).into_expr_err(loc)?
+                >= self.max_recursion_depth()
+        {
+            // recursed too deep to keep inlining, so return the full range of the return types
+            return self.summarize_call(arena, loc, ctx, callee_ctx, func_node, func_call_str);
+        }
+
         // begin modifier handling by making sure modifiers were set
         if !func_node.modifiers_set(self).into_expr_err(loc)? {
             self.set_modifiers(arena, func_node, ctx)?;
@@ -559,65 +568,102 @@ pub trait FuncCaller:
                 Ok(())
             }
         } else {
-            let ret_ctx = Context::new_subctx(
-                callee_ctx,
-                Some(caller_ctx),
-                loc,
-                None,
-                None,
-                false,
-                self,
-                caller_ctx
-                    .underlying(self)
-                    .into_expr_err(loc)?
-                    .modifier_state
-                    .clone(),
-            )
-            .unwrap();
-            let ret_subctx = ContextNode::from(self.add_node(Node::Context(ret_ctx)));
-            ret_subctx
-                .set_continuation_ctx(self, caller_ctx, "execute_call_inner")
-                .into_expr_err(loc)?;
-
-            let res = callee_ctx
-                .set_child_call(ret_subctx, self)
-                .into_expr_err(loc);
-            let _ = self.add_if_err(res);
-            self.apply_to_edges(callee_ctx, loc, arena, &|analyzer, arena, ctx, loc| {
-                #[allow(clippy::unnecessary_to_owned)]
-                func_node
-                    .returns(arena, analyzer)
-                    .into_iter()
-                    .try_for_each(|ret| {
-                        let underlying = ret.underlying(analyzer).unwrap();
-                        let mut var =
-                            ContextVar::new_from_func_ret(ctx, analyzer, underlying.clone())
-                                .unwrap()
-                                .expect("No type for return variable?");
-                        if let Some(func_call) = &func_call_str {
-                            var.name =
-                                format!("{}_{}", func_call, callee_ctx.new_tmp(analyzer).unwrap());
-                            var.display_name = func_call.to_string();
-                        }
-
-                        if ctx.contains_var(&var.name, analyzer).into_expr_err(loc)? {
-                            var.name = format!(
-                                "{}_ret{}",
-                                var.name,
-                                ctx.new_tmp(analyzer).into_expr_err(loc)?
-                            );
-                            var.display_name.clone_from(&var.name);
-                        }
-
-                        let node = analyzer.add_node(Node::ContextVar(var));
-                        ctx.add_var(node.into(), analyzer).into_expr_err(loc)?;
-                        analyzer.add_edge(node, ctx, Edge::Context(ContextEdge::Variable));
-                        analyzer.add_edge(node, ctx, Edge::Context(ContextEdge::Return));
-                        ctx.push_expr(ExprRet::Single(node), analyzer)
-                            .into_expr_err(loc)?;
-                        Ok(())
-                    })
-            })
+            self.summarize_call(arena, loc, caller_ctx, callee_ctx, func_node, func_call_str)
         }
     }
+
+    /// Returns from a call without executing it, with return variables that take the full
+    /// range of their types. The call may write any storage, so every storage variable in scope
+    /// is reset to the full range of its type as well
+    fn summarize_call(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        caller_ctx: ContextNode,
+        callee_ctx: ContextNode,
+        func_node: FunctionNode,
+        func_call_str: Option<&str>,
+    ) -> Result<(), ExprErr> {
+        let ret_ctx = Context::new_subctx(
+            callee_ctx,
+            Some(caller_ctx),
+            loc,
+            None,
+            None,
+            false,
+            self,
+            caller_ctx
+                .underlying(self)
+                .into_expr_err(loc)?
+                .modifier_state
+                .clone(),
+        )
+        .unwrap();
+        let ret_subctx = ContextNode::from(self.add_node(Node::Context(ret_ctx)));
+        ret_subctx
+            .set_continuation_ctx(self, caller_ctx, "execute_call_inner")
+            .into_expr_err(loc)?;
+
+        let res = callee_ctx
+            .set_child_call(ret_subctx, self)
+            .into_expr_err(loc);
+        let _ = self.add_if_err(res);
+        self.apply_to_edges(callee_ctx, loc, arena, &|analyzer, arena, ctx, loc| {
+            let storage = analyzer.storage_vars_in_scope(ctx).into_expr_err(loc)?;
+            storage.into_values().try_for_each(|var| {
+                let Some(range) = var
+                    .underlying(analyzer)
+                    .into_expr_err(loc)?
+                    .ty
+                    .default_range(analyzer)
+                    .into_expr_err(loc)?
+                else {
+                    return Ok(());
+                };
+                let new_var = analyzer.advance_var_in_ctx(var, loc, ctx)?;
+                let res = new_var
+                    .set_range_min(analyzer, arena, range.min)
+                    .into_expr_err(loc);
+                let _ = analyzer.add_if_err(res);
+                let res = new_var
+                    .set_range_max(analyzer, arena, range.max)
+                    .into_expr_err(loc);
+                let _ = analyzer.add_if_err(res);
+                Ok::<(), ExprErr>(())
+            })?;
+
+            #[allow(clippy::unnecessary_to_owned)]
+            func_node
+                .returns(arena, analyzer)
+                .into_iter()
+                .try_for_each(|ret| {
+                    let underlying = ret.underlying(analyzer).unwrap();
+                    let mut var = ContextVar::new_from_func_ret(ctx, analyzer, underlying.clone())
+                        .unwrap()
+                        .expect("No type for return variable?");
+                    if let Some(func_call) = &func_call_str {
+                        var.name =
+                            format!("{}_{}", func_call, callee_ctx.new_tmp(analyzer).unwrap());
+                        var.display_name = func_call.to_string();
+                    }
+
+                    if ctx.contains_var(&var.name, analyzer).into_expr_err(loc)? {
+                        var.name = format!(
+                            "{}_ret{}",
+                            var.name,
+                            ctx.new_tmp(analyzer).into_expr_err(loc)?
+                        );
+                        var.display_name.clone_from(&var.name);
+                    }
+
+                    let node = analyzer.add_node(Node::ContextVar(var));
+                    ctx.add_var(node.into(), analyzer).into_expr_err(loc)?;
+                    analyzer.add_edge(node, ctx, Edge::Context(ContextEdge::Variable));
+                    analyzer.add_edge(node, ctx, Edge::Context(ContextEdge::Return));
+                    ctx.push_expr(ExprRet::Single(node), analyzer)
+                        .into_expr_err(loc)?;
+                    Ok(())
+                })
+        })
+    }
 }
@@ -9,11 +9,15 @@ use graph::{
     },
     AnalyzerBackend, ContextEdge, Edge, Node, Range, VarType,
 };
-use shared::{ExprErr, IntoExprErr, NodeIdx, RangeArena, StorageLocation};
+use shared::{ExprErr, GraphError, IntoExprErr, NodeIdx, RangeArena, StorageLocation};
 
 use solang_parser::pt::{CodeLocation, Expression, Loc};
 
-use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    rc::Rc,
+};
 
 impl<T> CallerHelper for T where T: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {}
 /// Helper trait for performing function calls
@@ -432,6 +436,7 @@ pub trait CallerHelper: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> +
                     .set_child_call(ret_subctx, self)
                     .into_expr_err(loc);
                 let _ = self.add_if_err(res);
+                self.return_storage_changes(loc, caller_ctx, callee_ctx, ret_subctx)?;
 
                 let mut rets = callee_ctx.underlying(self).unwrap().ret.clone();
 
@@ -544,6 +549,70 @@ pub trait CallerHelper: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> +
         }
     }
 
+    /// The latest versions of the storage variables a context has looked up so far, in it or any
+    /// of the contexts it descends from, by name
+    fn storage_vars_in_scope(
+        &self,
+        ctx: ContextNode,
+    ) -> Result<BTreeMap<String, ContextVarNode>, GraphError> {
+        let mut vars = BTreeMap::new();
+        for scope in std::iter::once(ctx).chain(ctx.parent_list(self)?) {
+            for (name, var) in scope.local_vars(self).iter() {
+                if !vars.contains_key(name) && var.is_storage(self)? {
+                    vars.insert(
+                        name.clone(),
+                        var.latest_version_or_inherited_in_ctx(ctx, self),
+                    );
+                }
+            }
+        }
+        Ok(vars)
+    }
+
+    /// Brings the storage variables a call changed into the context the caller resumes in. The
+    /// caller looks variables up through its own contexts, so without this it would keep reading
+    /// the versions from before the call, or miss contract storage it hadn't read yet
+    fn return_storage_changes(
+        &mut self,
+        loc: Loc,
+        caller_ctx: ContextNode,
+        callee_ctx: ContextNode,
+        ret_subctx: ContextNode,
+    ) -> Result<(), ExprErr> {
+        let contract_vars = callee_ctx
+            .maybe_associated_contract(self)
+            .into_expr_err(loc)?
+            .map(|contract| contract.all_storage_vars(self))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|var| var.name(self))
+            .collect::<Result<BTreeSet<_>, _>>()
+            .into_expr_err(loc)?;
+        self.storage_vars_in_scope(callee_ctx)
+            .into_expr_err(loc)?
+            .into_iter()
+            .try_for_each(|(name, callee_var)| {
+                let changed = match caller_ctx
+                    .var_by_name_or_recurse(self, &name)
+                    .into_expr_err(loc)?
+                {
+                    Some(caller_var) => {
+                        caller_var.latest_version_or_inherited_in_ctx(caller_ctx, self)
+                            != callee_var
+                    }
+                    // storage references local to the call don't outlive it
+                    None => {
+                        let base = name.split(['.', '[']).next().unwrap_or(&name);
+                        contract_vars.contains(base)
+                    }
+                };
+                if changed && callee_var.next_version(self).is_none() {
+                    self.advance_var_in_ctx(callee_var, loc, ret_subctx)?;
+                }
+                Ok(())
+            })
+    }
+
     /// Inherit the input changes from a function call
     fn inherit_input_changes(
         &mut self,
@@ -8,8 +8,12 @@ use graph::{
 };
 use shared::{ExprErr, IntoExprErr, NodeIdx, RangeArena};
 
+use ethers_core::types::U256;
 use solang_parser::pt::{Expression, Loc};
 
+/// The largest length of a `new` list whose elements are tracked as zero
+const MAX_ZEROED_LEN: usize = 256;
+
 impl<T> ConstructorCaller for T where
     T: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized + CallerHelper
 {
@@ -51,6 +55,7 @@ pub trait ConstructorCaller:
                 is_tmp: true,
                 is_symbolic: false,
                 is_return: false,
+                source_repr: None,
                 tmp_of: None,
                 dep_on: None,
                 ty: ty.expect("No type for node"),
@@ -68,6 +73,7 @@ pub trait ConstructorCaller:
                 dep_on: None,
                 is_symbolic: true,
                 is_return: false,
+                source_repr: None,
                 ty: ContextVarNode::from(len_cvar)
                     .underlying(analyzer)
                     .into_expr_err(loc)?
@@ -86,6 +92,32 @@ pub trait ConstructorCaller:
                 Edge::Context(ContextEdge::AttrAccess("length")),
             );
 
+            // every element of a new list is its type's zero value. Positions are keyed
+            // concretely, so they are only filled in when the length is known to be small
+            let elem_zero = match arr
+                .ty(analyzer)
+                .into_expr_err(loc)?
+                .clone()
+                .dynamic_underlying_ty(analyzer)
+            {
+                Ok(VarType::BuiltIn(elem_ty, _)) => {
+                    elem_ty.zero_range(analyzer).into_expr_err(loc)?
+                }
+                _ => None,
+            };
+            let max_len = ContextVarNode::from(len_cvar)
+                .evaled_range_max(analyzer, arena)
+                .into_expr_err(loc)?
+                .and_then(|max| max.maybe_concrete())
+                .and_then(|max| max.val.into_u256())
+                .filter(|max| *max <= U256::from(MAX_ZEROED_LEN));
+            let zeroed: Vec<(Elem<Concrete>, Elem<Concrete>)> = match (elem_zero, max_len) {
+                (Some(zero), Some(max_len)) => (0..max_len.as_usize())
+                    .map(|i| (Elem::from(Concrete::from(U256::from(i))), zero.min.clone()))
+                    .collect(),
+                _ => vec![],
+            };
+
             // update the length
             if let Some(r) = arr.ref_range(analyzer).into_expr_err(loc)? {
                 let min = r.evaled_range_min(analyzer, arena).into_expr_err(loc)?;
@@ -93,13 +125,21 @@ pub trait ConstructorCaller:
 
                 if let Some(mut rd) = min.maybe_range_dyn() {
                     rd.len = Box::new(Elem::from(len_cvar));
+                    for (index, zero) in zeroed.iter().cloned() {
+                        rd.val.insert(index, (zero, rd.op_num));
+                        rd.op_num += 1;
+                    }
                     arr.set_range_min(analyzer, arena, Elem::ConcreteDyn(rd))
                         .into_expr_err(loc)?;
                 }
 
                 if let Some(mut rd) = max.maybe_range_dyn() {
                     rd.len = Box::new(Elem::from(len_cvar));
-                    arr.set_range_min(analyzer, arena, Elem::ConcreteDyn(rd))
+                    for (index, zero) in zeroed {
+                        rd.val.insert(index, (zero, rd.op_num));
+                        rd.op_num += 1;
+                    }
+                    arr.set_range_max(analyzer, arena, Elem::ConcreteDyn(rd))
                         .into_expr_err(loc)?;
                 }
             }
@@ -82,8 +82,10 @@ pub trait IntrinsicFuncCaller:
             };
             let ty_idx = ty.expect_single().into_expr_err(loc)?;
             match analyzer.node(ty_idx) {
-                Node::Builtin(Builtin::Array(_)) | Node::Builtin(Builtin::DynamicBytes) => {
-                    // construct a new list
+                Node::Builtin(Builtin::Array(_))
+                | Node::Builtin(Builtin::DynamicBytes)
+                | Node::Builtin(Builtin::String) => {
+                    // construct a new list, with a length of the input's range
                     analyzer.construct_array(arena,ty_idx, &NamedOrUnnamedArgs::Unnamed(inputs), loc, ctx)
                 }
                 Node::Contract(_c) => {
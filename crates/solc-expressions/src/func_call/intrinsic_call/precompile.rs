@@ -4,11 +4,16 @@ use graph::nodes::FunctionNode;
 
 use graph::{
     elem::Elem,
-    nodes::{Builtin, Concrete, Context, ContextNode, ContextVar, ContextVarNode, ExprRet},
+    nodes::{
+        Builtin, Concrete, ConcreteNode, Context, ContextNode, ContextVar, ContextVarNode, ExprRet,
+    },
     AnalyzerBackend, ContextEdge, Edge, Node,
 };
 use shared::{ExprErr, IntoExprErr, NodeIdx, RangeArena};
 
+use ethers_core::types::H256;
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
 use solang_parser::pt::{Expression, Loc};
 
 impl<T> PrecompileCaller for T where
@@ -33,52 +38,44 @@ pub trait PrecompileCaller:
         match &*func_name {
             "sha256" => {
                 self.parse_ctx_expr(arena, &input_exprs.unnamed_args().unwrap()[0], ctx)?;
-                self.apply_to_edges(ctx, loc, arena, &|analyzer, _arena, ctx, loc| {
+                self.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, loc| {
                     let Some(input) = ctx.pop_expr_latest(loc, analyzer).into_expr_err(loc)? else {
                         return Err(ExprErr::NoRhs(
                             loc,
                             "sha256 call was not given input".to_string(),
                         ));
                     };
-                    if matches!(input, ExprRet::CtxKilled(_)) {
-                        ctx.push_expr(input, analyzer).into_expr_err(loc)?;
-                        return Ok(());
-                    }
-                    let var = ContextVar::new_from_builtin(
+                    analyzer.hash_call(
+                        arena,
+                        input,
+                        Builtin::Bytes(32),
+                        |bytes| Concrete::from(H256::from_slice(&Sha256::digest(bytes))),
                         loc,
-                        analyzer.builtin_or_add(Builtin::Bytes(32)).into(),
-                        analyzer,
+                        ctx,
                     )
-                    .into_expr_err(loc)?;
-                    let cvar = analyzer.add_node(Node::ContextVar(var));
-                    ctx.push_expr(ExprRet::Single(cvar), analyzer)
-                        .into_expr_err(loc)?;
-                    Ok(())
                 })
             }
             "ripemd160" => {
                 self.parse_ctx_expr(arena, &input_exprs.unnamed_args().unwrap()[0], ctx)?;
-                self.apply_to_edges(ctx, loc, arena, &|analyzer, _arena, ctx, loc| {
+                self.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, loc| {
                     let Some(input) = ctx.pop_expr_latest(loc, analyzer).into_expr_err(loc)? else {
                         return Err(ExprErr::NoRhs(
                             loc,
                             "ripemd160 was not given input".to_string(),
                         ));
                     };
-                    if matches!(input, ExprRet::CtxKilled(_)) {
-                        ctx.push_expr(input, analyzer).into_expr_err(loc)?;
-                        return Ok(());
-                    }
-                    let var = ContextVar::new_from_builtin(
+                    analyzer.hash_call(
+                        arena,
+                        input,
+                        Builtin::Bytes(20),
+                        |bytes| {
+                            let mut out = [0; 20];
+                            out.copy_from_slice(&Ripemd160::digest(bytes));
+                            Concrete::from(out)
+                        },
                         loc,
-                        analyzer.builtin_or_add(Builtin::Bytes(32)).into(),
-                        analyzer,
+                        ctx,
                     )
-                    .into_expr_err(loc)?;
-                    let cvar = analyzer.add_node(Node::ContextVar(var));
-                    ctx.push_expr(ExprRet::Single(cvar), analyzer)
-                        .into_expr_err(loc)?;
-                    Ok(())
                 })
             }
             "ecrecover" => {
@@ -201,4 +198,42 @@ pub trait PrecompileCaller:
             )),
         }
     }
+
+    /// Pushes the result of a hashing intrinsic, like `sha256`. A constant input is hashed
+    /// with `hasher`, otherwise the result is an unknown `ty`
+    fn hash_call(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        input: ExprRet,
+        ty: Builtin,
+        hasher: impl Fn(&[u8]) -> Concrete,
+        loc: Loc,
+        ctx: ContextNode,
+    ) -> Result<(), ExprErr> {
+        if matches!(input, ExprRet::CtxKilled(_)) {
+            ctx.push_expr(input, self).into_expr_err(loc)?;
+            return Ok(());
+        }
+        let cvar = ContextVarNode::from(input.expect_single().into_expr_err(loc)?);
+
+        let bytes = if cvar.is_const(self, arena).into_expr_err(loc)? {
+            cvar.evaled_range_min(self, arena)
+                .into_expr_err(loc)?
+                .and_then(|min| min.as_bytes(self, true, arena))
+        } else {
+            None
+        };
+
+        let var = if let Some(bytes) = bytes {
+            let hash = ConcreteNode::from(self.add_node(Node::Concrete(hasher(&bytes))));
+            ContextVar::new_from_concrete(loc, ctx, hash, self).into_expr_err(loc)?
+        } else {
+            ContextVar::new_from_builtin(loc, self.builtin_or_add(ty).into(), self)
+                .into_expr_err(loc)?
+        };
+        let cvar = self.add_node(Node::ContextVar(var));
+        ctx.push_expr(ExprRet::Single(cvar), self)
+            .into_expr_err(loc)?;
+        Ok(())
+    }
 }
@@ -1,9 +1,12 @@
 use crate::func_caller::NamedOrUnnamedArgs;
-use crate::{func_call::helper::CallerHelper, require::Require, ContextBuilder, ExpressionParser};
+use crate::{
+    func_call::helper::CallerHelper, intrinsic_call::PrecompileCaller, require::Require,
+    ContextBuilder, ExpressionParser,
+};
 
 use graph::{
     elem::Elem,
-    nodes::{Builtin, Concrete, ConcreteNode, ContextNode, ContextVar, ContextVarNode, ExprRet},
+    nodes::{Builtin, Concrete, ContextNode, ContextVar, ExprRet, GuardKind},
     AnalyzerBackend, Node,
 };
 use shared::{ExprErr, IntoExprErr, RangeArena};
@@ -37,42 +40,18 @@ pub trait SolidityCaller:
                         return Err(ExprErr::NoRhs(loc, "No input into keccak256".to_string()));
                     };
 
-                    let cvar = if let Ok(var) = input.expect_single() {
-                        ContextVarNode::from(var)
-                    } else {
-                        return Err(ExprErr::NoRhs(loc, "No input into keccak256".to_string()));
-                    };
-
-                    if cvar.is_const(analyzer, arena).into_expr_err(loc)? {
-                        let bytes = cvar
-                            .evaled_range_min(analyzer, arena)
-                            .unwrap()
-                            .unwrap()
-                            .as_bytes(analyzer, true, arena)
-                            .unwrap();
-                        let mut out = [0; 32];
-                        keccak_hash::keccak_256(&bytes, &mut out);
-
-                        let hash = Node::Concrete(Concrete::from(H256(out)));
-                        let hash_node = ConcreteNode::from(analyzer.add_node(hash));
-                        let var = ContextVar::new_from_concrete(loc, ctx, hash_node, analyzer)
-                            .into_expr_err(loc)?;
-                        let cvar = analyzer.add_node(Node::ContextVar(var));
-                        ctx.push_expr(ExprRet::Single(cvar), analyzer)
-                            .into_expr_err(loc)?;
-                    } else {
-                        let var = ContextVar::new_from_builtin(
-                            loc,
-                            analyzer.builtin_or_add(Builtin::Bytes(32)).into(),
-                            analyzer,
-                        )
-                        .into_expr_err(loc)?;
-                        let cvar = analyzer.add_node(Node::ContextVar(var));
-                        ctx.push_expr(ExprRet::Single(cvar), analyzer)
-                            .into_expr_err(loc)?;
-                    }
-
-                    Ok(())
+                    analyzer.hash_call(
+                        arena,
+                        input,
+                        Builtin::Bytes(32),
+                        |bytes| {
+                            let mut out = [0; 32];
+                            keccak_hash::keccak_256(bytes, &mut out);
+                            Concrete::from(H256(out))
+                        },
+                        loc,
+                        ctx,
+                    )
                 })
             }
             "addmod" => {
@@ -111,7 +90,13 @@ pub trait SolidityCaller:
                 })
             }
             "require" | "assert" => {
-                self.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, _loc| {
+                let kind = if func_name == "assert" {
+                    GuardKind::Assert
+                } else {
+                    GuardKind::Require
+                };
+                self.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, loc| {
+                    ctx.add_guard(loc, kind, analyzer).into_expr_err(loc)?;
                     analyzer.handle_require(arena, input_exprs.unnamed_args().unwrap(), ctx)
                 })
             }
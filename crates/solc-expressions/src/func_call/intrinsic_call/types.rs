@@ -178,7 +178,10 @@ pub trait TypesCaller: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + S
                     let v_ty = VarType::try_from_idx(analyzer, func_idx).expect("");
                     let maybe_new_range =
                         cvar.cast_exprs(&v_ty, analyzer, arena).into_expr_err(loc)?;
-                    new_var.underlying_mut(analyzer).into_expr_err(loc)?.ty = v_ty;
+                    let underlying = new_var.underlying_mut(analyzer).into_expr_err(loc)?;
+                    underlying.ty = v_ty;
+                    // an explicit cast may change the value, so it no longer matches a literal's text
+                    underlying.source_repr = None;
 
                     if let Some((new_min, new_max)) = maybe_new_range {
                         new_var
@@ -6,7 +6,7 @@ use crate::{
     func_call::helper::CallerHelper,
     intrinsic_call::IntrinsicFuncCaller,
     member_access::MemberAccess,
-    ContextBuilder, ExpressionParser,
+    ContextBuilder, Env, ExpressionParser,
 };
 use graph::nodes::{Concrete, ContextVar};
 use graph::ContextEdge;
@@ -224,6 +224,32 @@ pub trait NameSpaceFuncCaller:
             ident.name
         );
 
+        if ident.name == "transfer"
+            && input_exprs.len() == 1
+            && matches!(self.node(member), Node::ContextVar(_))
+            && ContextVarNode::from(member)
+                .is_address(self)
+                .into_expr_err(loc)?
+        {
+            input_exprs.parse(arena, self, ctx, loc)?;
+            return self.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, loc| {
+                let Some(input) = ctx.pop_expr_latest(loc, analyzer).into_expr_err(loc)? else {
+                    return Err(ExprErr::NoRhs(
+                        loc,
+                        "Address transfer had no value".to_string(),
+                    ));
+                };
+                if matches!(input, ExprRet::CtxKilled(_)) {
+                    return ctx.push_expr(input, analyzer).into_expr_err(loc);
+                }
+                match analyzer.this_balance_transfer_out(arena, loc, ctx, input.flatten())? {
+                    killed @ ExprRet::CtxKilled(_) => ctx.push_expr(killed, analyzer),
+                    _ => ctx.push_expr(ExprRet::Multi(vec![]), analyzer),
+                }
+                .into_expr_err(loc)
+            });
+        }
+
         let funcs = self.visible_member_funcs(ctx, loc, member)?;
         // filter down all funcs to those that match
         let possible_funcs = funcs
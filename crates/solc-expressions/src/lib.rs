@@ -13,7 +13,9 @@ mod literal;
 mod loops;
 mod member_access;
 mod pre_post_in_decrement;
+mod relations;
 mod require;
+mod try_catch;
 mod variable;
 pub mod yul;
 
@@ -30,7 +32,9 @@ pub use literal::*;
 pub use loops::*;
 pub use member_access::*;
 pub use pre_post_in_decrement::*;
+pub use relations::*;
 pub use require::*;
+pub use try_catch::*;
 pub use variable::*;
 
 /// Supertrait for parsing expressions
@@ -47,6 +51,7 @@ pub trait ExprTyParser:
     + Env
     + PrePostIncDecrement
     + Assign
+    + Relations
 {
 }
 impl<T> ExprTyParser for T where
@@ -62,5 +67,6 @@ impl<T> ExprTyParser for T where
         + Env
         + PrePostIncDecrement
         + Assign
+        + Relations
 {
 }
@@ -78,6 +78,7 @@ pub trait List: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                         tmp_of: None,
                         dep_on: None,
                         is_return: false,
+                        source_repr: None,
                         ty,
                     };
                     let input_node = self.add_node(Node::ContextVar(var));
@@ -104,6 +105,7 @@ pub trait List: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
                                 tmp_of: None,
                                 dep_on: None,
                                 is_return: false,
+                                source_repr: None,
                                 ty,
                             };
                             let input_node = self.add_node(Node::ContextVar(new_lhs_underlying));
@@ -1,19 +1,51 @@
 use graph::{
     elem::*,
-    nodes::{Concrete, ConcreteNode, ContextNode, ContextVar, ContextVarNode, ExprRet},
-    AnalyzerBackend, ContextEdge, Edge, Node, TestCommand, VariableCommand,
+    nodes::{
+        Builtin, Concrete, ConcreteNode, ContextNode, ContextVar, ContextVarNode, EnumNode, ExprRet,
+    },
+    AnalyzerBackend, ContextEdge, Edge, Node, TestCommand, VarType, VariableCommand,
 };
-use shared::{ExprErr, IntoExprErr, RangeArena};
+use shared::{merge_locs, ExprErr, GraphError, IntoExprErr, RangeArena};
 
 use ethers_core::types::{Address, H256, I256, U256};
 use solang_parser::pt::{HexLiteral, Identifier, Loc};
 
-use std::str::FromStr;
+use std::{cmp::Ordering, collections::BTreeMap, str::FromStr};
+
+/// The smallest `uintN` size, in bits, that fits a value
+pub fn min_uint_size(val: U256) -> u16 {
+    (((32 - (val.leading_zeros() / 8)) * 8).max(8)) as u16
+}
+
+/// The smallest `intN` size, in bits, that fits a value. Unlike the unsigned size this accounts
+/// for the sign bit, so `-128` fits an `int8` but `128` and `-129` need an `int16`
+pub fn min_int_size(val: I256) -> u16 {
+    // the bits past the sign bit are the leading zeros of the value, or of its complement if
+    // negative
+    let raw = if val.is_negative() {
+        !val.into_raw()
+    } else {
+        val.into_raw()
+    };
+    let bits = 256 - raw.leading_zeros() + 1;
+    (bits.div_ceil(8) * 8).clamp(8, 256) as u16
+}
 
 impl<T> Literal for T where T: AnalyzerBackend + Sized {}
 
 /// Dealing with literal expression and parsing them into nodes
 pub trait Literal: AnalyzerBackend + Sized {
+    /// The source text of a number literal as written, i.e. `1_000 ether` or `0xFF`, prefixed
+    /// with `-` if it is negated. `None` if the literal isn't in a parsed source
+    fn number_source_repr(&self, loc: Loc, negative: bool) -> Option<String> {
+        let file_no = loc.try_file_no()?;
+        let text = self
+            .file_mapping()
+            .get(&file_no)?
+            .get(loc.start()..loc.end())?;
+        Some(format!("{}{text}", if negative { "-" } else { "" }))
+    }
+
     fn concrete_number_from_str(
         &mut self,
         loc: Loc,
@@ -31,18 +63,44 @@ pub trait Literal: AnalyzerBackend + Sized {
         let val = if !exponent.is_empty() {
             let exp = U256::from_dec_str(exponent)
                 .map_err(|e| ExprErr::ParseError(loc, e.to_string()))?;
-            int * U256::from(10).pow(exp)
+            if int.is_zero() {
+                int
+            } else {
+                U256::from(10)
+                    .checked_pow(exp)
+                    .and_then(|pow| int.checked_mul(pow))
+                    .ok_or_else(|| {
+                        ExprErr::ParseError(
+                            loc,
+                            format!(
+                                "{integer}e{exponent} is too large, it does not fit into a uint256"
+                            ),
+                        )
+                    })?
+            }
         } else {
             int
         };
 
         let val = if let Some(unit) = unit {
-            val * self.unit_to_uint(unit)
+            val.checked_mul(self.unit_to_uint(loc, unit)?)
+                .ok_or_else(|| {
+                    ExprErr::ParseError(
+                        loc,
+                        format!(
+                            "{val} {} is too large, it does not fit into a uint256",
+                            unit.name
+                        ),
+                    )
+                })?
         } else {
             val
         };
 
-        let size: u16 = ((32 - (val.leading_zeros() / 8)) * 8).max(8) as u16;
+        if negative && val.is_zero() {
+            return Ok(Self::negative_zero());
+        }
+
         if negative {
             let val = if val == U256::from(2).pow(255.into()) {
                 // no need to set upper bit
@@ -57,11 +115,17 @@ pub trait Literal: AnalyzerBackend + Sized {
                 }
                 I256::from(-1i32) * raw
             };
-            Ok(Concrete::Int(size, val))
+            Ok(Concrete::Int(min_int_size(val), val))
         } else {
-            Ok(Concrete::Uint(size, val))
+            Ok(Concrete::Uint(min_uint_size(val), val))
         }
     }
+    /// The single representation of a negated zero literal (i.e. `-0`), which compares equal to
+    /// an unsigned zero
+    fn negative_zero() -> Concrete {
+        Concrete::Int(8, I256::zero())
+    }
+
     fn number_literal(
         &mut self,
         ctx: ContextNode,
@@ -73,10 +137,10 @@ pub trait Literal: AnalyzerBackend + Sized {
     ) -> Result<(), ExprErr> {
         let conc = self.concrete_number_from_str(loc, integer, exponent, negative, unit)?;
         let concrete_node = ConcreteNode::from(self.add_node(Node::Concrete(conc)));
-        let ccvar = Node::ContextVar(
-            ContextVar::new_from_concrete(loc, ctx, concrete_node, self).into_expr_err(loc)?,
-        );
-        let node = self.add_node(ccvar);
+        let mut ccvar =
+            ContextVar::new_from_concrete(loc, ctx, concrete_node, self).into_expr_err(loc)?;
+        ccvar.source_repr = self.number_source_repr(loc, negative);
+        let node = self.add_node(Node::ContextVar(ccvar));
         ctx.add_var(node.into(), self).into_expr_err(loc)?;
         self.add_edge(node, ctx, Edge::Context(ContextEdge::Variable));
         ctx.push_expr(ExprRet::SingleLiteral(node), self)
@@ -84,15 +148,21 @@ pub trait Literal: AnalyzerBackend + Sized {
         Ok(())
     }
 
-    fn unit_to_uint(&self, unit: &Identifier) -> U256 {
+    fn unit_to_uint(&self, loc: Loc, unit: &Identifier) -> Result<U256, ExprErr> {
         match &*unit.name {
-            "gwei" => U256::from(10).pow(9.into()),
-            "ether" => U256::from(10).pow(18.into()),
-            "minutes" => U256::from(60),
-            "hours" => U256::from(3600),
-            "days" => U256::from(86400),
-            "weeks" => U256::from(604800),
-            _ => U256::from(1),
+            "gwei" => Ok(U256::from(10).pow(9.into())),
+            "ether" => Ok(U256::from(10).pow(18.into())),
+            "minutes" => Ok(U256::from(60)),
+            "hours" => Ok(U256::from(3600)),
+            "days" => Ok(U256::from(86400)),
+            "weeks" => Ok(U256::from(604800)),
+            "years" => Err(ExprErr::ParseError(
+                loc,
+                "The `years` unit was removed in Solidity 0.5.0 because of leap years, use an \
+                 explicit number of seconds (i.e. `365 days`) instead"
+                    .to_string(),
+            )),
+            _ => Ok(U256::from(1)),
         }
     }
 
@@ -116,36 +186,55 @@ pub trait Literal: AnalyzerBackend + Sized {
             U256::from(0)
         };
         let fraction_len = fraction.len();
-        let fraction_denom = U256::from(10).pow(fraction_len.into());
+        let unrepresentable = || {
+            ExprErr::ParseError(
+                loc,
+                format!("Invalid rational number: a fraction of {fraction_len} digits with an exponent of {exp} does not fit into a uint256"),
+            )
+        };
+        let fraction_denom = U256::from(10)
+            .checked_pow(fraction_len.into())
+            .ok_or_else(unrepresentable)?;
         let fraction =
             U256::from_dec_str(fraction).map_err(|e| ExprErr::ParseError(loc, e.to_string()))?;
 
         let unit_num = if let Some(unit) = unit {
-            self.unit_to_uint(unit)
+            self.unit_to_uint(loc, unit)?
         } else {
             U256::from(1)
         };
 
-        let int_elem = Elem::max(
-            Elem::from(Concrete::from(int)),
-            Elem::from(Concrete::from(U256::from(1))),
-        );
-
         // move the decimal place to the right
-        let mut rational_range = int_elem * Elem::from(Concrete::from(fraction_denom));
+        let mut rational_range =
+            Elem::from(Concrete::from(int)) * Elem::from(Concrete::from(fraction_denom));
         // add the fraction
         rational_range = rational_range + Elem::from(Concrete::from(fraction));
-        let mut rhs_power_res = U256::from(10).pow(exp) * unit_num;
 
-        if fraction > rhs_power_res {
-            return Err(ExprErr::ParseError(
-                loc,
-                format!("Invalid rational number: fraction part ({fraction}) has more precision than exponent ({exp}) and unit provide ({unit_num})"),
-            ));
-        }
+        // the digits are scaled by the exponent net of the places the decimal was moved over
+        let rhs_power_res = if exp >= U256::from(fraction_len) {
+            U256::from(10)
+                .checked_pow(exp - fraction_len)
+                .and_then(|pow| pow.checked_mul(unit_num))
+                .ok_or_else(unrepresentable)?
+        } else {
+            let power = U256::from(10)
+                .checked_pow(exp)
+                .and_then(|pow| pow.checked_mul(unit_num))
+                .ok_or_else(unrepresentable)?;
+            if fraction > power {
+                return Err(ExprErr::ParseError(
+                    loc,
+                    format!("Invalid rational number: fraction part ({fraction}) has more precision than exponent ({exp}) and unit provide ({unit_num})"),
+                ));
+            }
+            power / fraction_denom
+        };
 
-        // decrease the exponentiation by the number of places we moved the decimal over
-        rhs_power_res /= fraction_denom;
+        // the value itself has to fit as well
+        int.checked_mul(fraction_denom)
+            .and_then(|digits| digits.checked_add(fraction))
+            .and_then(|digits| digits.checked_mul(rhs_power_res))
+            .ok_or_else(unrepresentable)?;
 
         rational_range = rational_range * Elem::from(Concrete::from(rhs_power_res));
 
@@ -179,11 +268,11 @@ pub trait Literal: AnalyzerBackend + Sized {
             ConcreteNode::from(self.add_node(Node::Concrete(evaled)))
         };
 
-        let ccvar = Node::ContextVar(
-            ContextVar::new_from_concrete(loc, ctx, concrete_node, self).into_expr_err(loc)?,
-        );
+        let mut ccvar =
+            ContextVar::new_from_concrete(loc, ctx, concrete_node, self).into_expr_err(loc)?;
+        ccvar.source_repr = self.number_source_repr(loc, negative);
 
-        let node = ContextVarNode::from(self.add_node(ccvar));
+        let node = ContextVarNode::from(self.add_node(Node::ContextVar(ccvar)));
         ctx.add_var(node, self).into_expr_err(loc)?;
         self.add_edge(node, ctx, Edge::Context(ContextEdge::Variable));
         ctx.push_expr(ExprRet::SingleLiteral(node.into()), self)
@@ -199,28 +288,41 @@ pub trait Literal: AnalyzerBackend + Sized {
         integer: &str,
         negative: bool,
     ) -> Result<(), ExprErr> {
+        let integer = integer
+            .strip_prefix("0x")
+            .or_else(|| integer.strip_prefix("0X"))
+            .unwrap_or(integer);
         let integer: String = integer.chars().filter(|c| *c != '_').collect();
         let val = U256::from_str_radix(&integer, 16)
             .map_err(|e| ExprErr::ParseError(loc, e.to_string()))?;
-        let size: u16 = (((32 - (val.leading_zeros() / 8)) * 8).max(8)) as u16;
-        let concrete_node = if negative {
+        let concrete_node = if negative && val.is_zero() {
+            ConcreteNode::from(self.add_node(Node::Concrete(Self::negative_zero())))
+        } else if negative {
             let raw = I256::from_raw(val);
-            if raw < 0.into() {
+            let val = if raw == I256::MIN {
+                // `-0x8000...` is exactly the int256 minimum, whose magnitude doesn't fit itself
+                I256::MIN
+            } else if raw < 0.into() {
                 return Err(ExprErr::ParseError(
                     loc,
-                    "Negative value cannot fit into int256".to_string(),
+                    format!(
+                        "Negative value -0x{integer} cannot fit into int256: its magnitude {val} is larger than 2**255"
+                    ),
                 ));
-            }
-            let val = I256::from(-1i32) * raw;
-            ConcreteNode::from(self.add_node(Node::Concrete(Concrete::Int(size, val))))
+            } else {
+                I256::from(-1i32) * raw
+            };
+            ConcreteNode::from(self.add_node(Node::Concrete(Concrete::Int(min_int_size(val), val))))
         } else {
-            ConcreteNode::from(self.add_node(Node::Concrete(Concrete::Uint(size, val))))
+            ConcreteNode::from(
+                self.add_node(Node::Concrete(Concrete::Uint(min_uint_size(val), val))),
+            )
         };
 
-        let ccvar = Node::ContextVar(
-            ContextVar::new_from_concrete(loc, ctx, concrete_node, self).into_expr_err(loc)?,
-        );
-        let node = self.add_node(ccvar);
+        let mut ccvar =
+            ContextVar::new_from_concrete(loc, ctx, concrete_node, self).into_expr_err(loc)?;
+        ccvar.source_repr = self.number_source_repr(loc, negative);
+        let node = self.add_node(Node::ContextVar(ccvar));
         ctx.add_var(node.into(), self).into_expr_err(loc)?;
         self.add_edge(node, ctx, Edge::Context(ContextEdge::Variable));
         ctx.push_expr(ExprRet::SingleLiteral(node), self)
@@ -237,19 +339,18 @@ pub trait Literal: AnalyzerBackend + Sized {
             }
         });
 
-        let mut loc = hexes[0].loc;
-        loc.use_end_from(&hexes[hexes.len() - 1].loc);
+        // the parts of a hex literal are adjacent in a single file
+        let loc = merge_locs(&hexes.iter().map(|hex| hex.loc).collect::<Vec<_>>())
+            .unwrap_or(Loc::Implicit);
 
-        let concrete_node = if h.len() <= 32 {
+        // trailing zero bytes are part of the literal, so the size is the full byte count. There
+        // is no zero sized fixed bytes type, so an empty literal is dynamic bytes
+        let concrete_node = if !h.is_empty() && h.len() <= 32 {
             let mut target = H256::default();
-            let mut max = 1;
-            h.iter().enumerate().for_each(|(i, hex_byte)| {
-                if *hex_byte != 0x00u8 {
-                    max = i as u8 + 1;
-                }
-                target.0[i] = *hex_byte;
-            });
-            ConcreteNode::from(self.add_node(Node::Concrete(Concrete::Bytes(max, target))))
+            target.0[..h.len()].copy_from_slice(&h);
+            ConcreteNode::from(
+                self.add_node(Node::Concrete(Concrete::Bytes(h.len() as u8, target))),
+            )
         } else {
             ConcreteNode::from(self.add_node(Node::Concrete(Concrete::DynBytes(h))))
         };
@@ -371,6 +472,35 @@ pub trait Literal: AnalyzerBackend + Sized {
         Ok(())
     }
 
+    /// Pushes the ordinal of an enum member (i.e. `MyEnum.Foo`) as a `uint8` literal. The ordinal
+    /// is always within the enum's range of `[0, variant_count - 1]`
+    fn enum_literal(
+        &mut self,
+        ctx: ContextNode,
+        loc: Loc,
+        enum_node: EnumNode,
+        member: &str,
+    ) -> Result<(), ExprErr> {
+        let ordinal = enum_node
+            .variant_ordinal(member, self)
+            .map_err(|e| match e {
+                GraphError::UnknownVariable(msg) => ExprErr::MemberAccessNotFound(loc, msg),
+                e => ExprErr::from_graph_err(loc, e),
+            })?;
+        let concrete_node = ConcreteNode::from(
+            self.add_node(Node::Concrete(Concrete::Uint(8, U256::from(ordinal)))),
+        );
+        let ccvar = Node::ContextVar(
+            ContextVar::new_from_concrete(loc, ctx, concrete_node, self).into_expr_err(loc)?,
+        );
+        let node = self.add_node(ccvar);
+        ctx.add_var(node.into(), self).into_expr_err(loc)?;
+        self.add_edge(node, ctx, Edge::Context(ContextEdge::Variable));
+        ctx.push_expr(ExprRet::SingleLiteral(node), self)
+            .into_expr_err(loc)?;
+        Ok(())
+    }
+
     fn bool_literal(&mut self, ctx: ContextNode, loc: Loc, b: bool) -> Result<(), ExprErr> {
         let concrete_node = ConcreteNode::from(self.add_node(Node::Concrete(Concrete::Bool(b))));
         let ccvar = Node::ContextVar(
@@ -383,17 +513,171 @@ pub trait Literal: AnalyzerBackend + Sized {
             .into_expr_err(loc)?;
         Ok(())
     }
+
+    /// Folds a comparison of two literals (i.e. `1 < 2`) into a bool literal. Returns whether the
+    /// comparison was folded, which it isn't if either side isn't concrete or the values can't be
+    /// ordered
+    fn fold_literal_cmp(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        ctx: ContextNode,
+        loc: Loc,
+        lhs: ContextVarNode,
+        op: RangeOp,
+        rhs: ContextVarNode,
+    ) -> Result<bool, ExprErr> {
+        let (Some(lhs), Some(rhs)) = (
+            lhs.evaled_range_min(self, arena).into_expr_err(loc)?,
+            rhs.evaled_range_min(self, arena).into_expr_err(loc)?,
+        ) else {
+            return Ok(false);
+        };
+        if lhs.maybe_concrete().is_none() || rhs.maybe_concrete().is_none() {
+            return Ok(false);
+        }
+        let Some(ord) = lhs.range_ord(&rhs, arena) else {
+            return Ok(false);
+        };
+        let b = match op {
+            RangeOp::Lt => ord == Ordering::Less,
+            RangeOp::Lte => ord != Ordering::Greater,
+            RangeOp::Gt => ord == Ordering::Greater,
+            RangeOp::Gte => ord != Ordering::Less,
+            RangeOp::Eq => ord == Ordering::Equal,
+            RangeOp::Neq => ord != Ordering::Equal,
+            _ => return Ok(false),
+        };
+        self.bool_literal(ctx, loc, b)?;
+        Ok(true)
+    }
+
+    /// Pushes an array literal (i.e. `[1, 2, 3]` or `[[1, 2], [3, 4]]`) as a fixed size array
+    /// whose indices hold the elements. Integer elements of differing widths are widened to their
+    /// common type, as solc does for `[1, 256]`
+    fn array_literal(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        ctx: ContextNode,
+        loc: Loc,
+        elems: Vec<ExprRet>,
+    ) -> Result<(), ExprErr> {
+        let elems = elems
+            .into_iter()
+            .map(|elem| {
+                let elem = ContextVarNode::from(elem.expect_single().into_expr_err(loc)?);
+                Ok(elem.latest_version_or_inherited_in_ctx(ctx, self))
+            })
+            .collect::<Result<Vec<_>, ExprErr>>()?;
+        if elems.is_empty() {
+            return Err(ExprErr::ArrayTy(
+                loc,
+                "Array literals must have at least one element".to_string(),
+            ));
+        }
+
+        let elem_tys = elems
+            .iter()
+            .map(|elem| Ok((elem.ty(self)?.as_builtin(self)?, elem.is_concrete(self)?)))
+            .collect::<Result<Vec<_>, GraphError>>()
+            .into_expr_err(loc)?;
+        let mut elem_ty = elem_tys[0].0.clone();
+        for (ty, _) in elem_tys.iter().skip(1) {
+            elem_ty = match (elem_ty, ty.clone()) {
+                (Builtin::Uint(l), Builtin::Uint(r)) => Builtin::Uint(l.max(r)),
+                (Builtin::Int(l), Builtin::Int(r)) => Builtin::Int(l.max(r)),
+                (Builtin::Int(i), Builtin::Uint(u)) | (Builtin::Uint(u), Builtin::Int(i)) => {
+                    Builtin::Int(i.max(u.saturating_add(8)).min(256))
+                }
+                (elem_ty, _) => elem_ty,
+            };
+        }
+        // a uint never implicitly casts to an int, only an unsigned literal narrower than the int
+        // can be represented by it
+        let unrepresentable = elem_tys.iter().find(|(ty, literal)| {
+            !ty.implicitly_castable_to(&elem_ty)
+                && !matches!((ty, &elem_ty), (Builtin::Uint(u), Builtin::Int(i)) if *literal && u < i)
+        });
+        if let (true, Some((ty, _))) = (elem_ty.is_int(), unrepresentable) {
+            return Err(ExprErr::ArrayTy(
+                loc,
+                format!(
+                    "Array literal elements have no common type, `{}` cannot be represented as `{}`",
+                    ty.basic_as_string(),
+                    elem_ty.basic_as_string()
+                ),
+            ));
+        }
+
+        let elem_ty_idx = self.builtin_or_add(elem_ty.clone());
+        let arr_ty = VarType::try_from_idx(self, elem_ty_idx)
+            .map(|elem_var_ty| Builtin::SizedArray(U256::from(elems.len()), elem_var_ty))
+            .map(|arr_ty| self.builtin_or_add(arr_ty))
+            .and_then(|arr_ty_idx| VarType::try_from_idx(self, arr_ty_idx))
+            .ok_or_else(|| {
+                ExprErr::ArrayTy(loc, "Could not build the array literal's type".to_string())
+            })?;
+
+        let vals = elems
+            .iter()
+            .enumerate()
+            .map(|(i, elem)| {
+                let val = if elem.is_concrete(self)? {
+                    let conc = elem.as_concrete(self)?;
+                    Elem::from(conc.clone().cast(elem_ty.clone()).unwrap_or(conc))
+                } else {
+                    Elem::from(*elem)
+                };
+                Ok((Elem::from(Concrete::from(U256::from(i))), val))
+            })
+            .collect::<Result<BTreeMap<_, _>, GraphError>>()
+            .into_expr_err(loc)?;
+        let arr_range = Elem::ConcreteDyn(RangeDyn::new(
+            Elem::from(Concrete::from(U256::from(elems.len()))),
+            vals,
+            loc,
+        ));
+
+        let display_names = elems
+            .iter()
+            .map(|elem| elem.display_name(self))
+            .collect::<Result<Vec<_>, GraphError>>()
+            .into_expr_err(loc)?;
+        let arr = ContextVar {
+            loc: Some(loc),
+            name: format!("tmp_arr{}", ctx.new_tmp(self).into_expr_err(loc)?),
+            display_name: format!("[{}]", display_names.join(", ")),
+            storage: None,
+            is_tmp: true,
+            is_symbolic: false,
+            is_return: false,
+            source_repr: None,
+            tmp_of: None,
+            dep_on: None,
+            ty: arr_ty,
+        };
+        let arr = ContextVarNode::from(self.add_node(Node::ContextVar(arr)));
+        ctx.add_var(arr, self).into_expr_err(loc)?;
+        self.add_edge(arr, ctx, Edge::Context(ContextEdge::Variable));
+        arr.set_range_min(self, arena, arr_range.clone())
+            .into_expr_err(loc)?;
+        arr.set_range_max(self, arena, arr_range)
+            .into_expr_err(loc)?;
+        ctx.push_expr(ExprRet::Single(arr.into()), self)
+            .into_expr_err(loc)?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::Cmp;
     use eyre::Result;
     use graph::nodes::Context;
     use graph::nodes::Function;
     use pyrometer::Analyzer;
-    use solang_parser::pt::Loc;
+    use solang_parser::pt::{Expression, Loc};
 
     fn make_context_node_for_analyzer(analyzer: &mut Analyzer) -> ContextNode {
         // need to make a function, then provide the function to the new Context
@@ -477,6 +761,21 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_number_literal_exponent_overflow() -> Result<()> {
+        let expected = Concrete::Uint(8, U256::default()); // we aren't using `expected`
+        let result = test_number_literal("1", "78", false, None, expected);
+        assert!(
+            matches!(
+                result.as_ref().map_err(|e| e.downcast_ref::<ExprErr>()),
+                Err(Some(ExprErr::ParseError(..)))
+            ),
+            "expected a parse error, got {:?}",
+            result
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_number_literal_positive_with_exponent() -> Result<()> {
         // 123e18
@@ -518,6 +817,24 @@ mod tests {
         test_number_literal(num_literal, exponent, false, unit, expected)
     }
 
+    #[test]
+    fn test_number_literal_years_unit() -> Result<()> {
+        let unit = Some(Identifier {
+            name: "years".into(),
+            loc: Loc::File(0, 0, 0),
+        });
+        let expected = Concrete::Uint(8, U256::default()); // we aren't using `expected`
+        let result = test_number_literal("5", "", false, unit, expected);
+        match result.as_ref().map_err(|e| e.downcast_ref::<ExprErr>()) {
+            Err(Some(ExprErr::ParseError(_, msg))) => {
+                assert!(msg.contains("`years` unit was removed"), "{msg}");
+                assert!(msg.contains("seconds"), "{msg}");
+            }
+            _ => panic!("expected a parse error, got {result:?}"),
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_number_literal_negative() -> Result<()> {
         let num_literal = "123";
@@ -532,6 +849,54 @@ mod tests {
         test_number_literal(num_literal, "", true, None, expected)
     }
 
+    fn pushed_literal_min(
+        analyzer: &Analyzer,
+        ctx: ContextNode,
+        arena: &mut RangeArena<Elem<Concrete>>,
+    ) -> Result<Elem<Concrete>> {
+        let ret = ctx
+            .underlying(analyzer)?
+            .expr_ret_stack
+            .last()
+            .unwrap()
+            .clone();
+        let cvar_node = ContextVarNode::from(ret.expect_single()?);
+        Ok(cvar_node.evaled_range_min(analyzer, arena)?.unwrap())
+    }
+
+    #[test]
+    fn test_negative_zero_equals_zero() -> Result<()> {
+        let mut analyzer = Analyzer::default();
+        let mut arena = RangeArena::default();
+        let ctx = make_context_node_for_analyzer(&mut analyzer);
+        let loc = Loc::File(0, 0, 0);
+
+        analyzer.number_literal(ctx, loc, "0", "", false, &None)?;
+        let zero = pushed_literal_min(&analyzer, ctx, &mut arena)?;
+        analyzer.number_literal(ctx, loc, "0", "", true, &None)?;
+        let neg_zero = pushed_literal_min(&analyzer, ctx, &mut arena)?;
+        analyzer.number_literal(ctx, loc, "0", "18", true, &None)?;
+        let neg_zero_exp = pushed_literal_min(&analyzer, ctx, &mut arena)?;
+        analyzer.hex_num_literal(ctx, loc, "0x00", true)?;
+        let neg_zero_hex = pushed_literal_min(&analyzer, ctx, &mut arena)?;
+
+        for neg in [&neg_zero, &neg_zero_exp, &neg_zero_hex] {
+            assert_eq!(
+                neg.maybe_concrete().unwrap().val,
+                Concrete::Int(8, I256::zero())
+            );
+            assert_eq!(
+                neg.range_ord(&zero, &mut arena),
+                Some(std::cmp::Ordering::Equal)
+            );
+            assert_eq!(
+                zero.range_ord(neg, &mut arena),
+                Some(std::cmp::Ordering::Equal)
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_number_literal_max() -> Result<()> {
         let num_literal =
@@ -640,6 +1005,53 @@ mod tests {
         test_rational_number_literal(integer, fraction, exponent, true, None, expected)
     }
 
+    #[test]
+    fn test_rational_number_literal_exponent_overflow() -> Result<()> {
+        let expected = Concrete::Uint(8, U256::default()); // we aren't using `expected`
+        let result = test_rational_number_literal("1", "5", "1000000", false, None, expected);
+        assert!(
+            matches!(
+                result.as_ref().map_err(|e| e.downcast_ref::<ExprErr>()),
+                Err(Some(ExprErr::ParseError(..)))
+            ),
+            "expected a parse error, got {:?}",
+            result
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rational_number_literal_long_fraction_overflow() -> Result<()> {
+        let expected = Concrete::Uint(8, U256::default()); // we aren't using `expected`
+        let fraction = "9".repeat(70);
+        let result = test_rational_number_literal("9", &fraction, "77", false, None, expected);
+        let Err(err) = result else {
+            panic!("expected a parse error, got {result:?}");
+        };
+        let Some(ExprErr::ParseError(_, msg)) = err.downcast_ref::<ExprErr>() else {
+            panic!("expected a parse error, got {err:?}");
+        };
+        assert!(
+            msg.contains("a fraction of 70 digits with an exponent of 77"),
+            "{msg}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rational_number_literal_large_exponent() -> Result<()> {
+        // the exponent net of the fraction's digits is what scales the value
+        let expected = Concrete::Uint(256, U256::from(125) * U256::from(10).pow(U256::from(74)));
+        test_rational_number_literal("1", "25", "76", false, None, expected)
+    }
+
+    #[test]
+    fn test_rational_number_literal_zero_integer() -> Result<()> {
+        // 0.5e77 is 5e76, which fits even though 1.5e77 would not
+        let expected = Concrete::Uint(256, U256::from(5) * U256::from(10).pow(U256::from(76)));
+        test_rational_number_literal("0", "5", "77", false, None, expected)
+    }
+
     #[test]
     fn test_rational_number_literal_with_unit() -> Result<()> {
         let integer = "1";
@@ -708,6 +1120,13 @@ mod tests {
         test_hex_num_literal(hex_literal, false, expected)
     }
 
+    #[test]
+    fn test_hex_num_literal_prefixed() -> Result<()> {
+        let expected = Concrete::Uint(8, U256::from_dec_str("123").unwrap());
+        test_hex_num_literal("0x7B", false, expected.clone())?;
+        test_hex_num_literal("0X7B", false, expected)
+    }
+
     #[test]
     fn test_hex_num_literal_negative() -> Result<()> {
         let hex_literal = "7B"; // 123 in decimal
@@ -749,6 +1168,28 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_hex_num_literal_too_large_negative_msg() -> Result<()> {
+        let hex_literal = "0x8000000000000000000000000000000000000000000000000000000000000001";
+        let mut analyzer = Analyzer::default();
+        let ctx = make_context_node_for_analyzer(&mut analyzer);
+        let err = analyzer
+            .hex_num_literal(ctx, Loc::File(0, 0, 0), hex_literal, true)
+            .unwrap_err();
+        assert!(err.msg().contains(&hex_literal[2..]));
+        assert!(err
+            .msg()
+            .contains(&U256::from_str_radix(&hex_literal[2..], 16)?.to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_hex_num_literal_int256_min() -> Result<()> {
+        let hex_literal = "8000000000000000000000000000000000000000000000000000000000000000";
+        let expected = Concrete::Int(256, I256::MIN);
+        test_hex_num_literal(hex_literal, true, expected)
+    }
+
     #[test]
     fn test_hex_num_literal_zero() -> Result<()> {
         let hex_literal = "0"; // zero
@@ -838,7 +1279,11 @@ mod tests {
         assert!(cvar_node.is_const(&analyzer, arena)?);
         let min = cvar_node.evaled_range_min(&analyzer, arena)?.unwrap();
 
-        let conc_value = min.maybe_concrete().unwrap().val;
+        // dynamic bytes evaluate to a dynamic range element rather than a concrete
+        let conc_value = match min.maybe_concrete() {
+            Some(conc) => conc.val,
+            None => Concrete::DynBytes(min.as_bytes(&analyzer, true, arena).unwrap()),
+        };
         assert!(
             conc_value == expected,
             "Values do not match: {:?} != {:?}",
@@ -886,7 +1331,7 @@ mod tests {
             hex: "".to_string(),
             loc: Loc::File(0, 0, 0),
         };
-        let expected = Concrete::Bytes(1, H256::default());
+        let expected = Concrete::DynBytes(vec![]);
         test_hex_literals(&[hex_literal], expected)
     }
 
@@ -1139,4 +1584,295 @@ mod tests {
         let expected = Concrete::Bool(false);
         test_bool_literal(bool_value, expected)
     }
+
+    fn test_folded_cmp(lhs: &str, op: RangeOp, rhs: &str, expected: bool) -> Result<()> {
+        // setup
+        let mut analyzer = Analyzer {
+            debug_panic: true,
+            ..Default::default()
+        };
+        let mut arena_base = RangeArena::default();
+        let arena = &mut arena_base;
+        let ctx = make_context_node_for_analyzer(&mut analyzer);
+        let loc = Loc::File(0, 0, 0);
+        let num = |n: &str| Expression::NumberLiteral(loc, n.to_string(), "".to_string(), None);
+
+        // compare two number literals
+        analyzer.cmp(arena, loc, &num(lhs), op, &num(rhs), ctx)?;
+
+        // checks
+        let stack = &ctx.underlying(&analyzer)?.expr_ret_stack;
+        assert!(
+            stack.len() == 1,
+            "ret stack length should be 1, got {}",
+            stack.len()
+        );
+        assert!(
+            matches!(stack[0], ExprRet::SingleLiteral(_)),
+            "ret stack[0] should be a single literal, got {:?}",
+            stack[0]
+        );
+        let cvar_node = ContextVarNode::from(stack[0].expect_single()?);
+        assert!(cvar_node.is_const(&analyzer, arena)?);
+        let min = cvar_node.evaled_range_min(&analyzer, arena)?.unwrap();
+        let conc_value = min.maybe_concrete().unwrap().val;
+        assert!(
+            conc_value == Concrete::Bool(expected),
+            "Values do not match: {:?} != {:?}",
+            conc_value,
+            Concrete::Bool(expected)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_folded_cmp_lt() -> Result<()> {
+        test_folded_cmp("1", RangeOp::Lt, "2", true)
+    }
+
+    #[test]
+    fn test_folded_cmp_lte() -> Result<()> {
+        test_folded_cmp("2", RangeOp::Lte, "2", true)
+    }
+
+    #[test]
+    fn test_folded_cmp_eq() -> Result<()> {
+        test_folded_cmp("3", RangeOp::Eq, "4", false)
+    }
+
+    fn make_enum_for_analyzer(analyzer: &mut Analyzer, variants: &[&str]) -> EnumNode {
+        let ident = |name: &str| Identifier {
+            loc: Loc::Implicit,
+            name: name.to_string(),
+        };
+        let enu = graph::nodes::Enum {
+            loc: Loc::Implicit,
+            name: Some(ident("MyEnum")),
+            values: variants.iter().map(|v| Some(ident(v))).collect(),
+        };
+        EnumNode::from(analyzer.graph.add_node(Node::Enum(enu)))
+    }
+
+    #[test]
+    fn test_enum_literal() -> Result<()> {
+        let mut analyzer = Analyzer::default();
+        let mut arena = RangeArena::default();
+        let ctx = make_context_node_for_analyzer(&mut analyzer);
+        let enum_node = make_enum_for_analyzer(&mut analyzer, &["Foo", "Bar", "Baz"]);
+        let loc = Loc::File(0, 0, 0);
+
+        analyzer.enum_literal(ctx, loc, enum_node, "Foo")?;
+        analyzer.enum_literal(ctx, loc, enum_node, "Baz")?;
+
+        let stack = &ctx.underlying(&analyzer)?.expr_ret_stack;
+        assert_eq!(stack.len(), 2);
+        let ordinals = stack
+            .iter()
+            .map(|ret| {
+                let cvar_node = ContextVarNode::from(ret.expect_single().unwrap());
+                let min = cvar_node.evaled_range_min(&analyzer, &mut arena).unwrap();
+                min.unwrap().maybe_concrete().unwrap().val
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ordinals,
+            vec![
+                Concrete::Uint(8, U256::zero()),
+                Concrete::Uint(8, U256::from(2))
+            ]
+        );
+
+        let range = enum_node.maybe_default_range(&analyzer)?.unwrap();
+        assert_eq!(range.min, Concrete::from(U256::zero()).into());
+        assert_eq!(range.max, Concrete::from(U256::from(2)).into());
+        Ok(())
+    }
+
+    #[test]
+    fn test_enum_literal_unknown_member() {
+        let mut analyzer = Analyzer::default();
+        let ctx = make_context_node_for_analyzer(&mut analyzer);
+        let enum_node = make_enum_for_analyzer(&mut analyzer, &["Foo", "Bar", "Baz"]);
+
+        let err = analyzer
+            .enum_literal(ctx, Loc::File(0, 0, 0), enum_node, "Qux")
+            .unwrap_err();
+        let ExprErr::MemberAccessNotFound(_, msg) = err else {
+            panic!("expected a member access error, got {err:?}");
+        };
+        assert!(msg.contains("MyEnum"), "{msg}");
+        assert!(msg.contains("Qux"), "{msg}");
+        assert!(msg.contains("Foo, Bar, Baz"), "{msg}");
+        assert!(ctx.underlying(&analyzer).unwrap().expr_ret_stack.is_empty());
+    }
+
+    #[test]
+    fn test_array_literal_widens_mixed_widths() -> Result<()> {
+        let mut analyzer = Analyzer::default();
+        let mut arena = RangeArena::default();
+        let ctx = make_context_node_for_analyzer(&mut analyzer);
+        let loc = Loc::File(0, 0, 0);
+
+        analyzer.number_literal(ctx, loc, "1", "", false, &None)?;
+        analyzer.number_literal(ctx, loc, "256", "", false, &None)?;
+        let elems = std::mem::take(&mut ctx.underlying_mut(&mut analyzer)?.expr_ret_stack);
+        analyzer.array_literal(&mut arena, ctx, loc, elems)?;
+
+        let stack = &ctx.underlying(&analyzer)?.expr_ret_stack;
+        assert_eq!(stack.len(), 1);
+        let arr = ContextVarNode::from(stack[0].expect_single().unwrap());
+        assert_eq!(arr.ty(&analyzer)?.as_string(&analyzer)?, "uint16[2]");
+
+        let min = arr.evaled_range_min(&analyzer, &mut arena)?.unwrap();
+        let vals = min.maybe_range_dyn().unwrap().val;
+        let index = |i: u64| Elem::from(Concrete::from(U256::from(i)));
+        assert_eq!(
+            vals[&index(0)].0,
+            Elem::from(Concrete::Uint(16, U256::from(1)))
+        );
+        assert_eq!(
+            vals[&index(1)].0,
+            Elem::from(Concrete::Uint(16, U256::from(256)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_literal_mixed_signs() -> Result<()> {
+        let mut analyzer = Analyzer::default();
+        let mut arena = RangeArena::default();
+        let ctx = make_context_node_for_analyzer(&mut analyzer);
+        let loc = Loc::File(0, 0, 0);
+
+        // a small unsigned literal fits in a wider int
+        analyzer.number_literal(ctx, loc, "1", "", false, &None)?;
+        analyzer.number_literal(ctx, loc, "1", "", true, &None)?;
+        let elems = std::mem::take(&mut ctx.underlying_mut(&mut analyzer)?.expr_ret_stack);
+        analyzer.array_literal(&mut arena, ctx, loc, elems)?;
+        let arr = pushed_literal(&analyzer, ctx)?;
+        assert_eq!(arr.ty(&analyzer)?.as_string(&analyzer)?, "int16[2]");
+        ctx.underlying_mut(&mut analyzer)?.expr_ret_stack.clear();
+
+        // 2**255 is a uint256 that no int can represent
+        analyzer.number_literal(
+            ctx,
+            loc,
+            "57896044618658097711785492504343953926634992332820282019728792003956564819968",
+            "",
+            false,
+            &None,
+        )?;
+        analyzer.number_literal(ctx, loc, "1", "", true, &None)?;
+        let elems = std::mem::take(&mut ctx.underlying_mut(&mut analyzer)?.expr_ret_stack);
+        let err = analyzer
+            .array_literal(&mut arena, ctx, loc, elems)
+            .unwrap_err();
+        let ExprErr::ArrayTy(_, msg) = err else {
+            panic!("expected an array type error, got {err:?}");
+        };
+        assert!(
+            msg.contains("`uint256` cannot be represented as `int256`"),
+            "{msg}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_min_uint_size() {
+        assert_eq!(min_uint_size(U256::zero()), 8);
+        assert_eq!(min_uint_size(U256::from(255)), 8);
+        assert_eq!(min_uint_size(U256::from(256)), 16);
+        assert_eq!(min_uint_size(U256::from(65535)), 16);
+        assert_eq!(min_uint_size(U256::from(65536)), 24);
+        assert_eq!(min_uint_size(U256::MAX), 256);
+    }
+
+    #[test]
+    fn test_min_int_size() {
+        assert_eq!(min_int_size(I256::zero()), 8);
+        assert_eq!(min_int_size(I256::from(127)), 8);
+        assert_eq!(min_int_size(I256::from(128)), 16);
+        assert_eq!(min_int_size(I256::from(-128)), 8);
+        assert_eq!(min_int_size(I256::from(-129)), 16);
+        assert_eq!(min_int_size(I256::from(32767)), 16);
+        assert_eq!(min_int_size(I256::from(-32769)), 24);
+        assert_eq!(min_int_size(I256::MIN), 256);
+        assert_eq!(min_int_size(I256::MAX), 256);
+    }
+
+    fn pushed_literal(analyzer: &Analyzer, ctx: ContextNode) -> Result<ContextVarNode> {
+        let ret = ctx
+            .underlying(analyzer)?
+            .expr_ret_stack
+            .last()
+            .unwrap()
+            .clone();
+        Ok(ContextVarNode::from(ret.expect_single()?))
+    }
+
+    #[test]
+    fn test_concrete_accessors() -> Result<()> {
+        let mut analyzer = Analyzer::default();
+        let mut arena = RangeArena::default();
+        let ctx = make_context_node_for_analyzer(&mut analyzer);
+        let loc = Loc::File(0, 0, 0);
+
+        analyzer.number_literal(ctx, loc, "123", "", false, &None)?;
+        let uint = pushed_literal(&analyzer, ctx)?;
+        assert_eq!(
+            uint.as_concrete_uint(&analyzer, &mut arena)?,
+            Some(U256::from(123))
+        );
+        assert_eq!(uint.as_concrete_int(&analyzer, &mut arena)?, None);
+
+        analyzer.number_literal(ctx, loc, "123", "", true, &None)?;
+        let int = pushed_literal(&analyzer, ctx)?;
+        assert_eq!(
+            int.as_concrete_int(&analyzer, &mut arena)?,
+            Some(I256::from(-123))
+        );
+        assert_eq!(int.as_concrete_uint(&analyzer, &mut arena)?, None);
+
+        analyzer.bool_literal(ctx, loc, true)?;
+        let b = pushed_literal(&analyzer, ctx)?;
+        assert_eq!(b.as_concrete_bool(&analyzer, &mut arena)?, Some(true));
+
+        analyzer.address_literal(ctx, loc, "0x0000000000000000000000000000000000000001")?;
+        let addr = pushed_literal(&analyzer, ctx)?;
+        assert_eq!(
+            addr.as_concrete_address(&analyzer, &mut arena)?,
+            Some(Address::from_low_u64_be(1))
+        );
+
+        analyzer.hex_literals(
+            ctx,
+            &[HexLiteral {
+                hex: "7B".to_string(),
+                loc,
+            }],
+        )?;
+        let bytes = pushed_literal(&analyzer, ctx)?;
+        let mut expected = [0u8; 32];
+        expected[0] = 0x7B;
+        assert_eq!(
+            bytes.as_concrete_bytes(&analyzer, &mut arena)?,
+            Some(H256::from(expected))
+        );
+
+        analyzer.string_literal(ctx, loc, "abc")?;
+        let string = pushed_literal(&analyzer, ctx)?;
+        assert_eq!(
+            string.as_concrete_string(&analyzer, &mut arena)?,
+            Some("abc".to_string())
+        );
+        assert_eq!(string.as_concrete_bool(&analyzer, &mut arena)?, None);
+
+        // a variable that can take any value of its type has no concrete value
+        let uint256 = analyzer.graph.add_node(Node::Builtin(Builtin::Uint(256)));
+        let var = ContextVar::new_from_builtin(loc, uint256.into(), &mut analyzer)?;
+        let var = ContextVarNode::from(analyzer.graph.add_node(Node::ContextVar(var)));
+        assert_eq!(var.evaled_concrete(&analyzer, &mut arena)?, None);
+        assert_eq!(var.as_concrete_uint(&analyzer, &mut arena)?, None);
+        Ok(())
+    }
 }
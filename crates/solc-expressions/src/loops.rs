@@ -1,15 +1,22 @@
-use crate::{variable::Variable, ContextBuilder, StatementParser};
+use crate::{variable::Variable, ContextBuilder, ExpressionParser, StatementParser};
 use graph::ContextEdge;
 use graph::Edge;
 
 use graph::{
-    elem::Elem,
-    nodes::{Concrete, Context, ContextNode},
-    AnalyzerBackend, GraphBackend, Node,
+    elem::{Elem, RangeConcrete, RangeExpr, RangeOp},
+    nodes::{Builtin, Concrete, Context, ContextNode, ContextVarNode, ExprRet, LoopInfo},
+    AnalyzerBackend, GraphBackend, Node, SolcRange,
 };
 use shared::{ExprErr, IntoExprErr, RangeArena};
 
-use solang_parser::pt::{Expression, Loc, Statement};
+use ethers_core::types::U256;
+
+use solang_parser::{
+    helpers::CodeLocation,
+    pt::{Expression, Loc, Statement, StorageLocation},
+};
+
+use std::collections::BTreeSet;
 
 impl<T> Looper for T where
     T: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized + GraphBackend
@@ -28,8 +35,8 @@ pub trait Looper:
         loc: Loc,
         ctx: ContextNode,
         maybe_init: &Option<Box<Statement>>,
-        _maybe_limiter: &Option<Box<Expression>>,
-        _maybe_post: &Option<Box<Statement>>,
+        maybe_limiter: &Option<Box<Expression>>,
+        maybe_post: &Option<Box<Statement>>,
         maybe_body: &Option<Box<Statement>>,
     ) -> Result<(), ExprErr> {
         // TODO: improve this
@@ -38,21 +45,38 @@ pub trait Looper:
         }
 
         if let Some(body) = maybe_body {
+            // the post statement runs as part of every iteration, so its mutations need widening too
+            let body = if let Some(post) = maybe_post {
+                Statement::Block {
+                    loc: body.loc(),
+                    unchecked: false,
+                    statements: vec![*body.clone(), *post.clone()],
+                }
+            } else {
+                *body.clone()
+            };
             self.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, loc| {
-                analyzer.reset_vars(arena, loc, ctx, body)
+                analyzer.reset_vars(arena, loc, ctx, maybe_limiter.as_deref(), &body, 0)
             })
         } else {
             Ok(())
         }
     }
 
-    /// Resets all variables referenced in the loop because we don't elegantly handle loops
+    /// Resets all variables mutated in the loop because we don't elegantly handle loops. Variables
+    /// that are only read in the loop keep their incoming ranges, and counters the loop condition
+    /// bounds are widened to a threshold (see [`Looper::threshold_widened_range`]) instead of
+    /// their type's full range. `unrolled` is the number of iterations already interpreted before
+    /// the loop, i.e. the first iteration of a do-while loop
+    #[allow(clippy::too_many_arguments)]
     fn reset_vars(
         &mut self,
         arena: &mut RangeArena<Elem<Concrete>>,
         loc: Loc,
         ctx: ContextNode,
+        limiter: Option<&Expression>,
         body: &Statement,
+        unrolled: usize,
     ) -> Result<(), ExprErr> {
         let og_ctx = ctx;
         let sctx = Context::new_loop_subctx(ctx, loc, self).into_expr_err(loc)?;
@@ -60,39 +84,98 @@ pub trait Looper:
         ctx.set_child_call(subctx, self).into_expr_err(loc)?;
         self.add_edge(subctx, ctx, Edge::Context(ContextEdge::Loop));
         self.parse_ctx_statement(arena, body, false, Some(subctx));
+        let mutated = mutated_vars(body).map(|mut mutated| {
+            // writes through a storage reference declared before the loop mutate the storage it
+            // points to
+            let referents = mutated
+                .iter()
+                .filter_map(|name| {
+                    let var = og_ctx.var_by_name_or_recurse(self, name).ok()??;
+                    let referent = var.storage_referent(self)?.name(self).ok()?;
+                    Some(referent.split(['.', '[']).next()?.to_string())
+                })
+                .collect::<Vec<_>>();
+            mutated.extend(referents);
+            mutated
+        });
+        let widens = mutated.as_ref().map_or(true, |mutated| !mutated.is_empty());
+        let iterations = self
+            .estimate_iterations(arena, og_ctx, limiter, body)?
+            .and_then(|iterations| iterations.checked_add(unrolled));
+        let info = LoopInfo {
+            iterations,
+            interpreted: unrolled + 1,
+            widened: widens,
+        };
+        subctx.set_loop_info(info, self).into_expr_err(loc)?;
         self.apply_to_edges(subctx, loc, arena, &|analyzer, arena, ctx, loc| {
             let vars = subctx.local_vars(analyzer).clone();
-            vars.iter().for_each(|(name, var)| {
-                // widen to max range
-                if let Some(inheritor_var) = ctx.var_by_name(analyzer, name) {
-                    let inheritor_var = inheritor_var.latest_version(analyzer);
-                    if let Some(r) = var
+            let widened = vars
+                .iter()
+                .filter(|(name, _)| {
+                    let Some(mutated) = &mutated else {
+                        return true;
+                    };
+                    let base = name.split(['.', '[']).next().unwrap_or(name);
+                    mutated.contains(base)
+                })
+                .collect::<Vec<_>>();
+            let thresholds = loop_thresholds(limiter, body);
+            widened.iter().try_for_each(|(name, var)| {
+                let Some(inheritor_var) = ctx.var_by_name(analyzer, name) else {
+                    return Ok(());
+                };
+                let inheritor_var = inheritor_var.latest_version(analyzer);
+                let threshold_range = analyzer.threshold_widened_range(
+                    arena,
+                    og_ctx,
+                    name,
+                    **var,
+                    limiter,
+                    body,
+                    &thresholds,
+                )?;
+                let range = match threshold_range {
+                    Some(range) => Some(range),
+                    // widen to max range
+                    None => var
                         .underlying(analyzer)
-                        .unwrap()
+                        .into_expr_err(loc)?
                         .ty
                         .default_range(analyzer)
-                        .unwrap()
-                    {
-                        let new_inheritor_var = analyzer
-                            .advance_var_in_ctx(inheritor_var, loc, ctx)
-                            .unwrap();
-                        let res = new_inheritor_var
-                            .set_range_min(analyzer, arena, r.min)
-                            .into_expr_err(loc);
-                        let _ = analyzer.add_if_err(res);
-                        let res = new_inheritor_var
-                            .set_range_max(analyzer, arena, r.max)
-                            .into_expr_err(loc);
-                        let _ = analyzer.add_if_err(res);
-                    }
+                        .into_expr_err(loc)?,
+                };
+                if let Some(r) = range {
+                    let new_inheritor_var = analyzer.advance_var_in_ctx(inheritor_var, loc, ctx)?;
+                    new_inheritor_var.mark_written(analyzer);
+                    let res = new_inheritor_var
+                        .set_range_min(analyzer, arena, r.min)
+                        .into_expr_err(loc);
+                    let _ = analyzer.add_if_err(res);
+                    let res = new_inheritor_var
+                        .set_range_max(analyzer, arena, r.max)
+                        .into_expr_err(loc);
+                    let _ = analyzer.add_if_err(res);
                 }
-            });
+                Ok::<(), ExprErr>(())
+            })?;
 
             let sctx =
                 Context::new_subctx(ctx, Some(og_ctx), loc, None, None, false, analyzer, None)
                     .into_expr_err(loc)?;
             let sctx = ContextNode::from(analyzer.add_node(Node::Context(sctx)));
-            ctx.set_child_call(sctx, analyzer).into_expr_err(loc)
+            ctx.set_child_call(sctx, analyzer).into_expr_err(loc)?;
+
+            // the resuming context looks variables up through the original context, so carry
+            // the widened versions over explicitly. Equalities from before the loop no longer
+            // hold for them
+            widened.iter().try_for_each(|(name, _)| {
+                sctx.forget_linear_eqs(name, analyzer).into_expr_err(loc)?;
+                if let Some(var) = ctx.var_by_name(analyzer, name) {
+                    analyzer.advance_var_in_ctx(var.latest_version(analyzer), loc, sctx)?;
+                }
+                Ok(())
+            })
         })
     }
 
@@ -102,12 +185,613 @@ pub trait Looper:
         arena: &mut RangeArena<Elem<Concrete>>,
         loc: Loc,
         ctx: ContextNode,
-        _limiter: &Expression,
+        limiter: &Expression,
         body: &Statement,
     ) -> Result<(), ExprErr> {
-        // TODO: improve this
         self.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, loc| {
-            analyzer.reset_vars(arena, loc, ctx, body)
+            analyzer.reset_vars(arena, loc, ctx, Some(limiter), body, 0)
+        })
+    }
+
+    /// Handles a do-while loop. The body always executes once, so it is interpreted with real
+    /// ranges before falling back to widening for any subsequent iterations
+    fn do_while_loop(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        ctx: ContextNode,
+        limiter: &Expression,
+        body: &Statement,
+    ) -> Result<(), ExprErr> {
+        self.parse_ctx_statement(arena, body, false, Some(ctx));
+        self.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, loc| {
+            analyzer.parse_ctx_expr(arena, limiter, ctx)?;
+            analyzer.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, loc| {
+                let Some(cond) = ctx.pop_expr_latest(loc, analyzer).into_expr_err(loc)? else {
+                    return Err(ExprErr::NoRhs(
+                        loc,
+                        "Do-while loop condition had no value".to_string(),
+                    ));
+                };
+
+                match cond {
+                    ExprRet::CtxKilled(_) => ctx.push_expr(cond, analyzer).into_expr_err(loc),
+                    ExprRet::Single(idx) | ExprRet::SingleLiteral(idx)
+                        if analyzer.is_const_false(arena, idx.into(), loc)? =>
+                    {
+                        // the loop cannot run again, so the first iteration is the final state
+                        Ok(())
+                    }
+                    _ => analyzer.reset_vars(arena, loc, ctx, Some(limiter), body, 1),
+                }
+            })
         })
     }
+
+    /// The range an unsigned loop counter is widened to, if the loop only ever increments (or only
+    /// ever decrements) it by constants and the loop condition bounds it in that direction, i.e.
+    /// `i < n` with `i++`. Starting from the counter's range before the loop, the bound grows to
+    /// the nearest threshold one more iteration can't exceed: with the counter at most the
+    /// condition's bound inside the body, it is at most that bound plus the body's total step
+    /// after it. If the condition instead bounds a counter stepped by one by a variable the loop
+    /// doesn't mutate, the counter ends up bounded by that variable
+    #[allow(clippy::too_many_arguments)]
+    fn threshold_widened_range(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        og_ctx: ContextNode,
+        name: &str,
+        var: ContextVarNode,
+        limiter: Option<&Expression>,
+        body: &Statement,
+        thresholds: &BTreeSet<U256>,
+    ) -> Result<Option<SolcRange>, ExprErr> {
+        let (Some(limiter), Some((inc, dec))) = (limiter, counter_steps(body, name)) else {
+            return Ok(None);
+        };
+        let loc = limiter.loc();
+        let ty = var.underlying(self).into_expr_err(loc)?.ty.clone();
+        let Ok(Builtin::Uint(size)) = ty.as_builtin(self) else {
+            return Ok(None);
+        };
+        let Some(Concrete::Uint(_, ty_max)) = Builtin::Uint(size).max_concrete() else {
+            return Ok(None);
+        };
+        let Some((lo, hi)) = self.pre_loop_uint_range(arena, og_ctx, name, loc)? else {
+            return Ok(None);
+        };
+
+        let (guard_min, guard_max) = guard_bounds(limiter, name);
+        let (guard_min_var, guard_max_var) = guard_var_bounds(limiter, name);
+        let uint = |val: U256| Elem::from(Concrete::Uint(size, val));
+        let (min, max) = match (inc.is_zero(), dec.is_zero()) {
+            (false, true) => {
+                let reach = guard_max
+                    .and_then(|bound| bound.checked_add(inc))
+                    .filter(|reach| *reach <= ty_max);
+                if let Some(reach) = reach {
+                    let threshold = thresholds
+                        .range(reach.max(hi)..)
+                        .next()
+                        .map_or(ty_max, |threshold| (*threshold).min(ty_max));
+                    (uint(lo), uint(threshold))
+                } else {
+                    // stepping by one while below `n` stops at `n`
+                    let Some(bound) = self
+                        .loop_invariant_bound(og_ctx, guard_max_var, body, loc)?
+                        .filter(|_| inc == U256::one())
+                    else {
+                        return Ok(None);
+                    };
+                    let max = if hi.is_zero() {
+                        Elem::from(bound)
+                    } else {
+                        Elem::Expr(RangeExpr::new(bound.into(), RangeOp::Max, uint(hi)))
+                    };
+                    (uint(lo), max)
+                }
+            }
+            (true, false) => {
+                if let Some(reach) = guard_min.and_then(|bound| bound.checked_sub(dec)) {
+                    let threshold = thresholds
+                        .range(..=reach.min(lo))
+                        .next_back()
+                        .copied()
+                        .unwrap_or_default();
+                    (uint(threshold), uint(hi))
+                } else {
+                    // stepping by one while above `n` stops at `n`
+                    let Some(bound) = self
+                        .loop_invariant_bound(og_ctx, guard_min_var, body, loc)?
+                        .filter(|_| dec == U256::one())
+                    else {
+                        return Ok(None);
+                    };
+                    let min = if lo.is_zero() {
+                        uint(lo)
+                    } else {
+                        Elem::Expr(RangeExpr::new(bound.into(), RangeOp::Min, uint(lo)))
+                    };
+                    (min, uint(hi))
+                }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(SolcRange::new(min, max, vec![])))
+    }
+
+    /// The concrete bounds of the unsigned variable `name` before the loop
+    fn pre_loop_uint_range(
+        &self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        og_ctx: ContextNode,
+        name: &str,
+        loc: Loc,
+    ) -> Result<Option<(U256, U256)>, ExprErr> {
+        let Some(pre_loop) = og_ctx
+            .var_by_name_or_recurse(self, name)
+            .into_expr_err(loc)?
+        else {
+            return Ok(None);
+        };
+        let pre_loop = pre_loop.latest_version_or_inherited_in_ctx(og_ctx, self);
+        let as_uint = |elem: Option<Elem<Concrete>>| match elem?.maybe_concrete()?.val {
+            Concrete::Uint(_, val) => Some(val),
+            _ => None,
+        };
+        Ok(
+            as_uint(pre_loop.evaled_range_min(self, arena).into_expr_err(loc)?).zip(as_uint(
+                pre_loop.evaled_range_max(self, arena).into_expr_err(loc)?,
+            )),
+        )
+    }
+
+    /// An estimate of the most times a loop body runs, if the loop condition bounds an unsigned
+    /// counter the body only ever increments (or only ever decrements) by constants, i.e. `i < 3`
+    /// with `i++`. Counted from the counter's range before the loop, using the tightest counter
+    fn estimate_iterations(
+        &self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        og_ctx: ContextNode,
+        limiter: Option<&Expression>,
+        body: &Statement,
+    ) -> Result<Option<usize>, ExprErr> {
+        let (Some(limiter), Some(mutated)) = (limiter, mutated_vars(body)) else {
+            return Ok(None);
+        };
+        let mut estimate: Option<U256> = None;
+        for name in mutated {
+            let Some((inc, dec)) = counter_steps(body, &name) else {
+                continue;
+            };
+            let Some((lo, hi)) = self.pre_loop_uint_range(arena, og_ctx, &name, limiter.loc())?
+            else {
+                continue;
+            };
+            let (guard_min, guard_max) = guard_bounds(limiter, &name);
+            let runs = match (inc.is_zero(), dec.is_zero(), guard_min, guard_max) {
+                (false, true, _, Some(max)) if max < lo => U256::zero(),
+                (false, true, _, Some(max)) => (max - lo) / inc + 1,
+                (true, false, Some(min), _) if hi < min => U256::zero(),
+                (true, false, Some(min), _) => (hi - min) / dec + 1,
+                _ => continue,
+            };
+            estimate = Some(estimate.map_or(runs, |estimate| estimate.min(runs)));
+        }
+        Ok(estimate.and_then(|estimate| usize::try_from(estimate).ok()))
+    }
+
+    /// The pre-loop version of the unsigned variable `name` bounding a loop counter, if the loop
+    /// body doesn't mutate it
+    fn loop_invariant_bound(
+        &self,
+        og_ctx: ContextNode,
+        name: Option<String>,
+        body: &Statement,
+        loc: Loc,
+    ) -> Result<Option<ContextVarNode>, ExprErr> {
+        let Some(name) = name else {
+            return Ok(None);
+        };
+        if mutated_vars(body).map_or(true, |mutated| mutated.contains(&name)) {
+            return Ok(None);
+        }
+        let Some(bound) = og_ctx
+            .var_by_name_or_recurse(self, &name)
+            .into_expr_err(loc)?
+        else {
+            return Ok(None);
+        };
+        let bound = bound.latest_version_or_inherited_in_ctx(og_ctx, self);
+        let is_uint = matches!(
+            bound
+                .underlying(self)
+                .into_expr_err(loc)?
+                .ty
+                .as_builtin(self),
+            Ok(Builtin::Uint(_))
+        );
+        Ok(is_uint.then_some(bound))
+    }
+
+    /// Whether a loop condition is known to be `false`
+    fn is_const_false(
+        &self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        cond: ContextVarNode,
+        loc: Loc,
+    ) -> Result<bool, ExprErr> {
+        let max = cond.evaled_range_max(self, arena).into_expr_err(loc)?;
+        Ok(matches!(
+            max.and_then(|max| max.maybe_concrete()),
+            Some(RangeConcrete {
+                val: Concrete::Bool(false),
+                ..
+            })
+        ))
+    }
+}
+
+/// Collects the names of the variables a statement may mutate. Returns `None` if the statement
+/// contains anything whose effects can't be determined syntactically (i.e. function calls or
+/// assembly), in which case every variable has to be considered mutated. A storage reference
+/// declared in the statement is assumed to be written through, so the storage it points to counts
+/// as mutated
+fn mutated_vars(stmt: &Statement) -> Option<BTreeSet<String>> {
+    let mut mutated = BTreeSet::default();
+    stmt_mutations(stmt, &mut mutated)?;
+    Some(mutated)
+}
+
+fn stmt_mutations(stmt: &Statement, mutated: &mut BTreeSet<String>) -> Option<()> {
+    use Statement::*;
+    match stmt {
+        Block { statements, .. } => statements
+            .iter()
+            .try_for_each(|stmt| stmt_mutations(stmt, mutated)),
+        If(_, cond, true_stmt, false_stmt) => {
+            expr_mutations(cond, mutated)?;
+            stmt_mutations(true_stmt, mutated)?;
+            false_stmt
+                .iter()
+                .try_for_each(|stmt| stmt_mutations(stmt, mutated))
+        }
+        While(_, cond, body) | DoWhile(_, body, cond) => {
+            expr_mutations(cond, mutated)?;
+            stmt_mutations(body, mutated)
+        }
+        For(_, init, cond, post, body) => {
+            init.iter()
+                .chain(post)
+                .chain(body)
+                .try_for_each(|stmt| stmt_mutations(stmt, mutated))?;
+            cond.iter()
+                .try_for_each(|expr| expr_mutations(expr, mutated))
+        }
+        Expression(_, expr) => expr_mutations(expr, mutated),
+        VariableDefinition(_, decl, init) => {
+            if let (Some(StorageLocation::Storage(_)), Some(init)) = (&decl.storage, init) {
+                mutated.insert(base_var_name(init)?);
+            }
+            init.iter()
+                .try_for_each(|expr| expr_mutations(expr, mutated))
+        }
+        Return(_, expr) => expr
+            .iter()
+            .try_for_each(|expr| expr_mutations(expr, mutated)),
+        Revert(_, _, args) => args
+            .iter()
+            .try_for_each(|expr| expr_mutations(expr, mutated)),
+        Emit(_, solang_parser::pt::Expression::FunctionCall(_, _, args)) => args
+            .iter()
+            .try_for_each(|expr| expr_mutations(expr, mutated)),
+        Continue(_) | Break(_) | Args(..) | RevertNamedArgs(..) | Error(_) => Some(()),
+        Assembly { .. } | Emit(..) | Try(..) => None,
+    }
+}
+
+fn expr_mutations(expr: &Expression, mutated: &mut BTreeSet<String>) -> Option<()> {
+    use Expression::*;
+    match expr {
+        Assign(_, lhs, rhs)
+        | AssignOr(_, lhs, rhs)
+        | AssignAnd(_, lhs, rhs)
+        | AssignXor(_, lhs, rhs)
+        | AssignShiftLeft(_, lhs, rhs)
+        | AssignShiftRight(_, lhs, rhs)
+        | AssignAdd(_, lhs, rhs)
+        | AssignSubtract(_, lhs, rhs)
+        | AssignMultiply(_, lhs, rhs)
+        | AssignDivide(_, lhs, rhs)
+        | AssignModulo(_, lhs, rhs) => {
+            mutated.insert(base_var_name(lhs)?);
+            expr_mutations(lhs, mutated)?;
+            expr_mutations(rhs, mutated)
+        }
+        PreIncrement(_, expr)
+        | PostIncrement(_, expr)
+        | PreDecrement(_, expr)
+        | PostDecrement(_, expr)
+        | Delete(_, expr) => {
+            mutated.insert(base_var_name(expr)?);
+            expr_mutations(expr, mutated)
+        }
+        FunctionCall(_, func, args) => match &**func {
+            Variable(ident) if matches!(&*ident.name, "require" | "assert") => args
+                .iter()
+                .try_for_each(|expr| expr_mutations(expr, mutated)),
+            Type(..) => args
+                .iter()
+                .try_for_each(|expr| expr_mutations(expr, mutated)),
+            _ => None,
+        },
+        FunctionCallBlock(..) | NamedFunctionCall(..) | New(..) => None,
+        MemberAccess(_, expr, _) => expr_mutations(expr, mutated),
+        ArraySubscript(_, arr, idx) => {
+            expr_mutations(arr, mutated)?;
+            idx.iter()
+                .try_for_each(|expr| expr_mutations(expr, mutated))
+        }
+        ArraySlice(_, arr, start, end) => {
+            expr_mutations(arr, mutated)?;
+            start
+                .iter()
+                .chain(end)
+                .try_for_each(|expr| expr_mutations(expr, mutated))
+        }
+        ConditionalOperator(_, cond, true_expr, false_expr) => {
+            expr_mutations(cond, mutated)?;
+            expr_mutations(true_expr, mutated)?;
+            expr_mutations(false_expr, mutated)
+        }
+        ArrayLiteral(_, exprs) => exprs
+            .iter()
+            .try_for_each(|expr| expr_mutations(expr, mutated)),
+        _ => {
+            let (lhs, rhs) = expr.components();
+            lhs.into_iter()
+                .chain(rhs)
+                .try_for_each(|expr| expr_mutations(expr, mutated))
+        }
+    }
+}
+
+/// The name of the variable an assignment target ultimately writes to
+fn base_var_name(expr: &Expression) -> Option<String> {
+    match expr {
+        Expression::Variable(ident) => Some(ident.name.clone()),
+        Expression::MemberAccess(_, expr, _)
+        | Expression::ArraySubscript(_, expr, _)
+        | Expression::Parenthesis(_, expr) => base_var_name(expr),
+        _ => None,
+    }
+}
+
+/// The values a loop counter's range may be widened to: the integer literals in the loop's
+/// condition and body, zero and the powers of two
+fn loop_thresholds(limiter: Option<&Expression>, body: &Statement) -> BTreeSet<U256> {
+    let mut thresholds = (0..256)
+        .map(|exp| U256::one() << exp)
+        .collect::<BTreeSet<_>>();
+    thresholds.insert(U256::zero());
+    thresholds.insert(U256::MAX);
+    let mut exprs = limiter.into_iter().collect::<Vec<_>>();
+    stmt_exprs(body, &mut exprs);
+    while let Some(expr) = exprs.pop() {
+        thresholds.extend(literal_value(expr));
+        exprs.extend(sub_exprs(expr));
+    }
+    thresholds
+}
+
+/// The expressions directly contained in a statement or any statement nested in it
+fn stmt_exprs<'a>(stmt: &'a Statement, exprs: &mut Vec<&'a Expression>) {
+    use Statement::*;
+    match stmt {
+        Block { statements, .. } => statements.iter().for_each(|stmt| stmt_exprs(stmt, exprs)),
+        If(_, cond, true_stmt, false_stmt) => {
+            exprs.push(cond);
+            stmt_exprs(true_stmt, exprs);
+            false_stmt.iter().for_each(|stmt| stmt_exprs(stmt, exprs));
+        }
+        While(_, cond, body) | DoWhile(_, body, cond) => {
+            exprs.push(cond);
+            stmt_exprs(body, exprs);
+        }
+        For(_, init, cond, post, body) => {
+            init.iter()
+                .chain(post)
+                .chain(body)
+                .for_each(|stmt| stmt_exprs(stmt, exprs));
+            exprs.extend(cond.as_deref());
+        }
+        Expression(_, expr) | Emit(_, expr) => exprs.push(expr),
+        VariableDefinition(_, _, init) | Return(_, init) => exprs.extend(init),
+        Revert(_, _, args) => exprs.extend(args),
+        _ => {}
+    }
+}
+
+/// The subexpressions of an expression
+fn sub_exprs(expr: &Expression) -> Vec<&Expression> {
+    use Expression::*;
+    match expr {
+        FunctionCall(_, func, args) => std::iter::once(&**func).chain(args).collect(),
+        ArraySubscript(_, arr, idx) => std::iter::once(&**arr).chain(idx.as_deref()).collect(),
+        ArraySlice(_, arr, start, end) => std::iter::once(&**arr)
+            .chain(start.as_deref())
+            .chain(end.as_deref())
+            .collect(),
+        ConditionalOperator(_, cond, true_expr, false_expr) => {
+            vec![cond, true_expr, false_expr]
+        }
+        ArrayLiteral(_, exprs) => exprs.iter().collect(),
+        _ => {
+            let (lhs, rhs) = expr.components();
+            lhs.into_iter().chain(rhs).collect()
+        }
+    }
+}
+
+/// The value of a decimal or hex integer literal without a unit
+fn literal_value(expr: &Expression) -> Option<U256> {
+    match expr {
+        Expression::NumberLiteral(_, int, exp, None) => {
+            let int = U256::from_dec_str(&int.replace('_', "")).ok()?;
+            let exp = if exp.is_empty() {
+                0
+            } else {
+                exp.replace('_', "").parse().ok()?
+            };
+            int.checked_mul(U256::from(10).checked_pow(U256::from(exp))?)
+        }
+        Expression::HexNumberLiteral(_, hex, None) => {
+            U256::from_str_radix(&hex.trim_start_matches("0x").replace('_', ""), 16).ok()
+        }
+        _ => None,
+    }
+}
+
+/// The totals a statement increments and decrements the variable `name` by, if every write to it
+/// is an increment or decrement by a constant outside of any nested loop
+fn counter_steps(stmt: &Statement, name: &str) -> Option<(U256, U256)> {
+    let mutated = mutated_vars(stmt)?;
+    if !mutated.contains(name) {
+        return None;
+    }
+    let mut steps = (U256::zero(), U256::zero());
+    stmt_steps(stmt, name, &mut steps)?;
+    Some(steps)
+}
+
+fn stmt_steps(stmt: &Statement, name: &str, steps: &mut (U256, U256)) -> Option<()> {
+    use Statement::*;
+    match stmt {
+        Block { statements, .. } => statements
+            .iter()
+            .try_for_each(|stmt| stmt_steps(stmt, name, steps)),
+        If(_, cond, true_stmt, false_stmt) => {
+            expr_steps(cond, name, steps)?;
+            stmt_steps(true_stmt, name, steps)?;
+            false_stmt
+                .iter()
+                .try_for_each(|stmt| stmt_steps(stmt, name, steps))
+        }
+        // a nested loop may step the counter any number of times
+        While(..) | DoWhile(..) | For(..) => (!mutated_vars(stmt)?.contains(name)).then_some(()),
+        _ => {
+            let mut exprs = vec![];
+            stmt_exprs(stmt, &mut exprs);
+            exprs
+                .into_iter()
+                .try_for_each(|expr| expr_steps(expr, name, steps))
+        }
+    }
+}
+
+fn expr_steps(expr: &Expression, name: &str, steps: &mut (U256, U256)) -> Option<()> {
+    use Expression::*;
+    let is_counter = |expr: &Expression| matches!(expr, Variable(ident) if ident.name == name);
+    match expr {
+        PreIncrement(_, var) | PostIncrement(_, var) if is_counter(var) => {
+            steps.0 = steps.0.checked_add(U256::one())?;
+        }
+        PreDecrement(_, var) | PostDecrement(_, var) if is_counter(var) => {
+            steps.1 = steps.1.checked_add(U256::one())?;
+        }
+        AssignAdd(_, var, step) if is_counter(var) => {
+            steps.0 = steps.0.checked_add(literal_value(step)?)?;
+        }
+        AssignSubtract(_, var, step) if is_counter(var) => {
+            steps.1 = steps.1.checked_add(literal_value(step)?)?;
+        }
+        _ => {
+            let mut mutated = BTreeSet::default();
+            if let (Some(lhs), _) = expr.components() {
+                if matches!(
+                    expr,
+                    Assign(..)
+                        | AssignOr(..)
+                        | AssignAnd(..)
+                        | AssignXor(..)
+                        | AssignShiftLeft(..)
+                        | AssignShiftRight(..)
+                        | AssignAdd(..)
+                        | AssignSubtract(..)
+                        | AssignMultiply(..)
+                        | AssignDivide(..)
+                        | AssignModulo(..)
+                        | PreIncrement(..)
+                        | PostIncrement(..)
+                        | PreDecrement(..)
+                        | PostDecrement(..)
+                        | Delete(..)
+                ) {
+                    mutated.extend(base_var_name(lhs));
+                }
+            }
+            if mutated.contains(name) {
+                return None;
+            }
+            sub_exprs(expr)
+                .into_iter()
+                .try_for_each(|expr| expr_steps(expr, name, steps))?;
+        }
+    }
+    Some(())
+}
+
+/// The variables a loop condition strictly bounds the variable `name` by whenever the loop body
+/// runs, as `(min, max)`, i.e. `n` is the max for `i < n`
+fn guard_var_bounds(cond: &Expression, name: &str) -> (Option<String>, Option<String>) {
+    use Expression::*;
+    let is_counter = |expr: &Expression| matches!(expr, Variable(ident) if ident.name == name);
+    let var = |expr: &Expression| match expr {
+        Variable(ident) if ident.name != name => Some(ident.name.clone()),
+        _ => None,
+    };
+    match cond {
+        Parenthesis(_, cond) => guard_var_bounds(cond, name),
+        And(_, lhs, rhs) => {
+            let (lhs_min, lhs_max) = guard_var_bounds(lhs, name);
+            let (rhs_min, rhs_max) = guard_var_bounds(rhs, name);
+            (lhs_min.or(rhs_min), lhs_max.or(rhs_max))
+        }
+        Less(_, lhs, rhs) if is_counter(lhs) => (None, var(rhs)),
+        More(_, lhs, rhs) if is_counter(lhs) => (var(rhs), None),
+        Less(_, lhs, rhs) if is_counter(rhs) => (var(lhs), None),
+        More(_, lhs, rhs) if is_counter(rhs) => (None, var(lhs)),
+        _ => (None, None),
+    }
+}
+
+/// The bounds a loop condition places on the variable `name` whenever the loop body runs, as
+/// `(min, max)`
+fn guard_bounds(cond: &Expression, name: &str) -> (Option<U256>, Option<U256>) {
+    use Expression::*;
+    let is_counter = |expr: &Expression| matches!(expr, Variable(ident) if ident.name == name);
+    let value = |expr: &Expression| literal_value(expr);
+    let above = |expr: &Expression| value(expr).and_then(|val| val.checked_add(U256::one()));
+    let below = |expr: &Expression| value(expr).and_then(|val| val.checked_sub(U256::one()));
+    match cond {
+        Parenthesis(_, cond) => guard_bounds(cond, name),
+        And(_, lhs, rhs) => {
+            let (lhs_min, lhs_max) = guard_bounds(lhs, name);
+            let (rhs_min, rhs_max) = guard_bounds(rhs, name);
+            let max = match (lhs_max, rhs_max) {
+                (Some(lhs_max), Some(rhs_max)) => Some(lhs_max.min(rhs_max)),
+                (lhs_max, rhs_max) => lhs_max.or(rhs_max),
+            };
+            (lhs_min.max(rhs_min), max)
+        }
+        Less(_, lhs, rhs) if is_counter(lhs) => (None, below(rhs)),
+        LessEqual(_, lhs, rhs) if is_counter(lhs) => (None, value(rhs)),
+        More(_, lhs, rhs) if is_counter(lhs) => (above(rhs), None),
+        MoreEqual(_, lhs, rhs) if is_counter(lhs) => (value(rhs), None),
+        Less(_, lhs, rhs) if is_counter(rhs) => (above(lhs), None),
+        LessEqual(_, lhs, rhs) if is_counter(rhs) => (value(lhs), None),
+        More(_, lhs, rhs) if is_counter(rhs) => (None, below(lhs)),
+        MoreEqual(_, lhs, rhs) if is_counter(rhs) => (None, value(lhs)),
+        _ => (None, None),
+    }
 }
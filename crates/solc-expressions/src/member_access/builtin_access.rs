@@ -266,8 +266,7 @@ pub trait BuiltinAccess:
                         Ok(ExprRet::Single(cvar))
                     }
                     "min" => {
-                        let min = U256::zero();
-                        let c = Concrete::from(min);
+                        let c = Concrete::Uint(size, U256::zero());
                         let node = self.add_node(Node::Concrete(c)).into();
                         let mut var = ContextVar::new_from_concrete(loc, ctx, node, self)
                             .into_expr_err(loc)?;
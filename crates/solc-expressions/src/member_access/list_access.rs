@@ -2,7 +2,10 @@ use crate::{ContextBuilder, ExpressionParser, Variable};
 
 use graph::{
     elem::*,
-    nodes::{BuiltInNode, Builtin, Concrete, ContextNode, ContextVar, ContextVarNode, ExprRet},
+    nodes::{
+        BuiltInNode, Builtin, Concrete, ConcreteNode, ContextNode, ContextVar, ContextVarNode,
+        ExprRet,
+    },
     AnalyzerBackend, ContextEdge, Edge, Node, Range, SolcRange, VarType,
 };
 use shared::{ExprErr, IntoExprErr, RangeArena};
@@ -54,14 +57,51 @@ pub trait ListAccess: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Si
                 Ok(())
             }
             ExprRet::CtxKilled(kind) => ctx.kill(self, loc, kind).into_expr_err(loc),
-            ExprRet::Single(arr) => {
-                self.get_length(arena, ctx, loc, arr.into(), false)?;
+            ExprRet::Single(arr) | ExprRet::SingleLiteral(arr) => {
+                let arr = ContextVarNode::from(arr);
+                if arr.is_string(self).into_expr_err(loc)? {
+                    return Err(ExprErr::MemberAccessNotFound(
+                        loc,
+                        "`string` has no `length` member, convert it to `bytes` first, i.e. `bytes(s).length`".to_string(),
+                    ));
+                }
+                if self.literal_length(ctx, loc, arr)? {
+                    return Ok(());
+                }
+                self.get_length(arena, ctx, loc, arr, false)?;
                 Ok(())
             }
-            e => todo!("here: {e:?}"),
+            ExprRet::Multi(inner) => inner.into_iter().try_for_each(|elem_path| {
+                self.match_length(arena, ctx, loc, elem_path, _update_len_bound)
+            }),
         }
     }
 
+    /// If `arr` is a bytes literal, pushes its length as a constant and returns true
+    fn literal_length(
+        &mut self,
+        ctx: ContextNode,
+        loc: Loc,
+        arr: ContextVarNode,
+    ) -> Result<bool, ExprErr> {
+        if !arr.is_concrete(self).into_expr_err(loc)? {
+            return Ok(false);
+        }
+        let len = match arr.as_concrete(self).into_expr_err(loc)? {
+            Concrete::Bytes(size, _) => U256::from(size),
+            Concrete::DynBytes(bytes) => U256::from(bytes.len()),
+            _ => return Ok(false),
+        };
+        let len_node = ConcreteNode::from(self.add_node(Node::Concrete(Concrete::from(len))));
+        let len_var = ContextVar::new_from_concrete(loc, ctx, len_node, self).into_expr_err(loc)?;
+        let len_var = self.add_node(Node::ContextVar(len_var));
+        ctx.add_var(len_var.into(), self).into_expr_err(loc)?;
+        self.add_edge(len_var, ctx, Edge::Context(ContextEdge::Variable));
+        ctx.push_expr(ExprRet::SingleLiteral(len_var), self)
+            .into_expr_err(loc)?;
+        Ok(true)
+    }
+
     fn get_length(
         &mut self,
         arena: &mut RangeArena<Elem<Concrete>>,
@@ -125,6 +165,7 @@ pub trait ListAccess: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Si
             dep_on: None,
             is_symbolic: true,
             is_return: false,
+            source_repr: None,
             ty: VarType::BuiltIn(
                 BuiltInNode::from(self.builtin_or_add(Builtin::Uint(256))),
                 Some(range),
@@ -198,6 +239,7 @@ pub trait ListAccess: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Si
                 dep_on: None,
                 is_symbolic: true,
                 is_return: false,
+                source_repr: None,
                 ty: VarType::BuiltIn(
                     BuiltInNode::from(self.builtin_or_add(Builtin::Uint(256))),
                     range,
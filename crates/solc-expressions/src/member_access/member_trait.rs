@@ -50,6 +50,11 @@ pub trait MemberAccess:
             return self.length(arena, loc, member_expr, ctx);
         }
 
+        if ident.name == "balance" && is_address_this(member_expr) {
+            let ret = self.this_balance_access(arena, loc, ctx)?;
+            return ctx.push_expr(ret, self).into_expr_err(loc);
+        }
+
         self.parse_ctx_expr(arena, member_expr, ctx)?;
         self.apply_to_edges(ctx, loc, arena, &|analyzer, _arena, ctx, loc| {
             let Some(ret) = ctx.pop_expr_latest(loc, analyzer).into_expr_err(loc)? else {
@@ -318,8 +323,9 @@ pub trait MemberAccess:
         tracing::trace!("Function member access: {}", name);
         match &*ident.name {
             "selector" => {
+                let signature = func_node.canonical_signature(self).into_expr_err(loc)?;
                 let mut out = [0; 32];
-                keccak_hash::keccak_256(prefix_only_name.as_bytes(), &mut out);
+                keccak_hash::keccak_256(signature.as_bytes(), &mut out);
                 let selector: [u8; 4] = [out[0], out[1], out[2], out[3]];
                 let selector_conc = Node::Concrete(Concrete::from(selector));
                 let selector_node = ConcreteNode::from(self.add_node(selector_conc));
@@ -338,3 +344,17 @@ pub trait MemberAccess:
         }
     }
 }
+
+/// Whether the expression is `address(this)` or `payable(address(this))`
+fn is_address_this(expr: &Expression) -> bool {
+    use solang_parser::pt::Type;
+    match expr {
+        Expression::FunctionCall(_, func, args) if args.len() == 1 => match &**func {
+            Expression::Type(_, Type::Address | Type::AddressPayable | Type::Payable) => {
+                matches!(&args[0], Expression::This(_)) || is_address_this(&args[0])
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
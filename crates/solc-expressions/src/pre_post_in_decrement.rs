@@ -119,7 +119,8 @@ pub trait PrePostIncDecrement:
         })
     }
 
-    /// Match on the [`ExprRet`]s of a pre-or-post in/decrement and performs it
+    /// Match on the [`ExprRet`]s of a pre-or-post in/decrement and performs it, pushing the value
+    /// of the expression (the updated value if `pre`, otherwise the original one)
     fn match_in_de_crement(
         &mut self,
         arena: &mut RangeArena<Elem<Concrete>>,
@@ -145,88 +146,36 @@ pub trait PrePostIncDecrement:
                 let elem = Elem::from(cvar);
                 let one = Elem::from(Concrete::from(U256::from(1))).cast(elem.clone());
 
-                // if let Some(r) = cvar.range(self).into_expr_err(loc)? {
-                if increment {
-                    if pre {
-                        let dup = cvar.as_tmp(loc, ctx, self).into_expr_err(loc)?;
-                        dup.set_range_min(self, arena, elem.clone() + one.clone())
-                            .into_expr_err(loc)?;
-                        dup.set_range_max(self, arena, elem.clone() + one.clone())
-                            .into_expr_err(loc)?;
-                        let new_cvar = self.advance_var_in_ctx(cvar, loc, ctx)?;
-                        new_cvar
-                            .set_range_min(self, arena, elem.clone() + one.clone())
-                            .into_expr_err(loc)?;
-                        new_cvar
-                            .set_range_max(self, arena, elem + one)
-                            .into_expr_err(loc)?;
-                        ctx.push_expr(
-                            ExprRet::Single(
-                                dup.latest_version_or_inherited_in_ctx(ctx, self).into(),
-                            ),
-                            self,
-                        )
-                        .into_expr_err(loc)?;
-                        Ok(())
-                    } else {
-                        let dup = cvar.as_tmp(loc, ctx, self).into_expr_err(loc)?;
-                        dup.set_range_min(self, arena, elem.clone())
-                            .into_expr_err(loc)?;
-                        dup.set_range_max(self, arena, elem.clone())
-                            .into_expr_err(loc)?;
-                        let new_cvar = self.advance_var_in_ctx(cvar, loc, ctx)?;
-                        let res = new_cvar
-                            .set_range_min(self, arena, elem.clone() + one.clone())
-                            .into_expr_err(loc);
-                        let _ = self.add_if_err(res);
-                        new_cvar
-                            .set_range_max(self, arena, elem + one)
-                            .into_expr_err(loc)?;
-                        ctx.push_expr(
-                            ExprRet::Single(
-                                dup.latest_version_or_inherited_in_ctx(ctx, self).into(),
-                            ),
-                            self,
-                        )
-                        .into_expr_err(loc)?;
-                        Ok(())
-                    }
-                } else if pre {
-                    let dup = cvar.as_tmp(loc, ctx, self).into_expr_err(loc)?;
-                    dup.set_range_min(self, arena, elem.clone() - one.clone())
-                        .into_expr_err(loc)?;
-                    dup.set_range_max(self, arena, elem.clone() - one.clone())
-                        .into_expr_err(loc)?;
-                    let new_cvar = self.advance_var_in_ctx(cvar, loc, ctx)?;
-                    new_cvar
-                        .set_range_min(self, arena, elem.clone() - one.clone())
-                        .into_expr_err(loc)?;
-                    new_cvar
-                        .set_range_max(self, arena, elem - one)
-                        .into_expr_err(loc)?;
-                    ctx.push_expr(
-                        ExprRet::Single(dup.latest_version_or_inherited_in_ctx(ctx, self).into()),
-                        self,
-                    )
-                    .into_expr_err(loc)?;
-                    Ok(())
+                let new_val = if increment {
+                    elem.clone() + one
                 } else {
-                    let dup = cvar.as_tmp(loc, ctx, self).into_expr_err(loc)?;
-                    dup.set_range_min(self, arena, elem.clone())
-                        .into_expr_err(loc)?;
-                    dup.set_range_max(self, arena, elem.clone())
-                        .into_expr_err(loc)?;
-                    let new_cvar = self.advance_var_in_ctx(cvar, loc, ctx)?;
-                    new_cvar
-                        .set_range_min(self, arena, elem.clone() - one.clone())
-                        .into_expr_err(loc)?;
-                    new_cvar
-                        .set_range_max(self, arena, elem - one)
-                        .into_expr_err(loc)?;
-                    ctx.push_expr(ExprRet::Single(dup.into()), self)
-                        .into_expr_err(loc)?;
-                    Ok(())
-                }
+                    elem.clone() - one
+                };
+                // a pre-in/decrement evaluates to the updated value, a post-in/decrement to the
+                // value before the update
+                let expr_val = if pre { new_val.clone() } else { elem };
+
+                let dup = cvar.as_tmp(loc, ctx, self).into_expr_err(loc)?;
+                dup.set_range_min(self, arena, expr_val.clone())
+                    .into_expr_err(loc)?;
+                dup.set_range_max(self, arena, expr_val)
+                    .into_expr_err(loc)?;
+                let name = cvar.name(self).into_expr_err(loc)?;
+                ctx.forget_linear_eqs(&name, self).into_expr_err(loc)?;
+                let new_cvar = self.advance_var_in_ctx(cvar, loc, ctx)?;
+                new_cvar.mark_written(self);
+                new_cvar
+                    .set_range_min(self, arena, new_val.clone())
+                    .into_expr_err(loc)?;
+                new_cvar
+                    .set_range_max(self, arena, new_val)
+                    .into_expr_err(loc)?;
+                ctx.push_expr(
+                    ExprRet::Single(dup.latest_version_or_inherited_in_ctx(ctx, self).into()),
+                    self,
+                )
+                .into_expr_err(loc)?;
+                Ok(())
             }
             ExprRet::Multi(inner) => inner.iter().try_for_each(|expr| {
                 self.match_in_de_crement(arena, ctx, pre, increment, loc, expr)
@@ -0,0 +1,238 @@
+use crate::Variable;
+
+use graph::{
+    elem::{Elem, RangeOp},
+    nodes::{Concrete, ContextNode, ContextVarNode, LinearEq},
+    AnalyzerBackend, GraphBackend,
+};
+use shared::{ExprErr, GraphError, IntoExprErr, RangeArena};
+
+use ethers_core::types::{I256, U256};
+use solang_parser::pt::{Expression, Loc};
+
+use std::collections::BTreeMap;
+
+impl<T> Relations for T where T: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {}
+/// Tracks linear equalities between variables (i.e. `a + b == 100`) so that narrowing one
+/// variable narrows the others. Only active if the analyzer tracks relations
+pub trait Relations: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
+    /// Records that `lhs == rhs` holds in the context, if both sides are sums and differences of
+    /// variables and constants that reduce to exactly two variables, i.e. `a + b == 100` or
+    /// `b = 100 - a`
+    fn record_linear_eq(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        ctx: ContextNode,
+        loc: Loc,
+        lhs: ContextVarNode,
+        rhs: ContextVarNode,
+    ) -> Result<(), ExprErr> {
+        if !self.track_relations() {
+            return Ok(());
+        }
+        let (Some((mut terms, lhs_const)), Some((rhs_terms, rhs_const))) = (
+            linear_form(lhs, self, arena).into_expr_err(loc)?,
+            linear_form(rhs, self, arena).into_expr_err(loc)?,
+        ) else {
+            return Ok(());
+        };
+        for (name, coeff) in rhs_terms {
+            *terms.entry(name).or_default() -= coeff;
+        }
+        terms.retain(|_, coeff| *coeff != 0);
+
+        // lhs_terms - rhs_terms + lhs_const - rhs_const == 0
+        let Some(constant) = rhs_const.checked_sub(lhs_const) else {
+            return Ok(());
+        };
+        if terms.len() != 2 || terms.values().any(|coeff| coeff.abs() != 1) {
+            return Ok(());
+        }
+        let eq = LinearEq {
+            terms: terms
+                .into_iter()
+                .map(|(name, coeff)| (name, coeff < 0))
+                .collect(),
+            constant,
+        };
+        ctx.add_linear_eq(eq, self).into_expr_err(loc)
+    }
+
+    /// Narrows each variable in a linear equality of the context by the range the equality
+    /// implies from the other variable's range
+    fn propagate_relations(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        ctx: ContextNode,
+        loc: Loc,
+    ) -> Result<(), ExprErr> {
+        if !self.track_relations() {
+            return Ok(());
+        }
+        for eq in ctx.linear_eqs(self).into_expr_err(loc)? {
+            let [target, other] = &eq.terms[..] else {
+                continue;
+            };
+            self.narrow_by_relation(arena, ctx, loc, target, other, eq.constant)?;
+            self.narrow_by_relation(arena, ctx, loc, other, target, eq.constant)?;
+        }
+        Ok(())
+    }
+
+    /// Narrows `target` given `target + other == constant` (with the terms negated per their
+    /// flags)
+    fn narrow_by_relation(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        ctx: ContextNode,
+        loc: Loc,
+        (target, target_neg): &(String, bool),
+        (other, other_neg): &(String, bool),
+        constant: I256,
+    ) -> Result<(), ExprErr> {
+        let (Some(target), Some(other)) = (
+            ctx.var_by_name_or_recurse(self, target)
+                .into_expr_err(loc)?,
+            ctx.var_by_name_or_recurse(self, other).into_expr_err(loc)?,
+        ) else {
+            return Ok(());
+        };
+        let target = target.latest_version_or_inherited_in_ctx(ctx, self);
+        let other = other.latest_version_or_inherited_in_ctx(ctx, self);
+        let ((Some(target_min), target_max), (Some(other_min), Some(other_max))) = (
+            concrete_bounds(target, self, arena).into_expr_err(loc)?,
+            concrete_bounds(other, self, arena).into_expr_err(loc)?,
+        ) else {
+            return Ok(());
+        };
+        // a `uint256` max may not fit, but anything derived below it is still a narrowing
+        let target_max = target_max.unwrap_or(I256::MAX);
+
+        // target = ±(constant - ±other)
+        let (lo, hi) = if *other_neg {
+            (other_max.checked_neg(), other_min.checked_neg())
+        } else {
+            (Some(other_min), Some(other_max))
+        };
+        let (Some(min), Some(max)) = (
+            hi.and_then(|hi| constant.checked_sub(hi)),
+            lo.and_then(|lo| constant.checked_sub(lo)),
+        ) else {
+            return Ok(());
+        };
+        let (Some(min), Some(max)) = (if *target_neg {
+            (max.checked_neg(), min.checked_neg())
+        } else {
+            (Some(min), Some(max))
+        }) else {
+            return Ok(());
+        };
+
+        let new_min = min.max(target_min);
+        let new_max = max.min(target_max);
+        if new_min > new_max || (new_min == target_min && new_max == target_max) {
+            return Ok(());
+        }
+
+        let Some(ty_min) = target.evaled_range_min(self, arena).into_expr_err(loc)? else {
+            return Ok(());
+        };
+        let (Some(new_min), Some(new_max)) = (
+            concrete_like(&ty_min, new_min),
+            concrete_like(&ty_min, new_max),
+        ) else {
+            return Ok(());
+        };
+        let new_target = self.advance_var_in_ctx(target, loc, ctx)?;
+        new_target
+            .set_range_min(self, arena, Elem::from(new_min))
+            .into_expr_err(loc)?;
+        new_target
+            .set_range_max(self, arena, Elem::from(new_max))
+            .into_expr_err(loc)?;
+        Ok(())
+    }
+}
+
+/// Variable names with their integer coefficients, plus a constant
+type LinearForm = (BTreeMap<String, i64>, I256);
+
+/// A variable as a sum of variable names with integer coefficients plus a constant, if it is
+/// built from constants and named variables by checked additions and subtractions. Unchecked
+/// arithmetic may wrap, so it isn't linear
+fn linear_form(
+    cvar: ContextVarNode,
+    analyzer: &impl GraphBackend,
+    arena: &mut RangeArena<Elem<Concrete>>,
+) -> Result<Option<LinearForm>, GraphError> {
+    if cvar.is_const(analyzer, arena)? {
+        let (Some(val), _) = concrete_bounds(cvar, analyzer, arena)? else {
+            return Ok(None);
+        };
+        return Ok(Some((BTreeMap::default(), val)));
+    }
+
+    // named variables assigned from an expression keep its `tmp_of`, so check the flag itself
+    if !cvar.underlying(analyzer)?.is_tmp {
+        return Ok(Some((
+            BTreeMap::from([(cvar.name(analyzer)?, 1)]),
+            I256::zero(),
+        )));
+    }
+
+    if let Some(tmp) = cvar.tmp_of(analyzer)? {
+        let sign = match tmp.op {
+            RangeOp::Add(false) => 1,
+            RangeOp::Sub(false) => -1,
+            _ => return Ok(None),
+        };
+        let Some(rhs) = tmp.rhs else {
+            return Ok(None);
+        };
+        let (Some((mut terms, lhs_const)), Some((rhs_terms, rhs_const))) = (
+            linear_form(tmp.lhs, analyzer, arena)?,
+            linear_form(rhs, analyzer, arena)?,
+        ) else {
+            return Ok(None);
+        };
+        for (name, coeff) in rhs_terms {
+            *terms.entry(name).or_default() += sign * coeff;
+        }
+        let constant = if sign == 1 {
+            lhs_const.checked_add(rhs_const)
+        } else {
+            lhs_const.checked_sub(rhs_const)
+        };
+        return Ok(constant.map(|constant| (terms, constant)));
+    }
+
+    Ok(None)
+}
+
+/// The evaluated minimum and maximum of an integer variable, each if it is concrete and fits in
+/// an `I256`
+fn concrete_bounds(
+    cvar: ContextVarNode,
+    analyzer: &impl GraphBackend,
+    arena: &mut RangeArena<Elem<Concrete>>,
+) -> Result<(Option<I256>, Option<I256>), GraphError> {
+    let as_int = |elem: Option<Elem<Concrete>>| match elem?.maybe_concrete()?.val {
+        Concrete::Uint(_, val) => I256::try_from(val).ok(),
+        Concrete::Int(_, val) => Some(val),
+        _ => None,
+    };
+    let min = as_int(cvar.evaled_range_min(analyzer, arena)?);
+    let max = as_int(cvar.evaled_range_max(analyzer, arena)?);
+    Ok((min, max))
+}
+
+/// A value as a concrete of the same integer type as `like`
+fn concrete_like(like: &Elem<Concrete>, val: I256) -> Option<Concrete> {
+    match like.maybe_concrete()?.val {
+        Concrete::Uint(size, _) if !val.is_negative() => {
+            Some(Concrete::Uint(size, U256::try_from(val).ok()?))
+        }
+        Concrete::Int(size, _) => Some(Concrete::Int(size, val)),
+        _ => None,
+    }
+}
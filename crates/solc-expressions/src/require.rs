@@ -1,4 +1,4 @@
-use crate::{BinOp, ContextBuilder, ExpressionParser, Variable};
+use crate::{BinOp, ContextBuilder, ExpressionParser, Relations, Variable};
 
 use graph::{
     elem::*,
@@ -89,11 +89,20 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
                             ctx.push_expr(lhs_paths, analyzer).into_expr_err(loc)?;
                             return Ok(());
                         }
+                        let lhs_paths = lhs_paths.flatten();
+                        analyzer.note_constant_cmp_paths(
+                            arena,
+                            ctx,
+                            loc,
+                            &lhs_paths,
+                            RangeOp::Eq,
+                            &rhs_paths,
+                        )?;
                         analyzer.handle_require_inner(
                             arena,
                             ctx,
                             loc,
-                            &lhs_paths.flatten(),
+                            &lhs_paths,
                             &rhs_paths,
                             RangeOp::Eq,
                             RangeOp::Eq,
@@ -132,11 +141,20 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
                             ctx.push_expr(lhs_paths, analyzer).into_expr_err(loc)?;
                             return Ok(());
                         }
+                        let lhs_paths = lhs_paths.flatten();
+                        analyzer.note_constant_cmp_paths(
+                            arena,
+                            ctx,
+                            loc,
+                            &lhs_paths,
+                            RangeOp::Neq,
+                            &rhs_paths,
+                        )?;
                         analyzer.handle_require_inner(
                             arena,
                             ctx,
                             loc,
-                            &lhs_paths.flatten(),
+                            &lhs_paths,
                             &rhs_paths,
                             RangeOp::Neq,
                             RangeOp::Neq,
@@ -176,11 +194,20 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
                             ctx.push_expr(lhs_paths, analyzer).into_expr_err(loc)?;
                             return Ok(());
                         }
+                        let lhs_paths = lhs_paths.flatten();
+                        analyzer.note_constant_cmp_paths(
+                            arena,
+                            ctx,
+                            loc,
+                            &lhs_paths,
+                            RangeOp::Lt,
+                            &rhs_paths,
+                        )?;
                         analyzer.handle_require_inner(
                             arena,
                             ctx,
                             loc,
-                            &lhs_paths.flatten(),
+                            &lhs_paths,
                             &rhs_paths,
                             RangeOp::Lt,
                             RangeOp::Gt,
@@ -220,11 +247,20 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
                             ctx.push_expr(lhs_paths, analyzer).into_expr_err(loc)?;
                             return Ok(());
                         }
+                        let lhs_paths = lhs_paths.flatten();
+                        analyzer.note_constant_cmp_paths(
+                            arena,
+                            ctx,
+                            loc,
+                            &lhs_paths,
+                            RangeOp::Gt,
+                            &rhs_paths,
+                        )?;
                         analyzer.handle_require_inner(
                             arena,
                             ctx,
                             loc,
-                            &lhs_paths.flatten(),
+                            &lhs_paths,
                             &rhs_paths,
                             RangeOp::Gt,
                             RangeOp::Lt,
@@ -264,11 +300,20 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
                             ctx.push_expr(lhs_paths, analyzer).into_expr_err(loc)?;
                             return Ok(());
                         }
+                        let lhs_paths = lhs_paths.flatten();
+                        analyzer.note_constant_cmp_paths(
+                            arena,
+                            ctx,
+                            loc,
+                            &lhs_paths,
+                            RangeOp::Gte,
+                            &rhs_paths,
+                        )?;
                         analyzer.handle_require_inner(
                             arena,
                             ctx,
                             loc,
-                            &lhs_paths.flatten(),
+                            &lhs_paths,
                             &rhs_paths,
                             RangeOp::Gte,
                             RangeOp::Lte,
@@ -308,11 +353,20 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
                             ctx.push_expr(lhs_paths, analyzer).into_expr_err(loc)?;
                             return Ok(());
                         }
+                        let lhs_paths = lhs_paths.flatten();
+                        analyzer.note_constant_cmp_paths(
+                            arena,
+                            ctx,
+                            loc,
+                            &lhs_paths,
+                            RangeOp::Lte,
+                            &rhs_paths,
+                        )?;
                         analyzer.handle_require_inner(
                             arena,
                             ctx,
                             loc,
-                            &lhs_paths.flatten(),
+                            &lhs_paths,
                             &rhs_paths,
                             RangeOp::Lte,
                             RangeOp::Gte,
@@ -531,6 +585,7 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
                             is_symbolic: lhs_cvar.is_symbolic(analyzer).into_expr_err(loc)?
                                 || rhs_cvar.is_symbolic(analyzer).into_expr_err(loc)?,
                             is_return: false,
+                            source_repr: None,
                             tmp_of: Some(TmpConstruction::new(
                                 lhs_cvar,
                                 RangeOp::Or,
@@ -752,6 +807,13 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
             op.to_string(),
             new_rhs.display_name(self).into_expr_err(loc)?
         );
+        if !self.cond_always_holds(arena, new_lhs, op, new_rhs, loc)? {
+            ctx.add_failable_cond(loc, self).into_expr_err(loc)?;
+        }
+        // record before narrowing, which may collapse the sides into constants
+        if op == RangeOp::Eq {
+            self.record_linear_eq(arena, ctx, loc, new_lhs, new_rhs)?;
+        }
         let mut any_unsat = false;
         let mut tmp_cvar = None;
 
@@ -770,6 +832,7 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
                     (true, true) => {
                         if self.const_killable(arena, op, lhs_range, rhs_range) {
                             tracing::trace!("const killable");
+                            ctx.add_impossible_cond(loc, self).into_expr_err(loc)?;
                             ctx.kill(self, loc, KilledKind::Revert).into_expr_err(loc)?;
                             return Ok(None);
                         }
@@ -782,6 +845,7 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
                             arena, ctx, loc, rhs_op, new_lhs, new_rhs, rhs_range,
                         )? {
                             tracing::trace!("half-const killable");
+                            ctx.add_impossible_cond(loc, self).into_expr_err(loc)?;
                             ctx.kill(self, loc, KilledKind::Revert).into_expr_err(loc)?;
                             return Ok(None);
                         }
@@ -791,6 +855,7 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
                             arena, ctx, loc, op, new_rhs, new_lhs, lhs_range,
                         )? {
                             tracing::trace!("half-const killable");
+                            ctx.add_impossible_cond(loc, self).into_expr_err(loc)?;
                             ctx.kill(self, loc, KilledKind::Revert).into_expr_err(loc)?;
                             return Ok(None);
                         }
@@ -800,6 +865,7 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
                             arena, ctx, loc, op, new_lhs, new_rhs, lhs_range, rhs_range,
                         )? {
                             tracing::trace!("nonconst killable");
+                            ctx.add_impossible_cond(loc, self).into_expr_err(loc)?;
                             ctx.kill(self, loc, KilledKind::Revert).into_expr_err(loc)?;
                             return Ok(None);
                         }
@@ -858,6 +924,7 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
                 is_symbolic: new_lhs.is_symbolic(self).into_expr_err(loc)?
                     || new_rhs.is_symbolic(self).into_expr_err(loc)?,
                 is_return: false,
+                source_repr: None,
                 ty: VarType::BuiltIn(
                     BuiltInNode::from(self.builtin_or_add(Builtin::Bool)),
                     // we set the minimum to `true` so that if `elem` evaluates to false,
@@ -910,6 +977,7 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
                 is_symbolic: new_lhs.is_symbolic(self).into_expr_err(loc)?
                     || new_rhs.is_symbolic(self).into_expr_err(loc)?,
                 is_return: false,
+                source_repr: None,
                 ty: VarType::BuiltIn(
                     BuiltInNode::from(self.builtin_or_add(Builtin::Bool)),
                     // we set the minimum to `true` so that if `elem` evaluates to false,
@@ -935,9 +1003,12 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
                 .into_expr_err(loc)?;
 
             if any_unsat || ctx.unreachable(self, arena).into_expr_err(loc)? {
+                ctx.add_impossible_cond(loc, self).into_expr_err(loc)?;
                 ctx.kill(self, loc, KilledKind::Revert).into_expr_err(loc)?;
                 return Ok(None);
             }
+
+            self.propagate_relations(arena, ctx, loc)?;
         }
 
         tracing::trace!(
@@ -961,6 +1032,126 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
         Ok(tmp_cvar)
     }
 
+    /// Records a comparison written in the source, along with its value if the operands' current
+    /// ranges already decide it, i.e. `x >= 0` for an unsigned `x`. Comparisons between two
+    /// constants are skipped, as they are expected to be decided
+    fn note_constant_cmp(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        ctx: ContextNode,
+        loc: Loc,
+        lhs: ContextVarNode,
+        op: RangeOp,
+        rhs: ContextVarNode,
+    ) -> Result<(), ExprErr> {
+        let Some(inverse) = op.logical_inverse() else {
+            return Ok(());
+        };
+        if lhs.is_const(self, arena).into_expr_err(loc)?
+            && rhs.is_const(self, arena).into_expr_err(loc)?
+        {
+            return Ok(());
+        }
+        // a side whose bounds evaluate out of order would decide anything
+        let mut well_formed = true;
+        for cvar in [lhs, rhs] {
+            let (Some(min), Some(max)) = (
+                cvar.evaled_range_min(self, arena).into_expr_err(loc)?,
+                cvar.evaled_range_max(self, arena).into_expr_err(loc)?,
+            ) else {
+                well_formed = false;
+                break;
+            };
+            well_formed &= min.range_ord(&max, arena) != Some(Ordering::Greater);
+        }
+        let val = if !well_formed {
+            None
+        } else if self.cond_always_holds(arena, lhs, op, rhs, loc)? {
+            Some(true)
+        } else if self.cond_always_holds(arena, lhs, inverse, rhs, loc)? {
+            Some(false)
+        } else {
+            None
+        };
+        ctx.add_constant_cmp(loc, val, self).into_expr_err(loc)
+    }
+
+    /// [`Self::note_constant_cmp`] for the parsed sides of a comparison, if each is a single
+    /// variable
+    fn note_constant_cmp_paths(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        ctx: ContextNode,
+        loc: Loc,
+        lhs_paths: &ExprRet,
+        op: RangeOp,
+        rhs_paths: &ExprRet,
+    ) -> Result<(), ExprErr> {
+        match (lhs_paths, rhs_paths) {
+            (
+                ExprRet::Single(lhs) | ExprRet::SingleLiteral(lhs),
+                ExprRet::Single(rhs) | ExprRet::SingleLiteral(rhs),
+            ) => self.note_constant_cmp(
+                arena,
+                ctx,
+                loc,
+                ContextVarNode::from(*lhs).latest_version_or_inherited_in_ctx(ctx, self),
+                op,
+                ContextVarNode::from(*rhs).latest_version_or_inherited_in_ctx(ctx, self),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether `lhs op rhs` holds for every value in the sides' current ranges, i.e. checking
+    /// it cannot fail. Conservatively false if the ranges aren't concrete
+    fn cond_always_holds(
+        &self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        lhs: ContextVarNode,
+        op: RangeOp,
+        rhs: ContextVarNode,
+        loc: Loc,
+    ) -> Result<bool, ExprErr> {
+        let (Some(lhs_min), Some(lhs_max), Some(rhs_min), Some(rhs_max)) = (
+            lhs.evaled_range_min(self, arena).into_expr_err(loc)?,
+            lhs.evaled_range_max(self, arena).into_expr_err(loc)?,
+            rhs.evaled_range_min(self, arena).into_expr_err(loc)?,
+            rhs.evaled_range_max(self, arena).into_expr_err(loc)?,
+        ) else {
+            return Ok(false);
+        };
+        let ord = |a: &Elem<Concrete>, b: &Elem<Concrete>, arena: &mut _| {
+            if a.maybe_concrete().is_none() || b.maybe_concrete().is_none() {
+                return None;
+            }
+            a.range_ord(b, arena)
+        };
+        let holds = match op {
+            RangeOp::Eq => {
+                ord(&lhs_min, &lhs_max, arena) == Some(Ordering::Equal)
+                    && ord(&rhs_min, &rhs_max, arena) == Some(Ordering::Equal)
+                    && ord(&lhs_min, &rhs_min, arena) == Some(Ordering::Equal)
+            }
+            RangeOp::Neq => {
+                ord(&lhs_max, &rhs_min, arena) == Some(Ordering::Less)
+                    || ord(&lhs_min, &rhs_max, arena) == Some(Ordering::Greater)
+            }
+            RangeOp::Lt => ord(&lhs_max, &rhs_min, arena) == Some(Ordering::Less),
+            RangeOp::Lte => matches!(
+                ord(&lhs_max, &rhs_min, arena),
+                Some(Ordering::Less | Ordering::Equal)
+            ),
+            RangeOp::Gt => ord(&lhs_min, &rhs_max, arena) == Some(Ordering::Greater),
+            RangeOp::Gte => matches!(
+                ord(&lhs_min, &rhs_max, arena),
+                Some(Ordering::Greater | Ordering::Equal)
+            ),
+            _ => false,
+        };
+        Ok(holds)
+    }
+
     /// Checks and returns whether the require statement is killable (i.e. impossible)
     fn const_killable(
         &mut self,
@@ -1549,6 +1740,7 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
 
                     if new_lhs_range.unsat(self, arena) {
                         *any_unsat = true;
+                        ctx.add_impossible_cond(loc, self).into_expr_err(loc)?;
                         ctx.kill(self, loc, KilledKind::Revert).into_expr_err(loc)?;
                         return Ok(());
                     }
@@ -1781,6 +1973,7 @@ pub trait Require: AnalyzerBackend + Variable + BinOp + Sized {
 
                         if new_lhs_range.unsat(self, arena) {
                             *any_unsat = true;
+                            ctx.add_impossible_cond(loc, self).into_expr_err(loc)?;
                             ctx.kill(self, loc, KilledKind::Revert).into_expr_err(loc)?;
                             return Ok(());
                         }
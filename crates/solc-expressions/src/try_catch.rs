@@ -0,0 +1,219 @@
+use crate::{variable::Variable, ContextBuilder, ExpressionParser, StatementParser};
+
+use graph::{
+    elem::Elem,
+    nodes::{Concrete, Context, ContextNode, ContextVar, ContextVarNode, ExprRet},
+    AnalyzerBackend, ContextEdge, Edge, Node, VarType,
+};
+use shared::{ExprErr, IntoExprErr, NodeIdx, RangeArena};
+
+use solang_parser::pt::{
+    CatchClause, CodeLocation, Expression, Loc, Parameter, ParameterList, Statement,
+    VariableDeclaration,
+};
+
+impl<T> TryCatch for T where T: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {}
+
+/// Handles `try .. catch ..` statements
+pub trait TryCatch: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized {
+    /// Handles a `try` statement. The context is forked into one branch where the call succeeds
+    /// and one branch per catch clause, where the call reverted and the catch clause's parameter
+    /// is symbolic
+    #[tracing::instrument(level = "trace", skip_all)]
+    fn try_catch_stmt(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        ctx: ContextNode,
+        try_expr: &Expression,
+        success: &Option<(ParameterList, Box<Statement>)>,
+        clauses: &[CatchClause],
+    ) -> Result<(), ExprErr> {
+        self.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, loc| {
+            let Some((first, rest)) = clauses.split_first() else {
+                return analyzer.try_success(arena, loc, ctx, try_expr, success);
+            };
+            let (try_ctx, catch_ctx) = analyzer.fork_try(loc, ctx, "try", "catch")?;
+            analyzer.try_success(arena, loc, try_ctx, try_expr, success)?;
+            analyzer.catch_clauses(arena, loc, catch_ctx, first, rest)
+        })
+    }
+
+    /// Handles the remaining catch clauses of a `try` statement, forking for each after the first
+    fn catch_clauses(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        ctx: ContextNode,
+        clause: &CatchClause,
+        rest: &[CatchClause],
+    ) -> Result<(), ExprErr> {
+        let Some((next, rest)) = rest.split_first() else {
+            return self.catch_clause(arena, ctx, clause);
+        };
+        let (clause_ctx, rest_ctx) = self.fork_try(loc, ctx, "catch", "catch")?;
+        self.catch_clause(arena, clause_ctx, clause)?;
+        self.catch_clauses(arena, loc, rest_ctx, next, rest)
+    }
+
+    /// Forks the context into two named subcontexts
+    fn fork_try(
+        &mut self,
+        loc: Loc,
+        ctx: ContextNode,
+        lhs_name: &str,
+        rhs_name: &str,
+    ) -> Result<(ContextNode, ContextNode), ExprErr> {
+        let lhs_ctx = Context::new_subctx(ctx, None, loc, Some(lhs_name), None, false, self, None)
+            .into_expr_err(loc)?;
+        let lhs_ctx = ContextNode::from(self.add_node(Node::Context(lhs_ctx)));
+        let rhs_ctx = Context::new_subctx(ctx, None, loc, Some(rhs_name), None, false, self, None)
+            .into_expr_err(loc)?;
+        let rhs_ctx = ContextNode::from(self.add_node(Node::Context(rhs_ctx)));
+        ctx.set_child_fork(lhs_ctx, rhs_ctx, self)
+            .into_expr_err(loc)?;
+        lhs_ctx
+            .set_continuation_ctx(self, ctx, "fork_try")
+            .into_expr_err(loc)?;
+        rhs_ctx
+            .set_continuation_ctx(self, ctx, "fork_catch")
+            .into_expr_err(loc)?;
+        let ctx_fork = self.add_node(Node::ContextFork);
+        self.add_edge(ctx_fork, ctx, Edge::Context(ContextEdge::ContextFork));
+        self.add_edge(
+            NodeIdx::from(lhs_ctx.0),
+            ctx_fork,
+            Edge::Context(ContextEdge::Subcontext),
+        );
+        self.add_edge(
+            NodeIdx::from(rhs_ctx.0),
+            ctx_fork,
+            Edge::Context(ContextEdge::Subcontext),
+        );
+        Ok((lhs_ctx, rhs_ctx))
+    }
+
+    /// Performs the call of a `try` statement, binds its named returns and parses the success
+    /// block
+    fn try_success(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        ctx: ContextNode,
+        try_expr: &Expression,
+        success: &Option<(ParameterList, Box<Statement>)>,
+    ) -> Result<(), ExprErr> {
+        self.parse_ctx_expr(arena, try_expr, ctx)?;
+        let Some((returns, block)) = success else {
+            return self.apply_to_edges(ctx, loc, arena, &|analyzer, _arena, ctx, loc| {
+                let _ = ctx.pop_expr_latest(loc, analyzer).into_expr_err(loc)?;
+                Ok(())
+            });
+        };
+        self.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, loc| {
+            let Some(ret) = ctx.pop_expr_latest(loc, analyzer).into_expr_err(loc)? else {
+                return Err(ExprErr::NoRhs(
+                    loc,
+                    "try call had no return value".to_string(),
+                ));
+            };
+            if ret.is_killed() {
+                return ctx.push_expr(ret, analyzer).into_expr_err(loc);
+            }
+
+            let rets = match ret {
+                ExprRet::Multi(rets) => rets,
+                ret => vec![ret],
+            };
+            for ((param_loc, param), ret) in returns.iter().zip(rets) {
+                let Some(param) = param else {
+                    continue;
+                };
+                if param.name.is_none() {
+                    continue;
+                }
+                analyzer.parse_ctx_expr(arena, &param.ty, ctx)?;
+                let Some(ty) = ctx
+                    .pop_expr_latest(*param_loc, analyzer)
+                    .into_expr_err(loc)?
+                else {
+                    return Err(ExprErr::NoLhs(
+                        *param_loc,
+                        "try return had no type".to_string(),
+                    ));
+                };
+                let var_decl = VariableDeclaration {
+                    loc: *param_loc,
+                    ty: param.ty.clone(),
+                    storage: param.storage.clone(),
+                    name: param.name.clone(),
+                };
+                analyzer.match_var_def(arena, ctx, &var_decl, *param_loc, &ty, Some(&ret))?;
+            }
+            analyzer.parse_ctx_statement(arena, block, false, Some(ctx));
+            Ok(())
+        })
+    }
+
+    /// Binds the catch clause's parameter as a symbolic variable and parses its block
+    fn catch_clause(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        ctx: ContextNode,
+        clause: &CatchClause,
+    ) -> Result<(), ExprErr> {
+        let (param, block) = match clause {
+            CatchClause::Simple(_, param, block) => (param.as_ref(), block),
+            CatchClause::Named(_, _, param, block) => (Some(param), block),
+        };
+        if let Some(param) = param {
+            self.symbolic_catch_param(arena, ctx, param)?;
+        }
+        self.parse_ctx_statement(arena, block, false, Some(ctx));
+        Ok(())
+    }
+
+    /// Adds a catch clause's named parameter to the context as a symbolic variable of its type,
+    /// i.e. the revert data of `catch (bytes memory lowLevelData)`
+    fn symbolic_catch_param(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        ctx: ContextNode,
+        param: &Parameter,
+    ) -> Result<(), ExprErr> {
+        let Some(name) = &param.name else {
+            return Ok(());
+        };
+        let loc = param.loc();
+        self.parse_ctx_expr(arena, &param.ty, ctx)?;
+        let Some(ExprRet::Single(ty)) = ctx.pop_expr_latest(loc, self).into_expr_err(loc)? else {
+            return Err(ExprErr::ParseError(
+                loc,
+                "Catch clause parameter had no type".to_string(),
+            ));
+        };
+        let Some(ty) = VarType::try_from_idx(self, ty) else {
+            return Err(ExprErr::ParseError(
+                loc,
+                "Catch clause parameter had an unknown type".to_string(),
+            ));
+        };
+        let var = ContextVar {
+            loc: Some(loc),
+            name: name.name.clone(),
+            display_name: name.name.clone(),
+            storage: param.storage.clone().map(Into::into),
+            is_tmp: false,
+            tmp_of: None,
+            dep_on: None,
+            is_symbolic: true,
+            is_return: false,
+            source_repr: None,
+            ty,
+        };
+        let var = ContextVarNode::from(self.add_node(Node::ContextVar(var)));
+        ctx.add_var(var, self).into_expr_err(loc)?;
+        self.add_edge(var, ctx, Edge::Context(ContextEdge::Variable));
+        Ok(())
+    }
+}
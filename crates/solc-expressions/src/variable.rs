@@ -36,13 +36,18 @@ pub trait Variable: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Size
 
         // solang doesnt have `super` as a keyword
         if let Some(cvar) = ctx.var_by_name(self, &ident.name) {
+            // a storage reference reads and writes the storage it currently points to
             let cvar = cvar.latest_version_or_inherited_in_ctx(ctx, self);
+            let cvar = cvar.storage_referent(self).map_or(cvar, |referent| {
+                referent.latest_version_or_inherited_in_ctx(ctx, self)
+            });
             self.apply_to_edges(
                 target_ctx,
                 ident.loc,
                 arena,
                 &|analyzer, _arena, edge_ctx, _loc| {
                     let var = analyzer.advance_var_in_ctx(cvar, ident.loc, edge_ctx)?;
+                    analyzer.note_uninit_read(ident, var, edge_ctx)?;
                     edge_ctx
                         .push_expr(ExprRet::Single(var.into()), analyzer)
                         .into_expr_err(ident.loc)
@@ -57,12 +62,16 @@ pub trait Variable: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Size
         {
             // check if we can inherit it
             let cvar = cvar.latest_version_or_inherited_in_ctx(ctx, self);
+            let cvar = cvar.storage_referent(self).map_or(cvar, |referent| {
+                referent.latest_version_or_inherited_in_ctx(ctx, self)
+            });
             self.apply_to_edges(
                 target_ctx,
                 ident.loc,
                 arena,
                 &|analyzer, _arena, edge_ctx, _loc| {
                     let var = analyzer.advance_var_in_ctx(cvar, ident.loc, edge_ctx)?;
+                    analyzer.note_uninit_read(ident, var, edge_ctx)?;
                     edge_ctx
                         .push_expr(ExprRet::Single(var.into()), analyzer)
                         .into_expr_err(ident.loc)
@@ -108,9 +117,11 @@ pub trait Variable: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Size
             let mut is_contract_var = false;
             let const_var = if let Node::Var(_v) = self.node(idx) {
                 is_contract_var = true;
-                VarNode::from(idx)
-                    .const_value(ident.loc, self)
-                    .into_expr_err(ident.loc)?
+                let var = VarNode::from(idx);
+                match var.const_value(ident.loc, self).into_expr_err(ident.loc)? {
+                    Some(con) => Some(con),
+                    None => self.immutable_value(arena, ident.loc, var, ctx)?,
+                }
             } else {
                 None
             };
@@ -159,6 +170,13 @@ pub trait Variable: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Size
                 );
             }
 
+            let new_cvar = ContextVarNode::from(new_cvarnode);
+            if let Some(strukt) = new_cvar.ty(self).into_expr_err(ident.loc)?.maybe_struct() {
+                strukt
+                    .add_fields_to_cvar(self, ident.loc, new_cvar)
+                    .into_expr_err(ident.loc)?;
+            }
+
             target_ctx
                 .push_expr(ExprRet::Single(new_cvarnode), self)
                 .into_expr_err(ident.loc)?;
@@ -189,6 +207,62 @@ pub trait Variable: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Size
         }
     }
 
+    /// The value of an immutable that is set in its contract's constructor: a variable whose
+    /// range is the union of the immutable's ranges at the end of each successful path through
+    /// the constructor. `None` if the constructor hasn't been analyzed, or when reading the
+    /// immutable from the constructor itself
+    fn immutable_value(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        loc: Loc,
+        var: VarNode,
+        ctx: ContextNode,
+    ) -> Result<Option<ContextVar>, ExprErr> {
+        if !var.is_immutable(self).into_expr_err(loc)? {
+            return Ok(None);
+        }
+        let Some(constructor) = var
+            .maybe_associated_contract(self)
+            .and_then(|contract| contract.constructor(self))
+        else {
+            return Ok(None);
+        };
+        if ctx.associated_fn(self).into_expr_err(loc)? == constructor {
+            return Ok(None);
+        }
+        let Some(body) = constructor.maybe_body_ctx(self) else {
+            return Ok(None);
+        };
+
+        let mut ends = body.successful_edges(self).into_expr_err(loc)?;
+        if ends.is_empty() && !body.is_killed(self).into_expr_err(loc)? {
+            ends.push(body);
+        }
+        let name = var.name(self).into_expr_err(loc)?;
+        let mut cvars = vec![];
+        for end in ends {
+            let Some(cvar) = end.var_by_name_or_recurse(self, &name).into_expr_err(loc)? else {
+                return Ok(None);
+            };
+            cvars.push(cvar.latest_version_or_inherited_in_ctx(end, self));
+        }
+        if cvars.is_empty() {
+            return Ok(None);
+        }
+        let Some(range) = ContextNode::union_of(&cvars, self, arena).into_expr_err(loc)? else {
+            return Ok(None);
+        };
+
+        let Some(mut new_var) = ContextVar::maybe_from_user_ty(self, loc, var.into()) else {
+            return Ok(None);
+        };
+        let VarType::BuiltIn(ty, _) = new_var.ty else {
+            return Ok(None);
+        };
+        new_var.ty = VarType::BuiltIn(ty, Some(range));
+        Ok(Some(new_var))
+    }
+
     fn disambiguate(
         &mut self,
         ctx: ContextNode,
@@ -309,6 +383,24 @@ pub trait Variable: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Size
         }
     }
 
+    /// Records a read of a local that was declared without a value and not yet assigned on this
+    /// path. Only the first such read of each local is recorded
+    fn note_uninit_read(
+        &mut self,
+        ident: &Identifier,
+        var: ContextVarNode,
+        ctx: ContextNode,
+    ) -> Result<(), ExprErr> {
+        if ctx
+            .remove_uninit_local(&ident.name, self)
+            .into_expr_err(ident.loc)?
+        {
+            ctx.add_uninit_read(ident.loc, var, self)
+                .into_expr_err(ident.loc)?;
+        }
+        Ok(())
+    }
+
     /// Match on the [`ExprRet`]s of a variable definition and construct the variable
     fn match_var_def(
         &mut self,
@@ -352,6 +444,7 @@ pub trait Variable: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Size
                     tmp_of: None,
                     dep_on: None,
                     is_return: false,
+                    source_repr: None,
                     ty,
                 };
                 let lhs = ContextVarNode::from(self.add_node(Node::ContextVar(var)));
@@ -363,7 +456,17 @@ pub trait Variable: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Size
                         .add_fields_to_cvar(self, loc, lhs)
                         .into_expr_err(loc)?;
                 }
+
                 let rhs = ContextVarNode::from(*rhs);
+                // a storage reference aliases the storage it is initialized with instead of
+                // copying it
+                if matches!(var_decl.storage, Some(StorageLocation::Storage(_)))
+                    && rhs.is_storage(self).into_expr_err(loc)?
+                {
+                    let referent = rhs.storage_referent(self).unwrap_or(rhs);
+                    self.add_edge(lhs, referent, Edge::Context(ContextEdge::StorageAssign));
+                    return Ok(false);
+                }
 
                 self.apply_to_edges(ctx, loc, arena, &|analyzer, arena, ctx, loc| {
                     let _ = analyzer.assign(arena, loc, lhs, rhs, ctx)?;
@@ -387,11 +490,18 @@ pub trait Variable: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Size
                     tmp_of: None,
                     dep_on: None,
                     is_return: false,
+                    source_repr: None,
                     ty,
                 };
                 let lhs = ContextVarNode::from(self.add_node(Node::ContextVar(var)));
                 ctx.add_var(lhs, self).into_expr_err(loc)?;
                 self.add_edge(lhs, ctx, Edge::Context(ContextEdge::Variable));
+                // value types hold their zero value until assigned. Reference types need a data
+                // location and are left alone
+                if var_decl.storage.is_none() {
+                    ctx.add_uninit_local(&name.to_string(), self)
+                        .into_expr_err(loc)?;
+                }
                 if let Some(strukt) = maybe_struct {
                     strukt
                         .add_fields_to_cvar(self, loc, lhs)
@@ -13,9 +13,13 @@ use shared::{ExprErr, IntoExprErr, RangeArena};
 
 use solang_parser::{
     helpers::CodeLocation,
-    pt::{Expression, Loc, YulExpression, YulFor, YulStatement, YulSwitch},
+    pt::{
+        Expression, Loc, YulBlock, YulExpression, YulFor, YulStatement, YulSwitch, YulSwitchOptions,
+    },
 };
 
+use std::collections::BTreeSet;
+
 impl<T> YulBuilder for T where
     T: AnalyzerBackend<Expr = Expression, ExprErr = ExprErr> + Sized + ExpressionParser
 {
@@ -141,6 +145,7 @@ pub trait YulBuilder:
                                 dep_on: None,
                                 is_symbolic: true,
                                 is_return: false,
+                                source_repr: None,
                                 ty: VarType::try_from_idx(analyzer, b_ty).unwrap(),
                             };
                             let cvar =
@@ -183,44 +188,24 @@ pub trait YulBuilder:
                 }
                 For(YulFor {
                     loc,
-                    init_block: _,
+                    init_block,
                     condition: _,
-                    post_block: _,
-                    execution_block: _,
+                    post_block,
+                    execution_block,
                 }) => {
                     let sctx =
                         Context::new_subctx(ctx, None, *loc, None, None, false, analyzer, None)
                             .into_expr_err(*loc)?;
                     let subctx = ContextNode::from(analyzer.add_node(Node::Context(sctx)));
                     ctx.set_child_call(subctx, analyzer).into_expr_err(*loc)?;
+                    // the loop isn't executed, so widen anything it may assign to its max range
+                    let (mut assigned, mut referenced) = Default::default();
+                    [init_block, post_block, execution_block]
+                        .iter()
+                        .flat_map(|block| block.statements.iter())
+                        .for_each(|stmt| yul_stmt_vars(stmt, &mut assigned, &mut referenced));
                     analyzer.apply_to_edges(subctx, *loc, arena, &|analyzer, arena, subctx, loc| {
-                        let vars = subctx.local_vars(analyzer).clone();
-                        vars.iter().for_each(|(name, var)| {
-                            // widen to max range
-                            if let Some(inheritor_var) = ctx.var_by_name(analyzer, name) {
-                                let inheritor_var = inheritor_var.latest_version(analyzer);
-                                if let Some(r) = var
-                                    .underlying(analyzer)
-                                    .unwrap()
-                                    .ty
-                                    .default_range(analyzer)
-                                    .unwrap()
-                                {
-                                    let new_inheritor_var = analyzer
-                                        .advance_var_in_ctx(inheritor_var, loc, ctx)
-                                        .unwrap();
-                                    let res = new_inheritor_var
-                                        .set_range_min(analyzer, arena, r.min)
-                                        .into_expr_err(loc);
-                                    let _ = analyzer.add_if_err(res);
-                                    let res = new_inheritor_var
-                                        .set_range_max(analyzer, arena, r.max)
-                                        .into_expr_err(loc);
-                                    let _ = analyzer.add_if_err(res);
-                                }
-                            }
-                        });
-                        Ok(())
+                        analyzer.widen_yul_vars(arena, subctx, loc, &assigned)
                     })
                 }
                 Switch(YulSwitch {
@@ -435,6 +420,38 @@ pub trait YulBuilder:
         Ok(())
     }
 
+    /// Widens the variables with the given names that are visible in the context to the max
+    /// range of their type, for when yul that writes them could not be followed precisely
+    fn widen_yul_vars(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        ctx: ContextNode,
+        loc: Loc,
+        names: &BTreeSet<String>,
+    ) -> Result<(), ExprErr> {
+        names.iter().try_for_each(|name| {
+            let Some(var) = ctx.var_by_name_or_recurse(self, name).into_expr_err(loc)? else {
+                return Ok(());
+            };
+            let var = var.latest_version_or_inherited_in_ctx(ctx, self);
+            let Some(r) = var
+                .ty(self)
+                .into_expr_err(loc)?
+                .default_range(self)
+                .into_expr_err(loc)?
+            else {
+                return Ok(());
+            };
+            let new_var = self.advance_var_in_ctx(var, loc, ctx)?;
+            new_var.mark_written(self);
+            let res = new_var.set_range_min(self, arena, r.min).into_expr_err(loc);
+            let _ = self.add_if_err(res);
+            let res = new_var.set_range_max(self, arena, r.max).into_expr_err(loc);
+            let _ = self.add_if_err(res);
+            Ok(())
+        })
+    }
+
     fn slot(&mut self, ctx: ContextNode, loc: Loc, lhs: ContextVarNode) -> ContextVarNode {
         let lhs = lhs.first_version(self);
         let name = format!("{}.slot", lhs.name(self).unwrap());
@@ -452,6 +469,7 @@ pub trait YulBuilder:
                 dep_on: None,
                 is_symbolic: true,
                 is_return: false,
+                source_repr: None,
                 ty: VarType::BuiltIn(
                     BuiltInNode::from(self.builtin_or_add(Builtin::Uint(256))),
                     SolcRange::try_from_builtin(&Builtin::Uint(256)),
@@ -466,3 +484,97 @@ pub trait YulBuilder:
         }
     }
 }
+
+/// Collects the names of the variables a yul statement assigns into `assigned` and those it
+/// reads into `referenced`. Member accesses (i.e. `x.slot`) count as their base variable
+pub fn yul_stmt_vars(
+    stmt: &YulStatement,
+    assigned: &mut BTreeSet<String>,
+    referenced: &mut BTreeSet<String>,
+) {
+    use YulStatement::*;
+    let block_vars =
+        |block: &YulBlock, assigned: &mut BTreeSet<String>, referenced: &mut BTreeSet<String>| {
+            block
+                .statements
+                .iter()
+                .for_each(|stmt| yul_stmt_vars(stmt, assigned, referenced))
+        };
+    match stmt {
+        Assign(_, lhs, rhs) => {
+            lhs.iter().for_each(|expr| {
+                if let Some(name) = yul_base_var(expr) {
+                    assigned.insert(name);
+                }
+            });
+            yul_expr_vars(rhs, referenced);
+        }
+        VariableDeclaration(_, _, rhs) => {
+            if let Some(rhs) = rhs {
+                yul_expr_vars(rhs, referenced);
+            }
+        }
+        If(_, cond, block) => {
+            yul_expr_vars(cond, referenced);
+            block_vars(block, assigned, referenced);
+        }
+        For(YulFor {
+            init_block,
+            condition,
+            post_block,
+            execution_block,
+            ..
+        }) => {
+            yul_expr_vars(condition, referenced);
+            block_vars(init_block, assigned, referenced);
+            block_vars(post_block, assigned, referenced);
+            block_vars(execution_block, assigned, referenced);
+        }
+        Switch(YulSwitch {
+            condition,
+            cases,
+            default,
+            ..
+        }) => {
+            yul_expr_vars(condition, referenced);
+            cases.iter().chain(default).for_each(|case| match case {
+                YulSwitchOptions::Case(_, expr, block) => {
+                    yul_expr_vars(expr, referenced);
+                    block_vars(block, assigned, referenced);
+                }
+                YulSwitchOptions::Default(_, block) => block_vars(block, assigned, referenced),
+            });
+        }
+        Block(block) => block_vars(block, assigned, referenced),
+        FunctionDefinition(func_def) => block_vars(&func_def.body, assigned, referenced),
+        FunctionCall(func_call) => func_call
+            .arguments
+            .iter()
+            .for_each(|arg| yul_expr_vars(arg, referenced)),
+        Leave(_) | Break(_) | Continue(_) | Error(_) => {}
+    }
+}
+
+/// Collects the names of the variables a yul expression reads into `referenced`
+fn yul_expr_vars(expr: &YulExpression, referenced: &mut BTreeSet<String>) {
+    match expr {
+        YulExpression::FunctionCall(func_call) => func_call
+            .arguments
+            .iter()
+            .for_each(|arg| yul_expr_vars(arg, referenced)),
+        expr => {
+            if let Some(name) = yul_base_var(expr) {
+                referenced.insert(name);
+            }
+        }
+    }
+}
+
+/// The name of the variable a yul expression is or accesses a member of
+fn yul_base_var(expr: &YulExpression) -> Option<String> {
+    match expr {
+        YulExpression::Variable(ident) => Some(ident.name.clone()),
+        YulExpression::SuffixAccess(_, base, _) => yul_base_var(base),
+        _ => None,
+    }
+}
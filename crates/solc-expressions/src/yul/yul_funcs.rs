@@ -130,7 +130,7 @@ pub trait YulFuncCaller:
                     "mul" => RangeOp::Mul(true),
                     "div" | "sdiv" => RangeOp::Div(true),
                     "mod" | "smod" => RangeOp::Mod,
-                    "exp" => RangeOp::Exp,
+                    "exp" => RangeOp::Exp(true),
                     "and" => RangeOp::BitAnd,
                     "or" => RangeOp::BitOr,
                     "xor" => RangeOp::BitXor,
@@ -334,7 +334,7 @@ pub trait YulFuncCaller:
             }
             "calldatacopy" => {
                 // TODO: actually handle this. @MemoryModel
-                Ok(())
+                self.widen_memory_vars(arena, ctx, *loc)
             }
             "calldatasize" => {
                 // TODO: actually handle this. @MemoryModel
@@ -404,6 +404,8 @@ pub trait YulFuncCaller:
                 range.min = Elem::from(Concrete::from(U256::from(0)));
                 range.max = Elem::from(Concrete::from(U256::from(1)));
                 var.ty.set_range(range).into_expr_err(*loc)?;
+                // the call's return data is copied into memory
+                self.widen_memory_vars(arena, ctx, *loc)?;
                 let node = self.add_node(Node::ContextVar(var));
                 ctx.push_expr(ExprRet::Single(node), self)
                     .into_expr_err(*loc)?;
@@ -421,6 +423,7 @@ pub trait YulFuncCaller:
                 Ok(())
             }
             "returndatacopy" => {
+                self.widen_memory_vars(arena, ctx, *loc)?;
                 ctx.push_expr(ExprRet::Multi(vec![]), self)
                     .into_expr_err(*loc)?;
                 Ok(())
@@ -435,32 +438,7 @@ pub trait YulFuncCaller:
                 Ok(())
             }
             "mstore" | "mstore8" => {
-                // TODO: improve this. Right now we are extremely pessimistic and just say we know nothing about memory variables anymore.
-                // We should check if the location is a reference to an existing var and update based on that
-                // @MemoryModel
-                let vars = ctx.local_vars(self).clone();
-                vars.into_iter().try_for_each(|(_name, var)| {
-                    // widen to any  max range
-                    let latest_var = var.latest_version_or_inherited_in_ctx(ctx, self);
-                    if matches!(
-                        latest_var.underlying(self).into_expr_err(*loc)?.storage,
-                        Some(StorageLocation::Memory(_))
-                    ) {
-                        let res = latest_var.ty(self).into_expr_err(*loc)?;
-                        if let Some(r) = res.default_range(self).unwrap() {
-                            let new_var = self.advance_var_in_ctx(latest_var, *loc, ctx).unwrap();
-                            let res = new_var
-                                .set_range_min(self, arena, r.min)
-                                .into_expr_err(*loc);
-                            let _ = self.add_if_err(res);
-                            let res = new_var
-                                .set_range_max(self, arena, r.max)
-                                .into_expr_err(*loc);
-                            let _ = self.add_if_err(res);
-                        }
-                    }
-                    Ok(())
-                })?;
+                self.widen_memory_vars(arena, ctx, *loc)?;
                 ctx.push_expr(ExprRet::Multi(vec![]), self)
                     .into_expr_err(*loc)?;
                 Ok(())
@@ -568,14 +546,8 @@ pub trait YulFuncCaller:
                 })
             }
             "selfbalance" => {
-                let b = Builtin::Uint(256);
-                let mut var =
-                    ContextVar::new_from_builtin(*loc, self.builtin_or_add(b).into(), self)
-                        .into_expr_err(*loc)?;
-                var.display_name = "selfbalance()".to_string();
-                let node = self.add_node(Node::ContextVar(var));
-                ctx.push_expr(ExprRet::Single(node), self)
-                    .into_expr_err(*loc)
+                let ret = self.this_balance_access(arena, *loc, ctx)?;
+                ctx.push_expr(ret, self).into_expr_err(*loc)
             }
             "address" => {
                 let b = Builtin::Address;
@@ -638,7 +610,7 @@ pub trait YulFuncCaller:
                 }
 
                 self.parse_inputs(arena, ctx, *loc, arguments)?;
-                self.apply_to_edges(ctx, *loc, arena, &|analyzer, _arena, ctx, loc| {
+                self.apply_to_edges(ctx, *loc, arena, &|analyzer, arena, ctx, loc| {
                     let Some(_lhs_paths) = ctx.pop_expr_latest(loc, analyzer).into_expr_err(loc)?
                     else {
                         return Err(ExprErr::NoRhs(
@@ -646,6 +618,7 @@ pub trait YulFuncCaller:
                             "Yul `codecopy` operation had no input".to_string(),
                         ));
                     };
+                    analyzer.widen_memory_vars(arena, ctx, loc)?;
                     ctx.push_expr(ExprRet::Multi(vec![]), analyzer)
                         .into_expr_err(loc)
                 })
@@ -663,7 +636,7 @@ pub trait YulFuncCaller:
                 }
 
                 self.parse_inputs(arena, ctx, *loc, arguments)?;
-                self.apply_to_edges(ctx, *loc, arena, &|analyzer, _arena, ctx, loc| {
+                self.apply_to_edges(ctx, *loc, arena, &|analyzer, arena, ctx, loc| {
                     let Some(_lhs_paths) = ctx.pop_expr_latest(loc, analyzer).into_expr_err(loc)?
                     else {
                         return Err(ExprErr::NoRhs(
@@ -671,6 +644,7 @@ pub trait YulFuncCaller:
                             "Yul `extcodecopy` operation had no input".to_string(),
                         ));
                     };
+                    analyzer.widen_memory_vars(arena, ctx, loc)?;
                     ctx.push_expr(ExprRet::Multi(vec![]), analyzer)
                         .into_expr_err(loc)
                 })
@@ -710,6 +684,38 @@ pub trait YulFuncCaller:
         }
     }
 
+    /// Widens every memory variable in the context to the max range of its type, for yul that
+    /// writes to memory at a location we don't track
+    fn widen_memory_vars(
+        &mut self,
+        arena: &mut RangeArena<Elem<Concrete>>,
+        ctx: ContextNode,
+        loc: Loc,
+    ) -> Result<(), ExprErr> {
+        // TODO: improve this. Right now we are extremely pessimistic and just say we know nothing about memory variables anymore.
+        // We should check if the location is a reference to an existing var and update based on that
+        // @MemoryModel
+        let vars = ctx.local_vars(self).clone();
+        vars.into_iter().try_for_each(|(_name, var)| {
+            // widen to any  max range
+            let latest_var = var.latest_version_or_inherited_in_ctx(ctx, self);
+            if matches!(
+                latest_var.underlying(self).into_expr_err(loc)?.storage,
+                Some(StorageLocation::Memory(_))
+            ) {
+                let res = latest_var.ty(self).into_expr_err(loc)?;
+                if let Some(r) = res.default_range(self).unwrap() {
+                    let new_var = self.advance_var_in_ctx(latest_var, loc, ctx).unwrap();
+                    let res = new_var.set_range_min(self, arena, r.min).into_expr_err(loc);
+                    let _ = self.add_if_err(res);
+                    let res = new_var.set_range_max(self, arena, r.max).into_expr_err(loc);
+                    let _ = self.add_if_err(res);
+                }
+            }
+            Ok(())
+        })
+    }
+
     fn return_yul(&mut self, ctx: ContextNode, loc: Loc, size: ExprRet) -> Result<(), ExprErr> {
         match size {
             ExprRet::CtxKilled(kind) => ctx.kill(self, loc, kind).into_expr_err(loc),